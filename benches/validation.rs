@@ -0,0 +1,111 @@
+//! 编译和验证的基准测试
+//!
+//! 运行：`cargo bench --bench validation`。按字段数量分别测量编译、验证合法数据和验证不合法数据的耗时，
+//! 并输出 `LinkValidator::stats()`，用于判断大规则集的瓶颈在规则转换、JSON Schema 编译还是验证。
+//! `validate errors` 测量每个字段都不合法时的错误收集耗时。
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use link_validator::LinkValidator;
+use serde_json::{json, Map, Value};
+use std::hint::black_box;
+use std::time::Duration;
+
+/// 规则集的字段数量
+const FIELD_COUNTS: [usize; 3] = [10, 100, 1000];
+
+/// 生成包含 `fields` 个字段、每个字段带嵌套对象的 async-validator 规则
+fn rules(fields: usize) -> Value {
+    let mut rules = Map::new();
+    for i in 0..fields {
+        rules.insert(
+            format!("field{}", i),
+            json!({
+                "type": "object",
+                "required": i % 2 == 0,
+                "fields": {
+                    "name": {"type": "string", "required": true, "min": 2, "max": 50, "pattern": "^[a-z]+$"},
+                    "email": {"type": "email"},
+                    "age": {"type": "integer", "min": 0, "max": 150},
+                    "tags": {"type": "array", "max": 10, "fields": {"label": {"type": "string"}}}
+                }
+            }),
+        );
+    }
+    Value::Object(rules)
+}
+
+/// 与 `rules(fields)` 对应的合法数据
+fn data(fields: usize) -> Value {
+    let mut data = Map::new();
+    for i in 0..fields {
+        data.insert(
+            format!("field{}", i),
+            json!({"name": "alice", "email": "alice@example.com", "age": 30, "tags": [{"label": "a"}]}),
+        );
+    }
+    Value::Object(data)
+}
+
+fn print_stats(fields: usize, validator: &LinkValidator) {
+    let stats = validator.stats();
+    println!(
+        "stats ({} fields): conversion {:?}, compile {:?}, {} schema nodes, last validation {:?}",
+        fields,
+        stats.conversion_duration,
+        stats.compile_duration,
+        stats.schema_nodes,
+        stats.last_validation.unwrap_or(Duration::ZERO)
+    );
+}
+
+fn compile(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compile rules");
+    group.sample_size(20);
+    for fields in FIELD_COUNTS {
+        let rules = rules(fields);
+        group.bench_with_input(BenchmarkId::from_parameter(fields), &rules, |b, rules| {
+            b.iter(|| LinkValidator::new(black_box(rules)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn validate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate");
+    for fields in FIELD_COUNTS {
+        let validator = LinkValidator::new(&rules(fields)).unwrap();
+        let valid = data(fields);
+        let invalid = json!({"field0": {"name": "A", "age": -1}});
+
+        group.bench_with_input(BenchmarkId::new("valid", fields), &valid, |b, data| {
+            b.iter(|| validator.validate(black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new("invalid", fields), &invalid, |b, data| {
+            b.iter(|| validator.validate(black_box(data)))
+        });
+        print_stats(fields, &validator);
+    }
+    group.finish();
+}
+
+/// 每个字段都不合法的数据：错误收集和字段路径构建占主要耗时
+fn validate_errors(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate errors");
+    for fields in [2, 100] {
+        let mut rules = Map::new();
+        let mut data = Map::new();
+        for i in 0..fields {
+            rules.insert(format!("field{}", i), json!({"type": "string", "required": true, "min": 3}));
+            data.insert(format!("field{}", i), json!("a"));
+        }
+        let validator = LinkValidator::new(&Value::Object(rules)).unwrap();
+        let data = Value::Object(data);
+        group.bench_with_input(BenchmarkId::from_parameter(fields), &data, |b, data| {
+            b.iter(|| validator.validate(black_box(data)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, compile, validate, validate_errors);
+criterion_main!(benches);
@@ -0,0 +1,861 @@
+//! 字段规则到 JSON Schema 的转换
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use serde_json::{json, Map, Value};
+
+use crate::rules::{parse_async_rules, AsyncValidatorRule, AsyncValidatorRules};
+use crate::transform::{Case, RuleTransform, TransformPlan};
+use crate::{dates, form, keywords, Conversion, ConversionWarning, PatternCheck, WarningKind};
+
+/// 规则中无法识别的键的转换警告
+fn unknown_rule_warning(field_name: &str, key: &str) -> ConversionWarning {
+    ConversionWarning::field(field_name, key, format_args!("unsupported rule '{}'", key)).with_kind(WarningKind::UnknownRule)
+}
+
+/// 将 async-validator 规则转换为 JSON Schema
+pub(crate) fn convert_to_jsonschema(rules: &AsyncValidatorRules, patterns: PatternCheck<'_>) -> Result<Conversion, String> {
+    let mut schema_object = Map::new();
+    schema_object.insert("type".to_string(), Value::String("object".to_string()));
+    
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let mut unsupported = Vec::new();
+    let mut transforms = TransformPlan::default();
+    
+    for (field_name, field_rules) in rules {
+        let mut field_schema = Map::new();
+        let mut field_required = false;
+        let mut field_transforms = TransformPlan::default();
+        
+        // 第一遍：确定字段类型，min/max/len 等约束按字段最终的类型生成，与规则的先后顺序无关
+        let field_type = field_rules.iter().rev().find_map(|r| r.field_type.as_deref());
+        let json_type = json_type_of(field_rules);
+        let date_format = field_rules.iter().rev().find_map(|r| r.format.as_deref());
+        
+        // 第二遍：按规则顺序生成关键字
+        for rule in field_rules {
+            // 处理 type 规则
+            if let Some(ref type_name) = rule.field_type {
+                match type_name.as_str() {
+                    "string" => {
+                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                    }
+                    "number" => {
+                        field_schema.insert("type".to_string(), Value::String("number".to_string()));
+                    }
+                    "integer" => {
+                        field_schema.insert("type".to_string(), Value::String("integer".to_string()));
+                    }
+                    "boolean" => {
+                        field_schema.insert("type".to_string(), Value::String("boolean".to_string()));
+                    }
+                    "array" => {
+                        field_schema.insert("type".to_string(), Value::String("array".to_string()));
+                        // 处理嵌套数组项规则
+                        if let Some(ref nested_fields) = rule.fields {
+                            let nested_rules = parse_async_rules(nested_fields)?;
+                            let nested_conversion = convert_to_jsonschema(&nested_rules, patterns)?;
+                            field_schema.insert("items".to_string(), nested_conversion.schema);
+                            let parent = format!("{}[]", field_name);
+                            unsupported.extend(nested_conversion.warnings.into_iter().map(|w| w.nested_in(&parent)));
+                            if !nested_conversion.transforms.is_empty() {
+                                field_transforms.items = Some(Box::new(nested_conversion.transforms));
+                            }
+                        }
+                        // 处理按判别字段选择的数组项规则
+                        if let Some(ref items_by) = rule.items_by {
+                            let items = field_schema
+                                .entry("items".to_string())
+                                .or_insert_with(|| json!({"type": "object"}));
+                            unsupported.extend(convert_items_by(field_name, items_by, items, patterns)?);
+                        }
+                    }
+                    "object" => {
+                        field_schema.insert("type".to_string(), Value::String("object".to_string()));
+                        // 处理嵌套对象的 fields
+                        if let Some(ref nested_fields) = rule.fields {
+                            let nested_rules = parse_async_rules(nested_fields)?;
+                            let nested_conversion = convert_to_jsonschema(&nested_rules, patterns)?;
+                            field_schema.insert("properties".to_string(), nested_conversion.schema["properties"].clone());
+                            if nested_conversion.schema.get("required").is_some() {
+                                field_schema.insert("required".to_string(), nested_conversion.schema["required"].clone());
+                            }
+                            unsupported.extend(nested_conversion.warnings.into_iter().map(|w| w.nested_in(field_name)));
+                            field_transforms.properties = nested_conversion.transforms.properties;
+                        }
+                    }
+                    "method" => {
+                        field_schema.insert("type".to_string(), Value::String("object".to_string()));
+                        field_schema.insert("instanceof".to_string(), Value::String("Function".to_string()));
+                    }
+                    "regexp" => {
+                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                        // 注意：JSON Schema 没有内置的正则表达式类型验证
+                    }
+                    "date" => {
+                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                        // format: "date" 表示只有日期，默认为日期时间
+                        let format = match date_format {
+                            Some("date") => "date",
+                            None | Some("date-time") => "date-time",
+                            Some(other) => {
+                                unsupported.push(ConversionWarning::field(field_name, "format", format_args!("unsupported date format '{}'", other)));
+                                "date-time"
+                            }
+                        };
+                        field_schema.insert("format".to_string(), Value::String(format.to_string()));
+                    }
+                    "email" => {
+                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                        field_schema.insert("format".to_string(), Value::String("email".to_string()));
+                    }
+                    "url" => {
+                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                        field_schema.insert("format".to_string(), Value::String("uri".to_string()));
+                    }
+                    "hex" => {
+                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                        // 可以添加 pattern 来验证十六进制格式
+                        field_schema.insert("pattern".to_string(), Value::String("^[0-9a-fA-F]+$".to_string()));
+                    }
+                    "enum" => {
+                        // 枚举类型只由 enum 列表约束，不生成 type 关键字，避免同时报告类型错误和枚举错误
+                        if rule.enum_values.is_none() {
+                            unsupported.push(ConversionWarning::field(field_name, "type", "type 'enum' requires an 'enum' list"));
+                        }
+                    }
+                    "any" => {
+                        // JSON Schema 中没有 "any" 类型，使用 "type" 数组或者不指定类型
+                        // 这里我们选择不指定类型（即允许任何类型）
+                    }
+                    _ => {
+                        unsupported.push(ConversionWarning::field(field_name, "type", format_args!("unsupported type '{}'", type_name)));
+                    }
+                }
+            }
+            
+            if rule.format.is_some() && field_type != Some("date") {
+                unsupported.push(ConversionWarning::field(field_name, "format", "format rule only supported for date type"));
+            }
+            
+            // 处理日期范围规则，在 schema 验证之后检查
+            for (bound, keyword, name) in [(&rule.min_date, dates::MINIMUM, "minDate"), (&rule.max_date, dates::MAXIMUM, "maxDate")] {
+                let Some(bound) = bound else {
+                    continue;
+                };
+                if field_type != Some("date") {
+                    unsupported.push(ConversionWarning::field(field_name, name, format_args!("{} rule only supported for date type", name)));
+                    continue;
+                }
+                if dates::parse(bound).is_none() {
+                    return Err(format!("Field '{}': {} must be a date (YYYY-MM-DD) or RFC 3339 date-time", field_name, name));
+                }
+                field_schema.insert(keyword.to_string(), Value::String(bound.clone()));
+            }
+            
+            // 处理可辨识联合，未指定类型时为对象
+            if rule.discriminator.is_some() || rule.variants.is_some() {
+                match rule.field_type.as_deref() {
+                    None | Some("object") => {
+                        field_schema.insert("type".to_string(), Value::String("object".to_string()));
+                        let (discriminator, variants) = (rule.discriminator.as_deref(), rule.variants.as_ref());
+                        unsupported.extend(convert_union(field_name, discriminator, variants, &mut field_schema, patterns)?);
+                    }
+                    Some(_) => {
+                        unsupported.push(ConversionWarning::field(
+                            field_name,
+                            "discriminator",
+                            "discriminator/variants rules only supported for object type",
+                        ));
+                    }
+                }
+            }
+            
+            if rule.items_by.is_some() && rule.field_type.as_deref() != Some("array") {
+                unsupported.push(ConversionWarning::field(field_name, "itemsBy", "itemsBy rule only supported for array type"));
+            }
+            for (present, name) in [(rule.unique.is_some(), "unique"), (rule.contains.is_some(), "contains")] {
+                if present && field_type != Some("array") {
+                    unsupported.push(ConversionWarning::field(field_name, name, format_args!("{} rule only supported for array type", name)));
+                }
+            }
+            for (present, name) in [(rule.key_pattern.is_some(), "keyPattern"), (rule.value_rule.is_some(), "valueRule")] {
+                if present && field_type != Some("object") {
+                    unsupported.push(ConversionWarning::field(field_name, name, format_args!("{} rule only supported for object type", name)));
+                }
+            }
+            if field_type == Some("array") {
+                if rule.unique == Some(true) {
+                    field_schema.insert("uniqueItems".to_string(), Value::Bool(true));
+                }
+                // 处理 contains 规则（单条规则，转换方式与字段规则相同）
+                if let Some(ref contains) = rule.contains {
+                    let (schema, warnings) = convert_single_rule(field_name, contains, "contains", patterns)?;
+                    field_schema.insert("contains".to_string(), schema);
+                    unsupported.extend(warnings);
+                }
+            }
+            if field_type == Some("object") {
+                // 处理键名未知的映射对象
+                if let Some(ref key_pattern) = rule.key_pattern {
+                    patterns(key_pattern).map_err(|e| format!("Field '{}': keyPattern: {}", field_name, e))?;
+                    field_schema.insert("propertyNames".to_string(), json!({"pattern": key_pattern}));
+                }
+                if let Some(ref value_rule) = rule.value_rule {
+                    let (schema, warnings) = convert_single_rule(field_name, value_rule, "valueRule", patterns)?;
+                    field_schema.insert("additionalProperties".to_string(), schema);
+                    unsupported.extend(warnings);
+                }
+            }
+            
+            // 处理 required 规则
+            if let Some(true) = rule.required {
+                field_required = true;
+            }
+            
+            // 处理 min 规则
+            if let Some(ref min_value) = rule.min {
+                match json_type {
+                    Some("string") => {
+                        field_schema.insert("minLength".to_string(), min_value.clone());
+                    }
+                    Some("array") => {
+                        field_schema.insert("minItems".to_string(), min_value.clone());
+                    }
+                    Some("object") => {
+                        field_schema.insert("minProperties".to_string(), min_value.clone());
+                    }
+                    Some("number") | Some("integer") => {
+                        field_schema.insert("minimum".to_string(), min_value.clone());
+                    }
+                    _ => {
+                        // 默认当作数值处理
+                        field_schema.insert("minimum".to_string(), min_value.clone());
+                    }
+                }
+            }
+            
+            // 处理 max 规则
+            if let Some(ref max_value) = rule.max {
+                match json_type {
+                    Some("string") => {
+                        field_schema.insert("maxLength".to_string(), max_value.clone());
+                    }
+                    Some("array") => {
+                        field_schema.insert("maxItems".to_string(), max_value.clone());
+                    }
+                    Some("object") => {
+                        field_schema.insert("maxProperties".to_string(), max_value.clone());
+                    }
+                    Some("number") | Some("integer") => {
+                        field_schema.insert("maximum".to_string(), max_value.clone());
+                    }
+                    _ => {
+                        // 默认当作数值处理
+                        field_schema.insert("maximum".to_string(), max_value.clone());
+                    }
+                }
+            }
+            
+            // 处理数值精度和范围规则
+            let numeric = matches!(json_type, Some("number") | Some("integer"));
+            for (value, keyword, name) in [
+                (&rule.multiple_of, "multipleOf", "multipleOf"),
+                (&rule.exclusive_min, "exclusiveMinimum", "exclusiveMin"),
+                (&rule.exclusive_max, "exclusiveMaximum", "exclusiveMax"),
+            ] {
+                let Some(value) = value else {
+                    continue;
+                };
+                if !numeric {
+                    unsupported.push(ConversionWarning::field(
+                        field_name,
+                        name,
+                        format_args!("{} rule only supported for number and integer types", name),
+                    ));
+                    continue;
+                }
+                match value.as_f64() {
+                    Some(n) if keyword != "multipleOf" || n > 0.0 => {
+                        field_schema.insert(keyword.to_string(), value.clone());
+                    }
+                    Some(_) => return Err(format!("Field '{}': multipleOf must be greater than 0", field_name)),
+                    None => return Err(format!("Field '{}': {} must be a number", field_name, name)),
+                }
+            }
+            for (preset, keyword, lower, name) in [
+                (rule.positive, "exclusiveMinimum", true, "positive"),
+                (rule.non_negative, "minimum", true, "nonNegative"),
+                (rule.negative, "exclusiveMaximum", false, "negative"),
+                (rule.non_positive, "maximum", false, "nonPositive"),
+            ] {
+                if preset != Some(true) {
+                    continue;
+                }
+                if !numeric {
+                    unsupported.push(ConversionWarning::field(
+                        field_name,
+                        name,
+                        format_args!("{} rule only supported for number and integer types", name),
+                    ));
+                    continue;
+                }
+                // 已有更严格的边界时保留原边界
+                let existing = field_schema.get(keyword).and_then(Value::as_f64);
+                if existing.is_none_or(|n| if lower { n < 0.0 } else { n > 0.0 }) {
+                    field_schema.insert(keyword.to_string(), json!(0));
+                }
+            }
+            
+            // 处理 len 规则
+            if let Some(ref len_value) = rule.len {
+                match json_type {
+                    Some("string") => {
+                        field_schema.insert("minLength".to_string(), len_value.clone());
+                        field_schema.insert("maxLength".to_string(), len_value.clone());
+                    }
+                    Some("array") => {
+                        field_schema.insert("minItems".to_string(), len_value.clone());
+                        field_schema.insert("maxItems".to_string(), len_value.clone());
+                    }
+                    _ => {
+                        unsupported.push(ConversionWarning::field(field_name, "len", "len rule only supported for string and array types"));
+                    }
+                }
+            }
+            
+            // 处理 pattern 规则
+            if let Some(ref pattern) = rule.pattern {
+                patterns(pattern).map_err(|e| format!("Field '{}': {}", field_name, e))?;
+                field_schema.insert("pattern".to_string(), Value::String(pattern.clone()));
+            }
+            
+            // 处理 enum 规则：带标签的选项只保留值，标签写入 x-options 注解
+            if let Some(ref enum_values) = rule.enum_values {
+                let values = enum_values.iter().map(|entry| option_value(entry).unwrap_or(entry).clone()).collect();
+                field_schema.insert("enum".to_string(), Value::Array(values));
+                if enum_values.iter().any(|entry| option_value(entry).is_some()) {
+                    let options = enum_values
+                        .iter()
+                        .map(|entry| match option_value(entry) {
+                            Some(_) => entry.clone(),
+                            None => json!({"value": entry}),
+                        })
+                        .collect();
+                    field_schema.insert(form::OPTIONS.to_string(), Value::Array(options));
+                } else {
+                    field_schema.remove(form::OPTIONS);
+                }
+            }
+            
+            // 处理 const 规则
+            if let Some(ref value) = rule.const_value {
+                field_schema.insert("const".to_string(), value.clone());
+            }
+            
+            // 处理 trim 和 case 转换，在截断之前执行
+            let string_like = json_type == Some("string");
+            if rule.trim == Some(true) {
+                if string_like {
+                    field_transforms.ops.push(RuleTransform::Trim);
+                } else {
+                    unsupported.push(ConversionWarning::field(field_name, "trim", "trim rule only supported for string types"));
+                }
+            }
+            if let Some(ref case) = rule.case {
+                let case = match case.as_str() {
+                    "lower" => Case::Lower,
+                    "upper" => Case::Upper,
+                    other => return Err(format!("Field '{}': case must be 'lower' or 'upper', got '{}'", field_name, other)),
+                };
+                if string_like {
+                    field_transforms.ops.push(RuleTransform::Case(case));
+                } else {
+                    unsupported.push(ConversionWarning::field(field_name, "case", "case rule only supported for string types"));
+                }
+            }
+            
+            // 处理 truncate 转换
+            if let Some(max) = rule.truncate {
+                field_transforms.ops.push(RuleTransform::Truncate(max));
+            }
+            
+            // 处理 whitespace 规则
+            if rule.whitespace.is_some() {
+                // whitespace 规则需要自定义验证，JSON Schema 不直接支持
+                unsupported.push(ConversionWarning::field(field_name, "whitespace", "whitespace rule not supported in JSON Schema"));
+            }
+            
+            // 检查不支持的规则
+            if rule.validator.is_some() {
+                unsupported.push(ConversionWarning::field(field_name, "validator", "validator function not supported"));
+            }
+            
+            if rule.async_validator.is_some() {
+                unsupported.push(ConversionWarning::field(field_name, "asyncValidator", "asyncValidator function not supported"));
+            }
+            
+            if rule.trigger.is_some() {
+                unsupported.push(ConversionWarning::field(field_name, "trigger", "trigger option not supported"));
+            }
+            
+            if rule.extra.contains_key("transform") {
+                unsupported.push(ConversionWarning::field(field_name, "transform", "transform option not supported"));
+            }
+            
+            for (key, _) in &rule.extra {
+                match key.as_str() {
+                    "validator" | "asyncValidator" | "trigger" | "whitespace" | "transform" | "fields" => {
+                        // 已经处理过这些规则
+                    }
+                    _ => {
+                        unsupported.push(unknown_rule_warning(field_name, key));
+                    }
+                }
+            }
+        }
+        
+        // 如果没有指定类型，默认为字符串（枚举类型只由 enum 列表约束）
+        if !field_schema.contains_key("type")
+            && field_rules.iter().any(|r| r.field_type.as_deref().is_some_and(|t| t != "enum"))
+        {
+            field_schema.insert("type".to_string(), Value::String("string".to_string()));
+        }
+        
+        // 处理 ignoreCase 规则：字符串枚举转换为不区分大小写的 pattern，取值保留在 x-enum 注解中
+        if field_rules.iter().any(|r| r.ignore_case == Some(true)) {
+            match field_schema.get("enum").and_then(Value::as_array) {
+                Some(values) if values.iter().all(Value::is_string) => {
+                    let pattern = Value::String(ignore_case_pattern(values));
+                    if let Some(values) = field_schema.remove("enum") {
+                        field_schema.insert(form::ENUM.to_string(), values);
+                    }
+                    field_schema.insert("type".to_string(), Value::String("string".to_string()));
+                    if field_schema.contains_key("pattern") {
+                        let all_of = field_schema.entry("allOf".to_string()).or_insert_with(|| Value::Array(Vec::new()));
+                        if let Value::Array(all_of) = all_of {
+                            all_of.push(json!({"pattern": pattern}));
+                        }
+                    } else {
+                        field_schema.insert("pattern".to_string(), pattern);
+                    }
+                }
+                Some(_) => {
+                    unsupported.push(ConversionWarning::field(field_name, "ignoreCase", "ignoreCase requires string enum values"));
+                }
+                None => {
+                    unsupported.push(ConversionWarning::field(field_name, "ignoreCase", "ignoreCase requires an 'enum' list"));
+                }
+            }
+        }
+        
+        // 处理 $use 规则：对象字段引用定义，数组字段的元素引用定义
+        let nullable = field_rules.iter().any(|r| r.nullable == Some(true));
+        if let Some(name) = field_rules.iter().find_map(|r| r.use_def.as_ref()) {
+            if field_rules.iter().any(|r| r.fields.is_some()) {
+                return Err(format!("Field '{}': $use cannot be combined with fields", field_name));
+            }
+            let reference = json!({"$ref": format!("#/$defs/{}", name)});
+            match field_schema.get("type").and_then(Value::as_str) {
+                Some("array") => {
+                    field_schema.insert("items".to_string(), reference);
+                }
+                None | Some("object") => {
+                    field_schema.remove("type");
+                    let reference = if nullable { json!({"anyOf": [reference, {"type": "null"}]}) } else { reference };
+                    // draft 7 中 $ref 的同级关键字会被忽略，有其他约束时放入 allOf
+                    if field_schema.is_empty() {
+                        if let Value::Object(reference) = reference {
+                            field_schema = reference;
+                        }
+                    } else {
+                        field_schema.insert("allOf".to_string(), json!([reference]));
+                    }
+                }
+                Some(_) => {
+                    unsupported.push(ConversionWarning::field(field_name, "$use", "$use rule only supported for object and array types"));
+                }
+            }
+        }
+        
+        // 处理 nullable 规则：类型和枚举值中加入 null
+        if nullable {
+            if let Some(Value::String(type_name)) = field_schema.get("type") {
+                let types = json!([type_name, "null"]);
+                field_schema.insert("type".to_string(), types);
+            }
+            if let Some(Value::Array(values)) = field_schema.get_mut("enum")
+                && !values.contains(&Value::Null)
+            {
+                values.push(Value::Null);
+            }
+            if let Some(value) = field_schema.remove("const") {
+                let values = if value.is_null() { vec![value] } else { vec![value, Value::Null] };
+                field_schema.insert("enum".to_string(), Value::Array(values));
+            }
+        }
+        
+        if field_rules.iter().any(|r| r.sensitive == Some(true)) {
+            field_schema.insert("writeOnly".to_string(), Value::Bool(true));
+        }
+        
+        // 标题和说明注解：显式声明的 description 优先于 message
+        if let Some(title) = field_rules.iter().rev().find_map(|r| r.title.clone()) {
+            field_schema.insert("title".to_string(), Value::String(title));
+        }
+        let description = field_rules
+            .iter()
+            .rev()
+            .find_map(|r| r.description.clone())
+            .or_else(|| field_rules.iter().rev().find_map(|r| r.message.clone()));
+        if let Some(description) = description {
+            field_schema.insert("description".to_string(), Value::String(description));
+        }
+        
+        // 表单元数据：同一字段的多条规则以最后声明的为准
+        for rule in field_rules {
+            for (keyword, value) in [
+                (form::LABEL, rule.label.clone().map(Value::String)),
+                (form::PLACEHOLDER, rule.placeholder.clone().map(Value::String)),
+                (form::HELP, rule.help.clone().map(Value::String)),
+                (form::ORDER, rule.order.map(Value::from)),
+            ] {
+                if let Some(value) = value {
+                    field_schema.insert(keyword.to_string(), value);
+                }
+            }
+        }
+        
+        properties.insert(field_name.clone(), Value::Object(field_schema));
+        
+        if !field_transforms.is_empty() {
+            transforms.properties.insert(field_name.clone(), field_transforms);
+        }
+        
+        if field_required {
+            required.push(field_name.clone());
+        }
+    }
+    
+    schema_object.insert("properties".to_string(), Value::Object(properties));
+    
+    if !required.is_empty() {
+        schema_object.insert("required".to_string(), Value::Array(
+            required.into_iter().map(Value::String).collect()
+        ));
+    }
+    
+    let schema = Value::Object(schema_object);
+    
+    Ok(Conversion {
+        schema,
+        warnings: unsupported,
+        transforms,
+    })
+}
+
+/// 不区分大小写地匹配任一字符串枚举值的 pattern，例如 `^(?i:active|inactive)$`
+pub fn ignore_case_pattern(values: &[Value]) -> String {
+    let alternatives: Vec<String> = values.iter().filter_map(Value::as_str).map(escape_pattern).collect();
+    format!("^(?i:{})$", alternatives.join("|"))
+}
+
+/// 转义正则表达式元字符，使字符串按字面匹配
+fn escape_pattern(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// 带标签的枚举选项（只包含 `value` 和字符串 `label` 的对象）的值
+fn option_value(entry: &Value) -> Option<&Value> {
+    let obj = entry.as_object()?;
+    let value = obj.get("value")?;
+    let valid = obj.iter().all(|(key, v)| key == "value" || (key == "label" && v.is_string()));
+    valid.then_some(value)
+}
+
+/// 字段规则最终对应的 JSON Schema 类型，多条规则声明了类型时以最后声明的为准
+///
+/// 未声明类型的可辨识联合为对象；`enum`、`any` 和不支持的类型没有对应的类型。
+fn json_type_of(field_rules: &[AsyncValidatorRule]) -> Option<&'static str> {
+    let Some(type_name) = field_rules.iter().rev().find_map(|r| r.field_type.as_deref()) else {
+        let union = field_rules.iter().any(|r| r.discriminator.is_some() || r.variants.is_some());
+        return union.then_some("object");
+    };
+    match type_name {
+        "string" | "regexp" | "date" | "email" | "url" | "hex" => Some("string"),
+        "number" => Some("number"),
+        "integer" => Some("integer"),
+        "boolean" => Some("boolean"),
+        "array" => Some("array"),
+        "object" | "method" => Some("object"),
+        _ => None,
+    }
+}
+
+/// 将可辨识联合（`discriminator` + `variants`）转换为 `oneOf`，返回不支持的规则警告
+///
+/// 每个变体的字段规则成为一个分支，分支中判别字段为对应判别值的 `const`；
+/// 外层对象要求判别字段存在且取值为 `variants` 的键，取值错误时直接报告枚举错误。
+fn convert_union(
+    field_name: &str,
+    discriminator: Option<&str>,
+    variants: Option<&Value>,
+    field_schema: &mut Map<String, Value>,
+    patterns: PatternCheck<'_>,
+) -> Result<Vec<ConversionWarning>, String> {
+    let discriminator = discriminator
+        .ok_or_else(|| format!("Field '{}': variants requires a string 'discriminator'", field_name))?;
+    let variants = variants
+        .and_then(Value::as_object)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| format!("Field '{}': discriminator requires a non-empty 'variants' object", field_name))?;
+
+    let mut unsupported = Vec::new();
+    let mut branches = Vec::new();
+    for (tag, variant) in variants {
+        let variant_rules = parse_async_rules(variant)
+            .map_err(|e| format!("Field '{}': invalid variant rules for '{}': {}", field_name, tag, e))?;
+        let mut conversion = convert_to_jsonschema(&variant_rules, patterns)?;
+        unsupported.extend(conversion.warnings.into_iter().map(|w| w.nested_in(field_name)));
+        if !conversion.transforms.is_empty() {
+            unsupported.push(ConversionWarning::field(field_name, "variants", format_args!("transforms in variant '{}' are not applied", tag)));
+        }
+        if let Value::Object(branch) = &mut conversion.schema {
+            add_required_property(branch, discriminator, json!({"const": tag}));
+        }
+        branches.push(conversion.schema);
+    }
+
+    let tags: Vec<Value> = variants.keys().cloned().map(Value::String).collect();
+    add_required_property(field_schema, discriminator, json!({"enum": tags}));
+    field_schema.insert("oneOf".to_string(), Value::Array(branches));
+    Ok(unsupported)
+}
+
+/// 设置对象 schema 中的属性并将其加入 `required`
+fn add_required_property(schema: &mut Map<String, Value>, name: &str, property: Value) {
+    if let Value::Object(properties) = schema
+        .entry("properties".to_string())
+        .or_insert_with(|| Value::Object(Map::new()))
+    {
+        properties.insert(name.to_string(), property);
+    }
+    if let Value::Array(required) = schema
+        .entry("required".to_string())
+        .or_insert_with(|| Value::Array(Vec::new()))
+        && !required.iter().any(|r| r == name)
+    {
+        required.push(Value::String(name.to_string()));
+    }
+}
+
+/// 规则根节点中的定义：`defs` 的值为 定义名 -> 字段规则 的映射
+///
+/// 只有 `defs` 中每个定义都是字段规则映射（值均为规则对象或规则数组）时才视为定义，
+/// 否则 `defs` 是普通字段。
+pub fn split_definitions(value: &Value) -> Option<(&Map<String, Value>, Value)> {
+    let obj = value.as_object()?;
+    let defs = obj.get("defs")?.as_object().filter(|defs| !defs.is_empty())?;
+    let is_fields = |fields: &Value| {
+        fields.as_object().is_some_and(|f| f.values().all(|rule| rule.is_object() || rule.is_array()))
+    };
+    if !defs.values().all(is_fields) {
+        return None;
+    }
+    let mut rest = obj.clone();
+    rest.remove("defs");
+    Some((defs, Value::Object(rest)))
+}
+
+/// 转换 `defs` 中的定义，检查 `$use` 引用的名称存在，并拒绝只能无限嵌套的必填循环引用
+pub(crate) fn convert_definitions(defs: &Map<String, Value>, conversion: &mut Conversion, patterns: PatternCheck<'_>) -> Result<(), String> {
+    let mut converted = Map::new();
+    for (name, fields) in defs {
+        let rules = parse_async_rules(fields).map_err(|e| format!("Failed to parse definition '{}': {}", name, e))?;
+        let definition =
+            convert_to_jsonschema(&rules, patterns).map_err(|e| format!("Failed to convert definition '{}': {}", name, e))?;
+        conversion.warnings.extend(definition.warnings);
+        if !definition.transforms.is_empty() {
+            conversion.warnings.push(
+                ConversionWarning::new(format!("Definition '{}': transforms in definitions are not applied", name)).with_rule("defs"),
+            );
+        }
+        converted.insert(name.clone(), definition.schema);
+    }
+
+    let mut references = Vec::new();
+    keywords::visit_schemas(&conversion.schema, &mut |obj| references.extend(obj.get("$ref").cloned()));
+    for schema in converted.values() {
+        keywords::visit_schemas(schema, &mut |obj| references.extend(obj.get("$ref").cloned()));
+    }
+    for reference in references.iter().filter_map(Value::as_str) {
+        if let Some(name) = reference.strip_prefix("#/$defs/")
+            && !converted.contains_key(name)
+        {
+            return Err(format!("Unknown definition '{}' in $use", name));
+        }
+    }
+
+    // 必填字段构成的循环引用没有有限的合法数据
+    let graph: BTreeMap<&str, Vec<String>> =
+        converted.iter().map(|(name, schema)| (name.as_str(), required_references(schema))).collect();
+    for start in graph.keys() {
+        let mut path = vec![start.to_string()];
+        if let Some(cycle) = find_cycle(&graph, start, &mut path) {
+            return Err(format!("Definition '{}' requires itself: {}", start, cycle.join(" -> ")));
+        }
+    }
+
+    if let Value::Object(root) = &mut conversion.schema {
+        root.insert("$defs".to_string(), Value::Object(converted));
+    }
+    Ok(())
+}
+
+/// 对象 schema 中经必填字段（包括必填的嵌套对象）直接引用的定义名称
+fn required_references(schema: &Value) -> Vec<String> {
+    let mut names = Vec::new();
+    let required = schema.get("required").and_then(Value::as_array).into_iter().flatten();
+    for field in required.filter_map(Value::as_str) {
+        let Some(property) = schema.get("properties").and_then(|p| p.get(field)) else {
+            continue;
+        };
+        let reference = property.get("$ref").or_else(|| property.pointer("/allOf/0/$ref"));
+        match reference.and_then(Value::as_str).and_then(|r| r.strip_prefix("#/$defs/")) {
+            Some(name) => names.push(name.to_string()),
+            None => names.extend(required_references(property)),
+        }
+    }
+    names
+}
+
+fn find_cycle(graph: &BTreeMap<&str, Vec<String>>, node: &str, path: &mut Vec<String>) -> Option<Vec<String>> {
+    for next in graph.get(node).into_iter().flatten() {
+        if *next == path[0] {
+            let mut cycle = path.clone();
+            cycle.push(next.clone());
+            return Some(cycle);
+        }
+        if path.contains(next) {
+            continue;
+        }
+        path.push(next.clone());
+        if let Some(cycle) = find_cycle(graph, next, path) {
+            return Some(cycle);
+        }
+        path.pop();
+    }
+    None
+}
+
+/// 根节点为可辨识联合时使用的字段名
+pub const UNION_ROOT: &str = "$union";
+
+/// `LinkValidator::for_value` 中描述根节点的规则使用的字段名
+pub const VALUE_ROOT: &str = "$value";
+
+/// 规则根节点是否为可辨识联合（只包含字符串 `discriminator` 和对象 `variants`）
+pub fn is_union_rule(value: &Value) -> bool {
+    value.as_object().is_some_and(|obj| {
+        obj.len() == 2
+            && obj.get("discriminator").is_some_and(Value::is_string)
+            && obj.get("variants").is_some_and(Value::is_object)
+    })
+}
+
+/// 转换嵌在字段规则中的单条规则（`contains`、`valueRule`），返回 schema 和不支持的规则警告
+fn convert_single_rule(
+    field_name: &str,
+    rule: &Value,
+    name: &str,
+    patterns: PatternCheck<'_>,
+) -> Result<(Value, Vec<ConversionWarning>), String> {
+    let rules = parse_async_rules(&json!({ field_name: rule }))
+        .map_err(|e| format!("Field '{}': invalid {} rule: {}", field_name, name, e))?;
+    let mut conversion = convert_to_jsonschema(&rules, patterns)?;
+    if !conversion.transforms.is_empty() {
+        conversion.warnings.push(ConversionWarning::field(field_name, name, format_args!("transforms in {} rule are not applied", name)));
+    }
+    let schema = conversion.schema["properties"][field_name].take();
+    Ok((schema, conversion.warnings))
+}
+
+/// 将 `itemsBy` 规则转换为数组项上的 `if`/`then` 条件，返回不支持的规则警告
+///
+/// 判别字段成为数组项的必填字段，取值限定为 `mapping` 的键；每个键对应的规则只作用于
+/// 判别字段等于该键的数组项，例如 `{"field": "kind", "mapping": {"text": {...}, "image": {...}}}`。
+fn convert_items_by(
+    field_name: &str,
+    items_by: &Value,
+    items: &mut Value,
+    patterns: PatternCheck<'_>,
+) -> Result<Vec<ConversionWarning>, String> {
+    let discriminator = items_by
+        .get("field")
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Field '{}': itemsBy requires a string 'field'", field_name))?;
+    let mapping = items_by
+        .get("mapping")
+        .and_then(Value::as_object)
+        .filter(|m| !m.is_empty())
+        .ok_or_else(|| format!("Field '{}': itemsBy requires a non-empty 'mapping' object", field_name))?;
+
+    let parent = format!("{}[]", field_name);
+    let mut unsupported = Vec::new();
+    let mut branches = Vec::new();
+    for (tag, variant) in mapping {
+        let variant_rules = parse_async_rules(variant)
+            .map_err(|e| format!("Field '{}': invalid itemsBy rules for '{}': {}", field_name, tag, e))?;
+        let conversion = convert_to_jsonschema(&variant_rules, patterns)?;
+        unsupported.extend(conversion.warnings.into_iter().map(|w| w.nested_in(&parent)));
+        if !conversion.transforms.is_empty() {
+            unsupported.push(ConversionWarning::field(
+                field_name,
+                "itemsBy",
+                format_args!("transforms in itemsBy variant '{}' are not applied", tag),
+            ));
+        }
+        branches.push(json!({
+            "if": {"properties": {discriminator: {"const": tag}}, "required": [discriminator]},
+            "then": conversion.schema,
+        }));
+    }
+
+    let tags: Vec<Value> = mapping.keys().cloned().map(Value::String).collect();
+    let Value::Object(items) = items else {
+        return Err(format!("Field '{}': itemsBy requires object items", field_name));
+    };
+    let properties = items
+        .entry("properties".to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    if let Value::Object(properties) = properties {
+        let property = properties
+            .entry(discriminator.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(property) = property {
+            property.insert("enum".to_string(), Value::Array(tags));
+        }
+    }
+    let required = items
+        .entry("required".to_string())
+        .or_insert_with(|| Value::Array(Vec::new()));
+    if let Value::Array(required) = required
+        && !required.iter().any(|r| r == discriminator)
+    {
+        required.push(Value::String(discriminator.to_string()));
+    }
+    items.insert("allOf".to_string(), Value::Array(branches));
+    Ok(unsupported)
+}
@@ -0,0 +1,87 @@
+//! 日期范围约束关键字和日期解析
+//!
+//! `minDate` / `maxDate` 转换为 `formatMinimum` / `formatMaximum`，转换时只检查边界能否解析。
+
+use alloc::format;
+
+/// 日期下限关键字
+pub const MINIMUM: &str = "formatMinimum";
+
+/// 日期上限关键字
+pub const MAXIMUM: &str = "formatMaximum";
+
+/// 一天的纳秒数
+pub const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+
+/// 日期或日期时间字符串转换为 UTC 纳秒时间戳，只有日期时为当天零点
+///
+/// 支持 `YYYY-MM-DD` 和 RFC 3339 日期时间（`YYYY-MM-DDTHH:MM:SS[.fff](Z|±HH:MM)`）。
+pub fn parse(value: &str) -> Option<(i128, bool)> {
+    let date = value.get(..10)?;
+    let days = parse_date(date)?;
+    let rest = &value[10..];
+    if rest.is_empty() {
+        return Some((days as i128 * NANOS_PER_DAY, true));
+    }
+
+    let time = rest.strip_prefix(['T', 't', ' '])?;
+    let (clock, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(index) => time.split_at(index),
+        None => return None,
+    };
+    let offset_seconds: i64 = match offset {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = if offset.starts_with('-') { -1 } else { 1 };
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            sign * (number(hours, 0, 23)? * 3600 + number(minutes, 0, 59)? * 60)
+        }
+    };
+    let (clock, fraction) = clock.split_once('.').unwrap_or((clock, ""));
+    let mut parts = clock.split(':');
+    let hours = number(parts.next()?, 0, 23)?;
+    let minutes = number(parts.next()?, 0, 59)?;
+    // 允许闰秒 60
+    let seconds = number(parts.next()?, 0, 60)?;
+    if parts.next().is_some() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let nanos: i128 = format!("{:0<9}", fraction.get(..fraction.len().min(9))?).parse().ok()?;
+
+    let seconds = days * 86_400 + hours * 3600 + minutes * 60 + seconds - offset_seconds;
+    Some((seconds as i128 * 1_000_000_000 + nanos, false))
+}
+
+fn parse_date(date: &str) -> Option<i64> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = number(&date[..4], 0, 9999)?;
+    let month = number(&date[5..7], 1, 12)?;
+    let day = number(&date[8..], 1, 31)?;
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = [31, if leap { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if day > days_in_month[month as usize - 1] {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// 公历日期距 1970-01-01 的天数
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn number(digits: &str, min: i64, max: i64) -> Option<i64> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|n| (min..=max).contains(n))
+}
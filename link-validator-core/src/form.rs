@@ -0,0 +1,17 @@
+//! 表单元数据关键字
+//!
+//! 规则中的 `label`、`placeholder`、`help`、`order` 和枚举选项的标签不参与验证，转换为带 `x-` 前缀的注解。
+//! `ignoreCase` 枚举转换为 `pattern` 后，取值同样保留在注解中。
+
+/// 表单标签
+pub const LABEL: &str = "x-label";
+/// 占位提示
+pub const PLACEHOLDER: &str = "x-placeholder";
+/// 帮助文本
+pub const HELP: &str = "x-help";
+/// 字段排序
+pub const ORDER: &str = "x-order";
+/// 带标签的枚举选项，`[{"value": .., "label": ..}]`
+pub const OPTIONS: &str = "x-options";
+/// `ignoreCase` 枚举的取值（`enum` 转换为不区分大小写的 `pattern` 后保留）
+pub const ENUM: &str = "x-enum";
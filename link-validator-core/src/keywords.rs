@@ -0,0 +1,53 @@
+//! 子 schema 关键字和 schema 遍历
+
+use alloc::string::String;
+use serde_json::{Map, Value};
+
+/// 值为单个子 schema 的关键字
+pub const SUBSCHEMA_KEYWORDS: &[&str] = &[
+    "items",
+    "additionalItems",
+    "additionalProperties",
+    "contains",
+    "propertyNames",
+    "not",
+    "if",
+    "then",
+    "else",
+];
+
+/// 值为子 schema 数组的关键字（`items` 也可以是元组形式的数组）
+pub const SUBSCHEMA_ARRAY_KEYWORDS: &[&str] = &["allOf", "anyOf", "oneOf", "items"];
+
+/// 值为“名称 → 子 schema”映射的关键字
+pub const SUBSCHEMA_MAP_KEYWORDS: &[&str] = &[
+    "properties",
+    "patternProperties",
+    "definitions",
+    "$defs",
+    "dependencies",
+];
+
+/// 依次访问 schema 及其全部子 schema 对象
+pub fn visit_schemas(schema: &Value, visit: &mut impl FnMut(&Map<String, Value>)) {
+    let Some(obj) = schema.as_object() else {
+        return;
+    };
+    visit(obj);
+
+    for keyword in SUBSCHEMA_KEYWORDS {
+        if let Some(sub) = obj.get(*keyword) {
+            visit_schemas(sub, visit);
+        }
+    }
+    for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+        if let Some(Value::Array(subs)) = obj.get(*keyword) {
+            subs.iter().for_each(|sub| visit_schemas(sub, visit));
+        }
+    }
+    for keyword in SUBSCHEMA_MAP_KEYWORDS {
+        if let Some(Value::Object(subs)) = obj.get(*keyword) {
+            subs.values().for_each(|sub| visit_schemas(sub, visit));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! link-validator-core - async-validator 风格规则到 JSON Schema 的转换
+//!
+//! 只依赖 `alloc`（`#![no_std]`），可在嵌入式或精简的 WASM 环境中完成转换，
+//! 把生成的 JSON Schema 交给其他环境中的验证器（例如 link-validator）使用。
+//!
+//! ```
+//! use serde_json::json;
+//!
+//! let conversion = link_validator_core::convert(&json!({
+//!     "name": {"type": "string", "required": true, "trim": true},
+//!     "age": {"type": "integer", "min": 0, "validator": "custom"},
+//! }))
+//! .unwrap();
+//! assert_eq!(conversion.schema["required"], json!(["name"]));
+//! assert_eq!(conversion.warnings.len(), 1);
+//! assert!(!conversion.transforms.is_empty());
+//! ```
+//!
+//! 正则表达式引擎依赖标准库，`convert` 不检查 `pattern` 能否编译，需要时通过
+//! `convert_with` 传入检查函数。
+
+#![no_std]
+
+extern crate alloc;
+
+mod convert;
+mod dates;
+mod form;
+mod keywords;
+mod rules;
+mod transform;
+mod warning;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde_json::{json, Value};
+
+use convert::{convert_definitions, convert_to_jsonschema, is_union_rule, split_definitions, UNION_ROOT, VALUE_ROOT};
+use rules::parse_async_rules;
+
+pub use transform::{Case, RuleTransform, TransformPlan};
+pub use warning::{ConversionWarning, Severity, WarningKind};
+
+/// 正则表达式检查函数，返回 `Err` 时转换失败
+pub type PatternCheck<'a> = &'a dyn Fn(&str) -> Result<(), String>;
+
+/// 转换结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    /// 生成的 JSON Schema
+    pub schema: Value,
+    /// 无法转换或被忽略的规则
+    pub warnings: Vec<ConversionWarning>,
+    /// 规则中声明的数据规范化（`trim`、`case`、`truncate`），需要验证方在验证前应用
+    pub transforms: TransformPlan,
+}
+
+/// 转换 async-validator 规则，不检查 `pattern` 等正则表达式能否编译
+///
+/// 根节点为可辨识联合时，生成的 schema 即为联合本身（而不是包含联合字段的对象）；
+/// 根节点的 `defs` 转换为 `$defs`。
+pub fn convert(rules: &Value) -> Result<Conversion, String> {
+    convert_with(rules, &|_| Ok(()))
+}
+
+/// 转换 async-validator 规则，`pattern` 和 `keyPattern` 中的正则表达式交给 `patterns` 检查
+///
+/// 检查失败时转换失败，错误信息中包含 `patterns` 返回的说明。
+pub fn convert_with(value: &Value, patterns: PatternCheck<'_>) -> Result<Conversion, String> {
+    if let Some((defs, rest)) = split_definitions(value) {
+        let mut conversion = convert_with(&rest, patterns)?;
+        convert_definitions(defs, &mut conversion, patterns)?;
+        return Ok(conversion);
+    }
+    if is_union_rule(value) {
+        return convert_root(&json!({ UNION_ROOT: value }), Some(UNION_ROOT), patterns);
+    }
+    convert_root(value, None, patterns)
+}
+
+/// 转换描述根节点本身的单条规则（或规则数组），而不是字段名到规则的映射
+///
+/// 生成的 schema 直接约束根节点，警告中的字段路径相对于根节点。
+pub fn convert_value_with(rule: &Value, patterns: PatternCheck<'_>) -> Result<Conversion, String> {
+    convert_root(&json!({ VALUE_ROOT: rule }), Some(VALUE_ROOT), patterns)
+}
+
+/// 转换字段映射；`root` 不为空时取出该字段的 schema 作为根节点
+fn convert_root(value: &Value, root: Option<&str>, patterns: PatternCheck<'_>) -> Result<Conversion, String> {
+    let rules = parse_async_rules(value).map_err(|e| format!("Failed to parse async-validator rules: {}", e))?;
+    let mut conversion =
+        convert_to_jsonschema(&rules, patterns).map_err(|e| format!("Failed to convert async-validator rules: {}", e))?;
+    if let Some(root) = root {
+        conversion.schema = conversion.schema["properties"][root].take();
+        conversion.transforms = conversion.transforms.properties.remove(root).unwrap_or_default();
+        // 根节点规则的字段路径相对于根节点本身
+        for warning in &mut conversion.warnings {
+            if let Some(path) = warning.field_path.strip_prefix(root) {
+                warning.field_path = path.trim_start_matches('.').to_string();
+            }
+        }
+    }
+    Ok(conversion)
+}
+
+/// link-validator 内部使用的转换细节，不属于公开 API
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::convert::{ignore_case_pattern, is_union_rule, split_definitions, UNION_ROOT, VALUE_ROOT};
+
+    /// `link_schema!` 写入生成的 schema 根节点的标记关键字，值为输出格式版本
+    pub const LINK_SCHEMA_MARKER: &str = "x-link-schema";
+    /// `link_schema!` 当前的输出格式版本
+    pub const LINK_SCHEMA_VERSION: u64 = 1;
+
+    pub mod dates {
+        pub use crate::dates::*;
+    }
+
+    pub mod form {
+        pub use crate::form::*;
+    }
+
+    pub mod keywords {
+        pub use crate::keywords::*;
+    }
+}
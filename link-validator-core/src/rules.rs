@@ -0,0 +1,253 @@
+//! async-validator 字段规则的解析
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// 单条 async-validator 字段规则
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct AsyncValidatorRule {
+    /// 字段类型
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) field_type: Option<String>,
+    
+    /// 是否必填
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) required: Option<bool>,
+    
+    /// 最小长度（字符串）或最小值（数字）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) min: Option<Value>,
+    
+    /// 最大长度（字符串）或最大值（数字）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max: Option<Value>,
+    
+    /// 精确长度
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) len: Option<Value>,
+    
+    /// 正则表达式模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) pattern: Option<String>,
+    
+    /// 枚举值
+    #[serde(rename = "enum")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) enum_values: Option<Vec<Value>>,
+    
+    /// 字符串枚举值不区分大小写
+    #[serde(rename = "ignoreCase")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) ignore_case: Option<bool>,
+    
+    /// 固定值
+    #[serde(rename = "const")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) const_value: Option<Value>,
+    
+    /// 错误消息（未声明 `description` 时作为生成的 JSON Schema 的 `description`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) message: Option<String>,
+    
+    /// 字段标题（输出为 JSON Schema 的 `title` 注解）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+    
+    /// 字段说明（输出为 JSON Schema 的 `description` 注解）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) description: Option<String>,
+    
+    /// 是否检查空白字符
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) whitespace: Option<bool>,
+    
+    /// 字段验证器（不支持转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) validator: Option<Value>,
+    
+    /// 异步字段验证器（不支持转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) async_validator: Option<Value>,
+    
+    /// 触发方式（不支持转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) trigger: Option<Value>,
+    
+    /// 嵌套字段规则（用于支持深度嵌套）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fields: Option<Value>,
+    
+    /// 按字素簇截断字符串（验证前的规范化转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) truncate: Option<usize>,
+    
+    /// 验证前去除字符串首尾空白（规范化转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) trim: Option<bool>,
+    
+    /// 验证前转换字符串大小写：`"lower"` 或 `"upper"`（规范化转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) case: Option<String>,
+    
+    /// 日期格式（`date` 类型可用 `"date"` 表示只有日期）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) format: Option<String>,
+    
+    /// 最早日期（`date` 类型）
+    #[serde(rename = "minDate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) min_date: Option<String>,
+    
+    /// 最晚日期（`date` 类型）
+    #[serde(rename = "maxDate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_date: Option<String>,
+    
+    /// 数值必须是该数的整数倍（`number`/`integer` 类型）
+    #[serde(rename = "multipleOf")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) multiple_of: Option<Value>,
+    
+    /// 不含边界的最小值（`number`/`integer` 类型）
+    #[serde(rename = "exclusiveMin")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) exclusive_min: Option<Value>,
+    
+    /// 不含边界的最大值（`number`/`integer` 类型）
+    #[serde(rename = "exclusiveMax")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) exclusive_max: Option<Value>,
+    
+    /// 正数（大于 0）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) positive: Option<bool>,
+    
+    /// 非负数（大于等于 0）
+    #[serde(rename = "nonNegative")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) non_negative: Option<bool>,
+    
+    /// 负数（小于 0）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) negative: Option<bool>,
+    
+    /// 非正数（小于等于 0）
+    #[serde(rename = "nonPositive")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) non_positive: Option<bool>,
+    
+    /// 允许值为 `null`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) nullable: Option<bool>,
+    
+    /// 敏感字段：验证错误中不输出出错的值（转换为 `writeOnly` 注解）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) sensitive: Option<bool>,
+    
+    /// 规则生效的验证分组（编译时按分组筛选，不参与转换）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) groups: Option<Vec<String>>,
+    
+    /// 表单标签（转换为 `x-label` 注解，不参与验证）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
+    
+    /// 表单占位提示（转换为 `x-placeholder` 注解，不参与验证）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) placeholder: Option<String>,
+    
+    /// 表单帮助文本（转换为 `x-help` 注解，不参与验证）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) help: Option<String>,
+    
+    /// 表单字段排序（转换为 `x-order` 注解，不参与验证）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) order: Option<i64>,
+    
+    /// 引用规则根节点 `defs` 中的定义：对象字段的结构或数组元素的结构（可递归引用）
+    #[serde(rename = "$use")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) use_def: Option<String>,
+    
+    /// 可辨识联合的判别字段，与 `variants` 一起使用
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) discriminator: Option<String>,
+    
+    /// 可辨识联合的各个变体：判别值 -> 字段规则
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) variants: Option<Value>,
+    
+    /// 映射对象键名的正则表达式（`object` 类型）
+    #[serde(rename = "keyPattern")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) key_pattern: Option<String>,
+    
+    /// 映射对象中未在 `fields` 声明的值的规则（`object` 类型）
+    #[serde(rename = "valueRule")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) value_rule: Option<Value>,
+    
+    /// 数组元素不能重复
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) unique: Option<bool>,
+    
+    /// 数组中至少有一个元素满足该规则
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) contains: Option<Value>,
+    
+    /// 按判别字段选择数组元素规则（`{"field": "kind", "mapping": {...}}`）
+    #[serde(rename = "itemsBy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) items_by: Option<Value>,
+    
+    /// 其他未映射的属性
+    #[serde(flatten)]
+    pub(crate) extra: Map<String, Value>,
+}
+
+/// 字段名 -> 字段规则列表
+pub(crate) type AsyncValidatorRules = BTreeMap<String, Vec<AsyncValidatorRule>>;
+
+/// 解析 async-validator 规则，支持对象和数组两种格式
+pub(crate) fn parse_async_rules(value: &Value) -> Result<AsyncValidatorRules, String> {
+    let mut rules = BTreeMap::new();
+    
+    if let Value::Object(obj) = value {
+        for (field_name, field_rules) in obj {
+            match field_rules {
+                // 单个规则对象格式 { field: {type: "string", required: true} }
+                Value::Object(rule_obj) => {
+                    let rule: AsyncValidatorRule = serde_json::from_value(Value::Object(rule_obj.clone()))
+                        .map_err(|e| format!("Failed to parse rule for field '{}': {}", field_name, e))?;
+                    rules.insert(field_name.clone(), vec![rule]);
+                },
+                // 规则数组格式 { field: [{type: "string", required: true}, {min: 3}] }
+                Value::Array(rule_arr) => {
+                    let mut parsed_rules = Vec::new();
+                    for (index, rule_value) in rule_arr.iter().enumerate() {
+                        if let Value::Object(rule_obj) = rule_value {
+                            let rule: AsyncValidatorRule = serde_json::from_value(Value::Object(rule_obj.clone()))
+                                .map_err(|e| format!("Failed to parse rule {} for field '{}': {}", index, field_name, e))?;
+                            parsed_rules.push(rule);
+                        } else {
+                            return Err(format!("Rule {} for field '{}' is not an object", index, field_name));
+                        }
+                    }
+                    rules.insert(field_name.clone(), parsed_rules);
+                },
+                _ => {
+                    return Err(format!("Invalid rule format for field '{}'", field_name));
+                }
+            }
+        }
+        Ok(rules)
+    } else {
+        Err("Input is not an object".into())
+    }
+}
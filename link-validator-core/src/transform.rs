@@ -0,0 +1,52 @@
+//! 规则中声明的数据规范化
+//!
+//! `transform: "trim"`、`case`、`truncate` 不改变 schema，转换为与 schema 结构对应的转换计划，
+//! 由验证方在验证之前应用到数据副本上。
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// 规则声明的单个转换操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleTransform {
+    /// 去除字符串首尾空白
+    Trim,
+    /// 转换字符串大小写
+    Case(Case),
+    /// 按字素簇截断字符串，最多保留指定数量的字素簇
+    Truncate(usize),
+}
+
+/// 字符串大小写转换方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Case {
+    Lower,
+    Upper,
+}
+
+/// 转换计划树，结构与生成的 JSON Schema 的 properties/items 对应
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransformPlan {
+    /// 作用于当前值的转换
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ops: Vec<RuleTransform>,
+    /// 对象属性的转换
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub properties: BTreeMap<String, TransformPlan>,
+    /// 数组元素的转换
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<TransformPlan>>,
+}
+
+impl TransformPlan {
+    /// 转换计划是否为空
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty() && self.properties.is_empty() && self.items.is_none()
+    }
+}
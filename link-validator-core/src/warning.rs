@@ -0,0 +1,139 @@
+//! 规则转换警告
+
+use serde::{Deserialize, Serialize};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+
+/// 转换警告的严重程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    /// 不影响验证结果的提示，例如只作用于前端的 `trigger`
+    Info,
+    /// 规则无法转换或被忽略，验证结果可能比预期宽松
+    Warning,
+    /// 编译失败
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// 转换警告的类别，用于按类别处理警告而不依赖 `message` 的文字
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WarningKind {
+    /// 规则中无法识别的键（通常是拼写错误，例如 `requird`）
+    UnknownRule,
+    /// 其他警告：不支持的规则、被忽略的关键字等
+    #[default]
+    Other,
+}
+
+impl WarningKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WarningKind::UnknownRule => "unknown-rule",
+            WarningKind::Other => "other",
+        }
+    }
+
+    fn is_other(&self) -> bool {
+        *self == WarningKind::Other
+    }
+}
+
+/// 规则转换的警告，例如不支持的规则或被忽略的关键字
+///
+/// 序列化为 `{"field_path": ..., "rule": ..., "severity": ..., "message": ...}`，
+/// 类别不是 `Other` 时还包含 `kind`；`Display` 输出 `message`。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConversionWarning {
+    /// 相关字段的路径（例如 `address.city`，数组元素为 `lines[].sku`），与字段无关时为空字符串
+    pub field_path: String,
+    /// 产生警告的规则键，例如 `validator`、`trigger`、`minDate`
+    pub rule: Option<String>,
+    /// 严重程度，link-validator 中可通过 `CompileOptions::warning_severity` 按规则调整
+    pub severity: Severity,
+    /// 警告类别
+    #[serde(default, skip_serializing_if = "WarningKind::is_other")]
+    pub kind: WarningKind,
+    /// 警告说明，例如 `Field 'name': validator function not supported`
+    pub message: String,
+}
+
+impl ConversionWarning {
+    /// 与字段无关的警告
+    pub fn new(message: impl Into<String>) -> Self {
+        ConversionWarning {
+            field_path: String::new(),
+            rule: None,
+            severity: Severity::Warning,
+            kind: WarningKind::Other,
+            message: message.into(),
+        }
+    }
+
+    /// 字段上某条规则的警告，说明以 `Field '字段名': ` 开头
+    pub fn field(field_name: &str, rule: &str, detail: impl fmt::Display) -> Self {
+        ConversionWarning {
+            field_path: field_name.to_string(),
+            rule: Some(rule.to_string()),
+            severity: default_severity(rule),
+            kind: WarningKind::Other,
+            message: format!("Field '{}': {}", field_name, detail),
+        }
+    }
+
+    /// 设置字段路径
+    pub fn with_field_path(mut self, field_path: impl Into<String>) -> Self {
+        self.field_path = field_path.into();
+        self
+    }
+
+    /// 设置警告类别
+    pub fn with_kind(mut self, kind: WarningKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// 设置规则键
+    pub fn with_rule(mut self, rule: impl Into<String>) -> Self {
+        let rule = rule.into();
+        self.severity = default_severity(&rule);
+        self.rule = Some(rule);
+        self
+    }
+
+    /// 嵌套字段的警告，字段路径加上父字段前缀
+    pub fn nested_in(mut self, parent: &str) -> Self {
+        self.field_path = if self.field_path.is_empty() {
+            parent.to_string()
+        } else {
+            format!("{}.{}", parent, self.field_path)
+        };
+        self
+    }
+}
+
+impl fmt::Display for ConversionWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// 规则的默认严重程度：`trigger` 只影响前端的触发时机，为 `Info`，其他为 `Warning`
+fn default_severity(rule: &str) -> Severity {
+    match rule {
+        "trigger" => Severity::Info,
+        _ => Severity::Warning,
+    }
+}
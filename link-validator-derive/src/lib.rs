@@ -0,0 +1,275 @@
+//! link-validator-derive - 从 Rust 结构体生成 async-validator 规则
+//!
+//! 为结构体实现 `link_validator::LinkSchema`，字段规则通过 `#[rule(...)]` 属性声明：
+//!
+//! ```ignore
+//! use link_validator::LinkSchema;
+//!
+//! #[derive(LinkSchema)]
+//! struct SignUp {
+//!     #[rule(required, min = 3, max = 20)]
+//!     username: String,
+//!     #[rule(required, type = "email")]
+//!     email: String,
+//!     #[rule(min = 0)]
+//!     age: Option<u32>,
+//! }
+//!
+//! let validator = SignUp::validator().unwrap();
+//! ```
+//!
+//! 支持的属性：
+//! - `required` - 必填
+//! - `nullable` - 允许 `null`
+//! - `sensitive` - 敏感字段，验证错误中不输出出错的值
+//! - `groups = [..]` - 字段规则只在这些验证分组中生效，例如 `#[rule(required, groups = ["update"])]`
+//! - `title = "..."`、`description = "..."` - 输出为 JSON Schema 的 `title`/`description` 注解
+//! - `label = "..."`、`placeholder = "..."`、`help = "..."`、`order = ..` - 表单元数据，不参与验证
+//! - `type = "..."` - 字段类型，未声明时根据 Rust 类型推断（字符串、整数、浮点数、布尔、数组、映射）
+//! - `min = ..`、`max = ..`、`len = ..` - 长度或数值范围
+//! - `pattern = "..."`、`message = "..."`、`truncate = ..`、`trim`、`case = "lower"`
+//! - `format = "date"`、`min_date = "..."`、`max_date = "..."` - 日期格式和日期范围（`date` 类型）
+//! - `multiple_of = ..`、`exclusive_min = ..`、`exclusive_max = ..` - 倍数和不含边界的范围（数值类型）
+//! - `positive`、`non_negative`、`negative`、`non_positive` - 数值符号预设
+//! - `enum = [..]` - 枚举值
+//! - `const = ..` - 固定值
+//! - `unique` - 数组元素不能重复
+//! - `nested` - 嵌套结构体（或其 `Vec`/`Option`），使用其 `LinkSchema::rules()` 作为 `fields`
+//! - `rename = "..."` - 规则中使用的字段名
+//! - `skip` - 不生成该字段的规则
+//!
+//! `link_schema!` 在编译期转换规则，见该宏的文档。
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Expr, Fields, GenericArgument, LitStr, PathArguments, Type};
+
+mod schema;
+
+/// 为结构体派生 `link_validator::LinkSchema`
+#[proc_macro_derive(LinkSchema, attributes(rule))]
+pub fn derive_link_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// 在编译期把 async-validator 规则转换为 JSON Schema，展开为 `&'static str`
+///
+/// 规则使用 JSON 语法（允许末尾多余的逗号），转换失败、正则无法编译或存在被忽略的规则时产生编译错误。
+/// 生成的常量交给 `LinkValidator::from_link_schema` 创建验证器，运行时不再转换规则。
+/// 数据规范化（`trim`、`case`、`truncate`）无法放入 JSON Schema，使用时同样产生编译错误。
+///
+/// ```ignore
+/// use link_validator::{link_schema, LinkValidator};
+///
+/// const SIGNUP: &str = link_schema!({
+///     "username": {"type": "string", "required": true, "min": 3},
+///     "age": {"type": "integer", "min": 0},
+/// });
+///
+/// let validator = LinkValidator::from_link_schema(SIGNUP).unwrap();
+/// ```
+#[proc_macro]
+pub fn link_schema(input: TokenStream) -> TokenStream {
+    schema::expand(input.into()).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// 单个字段上解析出的规则
+#[derive(Default)]
+struct FieldRule {
+    required: bool,
+    field_type: Option<String>,
+    constraints: Vec<(&'static str, Expr)>,
+    nested: bool,
+    rename: Option<String>,
+    skip: bool,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "LinkSchema requires a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "LinkSchema can only be derived for structs")),
+    };
+
+    let mut field_tokens = Vec::new();
+    for field in fields {
+        let rule = parse_field_rule(&field.attrs)?;
+        if rule.skip {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field");
+        let name = rule
+            .rename
+            .clone()
+            .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+        field_tokens.push(field_rule_tokens(&name, &field.ty, &rule));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::link_validator::LinkSchema for #ident #ty_generics #where_clause {
+            fn rules() -> ::link_validator::__private::Value {
+                let mut rules = ::link_validator::__private::Map::new();
+                #(#field_tokens)*
+                ::link_validator::__private::Value::Object(rules)
+            }
+        }
+    })
+}
+
+fn parse_field_rule(attrs: &[syn::Attribute]) -> syn::Result<FieldRule> {
+    let mut rule = FieldRule::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("rule")) {
+        attr.parse_nested_meta(|meta| {
+            let key = meta
+                .path
+                .get_ident()
+                .map(ToString::to_string)
+                .ok_or_else(|| meta.error("unsupported rule attribute"))?;
+
+            match key.as_str() {
+                "required" => rule.required = true,
+                "nested" => rule.nested = true,
+                "skip" => rule.skip = true,
+                "type" => rule.field_type = Some(meta.value()?.parse::<LitStr>()?.value()),
+                "rename" => rule.rename = Some(meta.value()?.parse::<LitStr>()?.value()),
+                "min" => rule.constraints.push(("min", meta.value()?.parse()?)),
+                "max" => rule.constraints.push(("max", meta.value()?.parse()?)),
+                "len" => rule.constraints.push(("len", meta.value()?.parse()?)),
+                "pattern" => rule.constraints.push(("pattern", meta.value()?.parse()?)),
+                "message" => rule.constraints.push(("message", meta.value()?.parse()?)),
+                "truncate" => rule.constraints.push(("truncate", meta.value()?.parse()?)),
+                "trim" => rule.constraints.push(("trim", syn::parse_quote!(true))),
+                "unique" => rule.constraints.push(("unique", syn::parse_quote!(true))),
+                "nullable" => rule.constraints.push(("nullable", syn::parse_quote!(true))),
+                "sensitive" => rule.constraints.push(("sensitive", syn::parse_quote!(true))),
+                "groups" => rule.constraints.push(("groups", meta.value()?.parse()?)),
+                "title" => rule.constraints.push(("title", meta.value()?.parse()?)),
+                "description" => rule.constraints.push(("description", meta.value()?.parse()?)),
+                "label" => rule.constraints.push(("label", meta.value()?.parse()?)),
+                "placeholder" => rule.constraints.push(("placeholder", meta.value()?.parse()?)),
+                "help" => rule.constraints.push(("help", meta.value()?.parse()?)),
+                "order" => rule.constraints.push(("order", meta.value()?.parse()?)),
+                "case" => rule.constraints.push(("case", meta.value()?.parse()?)),
+                "enum" => rule.constraints.push(("enum", meta.value()?.parse()?)),
+                "const" => rule.constraints.push(("const", meta.value()?.parse()?)),
+                "format" => rule.constraints.push(("format", meta.value()?.parse()?)),
+                "min_date" => rule.constraints.push(("minDate", meta.value()?.parse()?)),
+                "max_date" => rule.constraints.push(("maxDate", meta.value()?.parse()?)),
+                "multiple_of" => rule.constraints.push(("multipleOf", meta.value()?.parse()?)),
+                "exclusive_min" => rule.constraints.push(("exclusiveMin", meta.value()?.parse()?)),
+                "exclusive_max" => rule.constraints.push(("exclusiveMax", meta.value()?.parse()?)),
+                "positive" => rule.constraints.push(("positive", syn::parse_quote!(true))),
+                "non_negative" => rule.constraints.push(("nonNegative", syn::parse_quote!(true))),
+                "negative" => rule.constraints.push(("negative", syn::parse_quote!(true))),
+                "non_positive" => rule.constraints.push(("nonPositive", syn::parse_quote!(true))),
+                _ => return Err(meta.error(format!("unsupported rule attribute `{}`", key))),
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(rule)
+}
+
+fn field_rule_tokens(name: &str, ty: &Type, rule: &FieldRule) -> TokenStream2 {
+    let inner = option_inner(ty).unwrap_or(ty);
+    let field_type = rule.field_type.clone().or_else(|| {
+        if rule.nested && collection_inner(inner).is_none() {
+            Some("object".to_string())
+        } else {
+            infer_type(inner).map(String::from)
+        }
+    });
+
+    let type_tokens = field_type.map(|t| {
+        quote! { rule.insert("type".to_string(), ::link_validator::__private::json!(#t)); }
+    });
+    let required_tokens = rule.required.then(|| {
+        quote! { rule.insert("required".to_string(), ::link_validator::__private::Value::Bool(true)); }
+    });
+    let constraint_tokens = rule.constraints.iter().map(|(key, expr)| {
+        quote! { rule.insert(#key.to_string(), ::link_validator::__private::json!(#expr)); }
+    });
+    let nested_tokens = rule.nested.then(|| {
+        let nested_ty = collection_inner(inner).unwrap_or(inner);
+        quote! {
+            rule.insert(
+                "fields".to_string(),
+                <#nested_ty as ::link_validator::LinkSchema>::rules(),
+            );
+        }
+    });
+
+    quote! {
+        {
+            let mut rule = ::link_validator::__private::Map::new();
+            #type_tokens
+            #required_tokens
+            #(#constraint_tokens)*
+            #nested_tokens
+            rules.insert(#name.to_string(), ::link_validator::__private::Value::Object(rule));
+        }
+    }
+}
+
+/// 类型路径的最后一段名称及其泛型参数
+fn last_segment(ty: &Type) -> Option<&syn::PathSegment> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last(),
+        Type::Reference(reference) => last_segment(&reference.elem),
+        _ => None,
+    }
+}
+
+fn first_type_argument(segment: &syn::PathSegment) -> Option<&Type> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// `Option<T>` 中的 `T`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let segment = last_segment(ty)?;
+    (segment.ident == "Option").then(|| first_type_argument(segment)).flatten()
+}
+
+/// 序列类型（`Vec<T>` 等）中的元素类型 `T`
+fn collection_inner(ty: &Type) -> Option<&Type> {
+    let segment = last_segment(ty)?;
+    let name = segment.ident.to_string();
+    matches!(name.as_str(), "Vec" | "VecDeque" | "HashSet" | "BTreeSet")
+        .then(|| first_type_argument(segment))
+        .flatten()
+}
+
+/// 根据 Rust 类型推断 async-validator 类型
+fn infer_type(ty: &Type) -> Option<&'static str> {
+    if matches!(ty, Type::Array(_) | Type::Slice(_)) {
+        return Some("array");
+    }
+    let segment = last_segment(ty)?;
+    match segment.ident.to_string().as_str() {
+        "String" | "str" | "char" => Some("string"),
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+            Some("integer")
+        }
+        "f32" | "f64" => Some("number"),
+        "bool" => Some("boolean"),
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => Some("array"),
+        "HashMap" | "BTreeMap" => Some("object"),
+        _ => None,
+    }
+}
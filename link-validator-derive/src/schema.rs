@@ -0,0 +1,109 @@
+//! `link_schema!`：在编译期把 async-validator 规则转换为 JSON Schema 字符串
+
+use link_validator_core::__private::{LINK_SCHEMA_MARKER, LINK_SCHEMA_VERSION};
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use serde_json::{Map, Number, Value};
+use std::iter::Peekable;
+
+/// 展开为转换后的 JSON Schema 字符串字面量
+pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let mut tokens = input.into_iter().peekable();
+    let rules = parse_value(&mut tokens, Span::call_site())?;
+    if let Some(extra) = tokens.next() {
+        return Err(syn::Error::new(extra.span(), "unexpected token after rules"));
+    }
+
+    let conversion = link_validator_core::convert_with(&rules, &|pattern| {
+        fancy_regex::Regex::new(pattern)
+            .map(|_| ())
+            .map_err(|e| format!("invalid pattern {:?}: {}", pattern, e))
+    })
+    .map_err(|e| syn::Error::new(Span::call_site(), e))?;
+    // 宏无法输出警告，被忽略的规则视为错误
+    if let Some(warning) = conversion.warnings.first() {
+        return Err(syn::Error::new(Span::call_site(), &warning.message));
+    }
+    if !conversion.transforms.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "link_schema! does not support trim, case or truncate; compile these rules with LinkValidator::new",
+        ));
+    }
+
+    // 写入标记和格式版本，`from_link_schema` 据此拒绝不是由本宏生成的 schema
+    let mut schema = conversion.schema;
+    if let Value::Object(obj) = &mut schema {
+        obj.insert(LINK_SCHEMA_MARKER.to_string(), Value::from(LINK_SCHEMA_VERSION));
+    }
+    let schema = serde_json::to_string(&schema).expect("JSON Schema serializes to a string");
+    Ok(quote::quote! { #schema })
+}
+
+/// 解析一个 JSON 值（允许末尾多余的逗号）
+fn parse_value(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>, span: Span) -> syn::Result<Value> {
+    let token = tokens.next().ok_or_else(|| syn::Error::new(span, "expected a JSON value"))?;
+    match token {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+            let mut entries = group.stream().into_iter().peekable();
+            let mut obj = Map::new();
+            while let Some(key) = entries.next() {
+                let key = match &key {
+                    TokenTree::Literal(literal) => syn::parse_str::<syn::LitStr>(&literal.to_string())
+                        .map(|lit| lit.value())
+                        .map_err(|_| syn::Error::new(literal.span(), "expected a string key"))?,
+                    other => return Err(syn::Error::new(other.span(), "expected a string key")),
+                };
+                expect_punct(&mut entries, ':', group.span())?;
+                let value = parse_value(&mut entries, group.span())?;
+                obj.insert(key, value);
+                if entries.peek().is_some() {
+                    expect_punct(&mut entries, ',', group.span())?;
+                }
+            }
+            Ok(Value::Object(obj))
+        }
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Bracket => {
+            let mut items = group.stream().into_iter().peekable();
+            let mut array = Vec::new();
+            while items.peek().is_some() {
+                array.push(parse_value(&mut items, group.span())?);
+                if items.peek().is_some() {
+                    expect_punct(&mut items, ',', group.span())?;
+                }
+            }
+            Ok(Value::Array(array))
+        }
+        TokenTree::Ident(ident) => match ident.to_string().as_str() {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            "null" => Ok(Value::Null),
+            _ => Err(syn::Error::new(ident.span(), "expected a JSON value")),
+        },
+        TokenTree::Punct(punct) if punct.as_char() == '-' => match tokens.next() {
+            Some(TokenTree::Literal(literal)) => number(&format!("-{}", literal), literal.span()),
+            _ => Err(syn::Error::new(punct.span(), "expected a number after `-`")),
+        },
+        TokenTree::Literal(literal) => {
+            let text = literal.to_string();
+            match syn::parse_str::<syn::LitStr>(&text) {
+                Ok(lit) => Ok(Value::String(lit.value())),
+                Err(_) => number(&text, literal.span()),
+            }
+        }
+        other => Err(syn::Error::new(other.span(), "expected a JSON value")),
+    }
+}
+
+fn number(text: &str, span: Span) -> syn::Result<Value> {
+    serde_json::from_str::<Number>(text)
+        .map(Value::Number)
+        .map_err(|_| syn::Error::new(span, format!("invalid JSON number `{}`", text)))
+}
+
+fn expect_punct(tokens: &mut impl Iterator<Item = TokenTree>, expected: char, span: Span) -> syn::Result<()> {
+    match tokens.next() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == expected => Ok(()),
+        Some(other) => Err(syn::Error::new(other.span(), format!("expected `{}`", expected))),
+        None => Err(syn::Error::new(span, format!("expected `{}`", expected))),
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * link-validator C ABI
+ *
+ * Build the shared library with:
+ *
+ *     cargo build --release -p link-validator-ffi
+ *
+ * All inputs and outputs are UTF-8, NUL-terminated JSON strings.
+ */
+
+#ifndef LINK_VALIDATOR_H
+#define LINK_VALIDATOR_H
+
+/* Generated by cbindgen from link-validator-ffi/src/lib.rs. Do not edit. */
+
+/*
+ 调用结果状态码
+ */
+typedef enum LvStatus {
+  /*
+   成功
+   */
+  LV_STATUS_OK = 0,
+  /*
+   失败，错误信息写入输出参数
+   */
+  LV_STATUS_ERROR = 1,
+  /*
+   内部发生 panic，输出参数为空指针
+   */
+  LV_STATUS_PANIC = 2,
+} LvStatus;
+
+/*
+ 编译后的验证器，对 C 调用方不透明
+ */
+typedef struct LvValidator LvValidator;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ 编译 schema（JSON 字符串），验证器写入 `validator_out`
+
+ 成功时返回 `LV_STATUS_OK`；编译失败时返回 `LV_STATUS_ERROR`，若 `error_out` 非空，
+ 会写入错误信息字符串（需使用 `lv_free_result` 释放）。`validator_out` 为空指针时返回 `LV_STATUS_ERROR`。
+
+ # Safety
+
+ `schema_json` 必须为空指针或指向以 NUL 结尾的有效字符串；`validator_out` 和 `error_out`
+ 必须为空指针或指向可写的指针变量。
+ */
+enum LvStatus lv_compile(const char *schema_json,
+                         struct LvValidator **validator_out,
+                         char **error_out);
+
+/*
+ 使用验证器验证数据（JSON 字符串），结果写入 `result_out`
+
+ 结果为 JSON 字符串 `{"valid": bool, "errors": [...]}`，需使用 `lv_free_result` 释放。
+ 数据无法解析时返回 `LV_STATUS_OK`，结果中 `valid` 为 `false`，`errors` 中包含解析错误；
+ 验证器为空指针时返回 `LV_STATUS_ERROR`，错误同样写入结果。`result_out` 为空指针时返回 `LV_STATUS_ERROR`。
+
+ # Safety
+
+ `validator` 必须为空指针或 `lv_compile` 写出且尚未释放的指针；`data_json` 必须为空指针或指向以 NUL
+ 结尾的有效字符串；`result_out` 必须为空指针或指向可写的指针变量。
+ */
+enum LvStatus lv_validate(const struct LvValidator *validator,
+                          const char *data_json,
+                          char **result_out);
+
+/*
+ 释放 `lv_validate` 写出的结果字符串或 `lv_compile` 写出的错误字符串
+
+ # Safety
+
+ `result` 必须为空指针或本库写出且尚未释放的字符串。
+ */
+void lv_free_result(char *result);
+
+/*
+ 释放 `lv_compile` 写出的验证器
+
+ # Safety
+
+ `validator` 必须为空指针或 `lv_compile` 写出且尚未释放的指针。
+ */
+void lv_free_validator(struct LvValidator *validator);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* LINK_VALIDATOR_H */
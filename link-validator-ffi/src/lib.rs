@@ -0,0 +1,184 @@
+//! link-validator 的 C ABI
+//!
+//! 以 JSON 字符串为输入输出，便于在 Python/Go/C++ 等服务中嵌入验证器。
+//! C 头文件 `include/link_validator.h` 由 cbindgen 根据本文件生成并提交到仓库，`tests/header.rs` 检查其是否与本文件一致。
+//!
+//! 所有导出函数都会捕获 panic，不让其越过 FFI 边界；返回 `LvStatus` 的函数在 panic 时返回
+//! `LV_STATUS_PANIC`，输出参数保持为空指针。
+//!
+//! 内存约定：
+//! - `lv_compile` 写出的验证器必须使用 `lv_free_validator` 释放
+//! - `lv_validate` 写出的结果字符串以及 `lv_compile` 写出的错误字符串必须使用 `lv_free_result` 释放
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+use link_validator::LinkValidator;
+use serde_json::{json, Value};
+
+/// 调用结果状态码
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LvStatus {
+    /// 成功
+    Ok = 0,
+    /// 失败，错误信息写入输出参数
+    Error = 1,
+    /// 内部发生 panic，输出参数为空指针
+    Panic = 2,
+}
+
+/// 编译后的验证器，对 C 调用方不透明
+#[derive(Debug)]
+pub struct LvValidator(LinkValidator);
+
+impl From<LinkValidator> for LvValidator {
+    fn from(validator: LinkValidator) -> Self {
+        LvValidator(validator)
+    }
+}
+
+/// 执行 `f`，panic 时返回 `LvStatus::Panic`
+fn guard(f: impl FnOnce() -> LvStatus) -> LvStatus {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or(LvStatus::Panic)
+}
+
+/// 把 Rust 字符串转换为由调用方释放的 C 字符串
+fn into_c_string(s: String) -> *mut c_char {
+    // JSON 序列化结果和错误信息中的 NUL 字符会被替换，保证转换成功
+    CString::new(s.replace('\0', "\\u0000"))
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut())
+}
+
+/// 读取并解析 C 字符串中的 JSON
+///
+/// # Safety
+///
+/// `input` 必须为空指针或指向以 NUL 结尾的有效字符串。
+unsafe fn parse_json(input: *const c_char) -> Result<Value, String> {
+    if input.is_null() {
+        return Err("Input is a null pointer".to_string());
+    }
+    // SAFETY: 调用方保证 input 指向以 NUL 结尾的有效字符串
+    let text = unsafe { CStr::from_ptr(input) }
+        .to_str()
+        .map_err(|e| format!("Input is not valid UTF-8: {}", e))?;
+    serde_json::from_str(text).map_err(|e| format!("Failed to parse JSON: {}", e))
+}
+
+/// 编译 schema（JSON 字符串），验证器写入 `validator_out`
+///
+/// 成功时返回 `LV_STATUS_OK`；编译失败时返回 `LV_STATUS_ERROR`，若 `error_out` 非空，
+/// 会写入错误信息字符串（需使用 `lv_free_result` 释放）。`validator_out` 为空指针时返回 `LV_STATUS_ERROR`。
+///
+/// # Safety
+///
+/// `schema_json` 必须为空指针或指向以 NUL 结尾的有效字符串；`validator_out` 和 `error_out`
+/// 必须为空指针或指向可写的指针变量。
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lv_compile(
+    schema_json: *const c_char,
+    validator_out: *mut *mut LvValidator,
+    error_out: *mut *mut c_char,
+) -> LvStatus {
+    if !error_out.is_null() {
+        // SAFETY: 调用方保证 error_out 指向可写的指针变量
+        unsafe { *error_out = ptr::null_mut() };
+    }
+    if validator_out.is_null() {
+        return LvStatus::Error;
+    }
+    // SAFETY: 调用方保证 validator_out 指向可写的指针变量
+    unsafe { *validator_out = ptr::null_mut() };
+
+    guard(|| {
+        // SAFETY: 由调用方保证指针有效
+        let result = unsafe { parse_json(schema_json) }.and_then(|schema| LinkValidator::new(&schema));
+        match result {
+            Ok(validator) => {
+                // SAFETY: 上面已检查 validator_out 非空
+                unsafe { *validator_out = Box::into_raw(Box::new(LvValidator(validator))) };
+                LvStatus::Ok
+            }
+            Err(e) => {
+                if !error_out.is_null() {
+                    // SAFETY: 调用方保证 error_out 指向可写的指针变量
+                    unsafe { *error_out = into_c_string(e) };
+                }
+                LvStatus::Error
+            }
+        }
+    })
+}
+
+/// 使用验证器验证数据（JSON 字符串），结果写入 `result_out`
+///
+/// 结果为 JSON 字符串 `{"valid": bool, "errors": [...]}`，需使用 `lv_free_result` 释放。
+/// 数据无法解析时返回 `LV_STATUS_OK`，结果中 `valid` 为 `false`，`errors` 中包含解析错误；
+/// 验证器为空指针时返回 `LV_STATUS_ERROR`，错误同样写入结果。`result_out` 为空指针时返回 `LV_STATUS_ERROR`。
+///
+/// # Safety
+///
+/// `validator` 必须为空指针或 `lv_compile` 写出且尚未释放的指针；`data_json` 必须为空指针或指向以 NUL
+/// 结尾的有效字符串；`result_out` 必须为空指针或指向可写的指针变量。
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lv_validate(
+    validator: *const LvValidator,
+    data_json: *const c_char,
+    result_out: *mut *mut c_char,
+) -> LvStatus {
+    if result_out.is_null() {
+        return LvStatus::Error;
+    }
+    // SAFETY: 调用方保证 result_out 指向可写的指针变量
+    unsafe { *result_out = ptr::null_mut() };
+
+    guard(|| {
+        // SAFETY: 调用方保证 validator 为空或有效
+        let (output, status) = match unsafe { validator.as_ref() } {
+            None => (
+                json!({"valid": false, "errors": [{"message": "Validator is a null pointer"}]}),
+                LvStatus::Error,
+            ),
+            // SAFETY: 由调用方保证指针有效
+            Some(LvValidator(validator)) => match unsafe { parse_json(data_json) } {
+                Ok(data) => {
+                    let result = validator.validate(&data);
+                    (json!({"valid": result.is_valid, "errors": result.errors}), LvStatus::Ok)
+                }
+                Err(e) => (json!({"valid": false, "errors": [{"message": e}]}), LvStatus::Ok),
+            },
+        };
+        // SAFETY: 上面已检查 result_out 非空
+        unsafe { *result_out = into_c_string(output.to_string()) };
+        status
+    })
+}
+
+/// 释放 `lv_validate` 写出的结果字符串或 `lv_compile` 写出的错误字符串
+///
+/// # Safety
+///
+/// `result` 必须为空指针或本库写出且尚未释放的字符串。
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lv_free_result(result: *mut c_char) {
+    if !result.is_null() {
+        // SAFETY: 调用方保证指针由 CString::into_raw 创建且未被释放
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(unsafe { CString::from_raw(result) })));
+    }
+}
+
+/// 释放 `lv_compile` 写出的验证器
+///
+/// # Safety
+///
+/// `validator` 必须为空指针或 `lv_compile` 写出且尚未释放的指针。
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn lv_free_validator(validator: *mut LvValidator) {
+    if !validator.is_null() {
+        // SAFETY: 调用方保证指针由 Box::into_raw 创建且未被释放
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(unsafe { Box::from_raw(validator) })));
+    }
+}
@@ -0,0 +1,78 @@
+//! FFI tests for link-validator
+
+use link_validator::{LinkValidator, ValidationError};
+use link_validator_ffi::{lv_compile, lv_free_result, lv_free_validator, lv_validate, LvStatus, LvValidator};
+use serde_json::{json, Value};
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// 读取并释放结果字符串
+fn take_json(result: *mut c_char) -> Value {
+    let output = serde_json::from_str(unsafe { CStr::from_ptr(result) }.to_str().unwrap()).unwrap();
+    unsafe { lv_free_result(result) };
+    output
+}
+
+#[test]
+fn test_ffi_compile_and_validate() {
+    let schema = CString::new(r#"{"name": {"type": "string", "required": true, "min": 3}}"#).unwrap();
+    let mut validator = ptr::null_mut();
+    let status = unsafe { lv_compile(schema.as_ptr(), &mut validator, ptr::null_mut()) };
+    assert_eq!(status, LvStatus::Ok);
+    assert!(!validator.is_null());
+
+    for (data, expected) in [(r#"{"name": "john"}"#, true), (r#"{"name": "jo"}"#, false), ("not json", false)] {
+        let data = CString::new(data).unwrap();
+        let mut result = ptr::null_mut();
+        assert_eq!(unsafe { lv_validate(validator, data.as_ptr(), &mut result) }, LvStatus::Ok);
+        let output = take_json(result);
+        assert_eq!(output["valid"], expected);
+        if !expected {
+            assert!(!output["errors"].as_array().unwrap().is_empty());
+        }
+    }
+
+    unsafe { lv_free_validator(validator) };
+}
+
+#[test]
+fn test_ffi_compile_error() {
+    let schema = CString::new("42").unwrap();
+    let mut validator = ptr::null_mut();
+    let mut error = ptr::null_mut();
+    let status = unsafe { lv_compile(schema.as_ptr(), &mut validator, &mut error) };
+    assert_eq!(status, LvStatus::Error);
+    assert!(validator.is_null());
+    assert!(!error.is_null());
+
+    let message = unsafe { CStr::from_ptr(error) }.to_str().unwrap().to_string();
+    assert!(message.contains("Failed to compile schema"));
+    unsafe { lv_free_result(error) };
+}
+
+#[test]
+fn test_ffi_null_arguments() {
+    let schema = CString::new("{}").unwrap();
+    assert_eq!(unsafe { lv_compile(schema.as_ptr(), ptr::null_mut(), ptr::null_mut()) }, LvStatus::Error);
+
+    let data = CString::new("{}").unwrap();
+    assert_eq!(unsafe { lv_validate(ptr::null(), data.as_ptr(), ptr::null_mut()) }, LvStatus::Error);
+
+    let mut result = ptr::null_mut();
+    assert_eq!(unsafe { lv_validate(ptr::null(), data.as_ptr(), &mut result) }, LvStatus::Error);
+    assert_eq!(take_json(result)["errors"][0]["message"], "Validator is a null pointer");
+}
+
+#[test]
+fn test_ffi_panic_returns_status() {
+    let mut validator = LinkValidator::new(&json!({"name": {"type": "string"}})).unwrap();
+    validator.register_document_validator(|_: &Value| -> Vec<ValidationError> { panic!("document validator failed") });
+    let validator = Box::into_raw(Box::new(LvValidator::from(validator)));
+
+    let data = CString::new(r#"{"name": "john"}"#).unwrap();
+    let mut result = ptr::null_mut();
+    assert_eq!(unsafe { lv_validate(validator, data.as_ptr(), &mut result) }, LvStatus::Panic);
+    assert!(result.is_null());
+
+    unsafe { lv_free_validator(validator) };
+}
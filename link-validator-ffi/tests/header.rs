@@ -0,0 +1,30 @@
+//! C header tests for link-validator
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// 提交的头文件必须与 cbindgen 根据当前源码生成的一致
+///
+/// 修改导出函数后，设置环境变量 `LV_UPDATE_HEADER=1` 运行本测试以重新生成头文件。
+#[test]
+fn test_header_up_to_date() {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml")).expect("Failed to read cbindgen.toml");
+    let mut generated = Vec::new();
+    cbindgen::generate_with_config(&dir, config)
+        .expect("Failed to generate C header")
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).expect("Header is not valid UTF-8");
+
+    let path = dir.join("include/link_validator.h");
+    if env::var_os("LV_UPDATE_HEADER").is_some() {
+        fs::write(&path, &generated).expect("Failed to write header");
+        return;
+    }
+    let committed = fs::read_to_string(&path).expect("Failed to read header");
+    assert!(
+        committed == generated,
+        "include/link_validator.h is out of date, rerun this test with LV_UPDATE_HEADER=1"
+    );
+}
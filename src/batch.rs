@@ -0,0 +1,59 @@
+//! 批量验证
+
+use serde::{Deserialize, Serialize};
+
+use crate::ValidationResult;
+
+/// 批量验证结果汇总
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{BatchSummary, LinkValidator};
+/// use serde_json::json;
+///
+/// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}})).unwrap();
+/// let results = validator.validate_batch(&[json!({"name": "john"}), json!({})]);
+///
+/// let summary = BatchSummary::from_results(&results);
+/// assert_eq!(summary.valid, 1);
+/// assert_eq!(summary.invalid_indices, vec![1]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchSummary {
+    /// 数据总条数
+    pub total: usize,
+    /// 验证通过的条数
+    pub valid: usize,
+    /// 验证失败的条数
+    pub invalid: usize,
+    /// 全部数据的错误总数
+    pub error_count: usize,
+    /// 验证失败的数据下标（升序）
+    pub invalid_indices: Vec<usize>,
+}
+
+impl BatchSummary {
+    /// 汇总 `validate_batch` 返回的逐条结果
+    pub fn from_results(results: &[ValidationResult]) -> Self {
+        let mut summary = BatchSummary {
+            total: results.len(),
+            ..BatchSummary::default()
+        };
+        for (index, result) in results.iter().enumerate() {
+            summary.error_count += result.errors.as_array().map_or(0, Vec::len);
+            if result.is_valid {
+                summary.valid += 1;
+            } else {
+                summary.invalid += 1;
+                summary.invalid_indices.push(index);
+            }
+        }
+        summary
+    }
+
+    /// 是否全部验证通过
+    pub fn all_valid(&self) -> bool {
+        self.invalid == 0
+    }
+}
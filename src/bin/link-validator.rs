@@ -0,0 +1,118 @@
+//! link-validator 命令行工具
+//!
+//! 提供规则转换、数据验证和 schema 检查三个子命令，输出机器可读的 JSON，
+//! 并通过退出码表示结果，便于在 CI 流水线中使用：
+//!
+//! - `0`：成功（转换成功、数据验证通过、schema 有效）
+//! - `1`：数据验证失败或 schema 无效
+//! - `2`：参数错误、文件读取失败或 JSON 解析失败
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use link_validator::LinkValidator;
+use serde_json::{json, Value};
+
+#[derive(Parser)]
+#[command(name = "link-validator", version, about = "Convert async-validator rules to JSON Schema and validate data")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 把 async-validator 规则转换为 JSON Schema
+    Convert {
+        /// 规则文件路径
+        rules: PathBuf,
+    },
+    /// 使用 schema 验证数据
+    Validate {
+        /// schema 文件路径（JSON Schema 或 async-validator 规则）
+        #[arg(long)]
+        schema: PathBuf,
+        /// 数据文件路径
+        #[arg(long)]
+        data: PathBuf,
+    },
+    /// 检查 schema 能否被转换和编译
+    Check {
+        /// schema 文件路径
+        schema: PathBuf,
+    },
+}
+
+/// 命令执行失败（退出码 2）
+struct CliError(String);
+
+fn read_json(path: &Path) -> Result<Value, CliError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| CliError(format!("Failed to read '{}': {}", path.display(), e)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| CliError(format!("Failed to parse '{}': {}", path.display(), e)))
+}
+
+fn print_json(value: &Value) {
+    println!("{}", serde_json::to_string_pretty(value).unwrap_or_default());
+}
+
+fn run(command: Command) -> Result<bool, CliError> {
+    match command {
+        Command::Convert { rules } => {
+            let rules = read_json(&rules)?;
+            let validator = LinkValidator::new(&rules).map_err(CliError)?;
+            print_json(&json!({
+                "format": validator.format().as_str(),
+                "schema": validator.json_schema(),
+                "warnings": validator.warnings(),
+            }));
+            Ok(true)
+        }
+        Command::Validate { schema, data } => {
+            let schema = read_json(&schema)?;
+            let data = read_json(&data)?;
+            let validator = LinkValidator::new(&schema).map_err(CliError)?;
+            let result = validator.validate(&data);
+            print_json(&json!({
+                "valid": result.is_valid,
+                "errors": result.errors,
+            }));
+            Ok(result.is_valid)
+        }
+        Command::Check { schema } => {
+            let schema = read_json(&schema)?;
+            match LinkValidator::new(&schema) {
+                Ok(validator) => {
+                    print_json(&json!({
+                        "valid": true,
+                        "format": validator.format().as_str(),
+                        "warnings": validator.warnings(),
+                    }));
+                    Ok(true)
+                }
+                Err(e) => {
+                    print_json(&json!({
+                        "valid": false,
+                        "error": e,
+                    }));
+                    Ok(false)
+                }
+            }
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(CliError(message)) => {
+            print_json(&json!({ "error": message }));
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! 验证结果缓存与验证器缓存
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{CompileOptions, LinkValidator, ValidationError, ValidationResult};
+
+/// 计算 JSON 值的哈希（基于规范化后的序列化结果，对象键有序）
+pub(crate) fn hash_value(value: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.to_string().hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    /// 原始数据，用于排除哈希冲突
+    data: Value,
+    result: ValidationResult,
+    /// 结果中的错误，命中时用于生成验证事件
+    errors: Vec<ValidationError>,
+    inserted_at: Instant,
+    last_used: u64,
+}
+
+/// 有容量上限和过期时间的验证结果缓存（按最近最少使用淘汰）
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: HashMap<u64, CacheEntry>,
+    tick: u64,
+}
+
+impl ResultCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        ResultCache {
+            capacity,
+            ttl,
+            entries: HashMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// 查找缓存的验证结果，过期条目会被移除
+    pub(crate) fn get(&mut self, key: u64, data: &Value) -> Option<(ValidationResult, Vec<ValidationError>)> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(&key)?;
+        if entry.inserted_at.elapsed() > self.ttl {
+            self.entries.remove(&key);
+            return None;
+        }
+        if entry.data != *data {
+            return None;
+        }
+        entry.last_used = self.tick;
+        Some((entry.result.clone(), entry.errors.clone()))
+    }
+
+    /// 写入验证结果，超出容量时淘汰最近最少使用的条目
+    pub(crate) fn insert(&mut self, key: u64, data: &Value, result: &ValidationResult, errors: &[ValidationError]) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.tick += 1;
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            let ttl = self.ttl;
+            self.entries.retain(|_, e| e.inserted_at.elapsed() <= ttl);
+            if self.entries.len() >= self.capacity
+                && let Some(oldest) = self.entries.iter().min_by_key(|(_, e)| e.last_used).map(|(k, _)| *k)
+            {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.entries.insert(key, CacheEntry {
+            data: data.clone(),
+            result: result.clone(),
+            errors: errors.to_vec(),
+            inserted_at: Instant::now(),
+            last_used: self.tick,
+        });
+    }
+
+    /// 创建容量和过期时间相同的空缓存
+    pub(crate) fn empty_like(&self) -> Self {
+        ResultCache::new(self.capacity, self.ttl)
+    }
+
+    /// 清空缓存
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// 当前缓存条目数量
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+#[derive(Debug)]
+struct ValidatorEntry {
+    /// 原始 schema 和编译选项，用于排除哈希冲突
+    schema: Value,
+    options: Value,
+    validator: Arc<LinkValidator>,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct ValidatorCacheState {
+    entries: HashMap<u64, ValidatorEntry>,
+    tick: u64,
+}
+
+/// 按 schema 哈希复用已编译验证器的缓存（按最近最少使用淘汰）
+///
+/// 适用于每个请求动态携带 schema 的服务，相同的 schema（对象键顺序无关）只编译一次。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::ValidatorCache;
+/// use serde_json::json;
+/// use std::sync::Arc;
+///
+/// let cache = ValidatorCache::new(100);
+/// let schema = json!({"name": {"type": "string", "required": true}});
+///
+/// let first = cache.get_or_compile(&schema).unwrap();
+/// let second = cache.get_or_compile(&schema).unwrap();
+/// assert!(Arc::ptr_eq(&first, &second));
+/// ```
+#[derive(Debug)]
+pub struct ValidatorCache {
+    capacity: usize,
+    state: Mutex<ValidatorCacheState>,
+}
+
+impl ValidatorCache {
+    /// 创建最多保存 `capacity` 个验证器的缓存
+    pub fn new(capacity: usize) -> Self {
+        ValidatorCache {
+            capacity,
+            state: Mutex::new(ValidatorCacheState::default()),
+        }
+    }
+
+    /// 获取 schema 对应的验证器，未缓存时编译并加入缓存
+    pub fn get_or_compile(&self, schema: &Value) -> Result<Arc<LinkValidator>, String> {
+        self.get_or_compile_with(schema, &CompileOptions::default())
+    }
+
+    /// 使用编译选项获取验证器，schema 和编译选项都相同时才会复用
+    ///
+    /// 编译失败的 schema 不会被缓存。编译在锁外进行，不会阻塞其他 schema 的查询。
+    pub fn get_or_compile_with(&self, schema: &Value, options: &CompileOptions) -> Result<Arc<LinkValidator>, String> {
+        // 资源限制也参与缓存键，避免绕过更严格的限制复用验证器
+        let options_value = json!([options.to_value(), options.limits_value()]);
+        let mut hasher = DefaultHasher::new();
+        hash_value(schema).hash(&mut hasher);
+        hash_value(&options_value).hash(&mut hasher);
+        let key = hasher.finish();
+
+        {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some(entry) = state.entries.get_mut(&key)
+                && entry.schema == *schema
+                && entry.options == options_value
+            {
+                entry.last_used = tick;
+                return Ok(entry.validator.clone());
+            }
+        }
+
+        let validator = Arc::new(LinkValidator::with_options(schema, options)?);
+        if self.capacity == 0 {
+            return Ok(validator);
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.tick += 1;
+        if !state.entries.contains_key(&key)
+            && state.entries.len() >= self.capacity
+            && let Some(oldest) = state.entries.iter().min_by_key(|(_, e)| e.last_used).map(|(k, _)| *k)
+        {
+            state.entries.remove(&oldest);
+        }
+        let last_used = state.tick;
+        state.entries.insert(key, ValidatorEntry {
+            schema: schema.clone(),
+            options: options_value,
+            validator: validator.clone(),
+            last_used,
+        });
+        Ok(validator)
+    }
+
+    /// 当前缓存的验证器数量
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).entries.len()
+    }
+
+    /// 缓存是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 清空缓存
+    pub fn clear(&self) {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).entries.clear();
+    }
+}
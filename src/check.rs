@@ -0,0 +1,80 @@
+//! 规则和 schema 的试编译检查
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{compile, convert_rules, is_async_rules, manifest, CompileOptions, ConversionWarning, SchemaFormat};
+
+/// 试编译检查结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckReport {
+    /// 检测到的 schema 格式
+    pub format: SchemaFormat,
+    /// 是否可以编译为验证器
+    pub valid: bool,
+    /// 规则转换的警告，例如不支持的规则（与 `LinkValidator::warnings()` 相同）
+    pub warnings: Vec<ConversionWarning>,
+    /// 生成的 JSON Schema 中无法识别的关键字（验证时忽略，通常是拼写错误或扩展关键字）
+    pub unknown_keywords: Vec<String>,
+    /// 编译失败的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CheckReport {
+    /// 是否可以编译且没有任何警告
+    pub fn is_clean(&self) -> bool {
+        self.valid && self.warnings.is_empty() && self.unknown_keywords.is_empty()
+    }
+}
+
+/// 检测格式、转换并编译规则或 JSON Schema，但不返回验证器
+///
+/// 与 `LinkValidator::new` 使用相同的编译流程（默认编译选项），适合在 CI 中检查规则文件：
+/// 编译失败时 `valid` 为 `false`，`error` 给出原因。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{check, SchemaFormat};
+/// use serde_json::json;
+///
+/// let report = check(&json!({
+///     "name": {"type": "string", "required": true, "validator": "fn"}
+/// }));
+///
+/// assert_eq!(report.format, SchemaFormat::AsyncValidator);
+/// assert!(report.valid);
+/// assert_eq!(report.warnings[0].message, "Field 'name': validator function not supported");
+/// assert!(!report.is_clean());
+/// ```
+pub fn check(schema: &Value) -> CheckReport {
+    let format = if is_async_rules(schema) { SchemaFormat::AsyncValidator } else { SchemaFormat::JsonSchema };
+    match compile(schema, &CompileOptions::default()) {
+        Ok(validator) => CheckReport {
+            format,
+            valid: true,
+            unknown_keywords: manifest::custom_keywords(&validator.json_schema).into_iter().collect(),
+            warnings: validator.warnings,
+            error: None,
+        },
+        Err(error) => {
+            // 编译失败时仍尽量给出转换结果中的警告和未识别关键字
+            let converted = match format {
+                SchemaFormat::AsyncValidator => convert_rules(schema).ok().map(|c| (c.schema, c.unsupported)),
+                _ => Some((schema.clone(), Vec::new())),
+            };
+            let (warnings, unknown_keywords) = match converted {
+                Some((converted, warnings)) => (warnings, manifest::custom_keywords(&converted).into_iter().collect()),
+                None => (Vec::new(), Vec::new()),
+            };
+            CheckReport {
+                format,
+                valid: false,
+                warnings,
+                unknown_keywords,
+                error: Some(error),
+            }
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! 由 schema 生成 Rust 类型定义
+
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::keywords;
+
+/// Rust 关键字，用作字段名时需要使用原始标识符
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do",
+    "final", "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try", "gen", "self", "super",
+];
+
+/// `LinkValidator::generate_rust_types` 的生成选项
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{CodegenOptions, LinkValidator};
+/// use serde_json::json;
+///
+/// let validator = LinkValidator::new(&json!({"userName": {"type": "string", "required": true}})).unwrap();
+/// let code = validator.generate_rust_types(&CodegenOptions::new().root_name("User"));
+///
+/// assert!(code.contains("pub struct User {"));
+/// assert!(code.contains("#[serde(rename = \"userName\")]"));
+/// assert!(code.contains("pub user_name: String,"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    root_name: Option<String>,
+    derives: Vec<String>,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        CodegenOptions {
+            root_name: None,
+            derives: ["Debug", "Clone", "Serialize", "Deserialize"].map(String::from).to_vec(),
+        }
+    }
+}
+
+impl CodegenOptions {
+    /// 创建默认生成选项（派生 `Debug`、`Clone`、`Serialize`、`Deserialize`）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 顶层类型名称，默认使用验证器名称，未设置名称时为 `Root`
+    pub fn root_name(mut self, name: impl Into<String>) -> Self {
+        self.root_name = Some(name.into());
+        self
+    }
+
+    /// 追加派生的 trait，例如 `PartialEq`
+    pub fn derive(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        if !self.derives.contains(&name) {
+            self.derives.push(name);
+        }
+        self
+    }
+}
+
+/// 生成 Rust 类型定义
+pub(crate) fn generate(schema: &Value, name: Option<&str>, options: &CodegenOptions) -> String {
+    let root = options
+        .root_name
+        .as_deref()
+        .or(name)
+        .map(pascal_case)
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "Root".to_string());
+
+    let mut generator = Generator {
+        options,
+        names: BTreeSet::from([root.clone()]),
+        items: Vec::new(),
+    };
+    // 先占用定义名称，保证引用与定义一致
+    let definitions: Vec<(String, &Value)> = ["definitions", "$defs"]
+        .iter()
+        .filter_map(|k| schema.get(*k).and_then(Value::as_object))
+        .flat_map(|defs| defs.iter())
+        .map(|(name, schema)| (generator.reserve(&pascal_case(name)), schema))
+        .collect();
+
+    let root_type = generator.named_type(schema, &root);
+    if root_type != root {
+        generator.items.insert(0, format!("pub type {} = {};\n", root, root_type));
+    }
+    for (name, schema) in definitions {
+        let definition_type = generator.named_type(schema, &name);
+        if definition_type != name {
+            generator.items.push(format!("pub type {} = {};\n", name, definition_type));
+        }
+    }
+
+    let mut code = String::from("// Generated by link-validator. Do not edit.\n\nuse serde::{Deserialize, Serialize};\n");
+    for item in generator.items {
+        code.push('\n');
+        code.push_str(&item);
+    }
+    code
+}
+
+struct Generator<'a> {
+    options: &'a CodegenOptions,
+    /// 已使用的类型名称
+    names: BTreeSet<String>,
+    /// 已生成的类型定义
+    items: Vec<String>,
+}
+
+impl Generator<'_> {
+    /// 占用类型名称，重名时追加序号
+    fn reserve(&mut self, name: &str) -> String {
+        let mut candidate = name.to_string();
+        let mut index = 2;
+        while !self.names.insert(candidate.clone()) {
+            candidate = format!("{}{}", name, index);
+            index += 1;
+        }
+        candidate
+    }
+
+    /// 名称已被占用的类型（顶层和定义）：需要生成结构体或枚举时直接使用该名称
+    fn named_type(&mut self, schema: &Value, name: &str) -> String {
+        self.type_for(schema, name, Some(name))
+    }
+
+    /// 返回 schema 对应的 Rust 类型，需要时生成新的结构体或枚举
+    ///
+    /// `hint` 为新类型的名称建议，`reserved` 为已占用的名称。
+    fn type_for(&mut self, schema: &Value, hint: &str, reserved: Option<&str>) -> String {
+        let Some(obj) = schema.as_object() else {
+            return "serde_json::Value".to_string();
+        };
+
+        if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+            return reference
+                .strip_prefix("#/definitions/")
+                .or_else(|| reference.strip_prefix("#/$defs/"))
+                .map(pascal_case)
+                .unwrap_or_else(|| "serde_json::Value".to_string());
+        }
+
+        if let Some(Value::Array(values)) = obj.get("enum") {
+            return self.enum_type(values, hint, reserved);
+        }
+
+        let (types, nullable) = match obj.get("type") {
+            Some(Value::String(t)) => (vec![t.as_str()], false),
+            Some(Value::Array(ts)) => {
+                let names: Vec<&str> = ts.iter().filter_map(Value::as_str).collect();
+                let nullable = names.contains(&"null");
+                (names.into_iter().filter(|t| *t != "null").collect(), nullable)
+            }
+            _ if obj.contains_key("properties") => (vec!["object"], false),
+            _ => (Vec::new(), false),
+        };
+
+        let base = match types.as_slice() {
+            ["string"] => "String".to_string(),
+            ["integer"] => "i64".to_string(),
+            ["number"] => "f64".to_string(),
+            ["boolean"] => "bool".to_string(),
+            ["array"] => {
+                let item = match obj.get("items") {
+                    Some(items @ Value::Object(_)) => self.type_for(items, &format!("{}Item", hint), None),
+                    _ => "serde_json::Value".to_string(),
+                };
+                format!("Vec<{}>", item)
+            }
+            ["object"] => self.object_type(obj, hint, reserved),
+            _ => "serde_json::Value".to_string(),
+        };
+        if nullable { format!("Option<{}>", base) } else { base }
+    }
+
+    /// 对象类型：有属性时生成结构体，`itemsBy` 分支生成带标签的枚举，否则为映射
+    fn object_type(&mut self, obj: &Map<String, Value>, hint: &str, reserved: Option<&str>) -> String {
+        if let Some((field, variants)) = keywords::discriminated_branches(obj) {
+            return self.tagged_enum(obj, &field, &variants, hint, reserved);
+        }
+        match obj.get("properties").and_then(Value::as_object) {
+            Some(properties) if !properties.is_empty() => {
+                let name = reserved.map(String::from).unwrap_or_else(|| self.reserve(hint));
+                let index = self.items.len();
+                self.items.push(String::new());
+                let body = self.struct_fields(properties, required(obj), &name, None);
+                let mut item = self.derive_line();
+                if obj.get("additionalProperties") == Some(&Value::Bool(false)) {
+                    item.push_str("#[serde(deny_unknown_fields)]\n");
+                }
+                let _ = write!(item, "pub struct {} {{\n{}}}\n", name, body);
+                self.items[index] = item;
+                name
+            }
+            _ => match obj.get("additionalProperties") {
+                Some(value @ Value::Object(_)) => {
+                    let value_type = self.type_for(value, &format!("{}Value", hint), None);
+                    format!("std::collections::BTreeMap<String, {}>", value_type)
+                }
+                _ => "serde_json::Map<String, serde_json::Value>".to_string(),
+            },
+        }
+    }
+
+    /// 生成结构体字段，`skip` 为不生成的字段（判别字段）
+    fn struct_fields(&mut self, properties: &Map<String, Value>, required: Vec<&str>, parent: &str, skip: Option<&str>) -> String {
+        let mut body = String::new();
+        let mut idents = BTreeSet::new();
+        for (key, property) in properties {
+            if Some(key.as_str()) == skip {
+                continue;
+            }
+            let field_type = self.type_for(property, &format!("{}{}", parent, pascal_case(key)), None);
+            let optional = !required.contains(&key.as_str()) && !field_type.starts_with("Option<");
+
+            for line in ["title", "description"].iter().filter_map(|k| property.get(*k).and_then(Value::as_str)) {
+                for line in line.lines() {
+                    let _ = writeln!(body, "    /// {}", line);
+                }
+            }
+            // 不同字段名转换后相同时（例如 `a-b` 和 `a_b`）追加序号
+            let base = field_ident(key);
+            let mut ident = base.clone();
+            let mut index = 2;
+            while !idents.insert(ident.clone()) {
+                ident = format!("{}_{}", base, index);
+                index += 1;
+            }
+            let mut attrs = Vec::new();
+            if ident.trim_start_matches("r#") != key {
+                attrs.push(format!("rename = {:?}", key));
+            }
+            if optional {
+                attrs.push("default, skip_serializing_if = \"Option::is_none\"".to_string());
+            }
+            if !attrs.is_empty() {
+                let _ = writeln!(body, "    #[serde({})]", attrs.join(", "));
+            }
+            let field_type = if optional { format!("Option<{}>", field_type) } else { field_type };
+            let _ = writeln!(body, "    pub {}: {},", ident, field_type);
+        }
+        body
+    }
+
+    /// 字符串枚举生成 Rust 枚举，其他枚举使用 `serde_json::Value`
+    fn enum_type(&mut self, values: &[Value], hint: &str, reserved: Option<&str>) -> String {
+        let Some(strings) = values.iter().map(Value::as_str).collect::<Option<Vec<&str>>>() else {
+            return "serde_json::Value".to_string();
+        };
+        let variants: Vec<String> = strings.iter().map(|s| variant_ident(s)).collect();
+        // 变体名称冲突（例如 `a-b` 和 `a_b`）时无法生成枚举
+        if strings.is_empty() || variants.iter().collect::<BTreeSet<_>>().len() != variants.len() {
+            return "serde_json::Value".to_string();
+        }
+
+        let name = reserved.map(String::from).unwrap_or_else(|| self.reserve(hint));
+        let mut item = self.derive_line();
+        let _ = writeln!(item, "pub enum {} {{", name);
+        for (value, variant) in strings.iter().zip(&variants) {
+            if variant != value {
+                let _ = writeln!(item, "    #[serde(rename = {:?})]", value);
+            }
+            let _ = writeln!(item, "    {},", variant);
+        }
+        item.push_str("}\n");
+        self.items.push(item);
+        name
+    }
+
+    /// `itemsBy` 分支生成内部标签枚举，每个分支生成一个结构体（包含公共字段）
+    fn tagged_enum(&mut self, obj: &Map<String, Value>, field: &str, variants: &[(Value, Value)], hint: &str, reserved: Option<&str>) -> String {
+        let name = reserved.map(String::from).unwrap_or_else(|| self.reserve(hint));
+        let index = self.items.len();
+        self.items.push(String::new());
+
+        let common = obj.get("properties").and_then(Value::as_object).cloned().unwrap_or_default();
+        let common_required = required(obj);
+        let mut item = self.derive_line();
+        let _ = writeln!(item, "#[serde(tag = {:?})]", field);
+        let _ = writeln!(item, "pub enum {} {{", name);
+        for (tag, schema) in variants {
+            let tag = match tag {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            let variant = variant_ident(&tag);
+            let struct_name = self.reserve(&format!("{}{}", name, variant));
+
+            let mut properties = common.clone();
+            if let Some(own) = schema.get("properties").and_then(Value::as_object) {
+                properties.extend(own.iter().map(|(k, v)| (k.clone(), v.clone())));
+            }
+            let mut all_required = common_required.clone();
+            all_required.extend(schema.as_object().map(required).unwrap_or_default());
+
+            let struct_index = self.items.len();
+            self.items.push(String::new());
+            let body = self.struct_fields(&properties, all_required, &struct_name, Some(field));
+            let mut variant_item = self.derive_line();
+            let _ = write!(variant_item, "pub struct {} {{\n{}}}\n", struct_name, body);
+            self.items[struct_index] = variant_item;
+
+            if variant != tag {
+                let _ = writeln!(item, "    #[serde(rename = {:?})]", tag);
+            }
+            let _ = writeln!(item, "    {}({}),", variant, struct_name);
+        }
+        item.push_str("}\n");
+        self.items[index] = item;
+        name
+    }
+
+    fn derive_line(&self) -> String {
+        format!("#[derive({})]\n", self.options.derives.join(", "))
+    }
+}
+
+/// 对象的必填字段
+pub(crate) fn required(obj: &Map<String, Value>) -> Vec<&str> {
+    obj.get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// 拆分字段名中的单词（camelCase、snake_case、kebab-case 及其他非字母数字分隔符）
+pub(crate) fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev = None;
+            continue;
+        }
+        if c.is_uppercase() && !current.is_empty() && prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+            words.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+        prev = Some(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// 转换为 PascalCase 类型名称
+pub(crate) fn pascal_case(name: &str) -> String {
+    let mut result: String = words(name)
+        .iter()
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    if result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    result
+}
+
+/// 枚举变体名称
+fn variant_ident(value: &str) -> String {
+    let name = pascal_case(value);
+    if name.is_empty() { "Empty".to_string() } else { name }
+}
+
+/// 结构体字段名称（snake_case，关键字使用原始标识符）
+fn field_ident(key: &str) -> String {
+    let mut name = words(key).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_");
+    if name.is_empty() {
+        name = "field".to_string();
+    }
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if RUST_KEYWORDS.contains(&name.as_str()) {
+        // 这些关键字不能作为原始标识符
+        if matches!(name.as_str(), "self" | "super" | "crate") {
+            name.push('_');
+        } else {
+            name.insert_str(0, "r#");
+        }
+    }
+    name
+}
@@ -0,0 +1,176 @@
+//! 错误码与约束参数
+//!
+//! 每个验证错误都带有稳定的 `code`，前端可以按错误码映射到自己的本地化文案，而不必解析英文错误信息。
+//! 错误码在版本之间保持不变，新增的关键字只会增加新的错误码。
+
+use jsonschema::error::{TypeKind, ValidationErrorKind};
+use serde_json::{json, Map, Value};
+
+/// 全部错误码
+///
+/// | 错误码 | 含义 |
+/// |---|---|
+/// | `required` | 缺少必填字段 |
+/// | `type.mismatch` | 类型不符 |
+/// | `enum.mismatch` | 不在枚举值中 |
+/// | `const.mismatch` | 不等于固定值 |
+/// | `pattern.mismatch` | 不匹配正则 |
+/// | `pattern.timeout` | 正则匹配超出回溯限制 |
+/// | `format.<name>` | 不符合格式，例如 `format.email`、`format.date-time` |
+/// | `string.min` / `string.max` | 字符串长度超出范围 |
+/// | `number.min` / `number.max` | 数值超出范围 |
+/// | `number.exclusiveMin` / `number.exclusiveMax` | 数值超出开区间范围 |
+/// | `number.multipleOf` | 不是指定值的倍数 |
+/// | `array.min` / `array.max` | 数组长度超出范围 |
+/// | `array.unique` | 数组元素重复 |
+/// | `array.contains` | 数组中没有满足 `contains` 的元素 |
+/// | `array.additionalItems` | 元组形式的数组元素过多 |
+/// | `object.min` / `object.max` | 对象的键数量超出范围 |
+/// | `object.additionalProperties` | 不允许的额外字段 |
+/// | `object.propertyNames` | 对象的键不符合规则 |
+/// | `date.min` / `date.max` | 日期超出范围 |
+/// | `union.noMatch` | 没有匹配的分支 |
+/// | `union.ambiguous` | 匹配了多个 `oneOf` 分支 |
+/// | `not.matched` | 匹配了 `not` 中的 schema |
+/// | `schema.false` | schema 为 `false`，不接受任何值 |
+/// | `content.encoding` / `content.mediaType` | 内容编码或媒体类型不符 |
+/// | `schema.invalid` | schema 本身无法用于验证（例如无法解析的引用） |
+/// | `schema.unknownKeyword` | schema 中无法识别的关键字（`validate_meta`） |
+/// | `deserialize` | 验证通过但无法反序列化为目标类型 |
+/// | `serialize` | 无法序列化为 JSON（`validate_serialize`） |
+/// | `parse.json` | 输入不是合法的 JSON（`validate_str` / `validate_slice`） |
+/// | `parse.form` | 表单键无法解析或相互冲突（`validate_form`） |
+/// | `patch.apply` | JSON Patch 无法应用到当前文档（`validate_patch`） |
+/// | `request.json` / `request.contentType` | 请求体不是 JSON（Web 集成） |
+/// | `custom` | 文档级验证器等自定义错误的默认错误码 |
+pub const ERROR_CODES: &[&str] = &[
+    "required",
+    "type.mismatch",
+    "enum.mismatch",
+    "const.mismatch",
+    "pattern.mismatch",
+    "pattern.timeout",
+    "format.<name>",
+    "string.min",
+    "string.max",
+    "number.min",
+    "number.max",
+    "number.exclusiveMin",
+    "number.exclusiveMax",
+    "number.multipleOf",
+    "array.min",
+    "array.max",
+    "array.unique",
+    "array.contains",
+    "array.additionalItems",
+    "object.min",
+    "object.max",
+    "object.additionalProperties",
+    "object.propertyNames",
+    "date.min",
+    "date.max",
+    "union.noMatch",
+    "union.ambiguous",
+    "not.matched",
+    "schema.false",
+    "content.encoding",
+    "content.mediaType",
+    "schema.invalid",
+    "schema.unknownKeyword",
+    "deserialize",
+    "serialize",
+    "parse.json",
+    "parse.form",
+    "patch.apply",
+    "request.json",
+    "request.contentType",
+    "custom",
+];
+
+/// 自定义错误的默认错误码
+pub(crate) const CUSTOM: &str = "custom";
+
+/// schema 中无法识别的关键字
+pub(crate) const UNKNOWN_KEYWORD: &str = "schema.unknownKeyword";
+
+/// JSON Schema 验证错误对应的错误码
+pub(crate) fn of(kind: &ValidationErrorKind) -> String {
+    let code = match kind {
+        ValidationErrorKind::Required { .. } => "required",
+        ValidationErrorKind::Type { .. } => "type.mismatch",
+        ValidationErrorKind::Enum { .. } => "enum.mismatch",
+        ValidationErrorKind::Constant { .. } => "const.mismatch",
+        ValidationErrorKind::Pattern { .. } => "pattern.mismatch",
+        ValidationErrorKind::BacktrackLimitExceeded { .. } => "pattern.timeout",
+        ValidationErrorKind::Format { format } => return format!("format.{}", format),
+        ValidationErrorKind::MinLength { .. } => "string.min",
+        ValidationErrorKind::MaxLength { .. } => "string.max",
+        ValidationErrorKind::Minimum { .. } => "number.min",
+        ValidationErrorKind::Maximum { .. } => "number.max",
+        ValidationErrorKind::ExclusiveMinimum { .. } => "number.exclusiveMin",
+        ValidationErrorKind::ExclusiveMaximum { .. } => "number.exclusiveMax",
+        ValidationErrorKind::MultipleOf { .. } => "number.multipleOf",
+        ValidationErrorKind::MinItems { .. } => "array.min",
+        ValidationErrorKind::MaxItems { .. } => "array.max",
+        ValidationErrorKind::UniqueItems => "array.unique",
+        ValidationErrorKind::Contains => "array.contains",
+        ValidationErrorKind::AdditionalItems { .. } => "array.additionalItems",
+        ValidationErrorKind::MinProperties { .. } => "object.min",
+        ValidationErrorKind::MaxProperties { .. } => "object.max",
+        ValidationErrorKind::AdditionalProperties { .. } | ValidationErrorKind::UnevaluatedProperties { .. } => {
+            "object.additionalProperties"
+        }
+        ValidationErrorKind::PropertyNames { .. } => "object.propertyNames",
+        ValidationErrorKind::AnyOf | ValidationErrorKind::OneOfNotValid => "union.noMatch",
+        ValidationErrorKind::OneOfMultipleValid => "union.ambiguous",
+        ValidationErrorKind::Not { .. } => "not.matched",
+        ValidationErrorKind::FalseSchema => "schema.false",
+        ValidationErrorKind::ContentEncoding { .. } => "content.encoding",
+        ValidationErrorKind::ContentMediaType { .. } => "content.mediaType",
+        _ => "schema.invalid",
+    };
+    code.to_string()
+}
+
+/// JSON Schema 验证错误的约束参数，例如 `{"limit": 3, "actual": 2}`
+pub(crate) fn params(kind: &ValidationErrorKind, instance: &Value) -> Map<String, Value> {
+    let params = match kind {
+        ValidationErrorKind::MinLength { limit } | ValidationErrorKind::MaxLength { limit } => {
+            let actual = instance.as_str().map_or(0, |s| s.chars().count());
+            json!({"limit": limit, "actual": actual})
+        }
+        ValidationErrorKind::MinItems { limit } | ValidationErrorKind::MaxItems { limit } => {
+            let actual = instance.as_array().map_or(0, Vec::len);
+            json!({"limit": limit, "actual": actual})
+        }
+        ValidationErrorKind::MinProperties { limit } | ValidationErrorKind::MaxProperties { limit } => {
+            let actual = instance.as_object().map_or(0, Map::len);
+            json!({"limit": limit, "actual": actual})
+        }
+        ValidationErrorKind::Minimum { limit }
+        | ValidationErrorKind::Maximum { limit }
+        | ValidationErrorKind::ExclusiveMinimum { limit }
+        | ValidationErrorKind::ExclusiveMaximum { limit } => json!({"limit": limit}),
+        ValidationErrorKind::MultipleOf { multiple_of } => json!({"multipleOf": multiple_of}),
+        ValidationErrorKind::Required { property } => json!({"property": property}),
+        ValidationErrorKind::Enum { options } => json!({"allowed": options}),
+        ValidationErrorKind::Constant { expected_value } => json!({"expected": expected_value}),
+        ValidationErrorKind::Pattern { pattern } => json!({"pattern": pattern}),
+        ValidationErrorKind::Format { format } => json!({"format": format}),
+        ValidationErrorKind::Type { kind } => {
+            let expected: Vec<String> = match kind {
+                TypeKind::Single(primitive) => vec![primitive.to_string()],
+                TypeKind::Multiple(primitives) => primitives.into_iter().map(|p| p.to_string()).collect(),
+            };
+            json!({"expected": expected})
+        }
+        ValidationErrorKind::AdditionalProperties { unexpected } | ValidationErrorKind::UnevaluatedProperties { unexpected } => {
+            json!({"unexpected": unexpected})
+        }
+        _ => return Map::new(),
+    };
+    match params {
+        Value::Object(params) => params,
+        _ => Map::new(),
+    }
+}
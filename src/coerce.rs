@@ -0,0 +1,172 @@
+//! 类型强制转换
+//!
+//! 在验证前把字符串形式的输入（例如来自电子表格、表单、CSV）转换为 schema 声明的类型。
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+/// 本地化数字字符串的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NumberFormat {
+    /// 小数点为 `.`，千位分隔符为 `,`，例如 `"1,234.56"`
+    DecimalPoint,
+    /// 小数点为 `,`，千位分隔符为 `.`，例如 `"1.234,56"`
+    DecimalComma,
+    /// JSON 数字语法，不含千位分隔符，例如 `"42"`、`"-0.5"`、`"1e3"`
+    Plain,
+}
+
+impl NumberFormat {
+    /// 用于清单等输出的名称
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            NumberFormat::DecimalPoint => "decimal-point",
+            NumberFormat::DecimalComma => "decimal-comma",
+            NumberFormat::Plain => "plain",
+        }
+    }
+
+    fn separators(self) -> (char, char) {
+        match self {
+            NumberFormat::DecimalPoint | NumberFormat::Plain => ('.', ','),
+            NumberFormat::DecimalComma => (',', '.'),
+        }
+    }
+}
+
+/// 按指定格式把本地化数字字符串解析为 JSON 数字
+///
+/// 千位分隔符可以是格式对应的分隔符、空格、不换行空格或 `'`，
+/// 并且必须按三位一组出现；无法解析时返回 `None`。
+pub(crate) fn parse_localized_number(input: &str, format: NumberFormat) -> Option<Value> {
+    if format == NumberFormat::Plain {
+        return parse_plain_number(input);
+    }
+    let (decimal, thousands) = format.separators();
+    let s = input.trim();
+    let (sign, body) = match s.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let (int_part, frac_part) = match body.split_once(decimal) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (body, None),
+    };
+
+    let is_group_separator = |c: char| c == thousands || matches!(c, ' ' | '\u{a0}' | '\u{202f}' | '\'');
+    let groups: Vec<&str> = int_part.split(is_group_separator).collect();
+    let digits_only = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+    if !groups.iter().all(|g| digits_only(g)) {
+        return None;
+    }
+    // 存在千位分隔符时，首组 1~3 位，其余每组必须 3 位
+    if groups.len() > 1 && (groups[0].len() > 3 || groups[1..].iter().any(|g| g.len() != 3)) {
+        return None;
+    }
+
+    let int_digits: String = groups.concat();
+    match frac_part {
+        None => {
+            let n: i64 = format!("{}{}", sign, int_digits).parse().ok()?;
+            Some(Value::Number(n.into()))
+        }
+        Some(frac) if digits_only(frac) => {
+            let n: f64 = format!("{}{}.{}", sign, int_digits, frac).parse().ok()?;
+            Number::from_f64(n).map(Value::Number)
+        }
+        Some(_) => None,
+    }
+}
+
+/// 按 JSON 数字语法（允许首尾空白和 `+` 号）解析字符串
+fn parse_plain_number(input: &str) -> Option<Value> {
+    let s = input.trim();
+    let s = s.strip_prefix('+').unwrap_or(s);
+    // 排除 `inf`、`NaN` 等 Rust 可以解析但 JSON 无法表示的写法
+    if !s.bytes().any(|b| b.is_ascii_digit()) || !s.bytes().all(|b| b.is_ascii_digit() || b"+-.eE".contains(&b)) {
+        return None;
+    }
+    if let Ok(n) = s.parse::<i64>() {
+        return Some(Value::Number(n.into()));
+    }
+    s.parse::<f64>().ok().and_then(Number::from_f64).map(Value::Number)
+}
+
+/// 表单输入的强制类型转换，通过 `CompileOptions::coercion` 启用
+///
+/// HTML 表单和查询字符串中的值都是字符串，启用后验证前把 `number`/`integer` 字段上的 `"42"`
+/// 解析为数字、`boolean` 字段上的 `"true"`/`"on"` 等映射为布尔值。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoercionOptions {
+    pub(crate) numbers: bool,
+    pub(crate) booleans: bool,
+}
+
+impl CoercionOptions {
+    /// 不做任何转换
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 把数字字段上的字符串按 JSON 数字语法解析为数字（`NumberFormat::Plain`）
+    pub fn coerce_numbers(mut self, enabled: bool) -> Self {
+        self.numbers = enabled;
+        self
+    }
+
+    /// 把布尔字段上的字符串按 `BooleanTokens::default()` 映射为布尔值
+    pub fn coerce_booleans(mut self, enabled: bool) -> Self {
+        self.booleans = enabled;
+        self
+    }
+}
+
+/// 布尔字段可接受的真值/假值字符串（比较时忽略大小写和首尾空白）
+///
+/// 默认接受 `"true"`/`"false"`、`"yes"`/`"no"`、`"1"`/`"0"`、`"on"`/`"off"`。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BooleanTokens {
+    truthy: Vec<String>,
+    falsy: Vec<String>,
+}
+
+impl BooleanTokens {
+    /// 使用自定义的真值和假值字符串创建转换表
+    pub fn new<T, F>(truthy: T, falsy: F) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<String>,
+        F: IntoIterator,
+        F::Item: Into<String>,
+    {
+        BooleanTokens {
+            truthy: truthy.into_iter().map(|t| t.into().to_lowercase()).collect(),
+            falsy: falsy.into_iter().map(|t| t.into().to_lowercase()).collect(),
+        }
+    }
+
+    /// 转换表的 JSON 表示
+    pub(crate) fn to_value(&self) -> Value {
+        serde_json::json!({"truthy": self.truthy, "falsy": self.falsy})
+    }
+
+    /// 把字符串解析为布尔值，不在转换表中时返回 `None`
+    pub(crate) fn parse(&self, input: &str) -> Option<bool> {
+        let token = input.trim().to_lowercase();
+        if self.truthy.contains(&token) {
+            Some(true)
+        } else if self.falsy.contains(&token) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for BooleanTokens {
+    fn default() -> Self {
+        BooleanTokens::new(["true", "yes", "1", "on"], ["false", "no", "0", "off"])
+    }
+}
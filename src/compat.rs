@@ -0,0 +1,354 @@
+//! Schema 向后兼容性检查
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+use crate::keywords::{CORE_KEYWORDS, SUBSCHEMA_ARRAY_KEYWORDS, SUBSCHEMA_KEYWORDS};
+use crate::{convert_rules, is_async_rules};
+
+/// 下限关键字：新 schema 提高下限会拒绝旧数据
+const LOWER_BOUNDS: &[&str] = &["minLength", "minItems", "minProperties", "minContains"];
+
+/// 上限关键字：新 schema 降低上限会拒绝旧数据
+const UPPER_BOUNDS: &[&str] = &["maxLength", "maxItems", "maxProperties", "maxContains"];
+
+/// 新增或修改后无法判断是否兼容的字符串约束
+const EXACT_KEYWORDS: &[&str] = &["pattern", "format", "contentEncoding", "contentMediaType"];
+
+/// 单项不兼容变更
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompatibilityIssue {
+    /// 新 schema 中的位置（JSON Pointer，例如 `/properties/name`，根节点为空字符串）
+    pub path: String,
+    /// 导致不兼容的关键字
+    pub keyword: String,
+    /// 不兼容的原因
+    pub reason: String,
+}
+
+impl fmt::Display for CompatibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}: {}", self.keyword, self.reason)
+        } else {
+            write!(f, "{} {}: {}", self.path, self.keyword, self.reason)
+        }
+    }
+}
+
+/// 向后兼容性检查结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    /// 旧 schema 下合法的数据在新 schema 下是否仍然合法
+    pub compatible: bool,
+    /// 全部不兼容变更
+    pub issues: Vec<CompatibilityIssue>,
+}
+
+impl CompatibilityReport {
+    /// 是否向后兼容
+    pub fn is_compatible(&self) -> bool {
+        self.compatible
+    }
+}
+
+/// 检查 `new_schema` 是否向后兼容 `old_schema`：旧 schema 下合法的数据在新 schema 下是否仍然合法
+///
+/// 两个参数均可为 JSON Schema 或 async-validator 规则，规则会先转换为 JSON Schema。
+/// 检查是保守的：放宽约束（例如降低 `minLength`、增加枚举值、取消必填）是兼容的，
+/// 收紧约束或无法判断的变更（例如修改 `pattern`、`anyOf`）都会报告为不兼容。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::is_backward_compatible;
+/// use serde_json::json;
+///
+/// let old = json!({"name": {"type": "string", "max": 20}});
+/// let new = json!({"name": {"type": "string", "required": true, "max": 10}});
+///
+/// let report = is_backward_compatible(&old, &new).unwrap();
+/// assert!(!report.is_compatible());
+/// assert_eq!(report.issues[0].keyword, "required");
+/// assert_eq!(report.issues[1].path, "/properties/name");
+/// assert_eq!(report.issues[1].reason, "maxLength lowered from 20 to 10");
+/// ```
+pub fn is_backward_compatible(old_schema: &Value, new_schema: &Value) -> Result<CompatibilityReport, String> {
+    let old = to_jsonschema(old_schema)?;
+    let new = to_jsonschema(new_schema)?;
+    let mut checker = Checker { issues: Vec::new() };
+    checker.compare(&old, &new, "");
+    Ok(CompatibilityReport {
+        compatible: checker.issues.is_empty(),
+        issues: checker.issues,
+    })
+}
+
+fn to_jsonschema(schema: &Value) -> Result<Value, String> {
+    if is_async_rules(schema) {
+        Ok(convert_rules(schema)?.schema)
+    } else {
+        Ok(schema.clone())
+    }
+}
+
+struct Checker {
+    issues: Vec<CompatibilityIssue>,
+}
+
+impl Checker {
+    fn issue(&mut self, path: &str, keyword: &str, reason: impl Into<String>) {
+        self.issues.push(CompatibilityIssue {
+            path: path.to_string(),
+            keyword: keyword.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    fn compare(&mut self, old: &Value, new: &Value, path: &str) {
+        match (old, new) {
+            (Value::Bool(false), _) | (_, Value::Bool(true)) => {}
+            (_, Value::Bool(false)) => self.issue(path, "false", "schema no longer accepts any value"),
+            (Value::Object(old), Value::Object(new)) => self.compare_objects(old, new, path),
+            (Value::Bool(true), Value::Object(new)) => self.compare_objects(&Map::new(), new, path),
+            _ => {}
+        }
+    }
+
+    fn compare_objects(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        self.compare_type(old, new, path);
+        self.compare_values(old, new, path);
+        for keyword in LOWER_BOUNDS {
+            if let Some(n) = new.get(*keyword).and_then(Value::as_f64) {
+                match old.get(*keyword).and_then(Value::as_f64) {
+                    None => self.issue(path, keyword, format!("{} {} added", keyword, n)),
+                    Some(o) if n > o => self.issue(path, keyword, format!("{} raised from {} to {}", keyword, o, n)),
+                    Some(_) => {}
+                }
+            }
+        }
+        for keyword in UPPER_BOUNDS {
+            if let Some(n) = new.get(*keyword).and_then(Value::as_f64) {
+                match old.get(*keyword).and_then(Value::as_f64) {
+                    None => self.issue(path, keyword, format!("{} {} added", keyword, n)),
+                    Some(o) if n < o => self.issue(path, keyword, format!("{} lowered from {} to {}", keyword, o, n)),
+                    Some(_) => {}
+                }
+            }
+        }
+        self.compare_range(old, new, path);
+        self.compare_multiple_of(old, new, path);
+        for keyword in EXACT_KEYWORDS {
+            if let Some(n) = new.get(*keyword) {
+                match old.get(*keyword) {
+                    None => self.issue(path, keyword, format!("{} {} added", keyword, n)),
+                    Some(o) if o != n => self.issue(path, keyword, format!("{} changed from {} to {}", keyword, o, n)),
+                    Some(_) => {}
+                }
+            }
+        }
+        if new.get("uniqueItems") == Some(&Value::Bool(true)) && old.get("uniqueItems") != Some(&Value::Bool(true)) {
+            self.issue(path, "uniqueItems", "items must now be unique");
+        }
+
+        let old_required = string_list(old.get("required"));
+        for field in string_list(new.get("required")) {
+            if !old_required.contains(&field) {
+                self.issue(path, "required", format!("field '{}' became required", field));
+            }
+        }
+        self.compare_properties(old, new, path);
+        self.compare_items(old, new, path);
+
+        // 其余组合/条件/引用关键字只能判断是否未变
+        for keyword in SUBSCHEMA_KEYWORDS.iter().chain(SUBSCHEMA_ARRAY_KEYWORDS).chain(CORE_KEYWORDS).chain(&[
+            "patternProperties",
+            "dependencies",
+            "dependentRequired",
+        ]) {
+            if matches!(*keyword, "items" | "additionalProperties" | "$id" | "id" | "$anchor" | "$vocabulary") {
+                continue;
+            }
+            if let Some(n) = new.get(*keyword)
+                && old.get(*keyword) != Some(n)
+            {
+                self.issue(path, keyword, format!("changes to {} cannot be verified", keyword));
+            }
+        }
+
+        for keyword in ["definitions", "$defs"] {
+            let (Some(Value::Object(old_defs)), Some(Value::Object(new_defs))) = (old.get(keyword), new.get(keyword)) else {
+                continue;
+            };
+            for (name, new_def) in new_defs {
+                if let Some(old_def) = old_defs.get(name) {
+                    self.compare(old_def, new_def, &child(&child(path, keyword), name));
+                }
+            }
+        }
+    }
+
+    fn compare_type(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        let Some(new_types) = type_list(new) else {
+            return;
+        };
+        let Some(old_types) = type_list(old) else {
+            self.issue(path, "type", format!("type restricted to {}", new_types.join(", ")));
+            return;
+        };
+        for t in old_types {
+            let accepted = new_types.contains(&t) || (t == "integer" && new_types.contains(&"number"));
+            if !accepted {
+                self.issue(path, "type", format!("type '{}' no longer accepted", t));
+            }
+        }
+    }
+
+    fn compare_values(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        // 旧 schema 允许的取值（`const` 视为单值枚举），None 表示不限
+        let old_values: Option<Vec<&Value>> = match (old.get("const"), old.get("enum")) {
+            (Some(value), _) => Some(vec![value]),
+            (None, Some(Value::Array(values))) => Some(values.iter().collect()),
+            _ => None,
+        };
+        if let Some(n) = new.get("const") {
+            match &old_values {
+                Some(values) if values.iter().all(|v| *v == n) => {}
+                _ => self.issue(path, "const", format!("value restricted to {}", n)),
+            }
+        }
+        if let Some(Value::Array(new_values)) = new.get("enum") {
+            match &old_values {
+                None => self.issue(path, "enum", "enum added"),
+                Some(values) => {
+                    for value in values.iter().filter(|v| !new_values.contains(v)) {
+                        self.issue(path, "enum", format!("value {} removed from enum", value));
+                    }
+                }
+            }
+        }
+    }
+
+    fn compare_range(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        let stricter = |old: Option<(f64, bool)>, new: (f64, bool), lower: bool| match old {
+            None => true,
+            Some(old) if old.0 == new.0 => new.1 && !old.1,
+            Some(old) => (new.0 > old.0) == lower,
+        };
+        for (lower, inclusive, exclusive) in [(true, "minimum", "exclusiveMinimum"), (false, "maximum", "exclusiveMaximum")] {
+            let Some(n) = bound(new, inclusive, exclusive, lower) else {
+                continue;
+            };
+            let o = bound(old, inclusive, exclusive, lower);
+            if stricter(o, n, lower) {
+                let keyword = if n.1 { exclusive } else { inclusive };
+                let reason = match o {
+                    None => format!("{} {} added", keyword, n.0),
+                    Some(o) if o.0 == n.0 => format!("{} {} became exclusive", inclusive, n.0),
+                    Some(o) => format!("{} tightened from {} to {}", keyword, o.0, n.0),
+                };
+                self.issue(path, keyword, reason);
+            }
+        }
+    }
+
+    fn compare_multiple_of(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        let Some(n) = new.get("multipleOf").and_then(Value::as_f64) else {
+            return;
+        };
+        match old.get("multipleOf").and_then(Value::as_f64) {
+            // 旧的倍数是新倍数的整数倍时，旧数据仍满足新约束
+            Some(o) if (o / n).fract() == 0.0 => {}
+            Some(o) => self.issue(path, "multipleOf", format!("multipleOf changed from {} to {}", o, n)),
+            None => self.issue(path, "multipleOf", format!("multipleOf {} added", n)),
+        }
+    }
+
+    fn compare_properties(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        let empty = Map::new();
+        let old_properties = old.get("properties").and_then(Value::as_object).unwrap_or(&empty);
+        let new_properties = new.get("properties").and_then(Value::as_object).unwrap_or(&empty);
+        let old_additional = additional(old);
+        let new_additional = additional(new);
+
+        for (name, new_property) in new_properties {
+            let property_path = child(&child(path, "properties"), name);
+            match old_properties.get(name) {
+                Some(old_property) => self.compare(old_property, new_property, &property_path),
+                // 旧 schema 中该字段由 additionalProperties 约束
+                None if old.contains_key("patternProperties") => {
+                    self.issue(&property_path, "properties", format!("property '{}' added while old schema has patternProperties", name));
+                }
+                None => self.compare(&old_additional, new_property, &property_path),
+            }
+        }
+        for (name, old_property) in old_properties {
+            if new_properties.contains_key(name) {
+                continue;
+            }
+            if new.contains_key("patternProperties") {
+                self.issue(path, "properties", format!("property '{}' removed while new schema has patternProperties", name));
+            } else if new_additional == Value::Bool(false) {
+                self.issue(path, "additionalProperties", format!("property '{}' removed while additional properties are not allowed", name));
+            } else {
+                self.compare(old_property, &new_additional, &child(&child(path, "properties"), name));
+            }
+        }
+        self.compare(&old_additional, &new_additional, &child(path, "additionalProperties"));
+    }
+
+    fn compare_items(&mut self, old: &Map<String, Value>, new: &Map<String, Value>, path: &str) {
+        match (old.get("items"), new.get("items")) {
+            (_, None) => {}
+            (None, Some(new_items @ (Value::Object(_) | Value::Bool(_)))) => self.compare(&json!({}), new_items, &child(path, "items")),
+            (Some(old_items @ (Value::Object(_) | Value::Bool(_))), Some(new_items @ (Value::Object(_) | Value::Bool(_)))) => {
+                self.compare(old_items, new_items, &child(path, "items"))
+            }
+            (old_items, Some(new_items)) => {
+                if old_items != Some(new_items) {
+                    self.issue(path, "items", "changes to tuple items cannot be verified");
+                }
+            }
+        }
+    }
+}
+
+/// `type` 的取值列表，None 表示不限类型
+fn type_list(obj: &Map<String, Value>) -> Option<Vec<&str>> {
+    match obj.get("type")? {
+        Value::String(t) => Some(vec![t.as_str()]),
+        Value::Array(ts) => Some(ts.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+fn string_list(value: Option<&Value>) -> Vec<&str> {
+    value
+        .and_then(Value::as_array)
+        .map(|values| values.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// 数值范围中最严格的边界：`(值, 是否排除边界值)`
+fn bound(obj: &Map<String, Value>, inclusive: &str, exclusive: &str, lower: bool) -> Option<(f64, bool)> {
+    let inclusive = obj.get(inclusive).and_then(Value::as_f64).map(|v| (v, false));
+    let exclusive = obj.get(exclusive).and_then(Value::as_f64).map(|v| (v, true));
+    match (inclusive, exclusive) {
+        (Some(i), Some(e)) => {
+            let exclusive_wins = if lower { e.0 >= i.0 } else { e.0 <= i.0 };
+            Some(if exclusive_wins { e } else { i })
+        }
+        (i, e) => i.or(e),
+    }
+}
+
+/// 未在 `properties` 中声明的字段所适用的 schema
+fn additional(obj: &Map<String, Value>) -> Value {
+    obj.get("additionalProperties").cloned().unwrap_or(Value::Bool(true))
+}
+
+/// 追加 JSON Pointer 片段
+fn child(path: &str, segment: &str) -> String {
+    format!("{}/{}", path, segment.replace('~', "~0").replace('/', "~1"))
+}
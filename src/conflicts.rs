@@ -0,0 +1,185 @@
+//! 同一字段多条规则中冲突的约束
+//!
+//! 规则数组中的多条规则为同一个约束键（如 `min`）给出不同的值时，按 `RuleMergeStrategy` 合并：
+//! 以最后声明的为准、取最严格的值，或使编译失败。合并后的值只保留在最后一条声明该键的规则中。
+
+use serde_json::Value;
+
+use crate::options::RuleMergeStrategy;
+use crate::{dates, is_union_rule, split_definitions, ConversionWarning};
+
+/// 参与冲突检查的约束键
+const CONSTRAINT_KEYS: &[&str] = &[
+    "type",
+    "min",
+    "max",
+    "len",
+    "pattern",
+    "enum",
+    "const",
+    "format",
+    "multipleOf",
+    "exclusiveMin",
+    "exclusiveMax",
+    "minDate",
+    "maxDate",
+    "keyPattern",
+    "case",
+    "truncate",
+];
+
+/// 按合并策略处理规则中的冲突，返回处理后的规则和冲突警告
+///
+/// `RuleMergeStrategy::Error` 下存在冲突时返回错误。
+pub(crate) fn resolve(rules: &Value, strategy: RuleMergeStrategy) -> Result<(Value, Vec<ConversionWarning>), String> {
+    let mut resolved = rules.clone();
+    let mut conflicts = Vec::new();
+    resolve_root(&mut resolved, strategy, &mut conflicts);
+    if strategy == RuleMergeStrategy::Error && !conflicts.is_empty() {
+        let conflicts: Vec<String> = conflicts.iter().map(|conflict| conflict.describe()).collect();
+        return Err(format!("Conflicting rules: {}", conflicts.join("; ")));
+    }
+    Ok((resolved, conflicts.iter().map(Conflict::warning).collect()))
+}
+
+/// 一个字段上的一处冲突
+struct Conflict {
+    field: String,
+    key: &'static str,
+    values: Vec<Value>,
+    resolved: Value,
+}
+
+impl Conflict {
+    fn detail(&self) -> String {
+        let values: Vec<String> = self.values.iter().map(Value::to_string).collect();
+        format!("conflicting '{}' rules ({})", self.key, values.join(", "))
+    }
+
+    fn describe(&self) -> String {
+        format!("Field '{}': {}", self.field, self.detail())
+    }
+
+    fn warning(&self) -> ConversionWarning {
+        ConversionWarning::field(&self.field, self.key, format_args!("{}, using {}", self.detail(), self.resolved))
+    }
+}
+
+fn resolve_root(rules: &mut Value, strategy: RuleMergeStrategy, conflicts: &mut Vec<Conflict>) {
+    if split_definitions(rules).is_some()
+        && let Some(obj) = rules.as_object_mut()
+        && let Some(mut defs) = obj.remove("defs")
+    {
+        for fields in defs.as_object_mut().into_iter().flat_map(|defs| defs.values_mut()) {
+            resolve_fields(fields, strategy, conflicts);
+        }
+        resolve_root(rules, strategy, conflicts);
+        if let Some(obj) = rules.as_object_mut() {
+            obj.insert("defs".to_string(), defs);
+        }
+        return;
+    }
+    if is_union_rule(rules) {
+        resolve_rule(rules, strategy, conflicts);
+        return;
+    }
+    resolve_fields(rules, strategy, conflicts);
+}
+
+/// 字段名 → 规则（对象或数组）
+fn resolve_fields(fields: &mut Value, strategy: RuleMergeStrategy, conflicts: &mut Vec<Conflict>) {
+    let Some(fields) = fields.as_object_mut() else {
+        return;
+    };
+    for (name, rules) in fields.iter_mut() {
+        match rules {
+            Value::Array(rules) => {
+                resolve_array(name, rules, strategy, conflicts);
+                for rule in rules {
+                    resolve_rule(rule, strategy, conflicts);
+                }
+            }
+            rule => resolve_rule(rule, strategy, conflicts),
+        }
+    }
+}
+
+/// 单条规则中嵌套的字段、联合变体和单条规则
+fn resolve_rule(rule: &mut Value, strategy: RuleMergeStrategy, conflicts: &mut Vec<Conflict>) {
+    let Some(obj) = rule.as_object_mut() else {
+        return;
+    };
+    if let Some(fields) = obj.get_mut("fields") {
+        resolve_fields(fields, strategy, conflicts);
+    }
+    if let Some(Value::Object(variants)) = obj.get_mut("variants") {
+        for fields in variants.values_mut() {
+            resolve_fields(fields, strategy, conflicts);
+        }
+    }
+    if let Some(Value::Object(mapping)) = obj.get_mut("itemsBy").and_then(|items_by| items_by.get_mut("mapping")) {
+        for fields in mapping.values_mut() {
+            resolve_fields(fields, strategy, conflicts);
+        }
+    }
+    for key in ["contains", "valueRule"] {
+        if let Some(nested) = obj.get_mut(key) {
+            resolve_rule(nested, strategy, conflicts);
+        }
+    }
+}
+
+/// 合并一个字段的规则数组中冲突的约束键
+fn resolve_array(field: &str, rules: &mut [Value], strategy: RuleMergeStrategy, conflicts: &mut Vec<Conflict>) {
+    for &key in CONSTRAINT_KEYS {
+        let declared: Vec<usize> = (0..rules.len()).filter(|&i| rules[i].get(key).is_some()).collect();
+        let values: Vec<Value> = declared.iter().map(|&i| rules[i][key].clone()).collect();
+        if values.iter().all(|value| *value == values[0]) {
+            continue;
+        }
+        let resolved = match strategy {
+            RuleMergeStrategy::Strictest => strictest(key, &values),
+            RuleMergeStrategy::LastWins | RuleMergeStrategy::Error => None,
+        };
+        let resolved = resolved.unwrap_or_else(|| values[values.len() - 1].clone());
+        for &i in &declared {
+            if let Some(rule) = rules[i].as_object_mut() {
+                rule.remove(key);
+            }
+        }
+        if let Some(Value::Object(rule)) = declared.last().map(|&i| &mut rules[i]) {
+            rule.insert(key.to_string(), resolved.clone());
+        }
+        conflicts.push(Conflict { field: field.to_string(), key, values, resolved });
+    }
+}
+
+/// 最严格的值，无法比较时返回 `None`（以最后声明的为准）
+fn strictest(key: &str, values: &[Value]) -> Option<Value> {
+    match key {
+        "min" | "exclusiveMin" => pick(values, Value::as_f64, |a, b| a > b),
+        "max" | "exclusiveMax" => pick(values, Value::as_f64, |a, b| a < b),
+        "truncate" => pick(values, Value::as_u64, |a, b| a < b),
+        "minDate" => pick(values, |v| v.as_str().and_then(dates::lower_bound), |a, b| a > b),
+        "maxDate" => pick(values, |v| v.as_str().and_then(dates::upper_bound), |a, b| a < b),
+        // 同时满足全部枚举列表的值
+        "enum" => {
+            let lists: Vec<&Vec<Value>> = values.iter().map(Value::as_array).collect::<Option<_>>()?;
+            let common = lists[0].iter().filter(|value| lists[1..].iter().all(|list| list.contains(value)));
+            Some(Value::Array(common.cloned().collect()))
+        }
+        _ => None,
+    }
+}
+
+/// 按 `stricter` 选出最严格的值，任意值无法转换时返回 `None`
+fn pick<T: Copy>(values: &[Value], key: impl Fn(&Value) -> Option<T>, stricter: impl Fn(T, T) -> bool) -> Option<Value> {
+    let mut best: Option<(T, &Value)> = None;
+    for value in values {
+        let k = key(value)?;
+        if best.is_none_or(|(b, _)| stricter(k, b)) {
+            best = Some((k, value));
+        }
+    }
+    best.map(|(_, value)| value.clone())
+}
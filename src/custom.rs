@@ -0,0 +1,46 @@
+//! 自定义验证器
+
+use serde_json::Value;
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::ValidationError;
+
+/// 文档级验证函数，第二个参数为调用方传入的验证上下文
+type DocumentFn = dyn Fn(&Value, Option<&dyn Any>) -> Vec<ValidationError> + Send + Sync;
+
+/// 文档级自定义验证器，用于跨多个字段的约束（例如合计必须等于明细之和）
+#[derive(Clone)]
+pub(crate) struct DocumentValidator(Arc<DocumentFn>);
+
+impl DocumentValidator {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(&Value) -> Vec<ValidationError> + Send + Sync + 'static,
+    {
+        DocumentValidator(Arc::new(move |data, _| f(data)))
+    }
+
+    /// 读取验证上下文的验证器，只在上下文为 `C` 类型时执行
+    pub(crate) fn with_context<C, F>(f: F) -> Self
+    where
+        C: Any,
+        F: Fn(&Value, &C) -> Vec<ValidationError> + Send + Sync + 'static,
+    {
+        DocumentValidator(Arc::new(move |data, context| match context.and_then(|c| c.downcast_ref::<C>()) {
+            Some(context) => f(data, context),
+            None => Vec::new(),
+        }))
+    }
+
+    pub(crate) fn validate(&self, data: &Value, context: Option<&dyn Any>) -> Vec<ValidationError> {
+        (self.0)(data, context)
+    }
+}
+
+impl fmt::Debug for DocumentValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DocumentValidator")
+    }
+}
@@ -0,0 +1,101 @@
+//! 日期范围约束（`formatMinimum` / `formatMaximum`）
+//!
+//! JSON Schema 验证器不支持这两个关键字，在 schema 验证之后单独检查。
+
+use serde_json::{Map, Value};
+
+use crate::error::ValidationError;
+use crate::keywords;
+
+pub(crate) use link_validator_core::__private::dates::{parse, MAXIMUM, MINIMUM, NANOS_PER_DAY};
+
+/// schema 中是否包含日期范围约束
+pub(crate) fn has_bounds(schema: &Value) -> bool {
+    let mut found = false;
+    keywords::visit_schemas(schema, &mut |obj| {
+        found |= obj.contains_key(MINIMUM) || obj.contains_key(MAXIMUM);
+    });
+    found
+}
+
+/// 纳秒时间戳格式化为 `YYYY-MM-DD` 或 `YYYY-MM-DDTHH:MM:SSZ`（截断到秒）
+pub(crate) fn format(instant: i128, date_only: bool) -> String {
+    let days = instant.div_euclid(NANOS_PER_DAY) as i64;
+    let (year, month, day) = civil_from_days(days);
+    if date_only {
+        return format!("{:04}-{:02}-{:02}", year, month, day);
+    }
+    let seconds = (instant.rem_euclid(NANOS_PER_DAY) / 1_000_000_000) as i64;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// 日期范围的上下限（纳秒时间戳），只有日期的上限包含当天全部时间
+pub(crate) fn bounds(obj: &Map<String, Value>) -> (Option<i128>, Option<i128>) {
+    let minimum = obj.get(MINIMUM).and_then(Value::as_str).and_then(lower_bound);
+    let maximum = obj.get(MAXIMUM).and_then(Value::as_str).and_then(upper_bound);
+    (minimum, maximum)
+}
+
+/// 作为下限时的纳秒时间戳
+pub(crate) fn lower_bound(value: &str) -> Option<i128> {
+    parse(value).map(|(start, _)| start)
+}
+
+/// 作为上限时的纳秒时间戳，只有日期时包含当天全部时间
+pub(crate) fn upper_bound(value: &str) -> Option<i128> {
+    parse(value).map(|(start, date_only)| if date_only { start + NANOS_PER_DAY - 1 } else { start })
+}
+
+/// 在 schema 验证之后检查日期范围
+pub(crate) fn check(root: &Value, data: &Value) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    keywords::walk_instance(root, data, &mut |obj, value, path| {
+        if let Value::String(value) = value {
+            check_value(obj, value, path, &mut errors);
+        }
+    });
+    errors
+}
+
+fn check_value(obj: &Map<String, Value>, value: &str, path: &str, errors: &mut Vec<ValidationError>) {
+    let (minimum, maximum) = bounds(obj);
+    // 格式错误已由 format 关键字报告
+    let Some((instant, _)) = parse(value).filter(|_| minimum.is_some() || maximum.is_some()) else {
+        return;
+    };
+    let bound = |keyword: &str| obj.get(keyword).and_then(Value::as_str).unwrap_or_default();
+    if minimum.is_some_and(|minimum| instant < minimum) {
+        errors.push(ValidationError::new(path, format!("\"{}\" is earlier than the minimum date {}", value, bound(MINIMUM)))
+            .with_code("date.min")
+            .with_value(value)
+            .with_param("limit", bound(MINIMUM)));
+    }
+    if maximum.is_some_and(|maximum| instant > maximum) {
+        errors.push(ValidationError::new(path, format!("\"{}\" is later than the maximum date {}", value, bound(MAXIMUM)))
+            .with_code("date.max")
+            .with_value(value)
+            .with_param("limit", bound(MAXIMUM)));
+    }
+}
+
+/// 1970-01-01 之后第 `days` 天对应的公历日期
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
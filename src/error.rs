@@ -0,0 +1,384 @@
+//! 验证错误
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::codes;
+use crate::options::ValidateOptions;
+use crate::{SchemaFormat, ValidationResult};
+
+/// 单个验证错误
+///
+/// 序列化为 `{"path": "/user/name", "message": "...", "code": "string.min", "value": ..., "params": {...}}`，
+/// 没有值或约束参数时省略 `value`/`params`。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationError {
+    /// 出错数据的路径（JSON Pointer，例如 `/user/name`，根节点为空字符串）
+    pub path: String,
+    /// 错误信息
+    pub message: String,
+    /// 机器可读的错误码，例如 `string.min`、`required`（见 `ERROR_CODES`）
+    #[serde(default = "custom_code")]
+    pub code: String,
+    /// 出错的值（只记录字符串、数字等标量，缺少字段或数组、对象时为 `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// 约束参数，例如 `{"limit": 3, "actual": 2}`
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub params: Map<String, Value>,
+    /// 出错的 async-validator 规则位置，例如 `user.fields.name.min`（只在使用 async-validator 规则时记录）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_path: Option<String>,
+    /// 修正建议，例如最接近的枚举值或疑似拼写错误的字段名
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl ValidationError {
+    /// 创建验证错误，错误码为 `custom`
+    pub fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        ValidationError {
+            path: path.into(),
+            message: message.into(),
+            code: codes::CUSTOM.to_string(),
+            value: None,
+            params: Map::new(),
+            rule_path: None,
+            hint: None,
+        }
+    }
+
+    /// 设置错误码
+    pub fn with_code(mut self, code: impl Into<String>) -> Self {
+        self.code = code.into();
+        self
+    }
+
+    /// 设置出错的值，数组和对象不记录
+    pub fn with_value(mut self, value: impl Into<Value>) -> Self {
+        let value = value.into();
+        self.value = (!value.is_array() && !value.is_object()).then_some(value);
+        self
+    }
+
+    /// 设置修正建议
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// 添加一个约束参数
+    pub fn with_param(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.params.insert(name.into(), value.into());
+        self
+    }
+
+    /// 路径拆分后的各段，根节点为空
+    ///
+    /// 由数字组成的段视为数组下标（`Segment::Index`），其余为对象键。
+    pub fn path_segments(&self) -> Vec<Segment> {
+        if self.path.is_empty() {
+            return Vec::new();
+        }
+        self.path
+            .strip_prefix('/')
+            .unwrap_or(&self.path)
+            .split('/')
+            .map(Segment::parse)
+            .collect()
+    }
+
+    /// 错误对应的字段（JSON Pointer），缺少必填字段时指向缺失的字段而不是所在的对象
+    pub(crate) fn field_pointer(&self) -> Cow<'_, str> {
+        match self.params.get("property").and_then(Value::as_str) {
+            Some(property) if self.code == "required" => {
+                Cow::Owned(format!("{}/{}", self.path, property.replace('~', "~0").replace('/', "~1")))
+            }
+            _ => Cow::Borrowed(&self.path),
+        }
+    }
+
+    /// 以点分隔的路径，例如 `user.profile.name`、`tags.2`，根节点为空字符串
+    pub fn dotted_path(&self) -> String {
+        dotted(&self.path_segments())
+    }
+
+    /// 按原始 schema 格式和验证选项生成错误对象
+    ///
+    /// 路径各段先经过 `rename_path` 重命名；`value` 按 `include_values`/`max_value_length` 输出，
+    /// 没有值或约束参数时省略对应的键。
+    pub(crate) fn render(&self, format: SchemaFormat, options: &ValidateOptions) -> Value {
+        let renamer = options.rename_path.as_ref();
+        let mut error = match format {
+            // async-validator 错误格式
+            SchemaFormat::AsyncValidator => {
+                let field = match renamer {
+                    Some(renamer) => dotted(&renamer.rename(self.path_segments())),
+                    None => self.dotted_path(),
+                };
+                json!({
+                    "message": self.message,
+                    "field": field,
+                    "code": self.code
+                })
+            }
+            // JSON Schema 错误格式
+            SchemaFormat::JsonSchema => {
+                let path = match renamer {
+                    Some(renamer) => pointer(&renamer.rename(self.path_segments())),
+                    None => self.path.clone(),
+                };
+                json!({
+                    "message": self.message,
+                    "instancePath": path,
+                    "code": self.code
+                })
+            }
+        };
+        if let Some(value) = self.value.as_ref().filter(|_| options.include_values) {
+            error["value"] = truncate(value, options.max_value_length);
+        }
+        if !self.params.is_empty() {
+            error["params"] = Value::Object(self.params.clone());
+        }
+        if let Some(rule_path) = &self.rule_path {
+            error["rulePath"] = Value::String(rule_path.clone());
+        }
+        if let Some(hint) = &self.hint {
+            error["hint"] = Value::String(hint.clone());
+        }
+        error
+    }
+}
+
+impl ValidationError {
+    /// 由 `render` 生成的错误对象还原错误（路径为渲染后的路径，截断的值保持截断）
+    pub(crate) fn from_rendered(error: &Value, format: SchemaFormat) -> ValidationError {
+        let text = |key: &str| error.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+        let path = match format {
+            SchemaFormat::AsyncValidator => {
+                let field = text("field");
+                if field.is_empty() {
+                    field
+                } else {
+                    pointer(&field.split('.').map(|key| Segment::Key(key.to_string())).collect::<Vec<_>>())
+                }
+            }
+            SchemaFormat::JsonSchema => text("instancePath"),
+        };
+        ValidationError {
+            path,
+            message: text("message"),
+            code: error.get("code").and_then(Value::as_str).map_or_else(custom_code, String::from),
+            value: error.get("value").cloned(),
+            params: error.get("params").and_then(Value::as_object).cloned().unwrap_or_default(),
+            rule_path: error.get("rulePath").and_then(Value::as_str).map(String::from),
+            hint: error.get("hint").and_then(Value::as_str).map(String::from),
+        }
+    }
+}
+
+/// 超出长度限制的字符串截断为前 `max` 个字符并追加省略号
+fn truncate(value: &Value, max: usize) -> Value {
+    match value.as_str() {
+        Some(s) if s.chars().count() > max => {
+            let mut truncated: String = s.chars().take(max).collect();
+            truncated.push('…');
+            Value::String(truncated)
+        }
+        _ => value.clone(),
+    }
+}
+
+fn custom_code() -> String {
+    codes::CUSTOM.to_string()
+}
+
+/// 错误路径中的一段
+///
+/// 序列化为字符串（对象键）或数字（数组下标）。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Segment {
+    /// 对象键
+    Key(String),
+    /// 数组下标
+    Index(usize),
+}
+
+impl Segment {
+    /// 解析 JSON Pointer 中的一段
+    fn parse(token: &str) -> Segment {
+        let is_index = !token.is_empty()
+            && token.bytes().all(|b| b.is_ascii_digit())
+            && (token == "0" || !token.starts_with('0'));
+        match token.parse() {
+            Ok(index) if is_index => Segment::Index(index),
+            _ => Segment::Key(token.replace("~1", "/").replace("~0", "~")),
+        }
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Segment::Key(key) => write!(f, "{}", key),
+            Segment::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+fn dotted(segments: &[Segment]) -> String {
+    segments.iter().map(ToString::to_string).collect::<Vec<_>>().join(".")
+}
+
+fn pointer(segments: &[Segment]) -> String {
+    segments
+        .iter()
+        .map(|segment| format!("/{}", segment.to_string().replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl From<&jsonschema::ValidationError<'_>> for ValidationError {
+    fn from(error: &jsonschema::ValidationError<'_>) -> Self {
+        let mut converted = ValidationError::new(error.instance_path.to_string(), error.to_string())
+            .with_code(codes::of(&error.kind));
+        converted.params = codes::params(&error.kind, &error.instance);
+        // 数组和对象（包括缺少字段时的父对象）不记录
+        let instance = error.instance.as_ref();
+        if !instance.is_array() && !instance.is_object() {
+            converted = converted.with_value(instance.clone());
+        }
+        converted
+    }
+}
+
+/// 验证失败时返回的错误集合
+///
+/// 序列化为 `{"errors": [...]}`。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationErrors {
+    /// 全部验证错误
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    /// 创建错误集合
+    pub fn new(errors: Vec<ValidationError>) -> Self {
+        ValidationErrors { errors }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self.errors.iter().map(ToString::to_string).collect();
+        write!(f, "Validation failed: {}", messages.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// 按验证选项收集错误（fail_fast / max_errors / first_fields）
+pub(crate) struct ErrorCollector<'a> {
+    options: &'a ValidateOptions,
+    errors: Vec<ValidationError>,
+    warnings: Vec<ValidationError>,
+    truncated: bool,
+    stopped: bool,
+}
+
+impl<'a> ErrorCollector<'a> {
+    pub(crate) fn new(options: &'a ValidateOptions) -> Self {
+        ErrorCollector {
+            options,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            truncated: false,
+            stopped: false,
+        }
+    }
+
+    /// 是否尚未收集到错误
+    pub(crate) fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// 已收集的错误
+    pub(crate) fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// 已收集的降级为警告的错误数量
+    pub(crate) fn warning_count(&self) -> usize {
+        self.warnings.len()
+    }
+
+    /// 是否已停止收集（fail_fast 或达到 max_errors）
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    /// 添加一个错误，返回是否应继续收集
+    pub(crate) fn push(&mut self, error: ValidationError) -> bool {
+        // first_fields 范围内的字段已有错误时忽略后续错误
+        let field = error.field_pointer();
+        if self.options.first_only(&field) && self.errors.iter().any(|e| e.field_pointer() == field) {
+            return true;
+        }
+        // 超出 max_errors 限制时停止收集并标记截断
+        if self.errors.len() >= self.options.max_errors.unwrap_or(usize::MAX) {
+            self.truncated = true;
+            self.stopped = true;
+            return false;
+        }
+        self.errors.push(error);
+        // fail_fast 模式下只取第一个错误
+        self.stopped = self.options.fail_fast;
+        !self.stopped
+    }
+
+    /// 是否收集到错误或警告
+    pub(crate) fn has_errors(&self) -> bool {
+        !self.errors.is_empty() || !self.warnings.is_empty()
+    }
+
+    /// 依次修改已收集的错误和警告
+    pub(crate) fn for_each_mut(&mut self, mut f: impl FnMut(&mut ValidationError)) {
+        self.errors.iter_mut().chain(self.warnings.iter_mut()).for_each(&mut f);
+    }
+
+    /// 添加一个降级为警告的错误（不影响验证结果，也不计入 max_errors）
+    pub(crate) fn warn(&mut self, error: ValidationError) {
+        if !self.options.omit_warnings {
+            self.warnings.push(error);
+        }
+    }
+
+    /// 取出已收集的错误
+    pub(crate) fn into_errors(self) -> Vec<ValidationError> {
+        self.errors
+    }
+
+    /// 生成验证结果
+    pub(crate) fn finish(self, format: SchemaFormat) -> ValidationResult {
+        ValidationResult {
+            is_valid: self.errors.is_empty(),
+            errors: Value::Array(self.errors.iter().map(|e| e.render(format, self.options)).collect()),
+            truncated: self.truncated,
+            warnings: Value::Array(self.warnings.iter().map(|e| e.render(format, self.options)).collect()),
+            format,
+        }
+    }
+}
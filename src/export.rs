@@ -0,0 +1,156 @@
+//! 编译结果的导出与导入
+//!
+//! 导出内容为带版本号的 JSON：转换后的 JSON Schema、数据规范化计划、转换警告和影响验证的编译选项。
+//! 导入时直接编译其中的 JSON Schema，跳过规则转换，适合启动时需要加载大量 schema 的服务缓存到磁盘。
+
+use jsonschema::JSONSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::coerce::{BooleanTokens, NumberFormat};
+use crate::options::{CompileOptions, LengthUnit, NullPolicy, TitleStyle};
+use crate::stats::Stats;
+use crate::transform::TransformNode;
+use crate::{dates, length_unit, lengths, redact, ConversionWarning, LinkValidator, SchemaFormat};
+
+/// 导出格式的当前版本
+const EXPORT_VERSION: u32 = 2;
+
+/// 导出的验证器
+#[derive(Serialize, Deserialize)]
+struct Exported {
+    version: u32,
+    #[serde(flatten)]
+    compiled: Compiled,
+}
+
+/// 单个验证器（或验证分组）的编译结果
+#[derive(Serialize, Deserialize)]
+struct Compiled {
+    format: SchemaFormat,
+    schema: Value,
+    #[serde(default)]
+    transforms: TransformNode,
+    #[serde(default)]
+    warnings: Vec<ConversionWarning>,
+    options: StoredOptions,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    groups: BTreeMap<String, Compiled>,
+}
+
+/// 影响验证和清单的编译选项（schema 限制只在编译时检查，不导出）
+#[derive(Serialize, Deserialize)]
+struct StoredOptions {
+    number_format: Option<NumberFormat>,
+    boolean_tokens: Option<BooleanTokens>,
+    #[serde(default)]
+    null_policy: NullPolicy,
+    titles: Option<TitleStyle>,
+    #[serde(default)]
+    length_unit: LengthUnit,
+    #[serde(default)]
+    form_annotations: bool,
+    #[serde(default)]
+    required_rejects_blank: bool,
+}
+
+impl From<&CompileOptions> for StoredOptions {
+    fn from(options: &CompileOptions) -> Self {
+        StoredOptions {
+            number_format: options.number_format,
+            boolean_tokens: options.boolean_tokens.clone(),
+            null_policy: options.null_policy,
+            titles: options.titles,
+            length_unit: options.length_unit,
+            form_annotations: options.form_annotations,
+            required_rejects_blank: options.required_rejects_blank,
+        }
+    }
+}
+
+impl From<StoredOptions> for CompileOptions {
+    fn from(stored: StoredOptions) -> Self {
+        CompileOptions {
+            number_format: stored.number_format,
+            boolean_tokens: stored.boolean_tokens,
+            null_policy: stored.null_policy,
+            titles: stored.titles,
+            length_unit: stored.length_unit,
+            form_annotations: stored.form_annotations,
+            required_rejects_blank: stored.required_rejects_blank,
+            ..CompileOptions::default()
+        }
+    }
+}
+
+/// 导出验证器的编译结果
+pub(crate) fn export(validator: &LinkValidator) -> Vec<u8> {
+    let exported = Exported {
+        version: EXPORT_VERSION,
+        compiled: compiled(validator),
+    };
+    serde_json::to_vec(&exported).expect("compiled validator serializes to JSON")
+}
+
+fn compiled(validator: &LinkValidator) -> Compiled {
+    Compiled {
+        format: validator.format,
+        schema: (*validator.json_schema).clone(),
+        transforms: validator.transforms.clone(),
+        warnings: validator.warnings.clone(),
+        options: StoredOptions::from(&validator.options),
+        groups: validator.groups.iter().map(|(name, group)| (name.clone(), compiled(group))).collect(),
+    }
+}
+
+/// 从导出的编译结果恢复验证器
+pub(crate) fn import(bytes: &[u8]) -> Result<LinkValidator, String> {
+    let exported: Exported =
+        serde_json::from_slice(bytes).map_err(|e| format!("Failed to import compiled validator: {}", e))?;
+    if exported.version != EXPORT_VERSION {
+        return Err(format!("Unsupported compiled validator version {}", exported.version));
+    }
+    restore(exported.compiled)
+}
+
+fn restore(compiled: Compiled) -> Result<LinkValidator, String> {
+    let options = CompileOptions::from(compiled.options);
+    let length_unit = length_unit(&options, &compiled.schema);
+    let compile_target = match length_unit {
+        Some(_) => Cow::Owned(lengths::strip(&compiled.schema)),
+        None => Cow::Borrowed(&compiled.schema),
+    };
+    let started = Instant::now();
+    let schema = JSONSchema::compile(&compile_target)
+        .map_err(|e| format!("Failed to compile imported schema: {}", e))?;
+    let stats = Stats::new(Duration::ZERO, started.elapsed(), &compiled.schema);
+
+    let groups = compiled
+        .groups
+        .into_iter()
+        .map(|(name, group)| Ok((name, restore(group)?)))
+        .collect::<Result<BTreeMap<_, _>, String>>()?;
+
+    Ok(LinkValidator {
+        schema: Arc::new(schema),
+        format: compiled.format,
+        transforms: compiled.transforms,
+        date_bounds: dates::has_bounds(&compiled.schema),
+        sensitive: redact::has_sensitive(&compiled.schema),
+        json_schema: Arc::new(compiled.schema),
+        warnings: compiled.warnings,
+        document_validators: Vec::new(),
+        result_cache: None,
+        client_policy: None,
+        name: None,
+        options,
+        length_unit,
+        groups: Arc::new(groups),
+        stats,
+        validation_hook: None,
+    })
+}
@@ -0,0 +1,106 @@
+//! Schema 指纹与等价比较
+//!
+//! 指纹基于转换后 JSON Schema 的规范形式：去掉注解，`required` 排序去重，整数值的浮点数写为整数，
+//! 对象键有序；数据规范化计划（包括编译选项中的强制转换和 `null` 处理）以及在 schema 之外检查的
+//! 编译选项（长度单位、拒绝空白字符串）和 `$schema` 声明的草案版本同样参与计算。错误格式和文档级验证器不参与。
+
+use serde_json::{json, Map, Number, Value};
+use sha2::{Digest, Sha256};
+
+use crate::keywords::{ANNOTATION_KEYWORDS, SUBSCHEMA_ARRAY_KEYWORDS, SUBSCHEMA_KEYWORDS, SUBSCHEMA_MAP_KEYWORDS};
+use crate::{manifest, LinkValidator};
+
+/// 验证器的规范形式
+pub(crate) fn canonical(validator: &LinkValidator) -> Value {
+    let mut schema = (*validator.json_schema).clone();
+    canonicalize(&mut schema);
+    normalize_numbers(&mut schema);
+    let mut canonical = json!({
+        "schema": schema,
+        "transforms": validator.transforms,
+    });
+    // `$schema` 作为注解被去掉，解析出的草案版本决定验证语义，非默认时写入
+    let draft = manifest::draft(&validator.json_schema);
+    if draft != "draft-07" {
+        canonical["draft"] = json!(draft);
+    }
+    // 只写入非默认值，默认选项的指纹保持不变
+    let mut options = Map::new();
+    if let Some(unit) = validator.length_unit {
+        options.insert("length_unit".to_string(), json!(unit.as_str()));
+    }
+    if validator.options.required_rejects_blank {
+        options.insert("required_rejects_blank".to_string(), json!(true));
+    }
+    if !options.is_empty() {
+        canonical["options"] = Value::Object(options);
+    }
+    canonical
+}
+
+/// 规范形式的 FNV-1a 64 位哈希
+pub(crate) fn fingerprint(validator: &LinkValidator) -> u64 {
+    fnv1a(canonical(validator).to_string().as_bytes())
+}
+
+/// 规范形式的 SHA-256（小写十六进制）
+pub(crate) fn sha256(validator: &LinkValidator) -> String {
+    Sha256::digest(canonical(validator).to_string().as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// FNV-1a 64 位哈希，跨进程和版本保持稳定
+fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+/// 去掉注解并排序 `required`
+fn canonicalize(schema: &mut Value) {
+    let Some(obj) = schema.as_object_mut() else {
+        return;
+    };
+    for keyword in ANNOTATION_KEYWORDS {
+        obj.remove(*keyword);
+    }
+    if let Some(Value::Array(required)) = obj.get_mut("required") {
+        required.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+        required.dedup();
+    }
+
+    for keyword in SUBSCHEMA_KEYWORDS {
+        if let Some(sub) = obj.get_mut(*keyword) {
+            canonicalize(sub);
+        }
+    }
+    for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+        if let Some(Value::Array(subs)) = obj.get_mut(*keyword) {
+            subs.iter_mut().for_each(canonicalize);
+        }
+    }
+    for keyword in SUBSCHEMA_MAP_KEYWORDS {
+        if let Some(Value::Object(subs)) = obj.get_mut(*keyword) {
+            subs.values_mut().for_each(canonicalize);
+        }
+    }
+}
+
+/// 整数值的浮点数（如 `3.0`）写为整数，与验证时的比较方式一致
+fn normalize_numbers(value: &mut Value) {
+    match value {
+        Value::Number(number) => {
+            if let Some(f) = number.as_f64().filter(|f| number.is_f64() && f.fract() == 0.0 && f.abs() < 9.0e15) {
+                *number = Number::from(f as i64);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(normalize_numbers),
+        Value::Object(obj) => obj.values_mut().for_each(normalize_numbers),
+        _ => {}
+    }
+}
@@ -0,0 +1,23 @@
+//! 表单元数据
+//!
+//! 规则中的 `label`、`placeholder`、`help`、`order` 和枚举选项的标签不参与验证，转换为带 `x-` 前缀的
+//! JSON Schema 注解保留下来，供 `schema_info` 和下游表单生成器读取。
+
+use serde_json::Value;
+
+use crate::keywords;
+
+pub(crate) use link_validator_core::__private::form::{ENUM, HELP, LABEL, OPTIONS, ORDER, PLACEHOLDER};
+
+/// 将 `x-label` 写入 `title`、`x-help` 写入 `description`（已有的值保持不变）
+pub(crate) fn annotate(schema: &mut Value) {
+    keywords::visit_schemas_mut(schema, &mut |obj| {
+        for (source, target) in [(LABEL, "title"), (HELP, "description")] {
+            if let Some(value) = obj.get(source).cloned()
+                && !obj.contains_key(target)
+            {
+                obj.insert(target.to_string(), value);
+            }
+        }
+    });
+}
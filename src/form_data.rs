@@ -0,0 +1,164 @@
+//! 表单和查询字符串的扁平键值对
+//!
+//! 把 `a.b`、`a[0].b`、`a[b]`、`a[]` 形式的键按 schema 的结构还原为嵌套的 JSON 对象，
+//! 叶子上的字符串按 schema 声明的类型转换为数字或布尔值。
+
+use serde_json::{Map, Value};
+
+use crate::coerce::{self, BooleanTokens, NumberFormat};
+use crate::error::ValidationError;
+use crate::keywords;
+
+/// 键中的一段路径
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// 对象属性（`a.b` 或 `a[b]`）
+    Key(String),
+    /// 数组下标（`a[0]`）
+    Index(usize),
+    /// 追加到数组末尾（`a[]`）
+    Push,
+}
+
+/// 把键值对还原为嵌套对象，键无法解析或与已有的值冲突时返回出错的键和原因
+pub(crate) fn unflatten<'a>(pairs: &'a [(String, String)], schema: &Value) -> Result<Value, (&'a str, &'static str)> {
+    let mut root = Value::Object(Map::new());
+    for (key, value) in pairs {
+        let segments = parse_key(key).ok_or((key.as_str(), "invalid key"))?;
+        insert(&mut root, schema, schema, &segments, value, pairs.len()).map_err(|message| (key.as_str(), message))?;
+    }
+    Ok(root)
+}
+
+/// 表单键的 `parse.form` 错误
+pub(crate) fn error(key: &str, message: &str) -> ValidationError {
+    ValidationError::new("", format!("Invalid form field '{}': {}", key, message))
+        .with_code("parse.form")
+        .with_param("key", key)
+}
+
+/// 解析 `a.b[0][c][]` 形式的键
+fn parse_key(key: &str) -> Option<Vec<Segment>> {
+    let end = key.find(['.', '[']).unwrap_or(key.len());
+    let (first, mut rest) = key.split_at(end);
+    if first.is_empty() {
+        return None;
+    }
+    let mut segments = vec![Segment::Key(first.to_string())];
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end == 0 {
+                return None;
+            }
+            segments.push(Segment::Key(after[..end].to_string()));
+            rest = &after[end..];
+        } else {
+            let after = rest.strip_prefix('[')?;
+            let end = after.find(']')?;
+            let inner = &after[..end];
+            segments.push(if inner.is_empty() {
+                Segment::Push
+            } else if inner.bytes().all(|b| b.is_ascii_digit()) {
+                Segment::Index(inner.parse().ok()?)
+            } else {
+                Segment::Key(inner.to_string())
+            });
+            rest = &after[end + 1..];
+        }
+    }
+    Some(segments)
+}
+
+/// 沿路径写入值，`max_index` 限制数组下标（不超过键值对的数量）
+fn insert(
+    target: &mut Value,
+    root: &Value,
+    schema: &Value,
+    segments: &[Segment],
+    value: &str,
+    max_index: usize,
+) -> Result<(), &'static str> {
+    let Some((segment, rest)) = segments.split_first() else {
+        // 数组字段可以重复同一个键，例如 `tags=a&tags=b`
+        let is_array = is_type(root, schema, "array");
+        let coerced = coerce(root, if is_array { items_schema(root, schema) } else { schema }, value);
+        match target {
+            Value::Null if is_array => *target = Value::Array(vec![coerced]),
+            Value::Null => *target = coerced,
+            Value::Array(items) => items.push(coerced),
+            // 其他字段重复时保留全部值，由 schema 报告类型错误
+            Value::String(_) | Value::Number(_) | Value::Bool(_) => *target = Value::Array(vec![target.take(), coerced]),
+            Value::Object(_) => return Err("value conflicts with nested fields"),
+        }
+        return Ok(());
+    };
+
+    match segment {
+        Segment::Key(name) => {
+            if target.is_null() {
+                *target = Value::Object(Map::new());
+            }
+            let Value::Object(obj) = target else {
+                return Err("nested field conflicts with a value");
+            };
+            let child = obj.entry(name.clone()).or_insert(Value::Null);
+            insert(child, root, property_schema(root, schema, name), rest, value, max_index)
+        }
+        Segment::Index(_) | Segment::Push => {
+            if target.is_null() {
+                *target = Value::Array(Vec::new());
+            }
+            let Value::Array(items) = target else {
+                return Err("array index on a non-array field");
+            };
+            let index = match segment {
+                Segment::Index(index) if *index < max_index => *index,
+                Segment::Index(_) => return Err("array index out of range"),
+                _ => items.len(),
+            };
+            if items.len() <= index {
+                items.resize(index + 1, Value::Null);
+            }
+            insert(&mut items[index], root, items_schema(root, schema), rest, value, max_index)
+        }
+    }
+}
+
+/// 按 schema 声明的类型转换叶子上的字符串，无法转换时保持原样
+fn coerce(root: &Value, schema: &Value, value: &str) -> Value {
+    let converted = if is_type(root, schema, "number") || is_type(root, schema, "integer") {
+        coerce::parse_localized_number(value, NumberFormat::Plain)
+    } else if is_type(root, schema, "boolean") {
+        BooleanTokens::default().parse(value).map(Value::Bool)
+    } else {
+        None
+    };
+    converted.unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+/// 解析本地 `$ref`（包括 `allOf`/`anyOf` 中的引用）
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    keywords::resolve_ref(root, schema).map_or(schema, |(_, target)| target)
+}
+
+fn is_type(root: &Value, schema: &Value, type_name: &str) -> bool {
+    match resolve(root, schema).get("type") {
+        Some(Value::String(t)) => t == type_name,
+        Some(Value::Array(types)) => types.iter().any(|t| t == type_name),
+        _ => false,
+    }
+}
+
+fn property_schema<'a>(root: &'a Value, schema: &'a Value, name: &str) -> &'a Value {
+    let schema = resolve(root, schema);
+    schema
+        .get("properties")
+        .and_then(|properties| properties.get(name))
+        .or_else(|| schema.get("additionalProperties"))
+        .unwrap_or(&Value::Null)
+}
+
+fn items_schema<'a>(root: &'a Value, schema: &'a Value) -> &'a Value {
+    resolve(root, schema).get("items").unwrap_or(&Value::Null)
+}
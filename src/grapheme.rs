@@ -0,0 +1,14 @@
+//! 字素簇（grapheme cluster）切分
+//!
+//! 基于 `unicode-segmentation` 的扩展字素簇（UAX #29），截断时不会拆开用户可见的单个字符：
+//! Emoji 序列、国旗、组合附加符号、印度系文字的元音符号和连字等。
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// 截断字符串，最多保留 `max` 个字素簇
+pub(crate) fn truncate(s: &str, max: usize) -> &str {
+    match s.grapheme_indices(true).nth(max) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
+}
@@ -0,0 +1,156 @@
+//! 验证分组（场景）
+//!
+//! 规则可以声明 `groups: ["create", "update"]`，只在按这些分组验证时生效；未声明分组的规则始终生效。
+//! 编译时为每个分组筛选规则并编译一个子验证器，不带分组的验证只使用未声明分组的规则。
+
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+use crate::{is_union_rule, split_definitions};
+
+/// 规则中出现的全部分组名称
+pub(crate) fn names(rules: &Value) -> Result<BTreeSet<String>, String> {
+    let mut names = BTreeSet::new();
+    visit_root(rules, &mut |rule| {
+        let Some(groups) = rule.get("groups") else {
+            return Ok(());
+        };
+        let groups = groups
+            .as_array()
+            .filter(|groups| !groups.is_empty())
+            .ok_or("groups must be a non-empty array of group names")?;
+        for group in groups {
+            let group = group.as_str().ok_or("groups must be a non-empty array of group names")?;
+            names.insert(group.to_string());
+        }
+        Ok(())
+    })?;
+    Ok(names)
+}
+
+/// 筛选在指定分组（`None` 为不带分组的验证）下生效的规则，并去掉 `groups` 键
+pub(crate) fn select(rules: &Value, group: Option<&str>) -> Value {
+    if let Some((defs, rest)) = split_definitions(rules) {
+        let mut selected = select(&rest, group);
+        let defs: Map<String, Value> = defs.iter().map(|(name, fields)| (name.clone(), select_fields(fields, group))).collect();
+        if let Value::Object(obj) = &mut selected {
+            obj.insert("defs".to_string(), Value::Object(defs));
+        }
+        return selected;
+    }
+    if is_union_rule(rules) {
+        return select_rule(rules, group).unwrap_or_else(|| Value::Object(Map::new()));
+    }
+    select_fields(rules, group)
+}
+
+/// 字段名 → 规则（对象或数组）
+fn select_fields(fields: &Value, group: Option<&str>) -> Value {
+    let Some(fields) = fields.as_object() else {
+        return fields.clone();
+    };
+    let mut selected = Map::new();
+    for (name, rules) in fields {
+        match rules {
+            Value::Array(rules) => {
+                let rules: Vec<Value> = rules.iter().filter_map(|rule| select_rule(rule, group)).collect();
+                if !rules.is_empty() {
+                    selected.insert(name.clone(), Value::Array(rules));
+                }
+            }
+            rule => {
+                if let Some(rule) = select_rule(rule, group) {
+                    selected.insert(name.clone(), rule);
+                }
+            }
+        }
+    }
+    Value::Object(selected)
+}
+
+/// 单条规则，不在该分组下生效时返回 `None`
+fn select_rule(rule: &Value, group: Option<&str>) -> Option<Value> {
+    let Some(obj) = rule.as_object() else {
+        return Some(rule.clone());
+    };
+    if let Some(groups) = obj.get("groups").and_then(Value::as_array) {
+        let active = group.is_some_and(|group| groups.iter().any(|g| g.as_str() == Some(group)));
+        if !active {
+            return None;
+        }
+    }
+
+    let mut obj = obj.clone();
+    obj.remove("groups");
+    if let Some(fields) = obj.get_mut("fields") {
+        *fields = select_fields(fields, group);
+    }
+    if let Some(Value::Object(variants)) = obj.get_mut("variants") {
+        for fields in variants.values_mut() {
+            *fields = select_fields(fields, group);
+        }
+    }
+    if let Some(Value::Object(mapping)) = obj.get_mut("itemsBy").and_then(|items_by| items_by.get_mut("mapping")) {
+        for fields in mapping.values_mut() {
+            *fields = select_fields(fields, group);
+        }
+    }
+    for key in ["contains", "valueRule"] {
+        if let Some(nested) = obj.get(key) {
+            match select_rule(nested, group) {
+                Some(nested) => obj.insert(key.to_string(), nested),
+                None => obj.remove(key),
+            };
+        }
+    }
+    Some(Value::Object(obj))
+}
+
+/// 依次访问全部规则对象（包括嵌套字段、定义、联合变体和 `itemsBy` 分支中的规则）
+fn visit_root(rules: &Value, visit: &mut impl FnMut(&Map<String, Value>) -> Result<(), String>) -> Result<(), String> {
+    if let Some((defs, rest)) = split_definitions(rules) {
+        for fields in defs.values() {
+            visit_fields(fields, visit)?;
+        }
+        return visit_root(&rest, visit);
+    }
+    if is_union_rule(rules) {
+        return visit_rule(rules, visit);
+    }
+    visit_fields(rules, visit)
+}
+
+fn visit_fields(fields: &Value, visit: &mut impl FnMut(&Map<String, Value>) -> Result<(), String>) -> Result<(), String> {
+    for rules in fields.as_object().into_iter().flat_map(Map::values) {
+        match rules {
+            Value::Array(rules) => {
+                for rule in rules {
+                    visit_rule(rule, visit)?;
+                }
+            }
+            rule => visit_rule(rule, visit)?,
+        }
+    }
+    Ok(())
+}
+
+fn visit_rule(rule: &Value, visit: &mut impl FnMut(&Map<String, Value>) -> Result<(), String>) -> Result<(), String> {
+    let Some(obj) = rule.as_object() else {
+        return Ok(());
+    };
+    visit(obj)?;
+    if let Some(fields) = obj.get("fields") {
+        visit_fields(fields, visit)?;
+    }
+    for nested in [obj.get("variants"), obj.get("itemsBy").and_then(|items_by| items_by.get("mapping"))] {
+        for fields in nested.and_then(Value::as_object).into_iter().flat_map(Map::values) {
+            visit_fields(fields, visit)?;
+        }
+    }
+    for key in ["contains", "valueRule"] {
+        if let Some(nested) = obj.get(key) {
+            visit_rule(nested, visit)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! 错误提示：按编辑距离给出最接近的枚举值或字段名
+
+use serde_json::Value;
+
+use crate::error::ValidationError;
+use crate::subtree;
+
+/// 枚举不匹配和缺少必填字段时的修正建议，没有足够接近的候选时返回 `None`
+///
+/// - 枚举：`did you mean 'inactive'?`
+/// - 必填字段：对象中有未声明且与缺少的字段名接近的字段时，提示可能是拼写错误
+pub(crate) fn suggest(error: &ValidationError, schema: &Value, data: &Value) -> Option<String> {
+    match error.code.as_str() {
+        "enum.mismatch" => {
+            let value = error.value.as_ref()?.as_str()?;
+            let candidates = error.params.get("allowed")?.as_array()?.iter().filter_map(Value::as_str);
+            closest(value, candidates).map(|option| format!("did you mean '{}'?", option))
+        }
+        "required" => {
+            let property = error.params.get("property")?.as_str()?;
+            let declared = subtree::locate(schema, &error.path)?.get("properties")?.as_object()?;
+            let unknown = data
+                .pointer(&error.path)?
+                .as_object()?
+                .keys()
+                .map(String::as_str)
+                .filter(|key| !declared.contains_key(*key));
+            closest(property, unknown)
+                .map(|key| format!("unknown property '{}', closest declared property '{}'", key, property))
+        }
+        _ => None,
+    }
+}
+
+/// 与 `target` 编辑距离最小的候选（忽略大小写，距离不超过长度的三分之一且至少允许 1）
+fn closest<'a>(target: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let target = target.to_lowercase();
+    let limit = (target.chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| (distance(&target, &candidate.to_lowercase()), candidate))
+        .filter(|(d, _)| *d <= limit)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, candidate)| candidate)
+}
+
+/// 两个字符串之间的编辑距离（Levenshtein，按字符计算）
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
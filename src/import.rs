@@ -0,0 +1,225 @@
+//! 导入 Joi / Yup 的 `describe()` 输出
+
+use serde_json::{json, Map, Value};
+
+use crate::ConversionWarning;
+
+/// 导入结果：async-validator 规则和无法转换的约束
+pub(crate) struct Imported {
+    pub(crate) rules: Value,
+    pub(crate) unsupported: Vec<ConversionWarning>,
+}
+
+/// 转换过程中的单个字段规则
+#[derive(Default)]
+struct FieldRule {
+    rule: Map<String, Value>,
+    unsupported: Vec<ConversionWarning>,
+}
+
+impl FieldRule {
+    fn set(&mut self, key: &str, value: Value) {
+        self.rule.insert(key.to_string(), value);
+    }
+
+    fn unsupported(&mut self, path: &str, what: impl std::fmt::Display) {
+        self.unsupported
+            .push(ConversionWarning::new(format!("Field '{}': {} not supported", path, what)).with_field_path(path));
+    }
+}
+
+/// 转换 Joi `describe()` 输出（顶层必须为 object 类型）
+pub(crate) fn from_joi(description: &Value) -> Result<Imported, String> {
+    if description.get("type").and_then(Value::as_str) != Some("object") {
+        return Err("Joi description must be an object schema".to_string());
+    }
+    let mut unsupported = Vec::new();
+    let rules = joi_keys(description, "", &mut unsupported)?;
+    Ok(Imported { rules, unsupported })
+}
+
+/// 转换 Yup `describe()` 输出（顶层必须为 object 类型）
+pub(crate) fn from_yup(description: &Value) -> Result<Imported, String> {
+    if description.get("type").and_then(Value::as_str) != Some("object") {
+        return Err("Yup description must be an object schema".to_string());
+    }
+    let mut unsupported = Vec::new();
+    let rules = yup_fields(description, "", &mut unsupported)?;
+    Ok(Imported { rules, unsupported })
+}
+
+/// 把 `/pattern/flags` 形式的正则转换为 pattern 字符串（支持 `i` 标志）
+fn regex_pattern(regex: &str) -> Option<String> {
+    let body = regex.strip_prefix('/')?;
+    let end = body.rfind('/')?;
+    let (pattern, flags) = (&body[..end], &body[end + 1..]);
+    Some(if flags.contains('i') { format!("(?i){}", pattern) } else { pattern.to_string() })
+}
+
+fn child_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() { key.to_string() } else { format!("{}.{}", parent, key) }
+}
+
+/// 字段类型映射到 async-validator 类型
+fn base_type(kind: &str) -> Option<&'static str> {
+    match kind {
+        "string" => Some("string"),
+        "number" => Some("number"),
+        "boolean" => Some("boolean"),
+        "object" => Some("object"),
+        "array" => Some("array"),
+        "date" => Some("date"),
+        "any" | "mixed" => Some("any"),
+        _ => None,
+    }
+}
+
+fn joi_keys(description: &Value, path: &str, unsupported: &mut Vec<ConversionWarning>) -> Result<Value, String> {
+    let mut rules = Map::new();
+    if let Some(keys) = description.get("keys").and_then(Value::as_object) {
+        for (key, field) in keys {
+            let field_path = child_path(path, key);
+            let rule = joi_field(field, &field_path, unsupported)?;
+            rules.insert(key.clone(), rule);
+        }
+    }
+    Ok(Value::Object(rules))
+}
+
+fn joi_field(field: &Value, path: &str, unsupported: &mut Vec<ConversionWarning>) -> Result<Value, String> {
+    let kind = field
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Field '{}': missing Joi type", path))?;
+    let mut out = FieldRule::default();
+    match base_type(kind) {
+        Some(t) => out.set("type", json!(t)),
+        None => out.unsupported(path, format_args!("Joi type '{}'", kind)),
+    }
+
+    let flags = field.get("flags");
+    match flags.and_then(|f| f.get("presence")).and_then(Value::as_str) {
+        Some("required") => out.set("required", json!(true)),
+        Some("forbidden") => out.unsupported(path, "presence 'forbidden'"),
+        _ => {}
+    }
+    if flags.and_then(|f| f.get("only")) == Some(&Value::Bool(true))
+        && let Some(Value::Array(allowed)) = field.get("allow")
+    {
+        out.set("type", json!("enum"));
+        out.set("enum", Value::Array(allowed.clone()));
+    }
+
+    for rule in field.get("rules").and_then(Value::as_array).into_iter().flatten() {
+        let name = rule.get("name").and_then(Value::as_str).unwrap_or_default();
+        let args = rule.get("args");
+        let arg = |key: &str| args.and_then(|a| a.get(key)).cloned();
+        match name {
+            "min" | "max" | "length" => match arg("limit") {
+                Some(limit) => out.set(if name == "length" { "len" } else { name }, limit),
+                None => out.unsupported(path, format_args!("Joi rule '{}' with a reference", name)),
+            },
+            "integer" => out.set("type", json!("integer")),
+            "email" => out.set("type", json!("email")),
+            "uri" => out.set("type", json!("url")),
+            "hex" => out.set("type", json!("hex")),
+            "pattern" => match arg("regex").as_ref().and_then(Value::as_str).and_then(regex_pattern) {
+                Some(pattern) => out.set("pattern", json!(pattern)),
+                None => out.unsupported(path, "Joi pattern"),
+            },
+            other => out.unsupported(path, format_args!("Joi rule '{}'", other)),
+        }
+    }
+
+    match kind {
+        "object" if field.get("keys").is_some() => {
+            out.set("fields", joi_keys(field, path, unsupported)?);
+        }
+        "array" => match field.get("items").and_then(Value::as_array).map(Vec::as_slice) {
+            Some([item]) if item.get("type").and_then(Value::as_str) == Some("object") => {
+                out.set("fields", joi_keys(item, &format!("{}[]", path), unsupported)?);
+            }
+            Some([]) | None => {}
+            Some(_) => out.unsupported(path, "Joi array item rules"),
+        },
+        _ => {}
+    }
+
+    unsupported.append(&mut out.unsupported);
+    Ok(Value::Object(out.rule))
+}
+
+fn yup_fields(description: &Value, path: &str, unsupported: &mut Vec<ConversionWarning>) -> Result<Value, String> {
+    let mut rules = Map::new();
+    if let Some(fields) = description.get("fields").and_then(Value::as_object) {
+        for (key, field) in fields {
+            let field_path = child_path(path, key);
+            let rule = yup_field(field, &field_path, unsupported)?;
+            rules.insert(key.clone(), rule);
+        }
+    }
+    Ok(Value::Object(rules))
+}
+
+fn yup_field(field: &Value, path: &str, unsupported: &mut Vec<ConversionWarning>) -> Result<Value, String> {
+    let kind = field
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| format!("Field '{}': missing Yup type", path))?;
+    let mut out = FieldRule::default();
+    match base_type(kind) {
+        Some(t) => out.set("type", json!(t)),
+        None => out.unsupported(path, format_args!("Yup type '{}'", kind)),
+    }
+
+    // Yup 1.x 使用 optional 标志，旧版本只有 required 测试
+    if field.get("optional") == Some(&Value::Bool(false)) {
+        out.set("required", json!(true));
+    }
+    if let Some(Value::Array(values)) = field.get("oneOf")
+        && !values.is_empty()
+    {
+        out.set("type", json!("enum"));
+        out.set("enum", Value::Array(values.clone()));
+    }
+
+    for test in field.get("tests").and_then(Value::as_array).into_iter().flatten() {
+        let name = test.get("name").and_then(Value::as_str).unwrap_or_default();
+        let param = |key: &str| test.get("params").and_then(|p| p.get(key)).cloned();
+        match name {
+            "required" => out.set("required", json!(true)),
+            "min" | "max" | "length" => match param(name) {
+                Some(limit) => out.set(if name == "length" { "len" } else { name }, limit),
+                None => out.unsupported(path, format_args!("Yup test '{}' without a limit", name)),
+            },
+            "integer" => out.set("type", json!("integer")),
+            "email" => out.set("type", json!("email")),
+            "url" => out.set("type", json!("url")),
+            "matches" => match param("regex").as_ref().and_then(Value::as_str).and_then(regex_pattern) {
+                Some(pattern) => out.set("pattern", json!(pattern)),
+                None => out.unsupported(path, "Yup matches test"),
+            },
+            other => out.unsupported(path, format_args!("Yup test '{}'", other)),
+        }
+        if let Some(Value::String(message)) = test.get("message") {
+            out.set("message", json!(message));
+        }
+    }
+
+    match kind {
+        "object" if field.get("fields").is_some() => {
+            out.set("fields", yup_fields(field, path, unsupported)?);
+        }
+        "array" => match field.get("innerType") {
+            Some(inner) if inner.get("type").and_then(Value::as_str) == Some("object") => {
+                out.set("fields", yup_fields(inner, &format!("{}[]", path), unsupported)?);
+            }
+            Some(_) => out.unsupported(path, "Yup array item rules"),
+            None => {}
+        },
+        _ => {}
+    }
+
+    unsupported.append(&mut out.unsupported);
+    Ok(Value::Object(out.rule))
+}
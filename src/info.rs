@@ -0,0 +1,201 @@
+//! 编译后规则的结构化描述
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::dates;
+use crate::form;
+use crate::keywords::VALIDATION_KEYWORDS;
+use crate::SchemaFormat;
+
+/// 验证器的结构化描述，用于生成文档表格或自动构建表单
+///
+/// 由编译后的 JSON Schema 生成，async-validator 规则和 JSON Schema 输入都可以使用。
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SchemaInfo {
+    /// 原始 schema 格式
+    pub format: SchemaFormat,
+    /// 根对象的字段
+    pub fields: Vec<FieldInfo>,
+    /// 可复用的定义（`defs` / `$defs`）：名称 -> 字段
+    pub definitions: BTreeMap<String, Vec<FieldInfo>>,
+}
+
+/// 单个字段的描述
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldInfo {
+    /// 字段名（数组元素为 `*`）
+    pub name: String,
+    /// 从根节点开始的点分隔路径，例如 `user.address.city`、`tags.*`
+    pub path: String,
+    /// JSON Schema 类型（不含 `null`），未声明时为空
+    pub types: Vec<String>,
+    /// 格式，例如 `email`、`date-time`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// 是否必填
+    pub required: bool,
+    /// 是否允许 `null`
+    pub nullable: bool,
+    /// 标题
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// 说明
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// 表单标签（规则中的 `label`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// 占位提示（规则中的 `placeholder`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    /// 帮助文本（规则中的 `help`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+    /// 表单中的排序（规则中的 `order`），字段按此升序排列，未设置的排在最后
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i64>,
+    /// 带标签的枚举选项（规则中 `enum` 的 `{"value": .., "label": ..}` 条目）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<EnumOption>,
+    /// 验证约束，使用 JSON Schema 关键字，例如 `{"minLength": 3, "pattern": "^[a-z]+$"}`；
+    /// `ignoreCase` 枚举的取值在 `x-enum` 中
+    pub constraints: Map<String, Value>,
+    /// 引用的定义名称（字段结构或数组元素结构为 `definitions` 中的定义）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<String>,
+    /// 对象的嵌套字段
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldInfo>,
+    /// 数组元素
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Box<FieldInfo>>,
+}
+
+/// 枚举选项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnumOption {
+    /// 验证使用的值
+    pub value: Value,
+    /// 显示标签
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// 生成 schema 的结构化描述
+pub(crate) fn describe(schema: &Value, format: SchemaFormat) -> SchemaInfo {
+    let definitions = ["$defs", "definitions"]
+        .iter()
+        .filter_map(|key| schema.get(*key).and_then(Value::as_object))
+        .flatten()
+        .map(|(name, definition)| (name.clone(), object_fields(definition, "")))
+        .collect();
+    SchemaInfo {
+        format,
+        fields: object_fields(schema, ""),
+        definitions,
+    }
+}
+
+/// 对象 schema 的字段
+fn object_fields(schema: &Value, prefix: &str) -> Vec<FieldInfo> {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let required: Vec<&str> = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    let mut fields: Vec<FieldInfo> = properties
+        .iter()
+        .map(|(name, property)| {
+            let path = if prefix.is_empty() { name.clone() } else { format!("{}.{}", prefix, name) };
+            field(name, path, property, required.contains(&name.as_str()))
+        })
+        .collect();
+    fields.sort_by_key(|field| (field.order.is_none(), field.order));
+    fields
+}
+
+fn field(name: &str, path: String, schema: &Value, required: bool) -> FieldInfo {
+    let empty = Map::new();
+    let obj = schema.as_object().unwrap_or(&empty);
+    let mut info = FieldInfo {
+        name: name.to_string(),
+        path,
+        types: Vec::new(),
+        format: obj.get("format").and_then(Value::as_str).map(String::from),
+        required,
+        nullable: false,
+        title: obj.get("title").and_then(Value::as_str).map(String::from),
+        description: obj.get("description").and_then(Value::as_str).map(String::from),
+        label: obj.get(form::LABEL).and_then(Value::as_str).map(String::from),
+        placeholder: obj.get(form::PLACEHOLDER).and_then(Value::as_str).map(String::from),
+        help: obj.get(form::HELP).and_then(Value::as_str).map(String::from),
+        order: obj.get(form::ORDER).and_then(Value::as_i64),
+        options: obj
+            .get(form::OPTIONS)
+            .and_then(|options| serde_json::from_value(options.clone()).ok())
+            .unwrap_or_default(),
+        constraints: Map::new(),
+        reference: None,
+        fields: Vec::new(),
+        items: None,
+    };
+
+    match obj.get("type") {
+        Some(Value::String(t)) => info.types.push(t.clone()),
+        Some(Value::Array(types)) => info.types.extend(types.iter().filter_map(Value::as_str).map(String::from)),
+        _ => {}
+    }
+    if let Some(position) = info.types.iter().position(|t| t == "null") {
+        info.types.remove(position);
+        info.nullable = true;
+    }
+    if obj.get("enum").and_then(Value::as_array).is_some_and(|values| values.contains(&Value::Null)) {
+        info.nullable = true;
+    }
+
+    for (key, value) in obj {
+        let is_constraint = (VALIDATION_KEYWORDS.contains(&key.as_str()) && !matches!(key.as_str(), "type" | "required" | "format"))
+            || key == dates::MINIMUM
+            || key == dates::MAXIMUM
+            || key == form::ENUM;
+        if is_constraint {
+            info.constraints.insert(key.clone(), value.clone());
+        }
+    }
+
+    // 引用定义：直接引用、有其他约束时的 allOf，或可为 null 的 anyOf
+    let branches = |keyword: &str| obj.get(keyword).and_then(Value::as_array).into_iter().flatten();
+    for candidate in std::iter::once(schema).chain(branches("allOf")).chain(branches("anyOf")) {
+        if let Some(name) = reference_name(candidate) {
+            info.reference = Some(name);
+        }
+    }
+    if branches("anyOf").any(|branch| branch.get("type").and_then(Value::as_str) == Some("null")) {
+        info.nullable = true;
+    }
+
+    info.fields = object_fields(schema, &info.path);
+    if let Some(items) = obj.get("items").filter(|items| items.is_object()) {
+        match reference_name(items) {
+            Some(name) => info.reference = Some(name),
+            None => info.items = Some(Box::new(field("*", format!("{}.*", info.path), items, false))),
+        }
+    }
+    info
+}
+
+/// `$ref` 指向的本地定义名称
+fn reference_name(schema: &Value) -> Option<String> {
+    let reference = schema.get("$ref")?.as_str()?;
+    let name = reference
+        .strip_prefix("#/$defs/")
+        .or_else(|| reference.strip_prefix("#/definitions/"))?;
+    Some(name.replace("~1", "/").replace("~0", "~"))
+}
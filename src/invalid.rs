@@ -0,0 +1,356 @@
+//! 生成违反单个约束的反例数据
+
+use jsonschema::JSONSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+
+use crate::keywords;
+
+/// 跟随 `$ref` 的最大层数，防止不消耗数据的循环引用
+const MAX_REF_DEPTH: usize = 64;
+
+/// 不匹配正则时尝试的候选字符串
+const PATTERN_CANDIDATES: &[&str] = &["!", " ", "0", "a", "A", "invalid value!", ""];
+
+/// 违反单个约束的反例数据
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvalidSample {
+    /// 反例数据
+    pub data: Value,
+    /// 预期出错的数据路径（JSON Pointer，根节点为空字符串）
+    pub path: String,
+    /// 预期失败的 JSON Schema 关键字，例如 `minLength`、`required`
+    pub keyword: String,
+    /// 反例的说明
+    pub description: String,
+}
+
+/// 以合法数据为基础，逐个约束生成反例
+pub(crate) struct Mutator<'a> {
+    root: &'a Value,
+    schema: &'a JSONSchema,
+    base: &'a Value,
+    limit: usize,
+    /// 已生成反例的 schema 位置和关键字，数组中多个元素违反同一约束时只保留一个
+    seen: BTreeSet<(String, String)>,
+    pub(crate) samples: Vec<InvalidSample>,
+}
+
+impl<'a> Mutator<'a> {
+    pub(crate) fn new(root: &'a Value, schema: &'a JSONSchema, base: &'a Value, limit: usize) -> Self {
+        Mutator {
+            root,
+            schema,
+            base,
+            limit,
+            seen: BTreeSet::new(),
+            samples: Vec::new(),
+        }
+    }
+
+    pub(crate) fn run(&mut self) {
+        self.walk(self.root, "", "", self.base, 0);
+    }
+
+    fn full(&self) -> bool {
+        self.samples.len() >= self.limit
+    }
+
+    /// 按顺序尝试候选修改，第一个只触发预期错误的修改作为反例
+    fn try_mutations(
+        &mut self,
+        schema_path: &str,
+        path: &str,
+        keyword: &str,
+        description: String,
+        candidates: impl IntoIterator<Item = Value>,
+    ) {
+        if self.full() || self.seen.contains(&(schema_path.to_string(), keyword.to_string())) {
+            return;
+        }
+        for candidate in candidates {
+            let mut data = self.base.clone();
+            match data.pointer_mut(path) {
+                Some(target) => *target = candidate,
+                None => return,
+            }
+            if self.accept(data, path, keyword, &description, schema_path) {
+                return;
+            }
+        }
+    }
+
+    /// 反例只能触发预期位置的预期关键字错误
+    fn accept(&mut self, data: Value, path: &str, keyword: &str, description: &str, schema_path: &str) -> bool {
+        let Err(errors) = self.schema.validate(&data) else {
+            return false;
+        };
+        let mut matched = false;
+        for error in errors {
+            let failed = error.schema_path.to_string();
+            if error.instance_path.to_string() != path || failed.rsplit('/').next() != Some(keyword) {
+                return false;
+            }
+            matched = true;
+        }
+        if matched {
+            self.seen.insert((schema_path.to_string(), keyword.to_string()));
+            self.samples.push(InvalidSample {
+                data,
+                path: path.to_string(),
+                keyword: keyword.to_string(),
+                description: description.to_string(),
+            });
+        }
+        matched
+    }
+
+    fn walk(&mut self, schema: &Value, schema_path: &str, path: &str, value: &Value, depth: usize) {
+        let Some(obj) = schema.as_object() else {
+            return;
+        };
+        if self.full() || depth > MAX_REF_DEPTH {
+            return;
+        }
+        if let Some(reference) = obj.get("$ref").and_then(Value::as_str)
+            && let Some(pointer) = reference.strip_prefix('#')
+            && let Some(target) = self.root.pointer(pointer)
+        {
+            self.walk(target, pointer, path, value, depth + 1);
+        }
+
+        self.mutate_type(obj, schema_path, path);
+        self.mutate_values(obj, schema_path, path);
+        match value {
+            Value::String(s) => self.mutate_string(obj, schema_path, path, s),
+            Value::Number(n) => self.mutate_number(obj, schema_path, path, n.as_f64().unwrap_or_default()),
+            Value::Array(items) => self.mutate_array(obj, schema_path, path, items),
+            Value::Object(fields) => self.mutate_object(obj, schema_path, path, fields),
+            _ => {}
+        }
+
+        if let Some(Value::Array(subs)) = obj.get("allOf") {
+            match keywords::discriminated_branches(obj) {
+                // `itemsBy` 分支：只检查数据选中的分支
+                Some((field, variants)) => {
+                    for (index, (tag, variant)) in variants.iter().enumerate() {
+                        if value.get(&field) == Some(tag) {
+                            let branch_path = format!("{}/allOf/{}/then", schema_path, index);
+                            self.walk(variant, &branch_path, path, value, depth);
+                        }
+                    }
+                }
+                None => {
+                    for (index, sub) in subs.iter().enumerate() {
+                        self.walk(sub, &format!("{}/allOf/{}", schema_path, index), path, value, depth);
+                    }
+                }
+            }
+        }
+    }
+
+    fn mutate_type(&mut self, obj: &Map<String, Value>, schema_path: &str, path: &str) {
+        let types: Vec<&str> = match obj.get("type") {
+            Some(Value::String(t)) => vec![t.as_str()],
+            Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => return,
+        };
+        let allowed = |t: &str| types.contains(&t) || (t == "integer" && types.contains(&"number"));
+        let wrong = [
+            ("string", json!("invalid")),
+            ("number", json!(1.5)),
+            ("integer", json!(1)),
+            ("boolean", json!(true)),
+            ("object", json!({})),
+            ("array", json!([])),
+            ("null", Value::Null),
+        ]
+        .into_iter()
+        .filter(|(t, _)| !allowed(t))
+        .map(|(_, value)| value);
+        let description = format!("value is not of type {}", types.join(" or "));
+        self.try_mutations(schema_path, path, "type", description, wrong);
+    }
+
+    fn mutate_values(&mut self, obj: &Map<String, Value>, schema_path: &str, path: &str) {
+        let (keyword, allowed) = match (obj.get("const"), obj.get("enum")) {
+            (Some(value), _) => ("const", vec![value.clone()]),
+            (None, Some(Value::Array(values))) => ("enum", values.clone()),
+            _ => return,
+        };
+        let candidates: Vec<Value> = allowed
+            .iter()
+            .flat_map(|value| match value {
+                Value::String(s) => vec![json!(format!("{}_invalid", s)), json!("invalid")],
+                Value::Number(n) => vec![number(n.as_f64().unwrap_or_default() + 1.0), json!(-999_999)],
+                Value::Bool(b) => vec![json!(!b)],
+                _ => vec![json!("invalid")],
+            })
+            .filter(|candidate| !allowed.contains(candidate))
+            .collect();
+        let description = format!("value is not one of the allowed values ({})", keyword);
+        self.try_mutations(schema_path, path, keyword, description, candidates);
+    }
+
+    fn mutate_string(&mut self, obj: &Map<String, Value>, schema_path: &str, path: &str, value: &str) {
+        let chars: Vec<char> = value.chars().collect();
+        if let Some(min) = obj.get("minLength").and_then(Value::as_u64).filter(|m| *m > 0) {
+            let short: String = chars.iter().take(min as usize - 1).collect();
+            let description = format!("string shorter than minLength {}", min);
+            self.try_mutations(schema_path, path, "minLength", description, [json!(short)]);
+        }
+        if let Some(max) = obj.get("maxLength").and_then(Value::as_u64) {
+            let fill = |c: char| {
+                let mut long = value.to_string();
+                long.extend(std::iter::repeat_n(c, (max as usize + 1).saturating_sub(chars.len())));
+                json!(long)
+            };
+            let candidates = [fill(chars.last().copied().unwrap_or('a')), fill('a'), fill('0')];
+            let description = format!("string longer than maxLength {}", max);
+            self.try_mutations(schema_path, path, "maxLength", description, candidates);
+        }
+        if let Some(pattern) = obj.get("pattern").and_then(Value::as_str) {
+            let mut candidates: Vec<Value> = vec![json!(format!("{}!", value)), json!(format!("!{}", value))];
+            candidates.extend(PATTERN_CANDIDATES.iter().map(|c| json!(c)));
+            let description = format!("string does not match pattern {}", pattern);
+            self.try_mutations(schema_path, path, "pattern", description, candidates);
+        }
+        if let Some(format) = obj.get("format").and_then(Value::as_str) {
+            let candidates = [json!(format!("not a valid {}", format)), json!("invalid"), json!("!")];
+            let description = format!("string is not a valid {}", format);
+            self.try_mutations(schema_path, path, "format", description, candidates);
+        }
+    }
+
+    fn mutate_number(&mut self, obj: &Map<String, Value>, schema_path: &str, path: &str, value: f64) {
+        let integer = obj.get("type").and_then(Value::as_str) == Some("integer");
+        let step = if integer { 1.0 } else { 0.5 };
+        for (keyword, below, exclusive) in [
+            ("minimum", true, false),
+            ("exclusiveMinimum", true, true),
+            ("maximum", false, false),
+            ("exclusiveMaximum", false, true),
+        ] {
+            let Some(bound) = obj.get(keyword).and_then(Value::as_f64) else {
+                continue;
+            };
+            let candidate = match (exclusive, below) {
+                (true, _) => bound,
+                (false, true) => bound - step,
+                (false, false) => bound + step,
+            };
+            let candidate = if integer && below { candidate.floor() } else if integer { candidate.ceil() } else { candidate };
+            let description = format!("number outside {} {}", keyword, number(bound));
+            self.try_mutations(schema_path, path, keyword, description, [number(candidate)]);
+        }
+        if let Some(step) = obj.get("multipleOf").and_then(Value::as_f64).filter(|m| *m > 0.0) {
+            let candidates = [number(value + 1.0), number(value + step / 2.0), number(value + step / 3.0)];
+            let description = format!("number is not a multiple of {}", number(step));
+            self.try_mutations(schema_path, path, "multipleOf", description, candidates);
+        }
+    }
+
+    fn mutate_array(&mut self, obj: &Map<String, Value>, schema_path: &str, path: &str, items: &[Value]) {
+        if let Some(min) = obj.get("minItems").and_then(Value::as_u64).filter(|m| *m > 0) {
+            let short = items[..(min as usize - 1).min(items.len())].to_vec();
+            let description = format!("array has fewer than minItems {}", min);
+            self.try_mutations(schema_path, path, "minItems", description, [Value::Array(short)]);
+        }
+        if let Some(max) = obj.get("maxItems").and_then(Value::as_u64)
+            && !items.is_empty()
+        {
+            // 重复已有元素补足数量，设置了 uniqueItems 时会同时触发唯一性错误而被跳过
+            let long: Vec<Value> = items.iter().cycle().take(max as usize + 1).cloned().collect();
+            let description = format!("array has more than maxItems {}", max);
+            self.try_mutations(schema_path, path, "maxItems", description, [Value::Array(long)]);
+        }
+        if obj.get("uniqueItems") == Some(&Value::Bool(true))
+            && let Some(first) = items.first()
+        {
+            let mut duplicated = items.to_vec();
+            duplicated.push(first.clone());
+            let mut replaced = items.to_vec();
+            if let Some(last) = replaced.last_mut() {
+                *last = first.clone();
+            }
+            let description = "array items are not unique".to_string();
+            self.try_mutations(schema_path, path, "uniqueItems", description, [Value::Array(replaced), Value::Array(duplicated)]);
+        }
+
+        match obj.get("items") {
+            Some(Value::Array(tuple)) => {
+                for (index, (schema, item)) in tuple.iter().zip(items).enumerate() {
+                    self.walk(schema, &format!("{}/items/{}", schema_path, index), &format!("{}/{}", path, index), item, 0);
+                }
+            }
+            Some(schema) => {
+                for (index, item) in items.iter().enumerate() {
+                    self.walk(schema, &format!("{}/items", schema_path), &format!("{}/{}", path, index), item, 0);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn mutate_object(&mut self, obj: &Map<String, Value>, schema_path: &str, path: &str, fields: &Map<String, Value>) {
+        for field in obj.get("required").and_then(Value::as_array).into_iter().flatten().filter_map(Value::as_str) {
+            if self.full() || !fields.contains_key(field) {
+                continue;
+            }
+            let mut data = self.base.clone();
+            if let Some(Value::Object(target)) = data.pointer_mut(path) {
+                target.remove(field);
+            }
+            // 每个必填字段单独记录
+            let required_path = format!("{}/required/{}", schema_path, escape(field));
+            if !self.seen.contains(&(required_path.clone(), "required".to_string())) {
+                let description = format!("required field '{}' is missing", field);
+                if self.accept(data, path, "required", &description, &required_path) {
+                    continue;
+                }
+            }
+        }
+        if obj.get("additionalProperties") == Some(&Value::Bool(false))
+            && !self.full()
+            && !self.seen.contains(&(schema_path.to_string(), "additionalProperties".to_string()))
+        {
+            let mut data = self.base.clone();
+            if let Some(Value::Object(target)) = data.pointer_mut(path) {
+                target.insert("__unexpected__".to_string(), json!(true));
+            }
+            let description = "unexpected field '__unexpected__'".to_string();
+            self.accept(data, path, "additionalProperties", &description, schema_path);
+        }
+
+        let properties = obj.get("properties").and_then(Value::as_object);
+        for (key, value) in fields {
+            let field_path = format!("{}/{}", path, escape(key));
+            match properties.and_then(|p| p.get(key)) {
+                Some(schema) => {
+                    let property_path = format!("{}/properties/{}", schema_path, escape(key));
+                    self.walk(schema, &property_path, &field_path, value, 0);
+                }
+                None => {
+                    if let Some(schema @ Value::Object(_)) = obj.get("additionalProperties") {
+                        self.walk(schema, &format!("{}/additionalProperties", schema_path), &field_path, value, 0);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 整数值使用整数表示，避免 `integer` 类型误判
+fn number(value: f64) -> Value {
+    if value.fract() == 0.0 && value.abs() < 9_007_199_254_740_992.0 {
+        json!(value as i64)
+    } else {
+        json!(value)
+    }
+}
+
+/// JSON Pointer 片段转义
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
@@ -0,0 +1,249 @@
+//! JSON Schema 关键字分类
+
+use serde_json::{Map, Value};
+
+pub(crate) use link_validator_core::__private::keywords::{
+    visit_schemas, SUBSCHEMA_ARRAY_KEYWORDS, SUBSCHEMA_KEYWORDS, SUBSCHEMA_MAP_KEYWORDS,
+};
+
+/// 注解类关键字，不影响验证结果
+pub(crate) const ANNOTATION_KEYWORDS: &[&str] = &[
+    "$comment",
+    "title",
+    "description",
+    "examples",
+    "default",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+    "$schema",
+    "x-label",
+    "x-placeholder",
+    "x-help",
+    "x-order",
+    "x-options",
+];
+
+/// 标识与引用类关键字
+pub(crate) const CORE_KEYWORDS: &[&str] = &["$id", "id", "$ref", "$anchor", "$vocabulary"];
+
+/// 验证约束关键字
+pub(crate) const VALIDATION_KEYWORDS: &[&str] = &[
+    "type",
+    "enum",
+    "const",
+    "multipleOf",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "format",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "maxContains",
+    "minContains",
+    "maxProperties",
+    "minProperties",
+    "required",
+    "dependentRequired",
+    "contentEncoding",
+    "contentMediaType",
+];
+
+/// 是否为 JSON Schema 定义的关键字
+pub(crate) fn is_known(keyword: &str) -> bool {
+    [
+        ANNOTATION_KEYWORDS,
+        SUBSCHEMA_KEYWORDS,
+        SUBSCHEMA_ARRAY_KEYWORDS,
+        SUBSCHEMA_MAP_KEYWORDS,
+        CORE_KEYWORDS,
+        VALIDATION_KEYWORDS,
+    ]
+    .iter()
+    .any(|group| group.contains(&keyword))
+}
+
+/// 依次访问 schema 及其全部子 schema 对象（可修改）
+///
+/// 先访问父 schema，访问函数对子 schema 关键字的修改会影响随后的遍历。
+pub(crate) fn visit_schemas_mut(schema: &mut Value, visit: &mut impl FnMut(&mut Map<String, Value>)) {
+    let Some(obj) = schema.as_object_mut() else {
+        return;
+    };
+    visit(obj);
+
+    for keyword in SUBSCHEMA_KEYWORDS {
+        if let Some(sub) = obj.get_mut(*keyword) {
+            visit_schemas_mut(sub, visit);
+        }
+    }
+    for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+        if let Some(Value::Array(subs)) = obj.get_mut(*keyword) {
+            subs.iter_mut().for_each(|sub| visit_schemas_mut(sub, visit));
+        }
+    }
+    for keyword in SUBSCHEMA_MAP_KEYWORDS {
+        if let Some(Value::Object(subs)) = obj.get_mut(*keyword) {
+            subs.values_mut().for_each(|sub| visit_schemas_mut(sub, visit));
+        }
+    }
+}
+
+/// 识别 `itemsBy` 生成的判别分支：`allOf` 中每一项均为
+/// `{"if": {"properties": {字段: {"const": 值}}, ...}, "then": schema}` 且判别字段相同
+///
+/// 返回判别字段以及各分支的判别值和 schema。
+pub(crate) fn discriminated_branches(obj: &Map<String, Value>) -> Option<(String, Vec<(Value, Value)>)> {
+    let branches = obj.get("allOf")?.as_array()?;
+    let mut field: Option<&String> = None;
+    let mut variants = Vec::new();
+    for branch in branches {
+        let (name, tag) = branch
+            .pointer("/if/properties")
+            .and_then(Value::as_object)
+            .filter(|p| p.len() == 1)
+            .and_then(|p| p.iter().next())
+            .and_then(|(name, condition)| Some((name, condition.get("const")?)))?;
+        if field.is_some_and(|f| f != name) {
+            return None;
+        }
+        field = Some(name);
+        variants.push((tag.clone(), branch.get("then")?.clone()));
+    }
+    Some((field?.clone(), variants))
+}
+
+/// 本地 `$ref`（包括 `allOf`/`anyOf`/`oneOf` 分支中的引用）指向的 schema 及其 JSON Pointer
+pub(crate) fn resolve_ref<'a>(root: &'a Value, schema: &'a Value) -> Option<(&'a str, &'a Value)> {
+    let branches = ["allOf", "anyOf", "oneOf"]
+        .iter()
+        .filter_map(|keyword| schema.get(*keyword).and_then(Value::as_array))
+        .flatten();
+    schema
+        .get("$ref")
+        .into_iter()
+        .chain(branches.filter_map(|branch| branch.get("$ref")))
+        .filter_map(Value::as_str)
+        .filter_map(|reference| reference.strip_prefix('#'))
+        .find_map(|pointer| root.pointer(pointer).map(|target| (pointer, target)))
+}
+
+/// 同时遍历 schema 和数据，对每个数据值访问作用于它的 schema 对象，路径为 JSON Pointer
+///
+/// 跟随 `$ref`、`allOf`（`itemsBy` 分支只访问条件满足的分支）、判别值一致的 `oneOf` 分支、
+/// `properties`/`additionalProperties` 和 `items`。用于 JSON Schema 验证器不支持、
+/// 需要在验证之后单独检查的约束。
+pub(crate) fn walk_instance(root: &Value, data: &Value, visit: &mut impl FnMut(&Map<String, Value>, &Value, &str)) {
+    walk(root, root, data, &mut String::new(), visit, 0);
+}
+
+fn walk(
+    root: &Value,
+    schema: &Value,
+    data: &Value,
+    path: &mut String,
+    visit: &mut impl FnMut(&Map<String, Value>, &Value, &str),
+    depth: usize,
+) {
+    let Some(obj) = schema.as_object() else {
+        return;
+    };
+    // 防止不消耗数据的循环引用
+    if depth > 64 {
+        return;
+    }
+    if let Some(target) = obj
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+    {
+        walk(root, target, data, path, visit, depth + 1);
+    }
+
+    visit(obj, data, path);
+    for branch in obj.get("allOf").and_then(Value::as_array).into_iter().flatten() {
+        match branch.get("if") {
+            Some(condition) if !matches_condition(condition, data) => {}
+            Some(_) => {
+                if let Some(then) = branch.get("then") {
+                    walk(root, then, data, path, visit, depth + 1);
+                }
+            }
+            None => walk(root, branch, data, path, visit, depth + 1),
+        }
+    }
+    for keyword in ["oneOf", "anyOf"] {
+        for branch in obj.get(keyword).and_then(Value::as_array).into_iter().flatten() {
+            if matches_condition(branch, data) && matches_type(branch, data) {
+                walk(root, branch, data, path, visit, depth + 1);
+            }
+        }
+    }
+
+    match data {
+        Value::Object(fields) => {
+            let properties = obj.get("properties").and_then(Value::as_object);
+            for (key, value) in fields {
+                let sub = properties.and_then(|p| p.get(key)).or_else(|| obj.get("additionalProperties"));
+                if let Some(sub) = sub {
+                    let len = path.len();
+                    path.push('/');
+                    path.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                    walk(root, sub, value, path, visit, 0);
+                    path.truncate(len);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let sub = match obj.get("items") {
+                    Some(Value::Array(tuple)) => tuple.get(index),
+                    other => other,
+                };
+                if let Some(sub) = sub {
+                    let len = path.len();
+                    path.push('/');
+                    path.push_str(&index.to_string());
+                    walk(root, sub, item, path, visit, 0);
+                    path.truncate(len);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `if` 条件或分支中的 `const` 属性是否与数据一致（用于选择 `itemsBy` 和可辨识联合的分支）
+fn matches_condition(condition: &Value, data: &Value) -> bool {
+    condition
+        .get("properties")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .all(|(key, property)| property.get("const").is_none_or(|expected| data.get(key) == Some(expected)))
+}
+
+/// 分支声明的 `type` 是否接受数据（未声明时接受）
+fn matches_type(branch: &Value, data: &Value) -> bool {
+    let accepts = |t: &Value| match t.as_str() {
+        Some("null") => data.is_null(),
+        Some("boolean") => data.is_boolean(),
+        Some("string") => data.is_string(),
+        Some("number") => data.is_number(),
+        Some("integer") => data.as_f64().is_some_and(|n| n.fract() == 0.0),
+        Some("array") => data.is_array(),
+        Some("object") => data.is_object(),
+        _ => true,
+    };
+    match branch.get("type") {
+        Some(Value::Array(types)) => types.iter().any(accepts),
+        Some(t) => accepts(t),
+        None => true,
+    }
+}
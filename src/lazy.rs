@@ -0,0 +1,132 @@
+//! 首次使用时才编译的验证器集合
+
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use crate::set::{compile_rules, compile_schema, shared_schemas_of};
+use crate::{is_async_rules, CompileOptions, LinkValidator, ValidationResult};
+
+/// 按名称注册 schema、在首次使用时才编译的验证器集合
+///
+/// 与 `ValidatorSet` 相同，通过 `define` 声明的定义在集合内共享。`add` 只保存原始 schema，
+/// 第一次 `validate` 或 `get` 时编译并缓存结果（编译失败的错误也会缓存），适合注册了大量但很少使用的
+/// schema 的应用缩短启动时间。编译结果缓存在集合内部，只需要 `&self`，可以在线程之间共享。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::LazyValidatorSet;
+/// use serde_json::json;
+///
+/// let mut set = LazyValidatorSet::new();
+/// set.define("address", json!({"city": {"type": "string", "required": true}}));
+/// set.add("user", json!({"address": {"type": "object", "required": true, "$use": "address"}}));
+/// set.add("order", json!({"id": {"type": "integer", "required": true}}));
+///
+/// assert!(!set.is_compiled("user"));
+/// assert!(!set.validate("user", &json!({"address": {}})).unwrap().is_valid);
+/// assert!(set.is_compiled("user"));
+/// assert!(!set.is_compiled("order"));
+/// ```
+#[derive(Debug, Default)]
+pub struct LazyValidatorSet {
+    options: CompileOptions,
+    /// 共享的定义：名称 -> 字段规则
+    defs: Map<String, Value>,
+    /// 共享定义转换后的 JSON Schema（第一个 JSON Schema 编译时生成）
+    shared_schemas: OnceLock<Result<Map<String, Value>, String>>,
+    entries: BTreeMap<String, Entry>,
+}
+
+/// 原始 schema 及其编译结果
+#[derive(Debug)]
+struct Entry {
+    schema: Value,
+    compiled: OnceLock<Result<LinkValidator, String>>,
+}
+
+impl Entry {
+    fn new(schema: Value) -> Self {
+        Entry {
+            schema,
+            compiled: OnceLock::new(),
+        }
+    }
+}
+
+impl LazyValidatorSet {
+    /// 创建空的验证器集合
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建使用指定编译选项的验证器集合
+    pub fn with_options(options: &CompileOptions) -> Self {
+        LazyValidatorSet {
+            options: options.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// 声明共享的定义（字段规则映射，与规则根节点 `defs` 中的定义相同）
+    ///
+    /// 已缓存的编译结果全部失效，之后使用时重新编译。
+    pub fn define(&mut self, name: impl Into<String>, fields: Value) -> &mut Self {
+        self.defs.insert(name.into(), fields);
+        self.shared_schemas = OnceLock::new();
+        for entry in self.entries.values_mut() {
+            entry.compiled = OnceLock::new();
+        }
+        self
+    }
+
+    /// 添加 schema（JSON Schema 或 async-validator 规则），首次使用时编译
+    ///
+    /// 同名 schema 会被替换，缓存的编译结果随之丢弃。
+    pub fn add(&mut self, name: impl Into<String>, schema: Value) -> &mut Self {
+        self.entries.insert(name.into(), Entry::new(schema));
+        self
+    }
+
+    /// 使用指定名称的验证器验证数据，尚未编译时先编译
+    ///
+    /// 名称不存在或编译失败时返回错误。
+    pub fn validate(&self, name: &str, data: &Value) -> Result<ValidationResult, String> {
+        Ok(self.get(name)?.validate(data))
+    }
+
+    /// 按名称获取验证器，尚未编译时先编译
+    pub fn get(&self, name: &str) -> Result<&LinkValidator, String> {
+        let entry = self.entries.get(name).ok_or_else(|| format!("Unknown schema '{}'", name))?;
+        entry.compiled.get_or_init(|| self.compile(name, &entry.schema)).as_ref().map_err(Clone::clone)
+    }
+
+    /// 指定名称的 schema 是否已经编译（编译失败也视为已编译）
+    pub fn is_compiled(&self, name: &str) -> bool {
+        self.entries.get(name).is_some_and(|entry| entry.compiled.get().is_some())
+    }
+
+    /// 已注册的名称（按字典序）
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// 已注册的 schema 数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 是否没有注册 schema
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn compile(&self, name: &str, schema: &Value) -> Result<LinkValidator, String> {
+        if is_async_rules(schema) {
+            return compile_rules(&self.options, &self.defs, name, schema);
+        }
+        let shared = self.shared_schemas.get_or_init(|| shared_schemas_of(&self.defs)).as_ref().map_err(Clone::clone)?;
+        compile_schema(&self.options, shared, name, schema)
+    }
+}
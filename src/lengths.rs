@@ -0,0 +1,63 @@
+//! 按 `CompileOptions::length_unit` 计数的字符串长度约束
+//!
+//! JSON Schema 验证器按 Unicode 标量值计数 `minLength`/`maxLength`。使用其他单位时，
+//! 编译的 schema 中移除这两个关键字，在 schema 验证之后单独检查。
+
+use serde_json::{Map, Value};
+
+use crate::error::ValidationError;
+use crate::keywords;
+use crate::options::LengthUnit;
+
+/// schema 中是否包含字符串长度约束
+pub(crate) fn has_bounds(schema: &Value) -> bool {
+    let mut found = false;
+    keywords::visit_schemas(schema, &mut |obj| {
+        found |= obj.contains_key("minLength") || obj.contains_key("maxLength");
+    });
+    found
+}
+
+/// 移除字符串长度约束后的 schema，交给 JSON Schema 验证器编译
+pub(crate) fn strip(schema: &Value) -> Value {
+    let mut schema = schema.clone();
+    keywords::visit_schemas_mut(&mut schema, &mut |obj| {
+        obj.remove("minLength");
+        obj.remove("maxLength");
+    });
+    schema
+}
+
+/// 在 schema 验证之后按指定单位检查字符串长度
+pub(crate) fn check(root: &Value, data: &Value, unit: LengthUnit) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    keywords::walk_instance(root, data, &mut |obj, value, path| {
+        if let Value::String(value) = value {
+            check_value(obj, value, path, unit, &mut errors);
+        }
+    });
+    errors
+}
+
+fn check_value(obj: &Map<String, Value>, value: &str, path: &str, unit: LengthUnit, errors: &mut Vec<ValidationError>) {
+    let minimum = obj.get("minLength").and_then(Value::as_u64);
+    let maximum = obj.get("maxLength").and_then(Value::as_u64);
+    if minimum.is_none() && maximum.is_none() {
+        return;
+    }
+    let length = unit.count(value) as u64;
+    if let Some(minimum) = minimum.filter(|m| length < *m) {
+        errors.push(ValidationError::new(path, format!("\"{}\" is shorter than {} {}", value, minimum, unit.noun()))
+            .with_code("string.min")
+            .with_value(value)
+            .with_param("limit", minimum)
+            .with_param("actual", length));
+    }
+    if let Some(maximum) = maximum.filter(|m| length > *m) {
+        errors.push(ValidationError::new(path, format!("\"{}\" is longer than {} {}", value, maximum, unit.noun()))
+            .with_code("string.max")
+            .with_value(value)
+            .with_param("limit", maximum)
+            .with_param("actual", length));
+    }
+}
@@ -28,14 +28,21 @@
 //! - `url` -> JSON Schema string 类型 + uri format
 //! - `hex` -> JSON Schema string 类型 + hex pattern
 //! - `any` -> JSON Schema 无类型限制
+//! - `enum` -> 仅由 `enum` 列表约束的 JSON Schema（不生成 type），缺少 `enum` 列表时输出警告
 //! 
 //! ### 验证规则转换
 //! - `required` -> JSON Schema required 字段
-//! - `min`/`max` -> 根据类型转换为 minLength/maxLength 或 minimum/maximum
+//! - `min`/`max` -> 根据类型转换为 minLength/maxLength、minItems/maxItems、minProperties/maxProperties 或 minimum/maximum
 //! - `len` -> 转换为 minLength 和 maxLength (字符串) 或 minItems/maxItems (数组)
 //! - `pattern` -> JSON Schema pattern (正则表达式)
 //! - `enum` -> JSON Schema enum (枚举值)
 //! - `fields` -> JSON Schema properties (嵌套对象)
+//!
+//! ### 规范化转换（验证前执行）
+//! - `trim` -> 去除字符串首尾空白
+//! - `case` -> `"lower"`/`"upper"` 转换字符串大小写
+//! - `truncate` -> 按字素簇（UAX #29 扩展字素簇）截断字符串，不会拆开 Emoji、国旗、组合符号或印度系文字的连字
+//! - 同一字段按 `trim`、`case`、`truncate` 的顺序执行，`validate_and_transform` 返回转换后的数据
 //! 
 //! ### 不支持的规则
 //! 以下规则不支持转换，会在转换时输出警告：
@@ -119,13 +126,127 @@
 //! assert!(result.is_valid);
 //! ```
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::{Value, Map};
+use serde_json::{json, Value, Map};
 use jsonschema::JSONSchema;
-use std::collections::HashMap;
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+mod batch;
+mod cache;
+mod check;
+mod codegen;
+mod codes;
+mod coerce;
+mod compat;
+mod conflicts;
+mod custom;
+mod dates;
+mod error;
+mod export;
+mod fingerprint;
+mod form;
+mod form_data;
+mod grapheme;
+mod groups;
+mod hint;
+mod import;
+mod info;
+mod invalid;
+mod lazy;
+mod keywords;
+mod lengths;
+mod patterns;
+mod limits;
+mod lint;
+mod manifest;
+mod merge;
+mod messages;
+mod meta;
+mod minify;
+mod observe;
+mod openapi;
+mod options;
+#[cfg(feature = "patch")]
+mod patch;
+mod policy;
+mod proto;
+mod redact;
+mod registry;
+mod required;
+mod rule_path;
+mod sample;
+mod set;
+mod stats;
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "tokio")]
+mod stream;
+mod subtree;
+#[cfg(feature = "toml")]
+mod toml;
+mod transform;
+mod warning;
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(feature = "web")]
+mod web;
+#[cfg(feature = "yaml")]
+mod yaml;
+mod zod;
+
+pub use batch::BatchSummary;
+pub use cache::ValidatorCache;
+pub use check::{check, CheckReport};
+pub use codegen::CodegenOptions;
+pub use codes::ERROR_CODES;
+pub use coerce::{BooleanTokens, CoercionOptions, NumberFormat};
+pub use compat::{is_backward_compatible, CompatibilityIssue, CompatibilityReport};
+pub use error::{Segment, ValidationError, ValidationErrors};
+pub use info::{EnumOption, FieldInfo, SchemaInfo};
+pub use invalid::InvalidSample;
+pub use lazy::LazyValidatorSet;
+pub use lint::{lint, LintFinding};
+#[cfg(feature = "derive")]
+pub use link_validator_derive::{link_schema, LinkSchema};
+pub use merge::{merge_rules, MergeStrategy};
+pub use meta::{validate_meta, Draft};
+pub use minify::{minify, MinifyOptions};
+pub use observe::ValidationEvent;
+pub use options::{
+    CompileOptions, LengthUnit, NullPolicy, Phase, RuleMergeStrategy, TitleStyle, UnknownRulePolicy, ValidateOptions,
+};
+pub use policy::ClientPolicy;
+pub use registry::SchemaRegistry;
+pub use sample::SampleOptions;
+pub use set::ValidatorSet;
+pub use stats::ValidatorStats;
+#[cfg(feature = "tokio")]
+pub use stream::ValidationStream;
+pub use warning::{ConversionWarning, Severity, WarningKind};
+#[cfg(feature = "watch")]
+pub use watch::WatchedValidator;
+#[cfg(feature = "web")]
+pub use web::{Validated, ValidationRejection};
+use cache::ResultCache;
+use custom::DocumentValidator;
+use error::ErrorCollector;
+use observe::ValidationHook;
+use stats::Stats;
+use transform::TransformNode;
+use link_validator_core::__private::{
+    is_union_rule, split_definitions, LINK_SCHEMA_MARKER, LINK_SCHEMA_VERSION, VALUE_ROOT,
+};
 
 /// Schema 格式类型枚举
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// 序列化为 `"json-schema"` 或 `"async-validator"`。
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum SchemaFormat {
     /// JSON Schema 格式
     JsonSchema,
@@ -133,15 +254,64 @@ pub enum SchemaFormat {
     AsyncValidator,
 }
 
+impl SchemaFormat {
+    /// 格式名称，`"json-schema"` 或 `"async-validator"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SchemaFormat::JsonSchema => "json-schema",
+            SchemaFormat::AsyncValidator => "async-validator",
+        }
+    }
+}
+
 /// LinkValidator 验证器，包含编译后的schema和原始格式信息
-#[derive(Debug)]
+/// 
+/// `LinkValidator` 实现了 `Send + Sync`，所有验证方法只需要 `&self`，
+/// 可以放入 `Arc` 或 Web 框架的应用状态中被多个线程同时使用。
+/// `clone()` 的开销很小：编译后的 schema 和结果缓存在副本之间共享，
+/// 之后在某个副本上注册文档级验证器只影响该副本（并为其使用独立的结果缓存）。
+#[derive(Debug, Clone)]
 pub struct LinkValidator {
     /// 编译后的 JSON Schema
-    schema: JSONSchema,
+    schema: Arc<JSONSchema>,
     /// 原始 schema 的格式类型
     format: SchemaFormat,
+    /// 验证前的数据规范化计划
+    transforms: TransformNode,
+    /// 编译使用的 JSON Schema（async-validator 规则转换后的结果）
+    json_schema: Arc<Value>,
+    /// 转换过程中产生的警告（不支持的规则等）
+    warnings: Vec<ConversionWarning>,
+    /// 文档级自定义验证器
+    document_validators: Vec<DocumentValidator>,
+    /// 验证结果缓存（可选，在副本之间共享）
+    result_cache: Option<Arc<Mutex<ResultCache>>>,
+    /// 客户端版本降级策略（可选）
+    client_policy: Option<ClientPolicy>,
+    /// 验证器名称（可选，用于清单）
+    name: Option<String>,
+    /// 编译选项
+    options: CompileOptions,
+    /// schema 中是否包含日期范围约束（需要在 schema 验证之后检查）
+    date_bounds: bool,
+    /// 按非默认单位检查的字符串长度约束（需要在 schema 验证之后检查）
+    length_unit: Option<LengthUnit>,
+    /// schema 中是否包含敏感字段（错误输出时需要脱敏）
+    sensitive: bool,
+    /// 各验证分组的子验证器（规则中声明了 `groups` 时）
+    groups: Arc<BTreeMap<String, LinkValidator>>,
+    /// 编译和验证耗时统计
+    stats: Stats,
+    /// 验证事件回调（可选）
+    validation_hook: Option<ValidationHook>,
 }
 
+// 验证器需要能在线程之间共享
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<LinkValidator>();
+};
+
 impl LinkValidator {
     /// 创建一个新的 LinkValidator 实例
     /// 
@@ -154,7 +324,1134 @@ impl LinkValidator {
     /// 
     /// # 返回值
     /// 
-    /// 返回 LinkValidator 验证器，包含编译后的 schema 和原始格式信息
+    /// 返回 LinkValidator 验证器，包含编译后的 schema 和原始格式信息
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let schema = json!({
+    ///     "username": {"type": "string", "required": true, "min": 3}
+    /// });
+    /// 
+    /// let validator = LinkValidator::new(&schema).unwrap();
+    /// ```
+    pub fn new(schema: &Value) -> Result<LinkValidator, String> {
+        compile(schema, &CompileOptions::default())
+    }
+
+    /// 使用编译选项创建一个新的 LinkValidator 实例
+    /// 
+    /// # 参数
+    /// 
+    /// * `schema` - 要编译的 schema（JSON 格式），可以是 JSON Schema 或 async-validator 规则格式
+    /// * `options` - 编译选项，例如验证前的强制类型转换
+    /// 
+    /// # 返回值
+    /// 
+    /// 返回 LinkValidator 验证器，包含编译后的 schema 和原始格式信息
+    pub fn with_options(schema: &Value, options: &CompileOptions) -> Result<LinkValidator, String> {
+        compile(schema, options)
+    }
+
+    /// 用单条规则（或规则数组）描述根节点本身，而不是字段名到规则的映射
+    ///
+    /// 适合验证独立的标量值，例如单独的邮箱地址或数字。错误的 `field` 为空字符串。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::for_value(&json!({"type": "email"})).unwrap();
+    /// assert!(validator.validate(&json!("alice@example.com")).is_valid);
+    /// assert!(!validator.validate(&json!("not-an-email")).is_valid);
+    /// ```
+    pub fn for_value(rule: &Value) -> Result<LinkValidator, String> {
+        let is_rule = |rule: &Value| rule.is_object();
+        let valid = match rule {
+            Value::Array(rules) => !rules.is_empty() && rules.iter().all(is_rule),
+            rule => is_rule(rule),
+        };
+        if !valid {
+            return Err("Root rule must be a rule object or a non-empty array of rule objects".to_string());
+        }
+        let options = CompileOptions::default();
+        let started = Instant::now();
+        limits::check_schema_bytes(&options, rule)?;
+        limits::check_depth(&options, rule)?;
+        compile_async_rules(&json!({ VALUE_ROOT: rule }), &options, true, started)
+    }
+
+    /// 从 YAML 文本编译验证器（需要启用 `yaml` feature）
+    /// 
+    /// YAML 会先解析为 JSON 值，再按 `LinkValidator::new` 的规则检测格式并编译。
+    /// 使用 `serde_norway`（维护中的 `serde_yaml` 分支）解析，支持锚点、别名、标签和多行标量，只接受单个文档。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// 
+    /// let validator = LinkValidator::from_yaml_str(r#"
+    /// name:
+    ///   type: string
+    ///   required: true
+    /// tags:
+    ///   type: array
+    ///   max: 3
+    /// "#).unwrap();
+    /// 
+    /// let result = validator.validate_yaml_str("name: john\ntags: [a, b]").unwrap();
+    /// assert!(result.is_valid);
+    /// ```
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml_str(schema: &str) -> Result<LinkValidator, String> {
+        let schema = yaml::from_str(schema).map_err(|e| format!("Failed to parse YAML: {}", e))?;
+        LinkValidator::new(&schema)
+    }
+
+    /// 从 TOML 文本编译验证器（需要启用 `toml` feature）
+    /// 
+    /// TOML 表会先转换为 JSON 对象，再按 `LinkValidator::new` 的规则检测格式并编译，
+    /// 适合把规则和 Rust 应用的配置文件放在一起维护。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// 
+    /// let validator = LinkValidator::from_toml_str(r#"
+    /// [port]
+    /// type = "integer"
+    /// required = true
+    /// min = 1
+    /// max = 65535
+    /// 
+    /// [log_level]
+    /// type = "enum"
+    /// enum = ["debug", "info", "warn", "error"]
+    /// "#).unwrap();
+    /// 
+    /// let result = validator.validate_toml_str("port = 8080\nlog_level = \"info\"").unwrap();
+    /// assert!(result.is_valid);
+    /// ```
+    #[cfg(feature = "toml")]
+    pub fn from_toml_str(schema: &str) -> Result<LinkValidator, String> {
+        let schema = toml::from_str(schema).map_err(|e| format!("Failed to parse TOML: {}", e))?;
+        LinkValidator::new(&schema)
+    }
+
+    /// 由 `link_schema!` 在编译期生成的 JSON Schema 创建验证器（需要启用 `derive` feature 才能使用该宏）
+    /// 
+    /// 规则已在 `cargo build` 时转换和检查，这里只编译 JSON Schema；错误使用 async-validator 格式
+    /// （`field`、`rulePath`），与 `LinkValidator::new` 编译同一份规则的结果一致。
+    /// 宏在根节点写入 `x-link-schema` 标记和输出格式版本，缺少标记或版本不匹配时返回错误，
+    /// 普通 JSON Schema 请使用 `LinkValidator::new`。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// // 等同于 link_schema!({"name": {"type": "string", "required": true}})
+    /// const RULES: &str =
+    ///     r#"{"type":"object","properties":{"name":{"type":"string"}},"required":["name"],"x-link-schema":1}"#;
+    /// 
+    /// let validator = LinkValidator::from_link_schema(RULES).unwrap();
+    /// assert_eq!(validator.validate(&json!({})).errors[0]["rulePath"], "name.required");
+    /// assert!(LinkValidator::from_link_schema(r#"{"type":"object"}"#).is_err());
+    /// ```
+    pub fn from_link_schema(schema: &str) -> Result<LinkValidator, String> {
+        let mut schema: Value = serde_json::from_str(schema).map_err(|e| format!("Failed to parse schema: {}", e))?;
+        match schema.as_object_mut().and_then(|obj| obj.remove(LINK_SCHEMA_MARKER)) {
+            Some(version) if version == LINK_SCHEMA_VERSION => {}
+            Some(version) => {
+                return Err(format!(
+                    "Unsupported link_schema! version {} (expected {})",
+                    version, LINK_SCHEMA_VERSION
+                ));
+            }
+            None => {
+                return Err(
+                    "Schema was not generated by link_schema!; use LinkValidator::new for other schemas".to_string(),
+                );
+            }
+        }
+        let mut validator = compile(&schema, &CompileOptions::default())?;
+        validator.format = SchemaFormat::AsyncValidator;
+        Ok(validator)
+    }
+
+    /// 从文件加载并编译验证器
+    /// 
+    /// 按扩展名解析文件：`.yaml`/`.yml` 需要启用 `yaml` feature，`.toml` 需要启用 `toml` feature，
+    /// 其他扩展名按 JSON 解析。schema 格式（JSON Schema 或 async-validator 规则）自动检测。
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<LinkValidator, String> {
+        registry::load_file(path.as_ref(), &CompileOptions::default()).map(|(_, validator)| validator)
+    }
+
+    /// 从 OpenAPI 3 文档中提取 schema 并编译
+    /// 
+    /// `pointer` 指向要使用的 schema，可以是 JSON Pointer（`/components/schemas/User`）、
+    /// 引用形式（`#/components/schemas/User`）或组件名称（`User`）。OpenAPI 特有的关键字会被转换：
+    /// `nullable: true` 允许 `null`，布尔形式的 `exclusiveMinimum`/`exclusiveMaximum` 转换为数值形式，
+    /// `#/components/schemas/...` 引用指向一并导入的组件，`discriminator`、`xml`、`example` 等被移除。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let spec = json!({
+    ///     "openapi": "3.0.3",
+    ///     "components": {"schemas": {"User": {
+    ///         "type": "object",
+    ///         "required": ["name"],
+    ///         "properties": {
+    ///             "name": {"type": "string"},
+    ///             "nickname": {"type": "string", "nullable": true}
+    ///         }
+    ///     }}}
+    /// });
+    /// let validator = LinkValidator::from_openapi(&spec, "User").unwrap();
+    /// assert!(validator.validate(&json!({"name": "john", "nickname": null})).is_valid);
+    /// ```
+    pub fn from_openapi(spec: &Value, pointer: &str) -> Result<LinkValidator, String> {
+        let schema = openapi::extract(spec, pointer)?;
+        LinkValidator::new(&schema)
+    }
+
+    /// 从 Joi 的 `describe()` 输出创建验证器
+    /// 
+    /// Joi 的约束（`presence`、`valid`、`min`/`max`/`length`、`pattern`、`email`、`uri`、`integer`、
+    /// 嵌套 `keys`）被转换为 async-validator 规则后编译，错误格式与 async-validator 规则相同。
+    /// 无法转换的约束记录在 `warnings()` 中。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// // Joi.object({ name: Joi.string().min(2).required() }).describe()
+    /// let description = json!({
+    ///     "type": "object",
+    ///     "keys": {
+    ///         "name": {
+    ///             "type": "string",
+    ///             "flags": {"presence": "required"},
+    ///             "rules": [{"name": "min", "args": {"limit": 2}}]
+    ///         }
+    ///     }
+    /// });
+    /// let validator = LinkValidator::from_joi_json(&description).unwrap();
+    /// assert!(!validator.validate(&json!({"name": "a"})).is_valid);
+    /// ```
+    pub fn from_joi_json(description: &Value) -> Result<LinkValidator, String> {
+        Self::from_imported(import::from_joi(description)?)
+    }
+
+    /// 从 Yup 的 `describe()` 输出创建验证器
+    /// 
+    /// Yup 的约束（`optional`/`required`、`oneOf`、`min`/`max`/`length`、`matches`、`email`、`url`、
+    /// `integer`、嵌套 `fields` 和对象数组的 `innerType`）被转换为 async-validator 规则后编译。
+    /// 无法转换的约束记录在 `warnings()` 中。
+    pub fn from_yup_json(description: &Value) -> Result<LinkValidator, String> {
+        Self::from_imported(import::from_yup(description)?)
+    }
+
+    fn from_imported(imported: import::Imported) -> Result<LinkValidator, String> {
+        let mut validator = compile_async_rules(&imported.rules, &CompileOptions::default(), false, Instant::now())?;
+        validator.warnings.splice(0..0, imported.unsupported);
+        Ok(validator)
+    }
+
+    /// 导出 OpenAPI 3.0 风格的 schema 对象，可直接放入 `components.schemas`
+    /// 
+    /// 与验证使用相同的规则生成 API 文档：`type` 中的 `null` 转换为 `nullable`，`examples` 转换为 `example`，
+    /// `itemsBy` 转换为 `oneOf` + `discriminator`，OpenAPI 不支持的关键字被移除。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "max": 20}})).unwrap();
+    /// let schema = validator.to_openapi_schema();
+    /// assert_eq!(schema["properties"]["name"]["maxLength"], 20);
+    /// assert_eq!(schema["required"], json!(["name"]));
+    /// ```
+    pub fn to_openapi_schema(&self) -> Value {
+        openapi::export(&self.json_schema)
+    }
+
+    /// 生成与 schema 对应的 Rust 类型定义（带 serde 属性的结构体和枚举）
+    /// 
+    /// 用于从已有的 async-validator 规则文件生成类型化的数据模型：非必填字段生成 `Option`，
+    /// 字段名转换为 snake_case 并通过 `#[serde(rename)]` 保留原名，字符串枚举生成 Rust 枚举，
+    /// `itemsBy` 生成以判别字段为标签的枚举。无法确定类型的字段使用 `serde_json::Value`。
+    pub fn generate_rust_types(&self, options: &CodegenOptions) -> String {
+        codegen::generate(&self.json_schema, self.name.as_deref(), options)
+    }
+
+    /// 导出等价的 Zod schema 表达式
+    /// 
+    /// 支持类型、长度和数值范围、正则、枚举、嵌套对象和数组，`itemsBy` 转换为 `z.discriminatedUnion`。
+    /// 适合前端从 async-validator 迁移到 Zod 时，仍以后端规则为准生成前端验证代码。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "max": 20}})).unwrap();
+    /// assert_eq!(validator.to_zod(), "z.object({\n  name: z.string().max(20),\n})");
+    /// ```
+    pub fn to_zod(&self) -> String {
+        zod::export(&self.json_schema)
+    }
+
+    /// 导出 protovalidate（`buf.validate`）字段约束
+    /// 
+    /// 生成 proto3 消息定义，`message` 为根消息名称，嵌套对象生成嵌套消息。
+    /// 字段按属性顺序从 1 开始编号，无法确定类型的字段使用 `google.protobuf.Value`。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "max": 20}})).unwrap();
+    /// let proto = validator.to_protovalidate("User");
+    /// assert!(proto.contains("string name = 1 [\n    (buf.validate.field).required = true,\n    (buf.validate.field).string = {max_len: 20}\n  ];"));
+    /// ```
+    pub fn to_protovalidate(&self, message: &str) -> String {
+        proto::export(&self.json_schema, message)
+    }
+
+    /// 生成一份满足 schema 的示例数据
+    /// 
+    /// 遵循类型、枚举、长度和数值范围、格式、正则和嵌套字段约束，可用于生成测试夹具或模糊测试的初始数据。
+    /// 设置 `SampleOptions::seed` 后结果是确定的。生成的数据会用 schema 验证，不满足时换用新的随机值重试，
+    /// 无法满足的约束（例如包含前瞻断言的正则）可能得到不合法的数据。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::{LinkValidator, SampleOptions};
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({
+    ///     "email": {"type": "email", "required": true},
+    ///     "age": {"type": "integer", "min": 18, "max": 60}
+    /// })).unwrap();
+    /// 
+    /// let sample = validator.generate_sample(&SampleOptions::new().seed(42));
+    /// assert!(validator.validate(&sample).is_valid);
+    /// ```
+    pub fn generate_sample(&self, options: &SampleOptions) -> Value {
+        let mut generator = sample::Generator::new(&self.json_schema, options);
+        let mut value = generator.generate();
+        for _ in 1..sample::MAX_ATTEMPTS {
+            if self.schema.is_valid(&value) && self.post_schema_errors(&value).is_empty() {
+                break;
+            }
+            value = generator.generate();
+        }
+        value
+    }
+
+    /// 生成最多 `n` 份违反单个约束的反例数据
+    /// 
+    /// 以 `generate_sample` 生成的合法数据为基础，每份反例只修改一处（字符串过短、缺少必填字段、类型错误、
+    /// 超出数值范围、不在枚举中等），并标注预期失败的关键字和数据路径，用于系统地测试错误处理逻辑。
+    /// 只保留验证时恰好触发该关键字错误的反例，同一约束在数组的多个元素中只生成一次。结果是确定的。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "min": 2}})).unwrap();
+    /// let samples = validator.generate_invalid_samples(10);
+    /// 
+    /// let keywords: Vec<&str> = samples.iter().map(|s| s.keyword.as_str()).collect();
+    /// assert_eq!(keywords, ["type", "required", "type", "minLength"]);
+    /// assert!(samples.iter().all(|s| !validator.validate(&s.data).is_valid));
+    /// ```
+    pub fn generate_invalid_samples(&self, n: usize) -> Vec<InvalidSample> {
+        let base = self.generate_sample(&SampleOptions::new().seed(0));
+        let mut mutator = invalid::Mutator::new(&self.json_schema, &self.schema, &base, n);
+        mutator.run();
+        mutator.samples
+    }
+
+    /// 生成合法数据的 proptest 策略（需要启用 `proptest` feature）
+    ///
+    /// 策略根据转换后 schema 的关键字构造，失败用例会向更小的数据收缩；
+    /// 生成器无法直接满足的约束（自定义验证器、文档级验证器等）通过 `validate` 过滤。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use proptest::strategy::{Strategy, ValueTree};
+    /// use proptest::test_runner::TestRunner;
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::new(&json!({"age": {"type": "integer", "required": true, "min": 18}})).unwrap();
+    /// let mut runner = TestRunner::deterministic();
+    /// let value = validator.arbitrary_valid().new_tree(&mut runner).unwrap().current();
+    /// assert!(value["age"].as_i64().unwrap() >= 18);
+    /// ```
+    #[cfg(feature = "proptest")]
+    pub fn arbitrary_valid(&self) -> impl proptest::strategy::Strategy<Value = Value> + use<> {
+        use proptest::strategy::Strategy;
+
+        let validator = self.clone();
+        strategy::schema(&self.json_schema)
+            .prop_filter("generated value must pass validate", move |value| validator.validate(value).is_valid)
+    }
+
+    /// 设置验证器名称，名称会出现在 `manifest()` 中
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// 验证器名称
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// 转换后 JSON Schema 的内容指纹（FNV-1a 64 位）
+    /// 
+    /// 基于规范形式计算：去掉 `title`、`description` 等注解，`required` 排序，对象键有序，
+    /// 数据规范化计划（`trim` 以及编译选项中的强制转换和 `null` 处理）同样参与；
+    /// 在 schema 之外检查的编译选项（`length_unit`、`required_rejects_blank`）和 `$schema` 声明的草案版本也参与计算。
+    /// 验证行为相同的规则得到相同的指纹，可用于注册表去重和按内容缓存；错误格式和文档级验证器不参与计算。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let a = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "message": "必填"}})).unwrap();
+    /// let b = LinkValidator::new(&json!({"name": [{"required": true}, {"type": "string"}]})).unwrap();
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint::fingerprint(self)
+    }
+
+    /// 规范形式的 SHA-256（64 位小写十六进制），适合作为跨服务共享的内容哈希
+    /// 
+    /// 计算范围与 `fingerprint` 相同。
+    pub fn sha256(&self) -> String {
+        fingerprint::sha256(self)
+    }
+
+    /// 生成描述当前验证配置的清单
+    /// 
+    /// 清单为键顺序稳定的 JSON，包含名称、指纹、草案版本、编译选项、规则数量、
+    /// 自定义关键字和转换警告，可以嵌入 API 响应或日志，
+    /// 便于调用方确认请求由哪一份验证配置处理。指纹为 `fingerprint()` 的 16 位十六进制形式。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let schema = json!({"name": {"type": "string", "required": true, "max": 20}});
+    /// let validator = LinkValidator::new(&schema).unwrap().with_name("user");
+    /// 
+    /// let manifest = validator.manifest();
+    /// assert_eq!(manifest["name"], "user");
+    /// assert_eq!(manifest["format"], "async-validator");
+    /// assert_eq!(manifest["rules"]["fields"], 1);
+    /// assert_eq!(manifest["fingerprint"].as_str().unwrap().len(), 16);
+    /// ```
+    pub fn manifest(&self) -> Value {
+        manifest::build(self)
+    }
+
+    /// 编译后规则的结构化描述：字段、类型、约束、是否必填和嵌套结构
+    /// 
+    /// 用于生成文档表格或自动构建表单，无需重新解析原始规则。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({
+    ///     "username": {"type": "string", "required": true, "min": 3},
+    ///     "address": {"type": "object", "fields": {"city": {"type": "string", "required": true}}}
+    /// })).unwrap();
+    /// 
+    /// let info = validator.schema_info();
+    /// let username = info.fields.iter().find(|f| f.name == "username").unwrap();
+    /// assert!(username.required);
+    /// assert_eq!(username.types, vec!["string"]);
+    /// assert_eq!(username.constraints["minLength"], 3);
+    /// 
+    /// let address = info.fields.iter().find(|f| f.name == "address").unwrap();
+    /// assert_eq!(address.fields[0].path, "address.city");
+    /// ```
+    pub fn schema_info(&self) -> SchemaInfo {
+        info::describe(&self.json_schema, self.format)
+    }
+
+    /// 原始 schema 的格式类型
+    pub fn format(&self) -> SchemaFormat {
+        self.format
+    }
+
+    /// 编译使用的 JSON Schema
+    /// 
+    /// 对于 async-validator 规则，返回转换后生成的 JSON Schema；
+    /// 对于 JSON Schema 输入，返回原始 schema。
+    pub fn json_schema(&self) -> &Value {
+        &self.json_schema
+    }
+
+    /// 导出编译结果，可写入磁盘并通过 `LinkValidator::import_compiled` 恢复
+    ///
+    /// 导出内容为带版本号的 JSON，包含转换后的 JSON Schema、数据规范化计划、转换警告、验证分组和影响验证的编译选项。
+    /// 文档级验证器、结果缓存、客户端策略和名称属于运行时配置，不会导出，恢复后需要重新设置。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "trim": true}})).unwrap();
+    /// let bytes = validator.export_compiled();
+    ///
+    /// let restored = LinkValidator::import_compiled(&bytes).unwrap();
+    /// assert_eq!(restored.json_schema(), validator.json_schema());
+    /// assert!(!restored.validate(&json!({})).is_valid);
+    /// ```
+    pub fn export_compiled(&self) -> Vec<u8> {
+        export::export(self)
+    }
+
+    /// 从 `export_compiled` 导出的内容恢复验证器
+    ///
+    /// 直接编译导出的 JSON Schema，跳过规则转换。内容无法解析、版本不受支持或 schema 无法编译时返回错误。
+    pub fn import_compiled(bytes: &[u8]) -> Result<LinkValidator, String> {
+        export::import(bytes)
+    }
+
+    /// 编译耗时、schema 规模和最近一次验证的耗时
+    ///
+    /// 最近一次验证的耗时在验证器的副本之间共享，并发验证时为其中某一次的耗时。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}})).unwrap();
+    /// assert_eq!(validator.stats().schema_nodes, 2);
+    /// assert!(validator.stats().last_validation.is_none());
+    ///
+    /// validator.validate(&json!({"name": "x"}));
+    /// assert!(validator.stats().last_validation.is_some());
+    /// ```
+    pub fn stats(&self) -> ValidatorStats {
+        self.stats.snapshot()
+    }
+
+    /// 设置验证事件回调，每次验证完成后调用，可用于记录耗时、错误数量等指标
+    ///
+    /// 回调在验证线程上同步执行，应尽快返回。再次设置会替换之前的回调，之后创建的副本共享同一个回调。
+    /// 启用 `tracing` feature 后，检测、转换、编译和验证阶段还会记录 `link_validator` span。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let failures = Arc::new(AtomicUsize::new(0));
+    /// let counter = failures.clone();
+    /// let mut validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}}))
+    ///     .unwrap()
+    ///     .with_name("user");
+    /// validator.on_validation(move |event| {
+    ///     if !event.is_valid() {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// });
+    ///
+    /// validator.validate(&json!({}));
+    /// assert_eq!(failures.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn on_validation<F>(&mut self, hook: F)
+    where
+        F: Fn(&ValidationEvent<'_>) + Send + Sync + 'static,
+    {
+        self.validation_hook = Some(ValidationHook::new(hook));
+    }
+
+    /// 转换过程中产生的警告（不支持的规则等）
+    pub fn warnings(&self) -> &[ConversionWarning] {
+        &self.warnings
+    }
+
+    /// 注册文档级自定义验证器
+    /// 
+    /// 文档级验证器在 schema 验证通过后执行，接收（规范化后的）整个文档，
+    /// 用于表达跨多个字段的约束，其错误与 schema 错误一起通过 ValidationResult 返回。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::{LinkValidator, ValidationError};
+    /// use serde_json::{json, Value};
+    /// 
+    /// let schema = json!({
+    ///     "total": {"type": "number", "required": true},
+    ///     "lines": {"type": "array", "required": true}
+    /// });
+    /// let mut validator = LinkValidator::new(&schema).unwrap();
+    /// 
+    /// validator.register_document_validator(|data: &Value| {
+    ///     let sum: f64 = data["lines"].as_array().unwrap().iter().filter_map(Value::as_f64).sum();
+    ///     if data["total"].as_f64() == Some(sum) {
+    ///         vec![]
+    ///     } else {
+    ///         vec![ValidationError::new("/total", "total must equal the sum of lines")]
+    ///     }
+    /// });
+    /// 
+    /// assert!(validator.validate(&json!({"total": 3, "lines": [1, 2]})).is_valid);
+    /// assert!(!validator.validate(&json!({"total": 4, "lines": [1, 2]})).is_valid);
+    /// ```
+    pub fn register_document_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&Value) -> Vec<ValidationError> + Send + Sync + 'static,
+    {
+        self.add_document_validator(DocumentValidator::new(validator));
+    }
+
+    /// 注册读取验证上下文的文档级验证器
+    /// 
+    /// 验证器只在 `validate_with_context` 传入 `C` 类型的上下文时执行，用于依赖调用方数据的约束
+    /// （例如当前用户角色、功能开关）；其他验证方法和其他类型的上下文不会执行它。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::{LinkValidator, ValidationError};
+    /// use serde_json::{json, Value};
+    /// 
+    /// struct Account {
+    ///     premium: bool,
+    /// }
+    /// 
+    /// let mut validator = LinkValidator::new(&json!({"lines": {"type": "array"}})).unwrap();
+    /// validator.register_context_validator(|data: &Value, account: &Account| {
+    ///     let count = data["lines"].as_array().map_or(0, Vec::len);
+    ///     if count > 5 && !account.premium {
+    ///         vec![ValidationError::new("/lines", "at most 5 lines unless premium")]
+    ///     } else {
+    ///         vec![]
+    ///     }
+    /// });
+    /// 
+    /// let data = json!({"lines": [1, 2, 3, 4, 5, 6]});
+    /// assert!(validator.validate_with_context(&data, &Account { premium: true }).is_valid);
+    /// assert!(!validator.validate_with_context(&data, &Account { premium: false }).is_valid);
+    /// ```
+    pub fn register_context_validator<C, F>(&mut self, validator: F)
+    where
+        C: Any,
+        F: Fn(&Value, &C) -> Vec<ValidationError> + Send + Sync + 'static,
+    {
+        self.add_document_validator(DocumentValidator::with_context(validator));
+    }
+
+    fn add_document_validator(&mut self, validator: DocumentValidator) {
+        self.document_validators.push(validator);
+        // 已缓存的结果不再有效，且不能继续与其他副本共享缓存
+        if let Some(cache) = &mut self.result_cache {
+            let fresh = cache.lock().unwrap_or_else(|e| e.into_inner()).empty_like();
+            *cache = Arc::new(Mutex::new(fresh));
+        }
+    }
+
+    /// 启用验证结果缓存
+    /// 
+    /// 适用于相同数据被反复验证的场景（重试、多个消费者扇出）。
+    /// `validate` 会以数据哈希为键缓存结果，命中时直接返回缓存结果的副本。
+    /// 缓存最多保存 `capacity` 条结果（按最近最少使用淘汰），每条结果在 `ttl` 后过期。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// use std::time::Duration;
+    /// 
+    /// let schema = json!({"name": {"type": "string", "required": true}});
+    /// let validator = LinkValidator::new(&schema)
+    ///     .unwrap()
+    ///     .with_result_cache(1000, Duration::from_secs(60));
+    /// 
+    /// let data = json!({"name": "john"});
+    /// assert!(validator.validate(&data).is_valid);
+    /// assert!(validator.validate(&data).is_valid); // 命中缓存
+    /// ```
+    pub fn with_result_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.result_cache = Some(Arc::new(Mutex::new(ResultCache::new(capacity, ttl))));
+        self
+    }
+
+    /// 设置客户端版本降级策略
+    /// 
+    /// `validate_with` 通过 `ValidateOptions::client_version` 指定客户端版本时，
+    /// 策略中匹配的字段错误会降级为 `ValidationResult::warnings`。
+    pub fn with_client_policy(mut self, policy: ClientPolicy) -> Self {
+        self.client_policy = Some(policy);
+        self
+    }
+
+    /// 清空验证结果缓存
+    pub fn clear_result_cache(&self) {
+        if let Some(ref cache) = self.result_cache {
+            cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        }
+    }
+
+    /// 当前缓存的验证结果数量，未启用缓存时为 0
+    pub fn result_cache_len(&self) -> usize {
+        self.result_cache
+            .as_ref()
+            .map(|cache| cache.lock().unwrap_or_else(|e| e.into_inner()).len())
+            .unwrap_or(0)
+    }
+
+    /// 使用当前验证器验证数据
+    /// 
+    /// # 参数
+    /// 
+    /// * `data` - 要验证的数据（JSON 格式）
+    /// 
+    /// # 返回值
+    /// 
+    /// 返回 ValidationResult 结构体，包含验证结果和错误信息
+    /// 
+    /// 启用结果缓存时，命中缓存同样触发 `on_validation` 回调和指标，事件的 `cached` 为 `true`。
+    pub fn validate(&self, data: &Value) -> ValidationResult {
+        let Some(ref cache) = self.result_cache else {
+            return self.validate_with(data, &ValidateOptions::default());
+        };
+        
+        let started = Instant::now();
+        let key = cache::hash_value(data);
+        let hit = cache.lock().unwrap_or_else(|e| e.into_inner()).get(key, data);
+        if let Some((result, errors)) = hit {
+            let warning_count = result.warnings.as_array().map_or(0, Vec::len);
+            self.emit(started.elapsed(), &errors, warning_count, true);
+            return result;
+        }
+        
+        let options = ValidateOptions::default();
+        let normalized = self.transforms.normalize(data);
+        let collector = self.run_phases(self, &normalized, &options, None);
+        let errors = collector.errors().to_vec();
+        let result = collector.finish(self.format);
+        cache.lock().unwrap_or_else(|e| e.into_inner()).insert(key, data, &result, &errors);
+        result
+    }
+
+    /// 逐条验证一批数据，结果顺序与输入一致
+    /// 
+    /// 可使用 `BatchSummary::from_results` 汇总结果。
+    pub fn validate_batch(&self, items: &[Value]) -> Vec<ValidationResult> {
+        items.iter().map(|item| self.validate(item)).collect()
+    }
+
+    /// 使用 rayon 线程池并行验证一批数据，结果顺序与输入一致（需要启用 `rayon` feature）
+    /// 
+    /// 适合批量导入等大数据量场景。
+    #[cfg(feature = "rayon")]
+    pub fn validate_batch_parallel(&self, items: &[Value]) -> Vec<ValidationResult> {
+        use rayon::prelude::*;
+
+        items.par_iter().map(|item| self.validate(item)).collect()
+    }
+
+    /// 把数据流转换为验证结果流（需要启用 `tokio` feature）
+    /// 
+    /// 适用于 Kafka 消费者、WebSocket 消息等异步管道，结果顺序与输入一致。
+    /// 验证器被克隆到返回的流中（共享编译结果，开销很小）。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use futures_util::{stream, StreamExt};
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"id": {"type": "integer", "required": true}})).unwrap();
+    /// let messages = stream::iter(vec![json!({"id": 1}), json!({"id": "x"})]);
+    /// 
+    /// let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    /// runtime.block_on(async {
+    ///     let mut results = validator.validate_stream(messages);
+    ///     assert!(results.next().await.unwrap().is_valid);
+    ///     assert!(!results.next().await.unwrap().is_valid);
+    ///     assert!(results.next().await.is_none());
+    /// });
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn validate_stream<S>(&self, stream: S) -> ValidationStream<S>
+    where
+        S: futures_core::Stream<Item = Value>,
+    {
+        ValidationStream::new(self.clone(), stream)
+    }
+
+    /// 使用指定的验证选项验证数据
+    /// 
+    /// # 参数
+    /// 
+    /// * `data` - 要验证的数据（JSON 格式）
+    /// * `options` - 验证选项，例如是否在第一个错误处停止
+    /// 
+    /// # 返回值
+    /// 
+    /// 返回 ValidationResult 结构体，包含验证结果和错误信息
+    pub fn validate_with(&self, data: &Value, options: &ValidateOptions) -> ValidationResult {
+        if options.runs(Phase::Coerce) {
+            let normalized = self.transforms.normalize(data);
+            self.validate_normalized(&normalized, options, None)
+        } else {
+            self.validate_normalized(data, options, None)
+        }
+    }
+
+    /// 使用 async-validator 形式的选项对象验证数据
+    /// 
+    /// `options` 与 async-validator `validate(source, options)` 的选项相同（`first`、`firstFields`、`keys`、
+    /// `suppressWarning`、`messages`），见 `ValidateOptions::from_async_options`。选项无效时返回错误。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}})).unwrap();
+    /// let result = validator
+    ///     .validate_with_options(&json!({}), &json!({"messages": {"required": "请填写 %s"}}))
+    ///     .unwrap();
+    /// assert_eq!(result.errors[0]["message"], "请填写 name");
+    /// ```
+    pub fn validate_with_options(&self, data: &Value, options: &Value) -> Result<ValidationResult, String> {
+        let options = ValidateOptions::from_async_options(options)?;
+        Ok(self.validate_with(data, &options))
+    }
+
+    /// 使用调用方提供的验证上下文验证数据
+    /// 
+    /// 上下文传给 `register_context_validator` 注册的验证器，其他阶段与 `validate` 相同。
+    /// 结果依赖上下文，不使用验证结果缓存。
+    pub fn validate_with_context(&self, data: &Value, context: &dyn Any) -> ValidationResult {
+        let options = ValidateOptions::default();
+        let normalized = self.transforms.normalize(data);
+        self.run_phases(self, &normalized, &options, Some(context)).finish(self.format)
+    }
+
+    /// 按验证分组（场景）验证数据
+    /// 
+    /// 声明了 `groups` 的规则只在按其中某个分组验证时生效，未声明分组的规则始终生效；
+    /// `validate` 等不带分组的方法只使用未声明分组的规则。文档级验证器和客户端策略对所有分组生效。
+    /// 分组不存在时返回错误。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({
+    ///     "id": [{"type": "string"}, {"required": true, "groups": ["update"]}],
+    ///     "name": [{"type": "string"}, {"required": true, "groups": ["create"]}]
+    /// })).unwrap();
+    /// 
+    /// let data = json!({"name": "widget"});
+    /// assert!(validator.validate_with_group(&data, "create").unwrap().is_valid);
+    /// assert!(!validator.validate_with_group(&data, "update").unwrap().is_valid);
+    /// ```
+    pub fn validate_with_group(&self, data: &Value, group: &str) -> Result<ValidationResult, String> {
+        let compiled = self
+            .groups
+            .get(group)
+            .ok_or_else(|| format!("Unknown validation group '{}'", group))?;
+        let options = ValidateOptions::default();
+        let normalized = compiled.transforms.normalize(data);
+        Ok(self.run_phases(compiled, &normalized, &options, None).finish(self.format))
+    }
+
+    /// 规则中声明的全部验证分组名称
+    pub fn groups(&self) -> Vec<&str> {
+        self.groups.keys().map(String::as_str).collect()
+    }
+
+    /// 规范化数据并验证，同时返回规范化后的数据副本
+    /// 
+    /// 规则中声明的转换（例如 `truncate`）会先作用于数据副本，再对副本进行验证。
+    /// 
+    /// # 参数
+    /// 
+    /// * `data` - 要验证的数据（JSON 格式）
+    /// 
+    /// # 返回值
+    /// 
+    /// 返回规范化后的数据和 ValidationResult
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let schema = json!({
+    ///     "nickname": {"type": "string", "truncate": 4}
+    /// });
+    /// let validator = LinkValidator::new(&schema).unwrap();
+    /// 
+    /// let (value, result) = validator.validate_and_transform(&json!({"nickname": "小明同学你好"}));
+    /// assert!(result.is_valid);
+    /// assert_eq!(value["nickname"], "小明同学");
+    /// ```
+    pub fn validate_and_transform(&self, data: &Value) -> (Value, ValidationResult) {
+        let mut value = data.clone();
+        self.transforms.apply(&mut value);
+        let result = self.validate_normalized(&value, &ValidateOptions::default(), None);
+        (value, result)
+    }
+
+    /// 验证 YAML 格式的数据（需要启用 `yaml` feature）
+    /// 
+    /// YAML 无法解析时返回错误信息，否则返回与 `validate` 相同的验证结果。
+    #[cfg(feature = "yaml")]
+    pub fn validate_yaml_str(&self, data: &str) -> Result<ValidationResult, String> {
+        let data = yaml::from_str(data).map_err(|e| format!("Failed to parse YAML: {}", e))?;
+        Ok(self.validate(&data))
+    }
+
+    /// 验证 TOML 格式的数据，例如应用配置文件（需要启用 `toml` feature）
+    /// 
+    /// TOML 无法解析时返回错误信息，否则返回与 `validate` 相同的验证结果。
+    #[cfg(feature = "toml")]
+    pub fn validate_toml_str(&self, data: &str) -> Result<ValidationResult, String> {
+        let data = toml::from_str(data).map_err(|e| format!("Failed to parse TOML: {}", e))?;
+        Ok(self.validate(&data))
+    }
+
+    /// 规范化并验证数据，验证通过后反序列化为目标类型
+    /// 
+    /// 规则中声明的转换和编译选项中的强制类型转换会先作用于数据副本，
+    /// 验证通过后把规范化后的数据反序列化为 `T`。
+    /// 
+    /// # 参数
+    /// 
+    /// * `data` - 要验证的数据（JSON 格式）
+    /// 
+    /// # 返回值
+    /// 
+    /// 验证并反序列化成功时返回 `T`；验证失败时返回全部验证错误，
+    /// 反序列化失败时返回一个路径为根节点的错误
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// 
+    /// #[derive(Debug, Deserialize)]
+    /// struct User {
+    ///     username: String,
+    ///     age: u32,
+    /// }
+    /// 
+    /// let schema = json!({
+    ///     "username": {"type": "string", "required": true, "min": 3},
+    ///     "age": {"type": "integer", "required": true, "min": 0}
+    /// });
+    /// let validator = LinkValidator::new(&schema).unwrap();
+    /// 
+    /// let user: User = validator.validate_into(&json!({"username": "john", "age": 30})).unwrap();
+    /// assert_eq!(user.username, "john");
+    /// 
+    /// let errors = validator.validate_into::<User>(&json!({"username": "jo", "age": 30})).unwrap_err();
+    /// assert_eq!(errors.errors[0].path, "/username");
+    /// ```
+    pub fn validate_into<T: DeserializeOwned>(&self, data: &Value) -> Result<T, ValidationErrors> {
+        let mut value = data.clone();
+        self.transforms.apply(&mut value);
+        
+        let errors = self.run_phases(self, &value, &ValidateOptions::default(), None).into_errors();
+        if !errors.is_empty() {
+            return Err(ValidationErrors::new(errors));
+        }
+        
+        serde_json::from_value(value).map_err(|e| {
+            ValidationErrors::new(vec![ValidationError::new("", format!("Failed to deserialize: {}", e)).with_code("deserialize")])
+        })
+    }
+
+    /// 验证可序列化的 Rust 值，无需手动构造 `Value`
+    /// 
+    /// 值通过 `serde_json::to_value` 转换后按 `validate` 验证（包括结果缓存）。
+    /// 无法序列化为 JSON 时（例如键不是字符串的映射）返回一个路径为根节点、错误码为 `serialize` 的错误。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde::Serialize;
+    /// use serde_json::json;
+    /// 
+    /// #[derive(Serialize)]
+    /// struct User {
+    ///     username: String,
+    ///     age: u32,
+    /// }
+    /// 
+    /// let validator = LinkValidator::new(&json!({
+    ///     "username": {"type": "string", "required": true, "min": 3}
+    /// })).unwrap();
+    /// 
+    /// let result = validator.validate_serialize(&User { username: "jo".into(), age: 30 });
+    /// assert!(!result.is_valid);
+    /// assert_eq!(result.errors[0]["field"], "username");
+    /// ```
+    pub fn validate_serialize<T: Serialize + ?Sized>(&self, value: &T) -> ValidationResult {
+        match serde_json::to_value(value) {
+            Ok(data) => self.validate(&data),
+            Err(e) => self.single_error(ValidationError::new("", format!("Failed to serialize: {}", e)).with_code("serialize")),
+        }
+    }
+
+    /// 解析并验证 JSON 文本
+    /// 
+    /// JSON 无法解析时返回一个路径为根节点、错误码为 `parse.json` 的错误（参数 `line`/`column` 为出错位置），
+    /// 否则返回与 `validate` 相同的验证结果。适合直接验证 HTTP 请求体，无需先单独解析。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({"id": {"type": "integer", "required": true}})).unwrap();
+    /// assert!(validator.validate_str(r#"{"id": 1}"#).is_valid);
+    /// 
+    /// let result = validator.validate_str(r#"{"id": "#);
+    /// assert_eq!(result.errors[0]["code"], "parse.json");
+    /// ```
+    pub fn validate_str(&self, json: &str) -> ValidationResult {
+        self.validate_slice(json.as_bytes())
+    }
+
+    /// 解析并验证 JSON 字节（UTF-8），与 `validate_str` 相同
+    ///
+    /// 启用 `simd` feature 后使用 simd-json 解析（先复制输入），验证结果与 serde_json 解析相同。
+    pub fn validate_slice(&self, json: &[u8]) -> ValidationResult {
+        #[cfg(feature = "simd")]
+        match simd_json::serde::from_slice::<Value>(&mut json.to_vec()) {
+            Ok(data) => self.validate(&data),
+            Err(e) => self.single_error(simd_parse_error(&e, json)),
+        }
+
+        #[cfg(not(feature = "simd"))]
+        match serde_json::from_slice(json) {
+            Ok(data) => self.validate(&data),
+            Err(e) => self.single_error(parse_error(&e)),
+        }
+    }
+
+    /// 使用 simd-json 就地解析并验证 JSON 字节（需要启用 `simd` feature）
+    ///
+    /// 输入缓冲区被用作解析的临时空间，调用后内容不再是原来的 JSON；不需要保留输入时可以省去
+    /// `validate_slice` 的复制。JSON 无法解析时同样返回错误码为 `parse.json` 的错误，
+    /// 出错位置按改写后的缓冲区计算，出错位置之前的字符串包含转义的换行时行号可能偏大。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::new(&json!({"id": {"type": "integer", "required": true}})).unwrap();
+    /// let mut body = br#"{"id": "x"}"#.to_vec();
+    /// assert!(!validator.validate_bytes(&mut body).is_valid);
+    /// ```
+    #[cfg(feature = "simd")]
+    pub fn validate_bytes(&self, json: &mut [u8]) -> ValidationResult {
+        match simd_json::serde::from_slice::<Value>(json) {
+            Ok(data) => self.validate(&data),
+            Err(e) => self.single_error(simd_parse_error(&e, json)),
+        }
+    }
+
+    /// 验证表单或查询字符串的键值对
+    /// 
+    /// 键支持 `a.b`、`a[0].b`、`a[b]` 和 `a[]` 写法，按 schema 的结构还原为嵌套对象；
+    /// 叶子上的字符串按 schema 声明的类型转换为数字或布尔值，数组字段可以重复同一个键。
+    /// 键无法解析或与其他键冲突时返回一个错误码为 `parse.form` 的错误（参数 `key` 为出错的键）。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({
+    ///     "page": {"type": "integer", "min": 1},
+    ///     "lines": {"type": "array", "fields": {"qty": {"type": "integer", "required": true}}}
+    /// })).unwrap();
+    /// 
+    /// let pairs = [("page".to_string(), "2".to_string()), ("lines[0].qty".to_string(), "3".to_string())];
+    /// assert!(validator.validate_form(&pairs).is_valid);
+    /// ```
+    pub fn validate_form(&self, pairs: &[(String, String)]) -> ValidationResult {
+        match form_data::unflatten(pairs, &self.json_schema) {
+            Ok(data) => self.validate(&data),
+            Err((key, message)) => self.single_error(form_data::error(key, message)),
+        }
+    }
+
+    /// 按数据路径（JSON Pointer）验证一棵子树
+    /// 
+    /// 在编译使用的 JSON Schema 中找到 `pointer` 对应的子 schema，用它验证 `data`，
+    /// 适合编辑器的增量验证和应用 JSON Patch 之前的检查。错误路径和规则位置相对于整个文档；
+    /// 文档级验证器和客户端策略作用于整个文档，子树验证时不执行。
+    /// 子 schema 在每次调用时编译，路径无效或 schema 中没有对应位置时返回错误。
     /// 
     /// # 示例
     /// 
@@ -162,137 +1459,477 @@ impl LinkValidator {
     /// use link_validator::LinkValidator;
     /// use serde_json::json;
     /// 
-    /// let schema = json!({
-    ///     "username": {"type": "string", "required": true, "min": 3}
-    /// });
+    /// let validator = LinkValidator::new(&json!({
+    ///     "user": {"type": "object", "fields": {
+    ///         "profile": {"type": "object", "fields": {"age": {"type": "integer", "min": 0}}}
+    ///     }}
+    /// })).unwrap();
     /// 
-    /// let validator = LinkValidator::new(&schema).unwrap();
+    /// let result = validator.validate_at("/user/profile", &json!({"age": -1})).unwrap();
+    /// assert!(!result.is_valid);
+    /// assert!(validator.validate_at("/user/missing", &json!({})).is_err());
     /// ```
-    pub fn new(schema: &Value) -> Result<LinkValidator, String> {
-        compile(schema)
+    pub fn validate_at(&self, pointer: &str, data: &Value) -> Result<ValidationResult, String> {
+        let schema = subtree::locate(&self.json_schema, pointer)
+            .ok_or_else(|| format!("No schema at pointer '{}'", pointer))?;
+        let schema = subtree::standalone(&self.json_schema, schema);
+        let compile_target = match self.length_unit {
+            Some(_) => Cow::Owned(lengths::strip(&schema)),
+            None => Cow::Borrowed(&schema),
+        };
+        let compiled = JSONSchema::compile(&compile_target)
+            .map_err(|e| format!("Failed to compile schema at pointer '{}': {}", pointer, e))?;
+        let sub = LinkValidator {
+            schema: Arc::new(compiled),
+            transforms: self.transforms.at(pointer),
+            date_bounds: dates::has_bounds(&schema),
+            sensitive: redact::has_sensitive(&schema),
+            json_schema: Arc::new(schema),
+            document_validators: Vec::new(),
+            result_cache: None,
+            client_policy: None,
+            groups: Arc::default(),
+            ..self.clone()
+        };
+        let options = ValidateOptions::default();
+        let normalized = sub.transforms.normalize(data);
+        let mut collector = sub.run_phases(&sub, &normalized, &options, None);
+        collector.for_each_mut(|error| {
+            if let Some(rule_path) = &error.rule_path {
+                error.rule_path = Some(rule_path::nest(pointer, rule_path, &error.code));
+            }
+            error.path = format!("{}{}", pointer, error.path);
+        });
+        Ok(collector.finish(self.format))
     }
 
-    /// 使用当前验证器验证数据
+    /// 把 JSON Patch 应用到当前文档的副本上并验证结果（需要启用 `patch` feature）
     /// 
-    /// # 参数
+    /// 每个错误的 `params.op` 为导致它的补丁操作的下标（修改了出错位置或其上下级的最后一个操作），
+    /// 与补丁无关的已有错误没有 `op`。补丁无法应用时返回一个错误码为 `patch.apply` 的错误。
+    /// 当前文档保持不变。
     /// 
-    /// * `data` - 要验证的数据（JSON 格式）
+    /// # 示例
     /// 
-    /// # 返回值
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::{from_value, json};
     /// 
-    /// 返回 ValidationResult 结构体，包含验证结果和错误信息
-    pub fn validate(&self, data: &Value) -> ValidationResult {
-        match self.schema.validate(data) {
-            Ok(_) => ValidationResult {
-                is_valid: true,
-                errors: Value::Array(vec![]),
-            },
-            Err(errors) => {
-                if self.format == SchemaFormat::AsyncValidator {
-                    // 转换为 async-validator 错误格式
-                    let error_messages: Vec<Value> = errors.into_iter().map(|e| {
-                        serde_json::json!({
-                            "message": e.to_string(),
-                            "field": e.instance_path.to_string()
-                        })
-                    }).collect();
-                    
-                    ValidationResult {
-                        is_valid: false,
-                        errors: Value::Array(error_messages),
+    /// let validator = LinkValidator::new(&json!({
+    ///     "title": {"type": "string", "required": true, "max": 10}
+    /// })).unwrap();
+    /// 
+    /// let patch = from_value(json!([{"op": "replace", "path": "/title", "value": "a much longer title"}])).unwrap();
+    /// let result = validator.validate_patch(&json!({"title": "draft"}), &patch);
+    /// assert_eq!(result.errors[0]["params"]["op"], 0);
+    /// ```
+    #[cfg(feature = "patch")]
+    pub fn validate_patch(&self, current: &Value, patch: &json_patch::Patch) -> ValidationResult {
+        let mut patched = current.clone();
+        if let Err(e) = json_patch::patch(&mut patched, &patch.0) {
+            return self.single_error(patch::apply_error(&e));
+        }
+        let options = ValidateOptions::default();
+        let normalized = self.transforms.normalize(&patched);
+        let mut collector = self.run_phases(self, &normalized, &options, None);
+        collector.for_each_mut(|error| {
+            if let Some(op) = patch::responsible_op(&patch.0, error) {
+                error.params.insert("op".to_string(), op.into());
+            }
+        });
+        collector.finish(self.format)
+    }
+
+    /// 只包含一个错误的验证结果（输入无法转换为 JSON 时）
+    fn single_error(&self, error: ValidationError) -> ValidationResult {
+        let options = ValidateOptions::default();
+        let mut collector = ErrorCollector::new(&options);
+        collector.push(error);
+        collector.finish(self.format)
+    }
+
+    /// 验证已经规范化的数据
+    fn validate_normalized(&self, data: &Value, options: &ValidateOptions, context: Option<&dyn Any>) -> ValidationResult {
+        self.run_phases(self, data, options, context).finish(self.format)
+    }
+
+    /// JSON Schema 验证器无法表达的约束（日期范围、非默认单位的字符串长度、必填字段的空白值）
+    fn post_schema_errors(&self, data: &Value) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if self.options.required_rejects_blank {
+            errors.extend(required::check_blank(&self.json_schema, data));
+        }
+        if self.date_bounds {
+            errors.extend(dates::check(&self.json_schema, data));
+        }
+        if let Some(unit) = self.length_unit {
+            errors.extend(lengths::check(&self.json_schema, data, unit));
+        }
+        errors
+    }
+
+    /// 依次执行 schema 验证和文档级验证阶段，收集错误
+    ///
+    /// schema 阶段使用 `compiled`（自身或某个验证分组的子验证器），文档级验证器和降级策略使用自身的。
+    fn run_phases<'o>(
+        &self,
+        compiled: &LinkValidator,
+        data: &Value,
+        options: &'o ValidateOptions,
+        context: Option<&dyn Any>,
+    ) -> ErrorCollector<'o> {
+        let _span = observe::enter("validate", self.name.as_deref());
+        let started = Instant::now();
+        let collector = self.collect_errors(compiled, data, options, context);
+        self.emit(started.elapsed(), collector.errors(), collector.warning_count(), false);
+        collector
+    }
+
+    /// 记录一次验证（包括命中结果缓存的验证）：更新统计，输出 tracing 事件和指标，调用 `on_validation` 回调
+    fn emit(&self, duration: Duration, errors: &[ValidationError], warning_count: usize, cached: bool) {
+        self.stats.record_validation(duration);
+        let event = ValidationEvent {
+            name: self.name.as_deref(),
+            format: self.format,
+            duration,
+            error_count: errors.len(),
+            warning_count,
+            errors,
+            cached,
+        };
+        observe::record(&event);
+        observe::metrics(&event);
+        if let Some(hook) = &self.validation_hook {
+            hook.call(&event);
+        }
+    }
+
+    /// `run_phases` 的实现
+    fn collect_errors<'o>(
+        &self,
+        compiled: &LinkValidator,
+        data: &Value,
+        options: &'o ValidateOptions,
+        context: Option<&dyn Any>,
+    ) -> ErrorCollector<'o> {
+        let mut collector = ErrorCollector::new(options);
+        // 当前客户端版本下降级为警告的字段
+        let downgraded = match (&self.client_policy, &options.client_version) {
+            (Some(policy), Some(version)) => policy.downgraded_fields(version),
+            _ => Vec::new(),
+        };
+        // 判断后续阶段是否继续执行
+        let proceed = |collector: &ErrorCollector| {
+            !collector.is_stopped() && (collector.is_empty() || !options.short_circuit)
+        };
+        
+        // 大部分数据验证通过，先用不生成错误的 is_valid 快速判断，失败时再收集错误
+        if options.runs(Phase::Schema)
+            && !compiled.schema.is_valid(data)
+            && let Err(errors) = compiled.schema.validate(data)
+        {
+            for error in errors {
+                let field = policy::error_field(&error);
+                if !options.includes_field(&field) {
+                    continue;
+                }
+                let mut converted = ValidationError::from(&error);
+                converted.hint = hint::suggest(&converted, &compiled.json_schema, data);
+                if compiled.format == SchemaFormat::AsyncValidator {
+                    converted.rule_path =
+                        Some(rule_path::from_schema_path(&error.schema_path, &converted.code, &converted.params));
+                }
+                if policy::is_downgraded(&downgraded, &field) {
+                    collector.warn(converted);
+                } else if !collector.push(converted) {
+                    break;
+                }
+            }
+        }
+        
+        // JSON Schema 不支持的日期范围、长度单位和空白值检查，在 schema 验证之后进行
+        if options.runs(Phase::Schema) && !collector.is_stopped() {
+            for mut error in compiled.post_schema_errors(data) {
+                if !options.includes_field(&error.path) {
+                    continue;
+                }
+                if compiled.format == SchemaFormat::AsyncValidator {
+                    error.rule_path = Some(rule_path::from_instance_path(&error.path, &error.code));
+                }
+                if policy::is_downgraded(&downgraded, &error.path) {
+                    collector.warn(error);
+                } else if !collector.push(error) {
+                    break;
+                }
+            }
+        }
+        
+        // 默认情况下文档级验证仅在 schema 验证通过后执行
+        if options.runs(Phase::Document) && proceed(&collector) {
+            'document: for validator in &self.document_validators {
+                for error in validator.validate(data, context) {
+                    if !options.includes_field(&error.path) {
+                        continue;
                     }
-                } else {
-                    // 保持 JSON Schema 错误格式
-                    let error_messages: Vec<Value> = errors.into_iter().map(|e| {
-                        serde_json::json!({
-                            "message": e.to_string(),
-                            "instancePath": e.instance_path.to_string()
-                        })
-                    }).collect();
-                    
-                    ValidationResult {
-                        is_valid: false,
-                        errors: Value::Array(error_messages),
+                    if policy::is_downgraded(&downgraded, &error.path) {
+                        collector.warn(error);
+                    } else if !collector.push(error) {
+                        break 'document;
                     }
                 }
             }
         }
+        
+        // 自定义错误信息在脱敏之前替换，模板中引用的值同样会被脱敏
+        if !options.messages.is_empty() {
+            collector.for_each_mut(|error| messages::apply(error, &options.messages));
+        }
+        
+        // 敏感字段上的错误不输出出错的值
+        if (compiled.sensitive || !options.redacted.is_empty()) && collector.has_errors() {
+            let pointers = if compiled.sensitive { redact::sensitive_paths(&compiled.json_schema, data) } else { Vec::new() };
+            collector.for_each_mut(|error| {
+                if redact::matches(error, &pointers, &options.redacted) {
+                    redact::apply(error, data);
+                }
+            });
+        }
+        
+        collector
+    }
+}
+
+/// 可以生成 async-validator 规则的类型
+/// 
+/// 通常通过 `#[derive(LinkSchema)]`（需要启用 `derive` feature）实现，
+/// 字段规则使用 `#[rule(...)]` 属性声明。
+/// 
+/// # 示例
+/// 
+/// ```
+/// use link_validator::LinkSchema;
+/// use serde_json::{json, Value};
+/// 
+/// struct Login;
+/// 
+/// impl LinkSchema for Login {
+///     fn rules() -> Value {
+///         json!({"username": {"type": "string", "required": true, "min": 3}})
+///     }
+/// }
+/// 
+/// let validator = Login::validator().unwrap();
+/// assert!(validator.validate(&json!({"username": "john"})).is_valid);
+/// ```
+pub trait LinkSchema {
+    /// 生成 async-validator 规则
+    fn rules() -> Value;
+
+    /// 根据规则创建验证器
+    fn validator() -> Result<LinkValidator, String> {
+        LinkValidator::new(&Self::rules())
     }
 }
 
+/// 派生宏生成代码使用的内部导出，不属于公开 API
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json::{json, Map, Value};
+}
+
 /// 验证结果
-#[derive(Debug)]
+///
+/// 实现了 `Serialize`/`Deserialize`，可以直接作为 HTTP 响应返回或保存。序列化格式带有版本号，
+/// 当前为版本 1：
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "format": "async-validator",
+///   "valid": false,
+///   "truncated": false,
+///   "errors": [{"message": "...", "field": "username", "code": "string.min"}],
+///   "warnings": []
+/// }
+/// ```
+///
+/// `errors`/`warnings` 中的错误对象与原始 schema 格式一致（`format` 为 `"json-schema"` 时使用 `instancePath`）。
+/// 反序列化时拒绝不认识的版本号。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{LinkValidator, ValidationResult};
+/// use serde_json::json;
+///
+/// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}})).unwrap();
+/// let result = validator.validate(&json!({}));
+///
+/// let stored = serde_json::to_value(&result).unwrap();
+/// assert_eq!(stored["version"], 1);
+/// assert_eq!(stored["valid"], false);
+///
+/// let restored: ValidationResult = serde_json::from_value(stored).unwrap();
+/// assert_eq!(restored.errors, result.errors);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "ResultDocument", try_from = "ResultDocument")]
 pub struct ValidationResult {
     /// 验证是否通过
     pub is_valid: bool,
     /// 错误信息（JSON 格式）
     pub errors: Value,
+    /// 错误列表是否因 `max_errors` 限制而被截断
+    pub truncated: bool,
+    /// 按客户端版本策略降级的错误（JSON 格式，与 `errors` 相同）
+    pub warnings: Value,
+    /// 错误对象使用的格式（与验证器的原始 schema 格式一致）
+    pub format: SchemaFormat,
 }
 
-// 内部结构，不对外公开
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct AsyncValidatorRule {
-    /// 字段类型
-    #[serde(rename = "type")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    field_type: Option<String>,
-    
-    /// 是否必填
-    #[serde(skip_serializing_if = "Option::is_none")]
-    required: Option<bool>,
-    
-    /// 最小长度（字符串）或最小值（数字）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    min: Option<Value>,
-    
-    /// 最大长度（字符串）或最大值（数字）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    max: Option<Value>,
-    
-    /// 精确长度
-    #[serde(skip_serializing_if = "Option::is_none")]
-    len: Option<Value>,
-    
-    /// 正则表达式模式
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pattern: Option<String>,
-    
-    /// 枚举值
-    #[serde(rename = "enum")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    enum_values: Option<Vec<Value>>,
-    
-    /// 错误消息
-    #[serde(skip_serializing_if = "Option::is_none")]
-    message: Option<String>,
-    
-    /// 是否检查空白字符
-    #[serde(skip_serializing_if = "Option::is_none")]
-    whitespace: Option<bool>,
-    
-    /// 字段验证器（不支持转换）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    validator: Option<Value>,
-    
-    /// 异步字段验证器（不支持转换）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    async_validator: Option<Value>,
-    
-    /// 触发方式（不支持转换）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    trigger: Option<Value>,
-    
-    /// 嵌套字段规则（用于支持深度嵌套）
-    #[serde(skip_serializing_if = "Option::is_none")]
-    fields: Option<Value>,
-    
-    /// 其他未映射的属性
-    #[serde(flatten)]
-    extra: Map<String, Value>,
+impl ValidationResult {
+    /// 按字段分组的错误
+    /// 
+    /// 键为结果中的字段路径（async-validator 格式为 `field`，JSON Schema 格式为 `instancePath`），
+    /// 根节点为空字符串；每组内保持原有顺序。
+    /// 
+    /// # 示例
+    /// 
+    /// ```
+    /// use link_validator::LinkValidator;
+    /// use serde_json::json;
+    /// 
+    /// let validator = LinkValidator::new(&json!({
+    ///     "name": [{"type": "string", "min": 3}, {"pattern": "^[a-z]+$"}]
+    /// })).unwrap();
+    /// 
+    /// let groups = validator.validate(&json!({"name": "A"})).grouped_by_field();
+    /// assert_eq!(groups["name"].len(), 2);
+    /// ```
+    pub fn grouped_by_field(&self) -> HashMap<String, Vec<ValidationError>> {
+        let mut groups: HashMap<String, Vec<ValidationError>> = HashMap::new();
+        for error in self.errors.as_array().into_iter().flatten() {
+            groups
+                .entry(self.field_of(error).to_string())
+                .or_default()
+                .push(ValidationError::from_rendered(error, self.format));
+        }
+        groups
+    }
+
+    /// 适合日志和命令行输出的多行摘要
+    /// 
+    /// 首行为结论，之后每个错误一行（`字段: 信息`，有修正建议时附在括号中），
+    /// 错误列表被截断时在末尾注明。
+    pub fn summary(&self) -> String {
+        let errors = self.errors.as_array().map(Vec::as_slice).unwrap_or_default();
+        if errors.is_empty() {
+            return "Validation passed".to_string();
+        }
+        let mut summary = format!(
+            "Validation failed with {} error{}:",
+            errors.len(),
+            if errors.len() == 1 { "" } else { "s" }
+        );
+        for error in errors {
+            let field = self.field_of(error);
+            summary.push_str("\n- ");
+            if !field.is_empty() {
+                summary.push_str(field);
+                summary.push_str(": ");
+            }
+            summary.push_str(error["message"].as_str().unwrap_or_default());
+            if let Some(hint) = error["hint"].as_str() {
+                summary.push_str(&format!(" ({})", hint));
+            }
+        }
+        if self.truncated {
+            summary.push_str("\n(more errors omitted)");
+        }
+        summary
+    }
+
+    /// 错误对象中的字段路径
+    fn field_of<'a>(&self, error: &'a Value) -> &'a str {
+        let key = match self.format {
+            SchemaFormat::AsyncValidator => "field",
+            SchemaFormat::JsonSchema => "instancePath",
+        };
+        error[key].as_str().unwrap_or_default()
+    }
+}
+
+/// 验证结果序列化格式的当前版本
+const RESULT_VERSION: u32 = 1;
+
+/// 验证结果的序列化格式
+#[derive(Serialize, Deserialize)]
+struct ResultDocument {
+    version: u32,
+    format: SchemaFormat,
+    valid: bool,
+    #[serde(default)]
+    truncated: bool,
+    errors: Value,
+    #[serde(default = "empty_array")]
+    warnings: Value,
 }
 
-// 内部类型别名
-type AsyncValidatorRules = HashMap<String, Vec<AsyncValidatorRule>>;
+fn empty_array() -> Value {
+    Value::Array(Vec::new())
+}
+
+impl From<ValidationResult> for ResultDocument {
+    fn from(result: ValidationResult) -> Self {
+        ResultDocument {
+            version: RESULT_VERSION,
+            format: result.format,
+            valid: result.is_valid,
+            truncated: result.truncated,
+            errors: result.errors,
+            warnings: result.warnings,
+        }
+    }
+}
+
+impl TryFrom<ResultDocument> for ValidationResult {
+    type Error = String;
+
+    fn try_from(document: ResultDocument) -> Result<Self, String> {
+        if document.version != RESULT_VERSION {
+            return Err(format!("Unsupported validation result version {}", document.version));
+        }
+        Ok(ValidationResult {
+            is_valid: document.valid,
+            errors: document.errors,
+            truncated: document.truncated,
+            warnings: document.warnings,
+            format: document.format,
+        })
+    }
+}
+
+/// 两个验证器的 schema 是否等价
+/// 
+/// 比较 `LinkValidator::fingerprint` 使用的规范形式（不比较哈希值，不会因碰撞误判）。
+/// 
+/// # 示例
+/// 
+/// ```
+/// use link_validator::{schemas_equivalent, LinkValidator};
+/// use serde_json::json;
+/// 
+/// let rules = LinkValidator::new(&json!({"age": {"type": "integer", "min": 0}})).unwrap();
+/// let schema = LinkValidator::new(&json!({
+///     "type": "object",
+///     "title": "Person",
+///     "properties": {"age": {"type": "integer", "minimum": 0.0}}
+/// })).unwrap();
+/// assert!(schemas_equivalent(&rules, &schema));
+/// ```
+pub fn schemas_equivalent(a: &LinkValidator, b: &LinkValidator) -> bool {
+    fingerprint::canonical(a) == fingerprint::canonical(b)
+}
 
 /// 编译 schema，返回 LinkValidator 验证器
 /// 
@@ -302,52 +1939,71 @@ type AsyncValidatorRules = HashMap<String, Vec<AsyncValidatorRule>>;
 /// # 参数
 /// 
 /// * `schema` - 要编译的 schema（JSON 格式），可以是 JSON Schema 或 async-validator 规则格式
+/// * `options` - 编译选项
 /// 
 /// # 返回值
 /// 
 /// 返回 LinkValidator 验证器，包含编译后的 schema 和原始格式信息
-fn compile(schema: &Value) -> Result<LinkValidator, String> {
+fn compile(schema: &Value, options: &CompileOptions) -> Result<LinkValidator, String> {
+    let started = Instant::now();
+    limits::check_schema_bytes(options, schema)?;
+    limits::check_depth(options, schema)?;
+    
     // 判断是否为 async-validator 规则格式
-    if is_async_rules(schema) {
-        // 如果是 async-validator 规则，则需要转换
-        match parse_async_rules(schema) {
-            Ok(rules) => {
-                match convert_to_jsonschema(&rules) {
-                    Ok(conversion_result) => {
-                        // 输出不支持的规则警告
-                        for unsupported in &conversion_result.unsupported {
-                            eprintln!("Warning: {}", unsupported);
-                        }
-                        
-                        // 编译转换后的 schema
-                        match JSONSchema::compile(&conversion_result.schema) {
-                            Ok(compiled_schema) => {
-                                Ok(LinkValidator {
-                                    schema: compiled_schema,
-                                    format: SchemaFormat::AsyncValidator,
-                                })
-                            },
-                            Err(e) => {
-                                Err(format!("Failed to compile converted schema: {}", e))
-                            }
-                        }
-                    },
-                    Err(e) => {
-                        Err(format!("Failed to convert async-validator rules: {}", e))
-                    }
-                }
-            },
-            Err(e) => {
-                Err(format!("Failed to parse async-validator rules: {}", e))
-            }
-        }
+    let is_async = {
+        let _span = observe::enter("detect", None);
+        is_async_rules(schema)
+    };
+    if is_async {
+        compile_async_rules(schema, options, false, started)
     } else {
         // 否则直接编译
-        match JSONSchema::compile(schema) {
+        limits::check_fields(options, schema)?;
+        patterns::check_schema(options, schema)?;
+        let length_unit = length_unit(options, schema);
+        let compile_target = match length_unit {
+            Some(_) => Cow::Owned(lengths::strip(schema)),
+            None => Cow::Borrowed(schema),
+        };
+        let compile_started = Instant::now();
+        let compiled = {
+            let _span = observe::enter("compile", None);
+            JSONSchema::compile(&compile_target)
+        };
+        match compiled {
             Ok(compiled_schema) => {
+                let stats = Stats::new(Duration::ZERO, compile_started.elapsed(), schema);
+                limits::check_compile_time(options, started)?;
+                let mut transforms = TransformNode::default();
+                transforms.add_option_transforms(schema, options);
+                
+                // 无法识别或不属于声明草案的关键字会被静默忽略，记录为警告
+                let draft = meta::Draft::of(schema);
+                let mut warnings: Vec<ConversionWarning> = meta::ignored_keywords(schema, draft)
+                    .iter()
+                    .map(|ignored| {
+                        ConversionWarning::new(ignored.to_finding(draft).to_string()).with_rule(ignored.keyword.as_str())
+                    })
+                    .collect();
+                warning::apply_severities(&mut warnings, &options.warning_severities)?;
+                log_warnings(&warnings);
                 Ok(LinkValidator {
-                    schema: compiled_schema,
+                    schema: Arc::new(compiled_schema),
                     format: SchemaFormat::JsonSchema,
+                    transforms,
+                    json_schema: Arc::new(schema.clone()),
+                    warnings,
+                    document_validators: Vec::new(),
+                    result_cache: None,
+                    client_policy: None,
+                    name: None,
+                    options: options.clone(),
+                    date_bounds: dates::has_bounds(schema),
+                    length_unit,
+                    sensitive: redact::has_sensitive(schema),
+                    groups: Arc::default(),
+                    stats,
+                    validation_hook: None,
                 })
             },
             Err(e) => {
@@ -357,8 +2013,154 @@ fn compile(schema: &Value) -> Result<LinkValidator, String> {
     }
 }
 
+/// 编译 async-validator 规则（不做格式检测）
+///
+/// `root_value` 为 `true` 时，规则映射中只有 `VALUE_ROOT` 一个字段，它描述根节点本身（见 `for_value`）。
+fn compile_async_rules(
+    schema: &Value,
+    options: &CompileOptions,
+    root_value: bool,
+    started: Instant,
+) -> Result<LinkValidator, String> {
+    // 声明了验证分组时，不带分组的规则和每个分组分别编译
+    let group_names = groups::names(schema)?;
+    if !group_names.is_empty() {
+        let mut validator = compile_async_rules(&groups::select(schema, None), options, root_value, started)?;
+        let mut compiled = BTreeMap::new();
+        for name in group_names {
+            let group = compile_async_rules(&groups::select(schema, Some(&name)), options, root_value, started)?;
+            compiled.insert(name, group);
+        }
+        validator.groups = Arc::new(compiled);
+        return Ok(validator);
+    }
+    
+    // 如果是 async-validator 规则，则需要转换
+    let conversion_started = Instant::now();
+    let (schema, conflicts) = conflicts::resolve(schema, options.merge_strategy)?;
+    let mut conversion_result = {
+        let _span = observe::enter("convert", None);
+        if root_value {
+            convert_value_rules(&schema[VALUE_ROOT])?
+        } else {
+            convert_rules(&schema)?
+        }
+    };
+    conversion_result.unsupported.splice(0..0, conflicts);
+    if options.form_annotations {
+        form::annotate(&mut conversion_result.schema);
+    }
+    if let Some(style) = options.titles {
+        add_titles(&mut conversion_result.schema, style);
+    }
+    if options.required_rejects_empty {
+        required::reject_empty(&mut conversion_result.schema, !options.required_rejects_blank);
+    }
+    
+    match options.unknown_rules {
+        UnknownRulePolicy::Warn => {}
+        UnknownRulePolicy::Ignore => conversion_result.unsupported.retain(|warning| warning.kind != WarningKind::UnknownRule),
+        UnknownRulePolicy::Error => {
+            let unknown: Vec<&str> = conversion_result
+                .unsupported
+                .iter()
+                .filter(|warning| warning.kind == WarningKind::UnknownRule)
+                .map(|warning| warning.message.as_str())
+                .collect();
+            if !unknown.is_empty() {
+                return Err(format!("Unknown rules are not allowed: {}", unknown.join("; ")));
+            }
+        }
+    }
+    
+    warning::apply_severities(&mut conversion_result.unsupported, &options.warning_severities)?;
+    log_warnings(&conversion_result.unsupported);
+    
+    let conversion_duration = conversion_started.elapsed();
+    limits::check_fields(options, &conversion_result.schema)?;
+    patterns::check_schema(options, &conversion_result.schema)?;
+    limits::check_compile_time(options, started)?;
+    
+    // 编译转换后的 schema
+    let length_unit = length_unit(options, &conversion_result.schema);
+    let compile_target = match length_unit {
+        Some(_) => Cow::Owned(lengths::strip(&conversion_result.schema)),
+        None => Cow::Borrowed(&conversion_result.schema),
+    };
+    let compile_started = Instant::now();
+    let compiled = {
+        let _span = observe::enter("compile", None);
+        JSONSchema::compile(&compile_target)
+    };
+    match compiled {
+        Ok(compiled_schema) => {
+            let stats = Stats::new(conversion_duration, compile_started.elapsed(), &conversion_result.schema);
+            limits::check_compile_time(options, started)?;
+            let mut transforms = conversion_result.transforms;
+            transforms.add_option_transforms(&conversion_result.schema, options);
+            let date_bounds = dates::has_bounds(&conversion_result.schema);
+            let sensitive = redact::has_sensitive(&conversion_result.schema);
+            Ok(LinkValidator {
+                schema: Arc::new(compiled_schema),
+                format: SchemaFormat::AsyncValidator,
+                transforms,
+                json_schema: Arc::new(conversion_result.schema),
+                warnings: conversion_result.unsupported,
+                document_validators: Vec::new(),
+                result_cache: None,
+                client_policy: None,
+                name: None,
+                options: options.clone(),
+                date_bounds,
+                length_unit,
+                sensitive,
+                groups: Arc::default(),
+                stats,
+                validation_hook: None,
+            })
+        },
+        Err(e) => {
+            Err(format!("Failed to compile converted schema: {}", e))
+        }
+    }
+}
+
+/// 按严重程度输出转换警告，由调用方配置的日志实现决定去向
+fn log_warnings(warnings: &[ConversionWarning]) {
+    warnings.iter().for_each(observe::warning);
+}
+
+/// JSON 解析错误
+#[cfg(not(feature = "simd"))]
+fn parse_error(error: &serde_json::Error) -> ValidationError {
+    ValidationError::new("", format!("Invalid JSON: {}", error))
+        .with_code("parse.json")
+        .with_param("line", error.line())
+        .with_param("column", error.column())
+}
+
+/// simd-json 解析错误，按输入计算出错的行和列（从 1 开始）
+#[cfg(feature = "simd")]
+fn simd_parse_error(error: &simd_json::Error, input: &[u8]) -> ValidationError {
+    let before = &input[..error.index().min(input.len())];
+    let line = before.iter().filter(|b| **b == b'\n').count() + 1;
+    let column = before.iter().rev().take_while(|b| **b != b'\n').count() + 1;
+    ValidationError::new("", format!("Invalid JSON: {}", error))
+        .with_code("parse.json")
+        .with_param("line", line)
+        .with_param("column", column)
+}
+
+/// 需要在 schema 验证之后检查的长度单位（默认单位或没有长度约束时为 None）
+fn length_unit(options: &CompileOptions, schema: &Value) -> Option<LengthUnit> {
+    (options.length_unit != LengthUnit::Chars && lengths::has_bounds(schema)).then_some(options.length_unit)
+}
+
 /// 判断给定的值是否为 async-validator 规则格式
 fn is_async_rules(value: &Value) -> bool {
+    if is_union_rule(value) {
+        return true;
+    }
     // 简单检查是否为 async-validator 规则格式
     // async-validator 规则通常是对象，其中值是规则对象或规则对象数组
     match value {
@@ -368,11 +2170,10 @@ fn is_async_rules(value: &Value) -> bool {
                 let type_value = obj.get("type").unwrap().as_str().unwrap();
                 // JSON Schema 通常具有这些类型值
                 match type_value {
-                    "object" | "array" | "string" | "number" | "integer" | "boolean" => {
-                        // 进一步检查是否具有 JSON Schema 特征字段
-                        if obj.contains_key("properties") || obj.contains_key("items") {
-                            return false; // 很可能是 JSON Schema
-                        }
+                    // 进一步检查是否具有 JSON Schema 特征字段
+                    "object" | "array" | "string" | "number" | "integer" | "boolean"
+                        if obj.contains_key("properties") || obj.contains_key("items") => {
+                        return false; // 很可能是 JSON Schema
                     }
                     _ => ()
                 }
@@ -399,12 +2200,10 @@ fn is_async_rules(value: &Value) -> bool {
                     },
                     // async-validator 规则可以是对象数组
                     Value::Array(arr) => {
-                        if !arr.is_empty() {
-                            if let Value::Object(rule_obj) = &arr[0] {
-                                // 检查数组中的对象是否符合 async-validator 规则特征
-                                if is_async_rule_object(rule_obj) {
-                                    return true;
-                                }
+                        if let Some(Value::Object(rule_obj)) = arr.first() {
+                            // 检查数组中的对象是否符合 async-validator 规则特征
+                            if is_async_rule_object(rule_obj) {
+                                return true;
                             }
                         }
                     }
@@ -434,9 +2233,17 @@ fn is_async_rule_object(obj: &Map<String, Value>) -> bool {
         "exclusiveMaximum", "format"
     ];
     
+    // `date` 不是 JSON Schema 类型，此时 `format` 为日期规则；数值规则也可以使用 `multipleOf`
+    let rule_type = obj.get("type").and_then(Value::as_str);
+    let is_rule_keyword = |field: &str| match field {
+        "format" => rule_type == Some("date"),
+        "multipleOf" => matches!(rule_type, Some("number") | Some("integer")),
+        _ => false,
+    };
+
     // 如果包含 JSON Schema 特有字段，则不是 async-validator 规则
     for field in &json_schema_fields {
-        if obj.contains_key(*field) {
+        if obj.contains_key(*field) && !is_rule_keyword(field) {
             return false;
         }
     }
@@ -452,291 +2259,63 @@ fn is_async_rule_object(obj: &Map<String, Value>) -> bool {
     !obj.is_empty()
 }
 
-/// 解析 async-validator 规则，支持对象和数组两种格式
-fn parse_async_rules(value: &Value) -> Result<AsyncValidatorRules, Box<dyn std::error::Error>> {
-    let mut rules = HashMap::new();
-    
-    if let Value::Object(obj) = value {
-        for (field_name, field_rules) in obj {
-            match field_rules {
-                // 单个规则对象格式 { field: {type: "string", required: true} }
-                Value::Object(rule_obj) => {
-                    let rule: AsyncValidatorRule = serde_json::from_value(Value::Object(rule_obj.clone()))
-                        .map_err(|e| format!("Failed to parse rule for field '{}': {}", field_name, e))?;
-                    rules.insert(field_name.clone(), vec![rule]);
-                },
-                // 规则数组格式 { field: [{type: "string", required: true}, {min: 3}] }
-                Value::Array(rule_arr) => {
-                    let mut parsed_rules = Vec::new();
-                    for (index, rule_value) in rule_arr.iter().enumerate() {
-                        if let Value::Object(rule_obj) = rule_value {
-                            let rule: AsyncValidatorRule = serde_json::from_value(Value::Object(rule_obj.clone()))
-                                .map_err(|e| format!("Failed to parse rule {} for field '{}': {}", index, field_name, e))?;
-                            parsed_rules.push(rule);
-                        } else {
-                            return Err(format!("Rule {} for field '{}' is not an object", index, field_name).into());
-                        }
-                    }
-                    rules.insert(field_name.clone(), parsed_rules);
-                },
-                _ => {
-                    return Err(format!("Invalid rule format for field '{}'", field_name).into());
-                }
+/// 为生成的 schema 中缺少 `title` 的属性填充标题（递归处理嵌套对象、数组项和 `itemsBy` 分支）
+fn add_titles(schema: &mut Value, style: TitleStyle) {
+    let Value::Object(obj) = schema else {
+        return;
+    };
+    if let Some(Value::Object(properties)) = obj.get_mut("properties") {
+        for (name, property) in properties.iter_mut() {
+            if let Value::Object(property) = property {
+                property
+                    .entry("title".to_string())
+                    .or_insert_with(|| Value::String(style.title(name)));
             }
+            add_titles(property, style);
         }
-        Ok(rules)
-    } else {
-        Err("Input is not an object".into())
     }
-}
-
-/// 将 async-validator 规则转换为 JSON Schema
-fn convert_to_jsonschema(rules: &AsyncValidatorRules) -> Result<ConversionResult, Box<dyn std::error::Error>> {
-    let mut schema_object = Map::new();
-    schema_object.insert("type".to_string(), Value::String("object".to_string()));
-    
-    let mut properties = Map::new();
-    let mut required = Vec::new();
-    let mut unsupported = Vec::new();
-    
-    for (field_name, field_rules) in rules {
-        let mut field_schema = Map::new();
-        let mut field_required = false;
-        
-        for rule in field_rules {
-            // 处理 type 规则
-            if let Some(ref type_name) = rule.field_type {
-                match type_name.as_str() {
-                    "string" => {
-                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
-                    }
-                    "number" => {
-                        field_schema.insert("type".to_string(), Value::String("number".to_string()));
-                    }
-                    "integer" => {
-                        field_schema.insert("type".to_string(), Value::String("integer".to_string()));
-                    }
-                    "boolean" => {
-                        field_schema.insert("type".to_string(), Value::String("boolean".to_string()));
-                    }
-                    "array" => {
-                        field_schema.insert("type".to_string(), Value::String("array".to_string()));
-                        // 处理嵌套数组项规则
-                        if let Some(ref nested_fields) = rule.fields {
-                            let nested_rules = parse_async_rules(nested_fields)?;
-                            let nested_conversion = convert_to_jsonschema(&nested_rules)?;
-                            field_schema.insert("items".to_string(), nested_conversion.schema);
-                            unsupported.extend(nested_conversion.unsupported);
-                        }
-                    }
-                    "object" => {
-                        field_schema.insert("type".to_string(), Value::String("object".to_string()));
-                        // 处理嵌套对象的 fields
-                        if let Some(ref nested_fields) = rule.fields {
-                            let nested_rules = parse_async_rules(nested_fields)?;
-                            let nested_conversion = convert_to_jsonschema(&nested_rules)?;
-                            field_schema.insert("properties".to_string(), nested_conversion.schema["properties"].clone());
-                            if nested_conversion.schema.get("required").is_some() {
-                                field_schema.insert("required".to_string(), nested_conversion.schema["required"].clone());
-                            }
-                            unsupported.extend(nested_conversion.unsupported);
-                        }
-                    }
-                    "method" => {
-                        field_schema.insert("type".to_string(), Value::String("object".to_string()));
-                        field_schema.insert("instanceof".to_string(), Value::String("Function".to_string()));
-                    }
-                    "regexp" => {
-                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
-                        // 注意：JSON Schema 没有内置的正则表达式类型验证
-                    }
-                    "date" => {
-                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
-                        field_schema.insert("format".to_string(), Value::String("date-time".to_string()));
-                    }
-                    "email" => {
-                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
-                        field_schema.insert("format".to_string(), Value::String("email".to_string()));
-                    }
-                    "url" => {
-                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
-                        field_schema.insert("format".to_string(), Value::String("uri".to_string()));
-                    }
-                    "hex" => {
-                        field_schema.insert("type".to_string(), Value::String("string".to_string()));
-                        // 可以添加 pattern 来验证十六进制格式
-                        field_schema.insert("pattern".to_string(), Value::String("^[0-9a-fA-F]+$".to_string()));
-                    }
-                    "any" => {
-                        // JSON Schema 中没有 "any" 类型，使用 "type" 数组或者不指定类型
-                        // 这里我们选择不指定类型（即允许任何类型）
-                    }
-                    _ => {
-                        unsupported.push(format!("Field '{}': unsupported type '{}'", field_name, type_name));
-                    }
-                }
-            }
-            
-            // 处理 required 规则
-            if let Some(true) = rule.required {
-                field_required = true;
-            }
-            
-            // 处理 min 规则
-            if let Some(ref min_value) = rule.min {
-                match field_schema.get("type").and_then(|v| v.as_str()) {
-                    Some("string") => {
-                        field_schema.insert("minLength".to_string(), min_value.clone());
-                    }
-                    Some("array") => {
-                        field_schema.insert("minItems".to_string(), min_value.clone());
-                    }
-                    Some("number") | Some("integer") => {
-                        field_schema.insert("minimum".to_string(), min_value.clone());
-                    }
-                    _ => {
-                        // 默认当作数值处理
-                        field_schema.insert("minimum".to_string(), min_value.clone());
-                    }
-                }
-            }
-            
-            // 处理 max 规则
-            if let Some(ref max_value) = rule.max {
-                match field_schema.get("type").and_then(|v| v.as_str()) {
-                    Some("string") => {
-                        field_schema.insert("maxLength".to_string(), max_value.clone());
-                    }
-                    Some("array") => {
-                        field_schema.insert("maxItems".to_string(), max_value.clone());
-                    }
-                    Some("number") | Some("integer") => {
-                        field_schema.insert("maximum".to_string(), max_value.clone());
-                    }
-                    _ => {
-                        // 默认当作数值处理
-                        field_schema.insert("maximum".to_string(), max_value.clone());
-                    }
-                }
-            }
-            
-            // 处理 len 规则
-            if let Some(ref len_value) = rule.len {
-                match field_schema.get("type").and_then(|v| v.as_str()) {
-                    Some("string") => {
-                        field_schema.insert("minLength".to_string(), len_value.clone());
-                        field_schema.insert("maxLength".to_string(), len_value.clone());
-                    }
-                    Some("array") => {
-                        field_schema.insert("minItems".to_string(), len_value.clone());
-                        field_schema.insert("maxItems".to_string(), len_value.clone());
-                    }
-                    _ => {
-                        unsupported.push(format!("Field '{}': len rule only supported for string and array types", field_name));
-                    }
-                }
-            }
-            
-            // 处理 pattern 规则
-            if let Some(ref pattern) = rule.pattern {
-                field_schema.insert("pattern".to_string(), Value::String(pattern.clone()));
-            }
-            
-            // 处理 enum 规则
-            if let Some(ref enum_values) = rule.enum_values {
-                field_schema.insert("enum".to_string(), Value::Array(enum_values.clone()));
-            }
-            
-            // 处理 whitespace 规则
-            if rule.whitespace.is_some() {
-                // whitespace 规则需要自定义验证，JSON Schema 不直接支持
-                unsupported.push(format!("Field '{}': whitespace rule not supported in JSON Schema", field_name));
-            }
-            
-            // 检查不支持的规则
-            if rule.validator.is_some() {
-                unsupported.push(format!("Field '{}': validator function not supported", field_name));
-            }
-            
-            if rule.async_validator.is_some() {
-                unsupported.push(format!("Field '{}': asyncValidator function not supported", field_name));
-            }
-            
-            if rule.trigger.is_some() {
-                unsupported.push(format!("Field '{}': trigger option not supported", field_name));
-            }
-            
-            if rule.extra.contains_key("transform") {
-                unsupported.push(format!("Field '{}': transform option not supported", field_name));
-            }
-            
-            for (key, _) in &rule.extra {
-                match key.as_str() {
-                    "validator" | "asyncValidator" | "trigger" | "whitespace" | "transform" | "fields" => {
-                        // 已经处理过这些规则
-                    }
-                    _ => {
-                        unsupported.push(format!("Field '{}': unsupported rule '{}'", field_name, key));
-                    }
-                }
+    if let Some(items) = obj.get_mut("items") {
+        add_titles(items, style);
+    }
+    if let Some(Value::Array(branches)) = obj.get_mut("allOf") {
+        for branch in branches {
+            if let Some(then) = branch.get_mut("then") {
+                add_titles(then, style);
             }
         }
-        
-        // 如果没有指定类型，默认为字符串
-        if !field_schema.contains_key("type") && field_rules.iter().any(|r| r.field_type.is_some()) {
-            field_schema.insert("type".to_string(), Value::String("string".to_string()));
-        }
-        
-        properties.insert(field_name.clone(), Value::Object(field_schema));
-        
-        if field_required {
-            required.push(field_name.clone());
-        }
-    }
-    
-    schema_object.insert("properties".to_string(), Value::Object(properties));
-    
-    if !required.is_empty() {
-        schema_object.insert("required".to_string(), Value::Array(
-            required.into_iter().map(Value::String).collect()
-        ));
     }
-    
-    let schema = Value::Object(schema_object);
-    
-    Ok(ConversionResult {
-        schema,
-        unsupported,
-    })
 }
 
 /// 转换结果（内部使用）
 #[derive(Debug)]
-struct ConversionResult {
+pub(crate) struct ConversionResult {
     /// 生成的 JSON Schema
-    schema: Value,
+    pub(crate) schema: Value,
     /// 不支持的验证规则列表
-    unsupported: Vec<String>,
+    pub(crate) unsupported: Vec<ConversionWarning>,
+    /// 验证前的数据规范化计划
+    pub(crate) transforms: TransformNode,
+}
+
+/// 解析并转换 async-validator 规则，转换本身由 `link-validator-core` 完成
+///
+/// 根节点为可辨识联合时，生成的 schema 即为联合本身（而不是包含联合字段的对象）；
+/// 根节点的 `defs` 转换为 `$defs`。
+pub(crate) fn convert_rules(value: &Value) -> Result<ConversionResult, String> {
+    link_validator_core::convert_with(value, &patterns::check).map(ConversionResult::from)
+}
+
+/// 转换描述根节点本身的规则（见 `LinkValidator::for_value`）
+fn convert_value_rules(rule: &Value) -> Result<ConversionResult, String> {
+    link_validator_core::convert_value_with(rule, &patterns::check).map(ConversionResult::from)
 }
 
-impl Default for AsyncValidatorRule {
-    fn default() -> Self {
-        AsyncValidatorRule {
-            field_type: None,
-            required: None,
-            min: None,
-            max: None,
-            len: None,
-            pattern: None,
-            enum_values: None,
-            message: None,
-            whitespace: None,
-            validator: None,
-            async_validator: None,
-            trigger: None,
-            fields: None,
-            extra: Map::new(),
+impl From<link_validator_core::Conversion> for ConversionResult {
+    fn from(conversion: link_validator_core::Conversion) -> Self {
+        ConversionResult {
+            schema: conversion.schema,
+            unsupported: conversion.warnings,
+            transforms: conversion.transforms.into(),
         }
     }
 }
-
@@ -0,0 +1,101 @@
+//! 编译 schema 时的资源限制
+
+use serde_json::Value;
+use std::io::{self, Write};
+use std::time::Instant;
+
+use crate::keywords;
+use crate::options::CompileOptions;
+
+/// 统计序列化长度的写入器，不保存数据
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// schema 序列化为紧凑 JSON 后的字节数
+fn schema_bytes(schema: &Value) -> usize {
+    let mut counter = ByteCounter(0);
+    // 写入器不会返回错误
+    let _ = serde_json::to_writer(&mut counter, schema);
+    counter.0
+}
+
+/// 检查 schema 大小
+pub(crate) fn check_bytes(options: &CompileOptions, bytes: usize) -> Result<(), String> {
+    match options.max_schema_bytes {
+        Some(max) if bytes > max => Err(format!("Schema limit exceeded: {} bytes (max_schema_bytes {})", bytes, max)),
+        _ => Ok(()),
+    }
+}
+
+/// 检查 schema 序列化后的大小
+pub(crate) fn check_schema_bytes(options: &CompileOptions, schema: &Value) -> Result<(), String> {
+    if options.max_schema_bytes.is_none() {
+        return Ok(());
+    }
+    check_bytes(options, schema_bytes(schema))
+}
+
+/// 检查 JSON Schema 中声明的属性总数（包含嵌套对象和数组项中的属性）
+pub(crate) fn check_fields(options: &CompileOptions, schema: &Value) -> Result<(), String> {
+    let Some(max) = options.max_fields else {
+        return Ok(());
+    };
+    let mut fields = 0;
+    keywords::visit_schemas(schema, &mut |obj| {
+        if let Some(Value::Object(properties)) = obj.get("properties") {
+            fields += properties.len();
+        }
+    });
+    if fields > max {
+        return Err(format!("Schema limit exceeded: {} fields (max_fields {})", fields, max));
+    }
+    Ok(())
+}
+
+/// 检查 schema 的嵌套深度
+///
+/// 使用显式栈遍历，深度超出限制时立即返回，不会因 schema 过深而栈溢出。
+pub(crate) fn check_depth(options: &CompileOptions, schema: &Value) -> Result<(), String> {
+    let Some(max) = options.max_depth else {
+        return Ok(());
+    };
+    let mut stack = vec![(schema, 1)];
+    while let Some((value, depth)) = stack.pop() {
+        let children: Box<dyn Iterator<Item = &Value>> = match value {
+            Value::Object(obj) => Box::new(obj.values()),
+            Value::Array(items) => Box::new(items.iter()),
+            _ => continue,
+        };
+        if depth > max {
+            return Err(format!("Schema limit exceeded: nesting depth {} (max_depth {})", depth, max));
+        }
+        stack.extend(children.map(|child| (child, depth + 1)));
+    }
+    Ok(())
+}
+
+/// 检查编译耗时
+pub(crate) fn check_compile_time(options: &CompileOptions, started: Instant) -> Result<(), String> {
+    let Some(budget) = options.compile_budget else {
+        return Ok(());
+    };
+    let elapsed = started.elapsed();
+    if elapsed > budget {
+        return Err(format!(
+            "Schema limit exceeded: compilation took {}ms (compile_budget {}ms)",
+            elapsed.as_millis(),
+            budget.as_millis()
+        ));
+    }
+    Ok(())
+}
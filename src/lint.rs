@@ -0,0 +1,334 @@
+//! 规则和 schema 检查
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+use crate::{is_async_rules, is_union_rule, split_definitions};
+use crate::meta::{self, Draft};
+use crate::keywords::{SUBSCHEMA_ARRAY_KEYWORDS, SUBSCHEMA_KEYWORDS, SUBSCHEMA_MAP_KEYWORDS};
+
+/// async-validator 支持的类型名称
+const RULE_TYPES: &[&str] = &[
+    "string", "number", "integer", "boolean", "array", "object", "method", "regexp", "date", "email", "url", "hex",
+    "enum", "any",
+];
+
+/// JSON Schema 的类型名称
+const SCHEMA_TYPES: &[&str] = &["string", "number", "integer", "boolean", "array", "object", "null"];
+
+/// 上下限成对的 JSON Schema 关键字
+const BOUND_PAIRS: &[(&str, &str)] = &[
+    ("minLength", "maxLength"),
+    ("minItems", "maxItems"),
+    ("minProperties", "maxProperties"),
+    ("minContains", "maxContains"),
+    ("minimum", "maximum"),
+];
+
+/// 单项检查结果
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintFinding {
+    /// 问题位置：规则中为字段路径（例如 `address.city`，数组元素为 `lines[].sku`），
+    /// JSON Schema 中为 JSON Pointer（例如 `/properties/name`，根节点为空字符串）
+    pub path: String,
+    /// 问题代码，例如 `min-greater-than-max`
+    pub code: String,
+    /// 问题说明
+    pub message: String,
+}
+
+impl fmt::Display for LintFinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "[{}] {}", self.code, self.message)
+        } else {
+            write!(f, "{} [{}] {}", self.path, self.code, self.message)
+        }
+    }
+}
+
+/// 检查规则或 schema 中可疑的组合
+///
+/// 可检查的问题（问题代码）：
+///
+/// - `min-greater-than-max`：下限大于上限，任何值都无法通过
+/// - `len-with-min-max`：`len` 与 `min`/`max` 同时使用
+/// - `enum-type-mismatch`：枚举值（或 `const`）与声明的类型不符
+/// - `invalid-pattern` / `pattern-never-matches`：正则无法编译或不可能匹配
+/// - `unreachable-branch`：永远不会生效的条件分支或规则（例如没有 `if` 的 `then`、非数组类型的 `itemsBy`）
+/// - `unknown-type`：未知的类型名称
+/// - `unknown-keyword`：JSON Schema 中无法识别的关键字（通常是拼写错误，编译时被忽略）
+/// - `keyword-not-in-draft`：JSON Schema 中声明的草案不支持的关键字（例如 draft-04 中的 `if`）
+///
+/// 参数可为 async-validator 规则或 JSON Schema，检查不会编译 schema，格式错误的规则不会报错。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::lint;
+/// use serde_json::json;
+///
+/// let findings = lint(&json!({
+///     "name": {"type": "string", "min": 10, "max": 5},
+///     "age": {"type": "int"}
+/// }));
+///
+/// assert_eq!(findings[0].path, "age");
+/// assert_eq!(findings[0].code, "unknown-type");
+/// assert_eq!(findings[1].code, "min-greater-than-max");
+/// ```
+pub fn lint(schema: &Value) -> Vec<LintFinding> {
+    let mut linter = Linter { findings: Vec::new() };
+    if let Some((defs, rest)) = split_definitions(schema) {
+        for (name, fields) in defs {
+            linter.rules(fields, &format!("defs.{}", name));
+        }
+        linter.findings.extend(lint(&rest));
+        return linter.findings;
+    }
+    if is_union_rule(schema) {
+        linter.field(&schema.as_object().into_iter().collect::<Vec<_>>(), "");
+    } else if is_async_rules(schema) {
+        linter.rules(schema, "");
+    } else {
+        linter.schema(schema, "");
+        let draft = Draft::of(schema);
+        linter.findings.extend(meta::ignored_keywords(schema, draft).iter().map(|ignored| ignored.to_finding(draft)));
+    }
+    linter.findings
+}
+
+struct Linter {
+    findings: Vec<LintFinding>,
+}
+
+impl Linter {
+    fn finding(&mut self, path: &str, code: &str, message: impl Into<String>) {
+        self.findings.push(LintFinding {
+            path: path.to_string(),
+            code: code.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// 检查 async-validator 规则对象
+    fn rules(&mut self, rules: &Value, parent: &str) {
+        let Some(fields) = rules.as_object() else {
+            return;
+        };
+        for (name, rule) in fields {
+            let path = if parent.is_empty() { name.clone() } else { format!("{}.{}", parent, name) };
+            let list: Vec<&Map<String, Value>> = match rule {
+                Value::Object(rule) => vec![rule],
+                Value::Array(list) => list.iter().filter_map(Value::as_object).collect(),
+                _ => continue,
+            };
+            self.field(&list, &path);
+        }
+    }
+
+    /// 检查同一字段的全部规则（规则数组中的约束合并检查）
+    fn field(&mut self, rules: &[&Map<String, Value>], path: &str) {
+        let get = |key: &str| rules.iter().rev().find_map(|r| r.get(key));
+        let kind = get("type").and_then(Value::as_str);
+
+        if let Some(kind) = kind
+            && !RULE_TYPES.contains(&kind)
+        {
+            self.finding(path, "unknown-type", format!("unknown type '{}'", kind));
+        }
+        let (min, max, len) = (get("min"), get("max"), get("len"));
+        if let (Some(min), Some(max)) = (min.and_then(Value::as_f64), max.and_then(Value::as_f64))
+            && min > max
+        {
+            self.finding(path, "min-greater-than-max", format!("min {} is greater than max {}", min, max));
+        }
+        if len.is_some() && (min.is_some() || max.is_some()) {
+            self.finding(path, "len-with-min-max", "len is used together with min/max; len takes precedence");
+        }
+        if let Some(Value::Array(values)) = get("enum")
+            && let Some(kind) = kind
+            && let Some(json_type) = rule_json_type(kind)
+        {
+            self.enum_types(values, &[json_type], path);
+        }
+        if let Some(value) = get("const")
+            && let Some(json_type) = kind.and_then(rule_json_type)
+        {
+            self.enum_types(std::slice::from_ref(value), &[json_type], path);
+        }
+        if let Some(pattern) = get("pattern").and_then(Value::as_str) {
+            self.pattern(pattern, path);
+        }
+
+        if get("itemsBy").is_some() && kind != Some("array") {
+            self.finding(path, "unreachable-branch", "itemsBy is only applied to array fields");
+        }
+        match (kind, get("fields")) {
+            (Some("object"), Some(fields)) => self.rules(fields, path),
+            (Some("array"), Some(fields)) => self.rules(fields, &format!("{}[]", path)),
+            (_, Some(_)) => self.finding(path, "unreachable-branch", "fields is only applied to object and array fields"),
+            _ => {}
+        }
+        if let Some(Value::Object(mapping)) = get("itemsBy").and_then(|i| i.get("mapping")) {
+            for (tag, fields) in mapping {
+                self.rules(fields, &format!("{}[{}]", path, tag));
+            }
+        }
+        if let Some(Value::Object(variants)) = get("variants") {
+            for (tag, fields) in variants {
+                let prefix = if path.is_empty() { format!("[{}]", tag) } else { format!("{}[{}]", path, tag) };
+                self.rules(fields, &prefix);
+            }
+        }
+    }
+
+    /// 检查 JSON Schema，`path` 为 JSON Pointer
+    fn schema(&mut self, schema: &Value, path: &str) {
+        let Some(obj) = schema.as_object() else {
+            return;
+        };
+
+        let types: Vec<&str> = match obj.get("type") {
+            Some(Value::String(t)) => vec![t.as_str()],
+            Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        for t in types.iter().filter(|t| !SCHEMA_TYPES.contains(t)) {
+            self.finding(path, "unknown-type", format!("unknown type '{}'", t));
+        }
+        for (min_keyword, max_keyword) in BOUND_PAIRS {
+            if let (Some(min), Some(max)) = (
+                obj.get(*min_keyword).and_then(Value::as_f64),
+                obj.get(*max_keyword).and_then(Value::as_f64),
+            ) && min > max
+            {
+                let message = format!("{} {} is greater than {} {}", min_keyword, min, max_keyword, max);
+                self.finding(path, "min-greater-than-max", message);
+            }
+        }
+        if !types.is_empty() {
+            if let Some(Value::Array(values)) = obj.get("enum") {
+                self.enum_types(values, &types, path);
+            }
+            if let Some(value) = obj.get("const") {
+                self.enum_types(std::slice::from_ref(value), &types, path);
+            }
+        }
+        if let Some(pattern) = obj.get("pattern").and_then(Value::as_str) {
+            self.pattern(pattern, path);
+        }
+        self.conditionals(obj, path);
+
+        for keyword in SUBSCHEMA_KEYWORDS {
+            if let Some(sub) = obj.get(*keyword) {
+                self.schema(sub, &format!("{}/{}", path, keyword));
+            }
+        }
+        for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+            if let Some(Value::Array(subs)) = obj.get(*keyword) {
+                for (index, sub) in subs.iter().enumerate() {
+                    self.schema(sub, &format!("{}/{}/{}", path, keyword, index));
+                }
+            }
+        }
+        for keyword in SUBSCHEMA_MAP_KEYWORDS {
+            if let Some(Value::Object(subs)) = obj.get(*keyword) {
+                for (name, sub) in subs {
+                    self.schema(sub, &format!("{}/{}/{}", path, keyword, name.replace('~', "~0").replace('/', "~1")));
+                }
+            }
+        }
+    }
+
+    /// 永远不会生效的条件分支
+    fn conditionals(&mut self, obj: &Map<String, Value>, path: &str) {
+        match obj.get("if") {
+            None => {
+                for keyword in ["then", "else"] {
+                    if obj.contains_key(keyword) {
+                        self.finding(path, "unreachable-branch", format!("{} without if is ignored", keyword));
+                    }
+                }
+            }
+            Some(Value::Bool(true)) if obj.contains_key("else") => {
+                self.finding(path, "unreachable-branch", "else is unreachable because if is always true");
+            }
+            Some(Value::Bool(false)) if obj.contains_key("then") => {
+                self.finding(path, "unreachable-branch", "then is unreachable because if is always false");
+            }
+            _ => {}
+        }
+
+        // if 要求某字段等于某值，但该字段的 enum 不包含这个值
+        for (index, branch) in obj.get("allOf").and_then(Value::as_array).into_iter().flatten().enumerate() {
+            let Some(conditions) = branch.pointer("/if/properties").and_then(Value::as_object) else {
+                continue;
+            };
+            for (field, condition) in conditions {
+                let allowed = obj.get("properties").and_then(|p| p.get(field)).and_then(|p| p.get("enum"));
+                if let (Some(value), Some(Value::Array(allowed))) = (condition.get("const"), allowed)
+                    && !allowed.contains(value)
+                {
+                    let message = format!("branch for {} = {} is unreachable because {} is not an allowed value", field, value, value);
+                    self.finding(&format!("{}/allOf/{}", path, index), "unreachable-branch", message);
+                }
+            }
+        }
+    }
+
+    fn enum_types(&mut self, values: &[Value], types: &[&str], path: &str) {
+        for value in values {
+            if !types.iter().any(|t| matches_type(value, t)) {
+                let message = format!("enum value {} does not match type {}", value, types.join(" or "));
+                self.finding(path, "enum-type-mismatch", message);
+            }
+        }
+    }
+
+    fn pattern(&mut self, pattern: &str, path: &str) {
+        // regex-syntax 不支持前后查找和反向引用，这类正则不做检查
+        if ["(?=", "(?!", "(?<=", "(?<!"].iter().any(|s| pattern.contains(s))
+            || (1..=9).any(|n| pattern.contains(&format!("\\{}", n)))
+        {
+            return;
+        }
+        match regex_syntax::parse(pattern) {
+            Err(e) => {
+                let message = format!("pattern {:?} is not a valid regular expression: {}", pattern, e.to_string().lines().last().unwrap_or_default());
+                self.finding(path, "invalid-pattern", message);
+            }
+            Ok(hir) if hir.properties().minimum_len().is_none() => {
+                self.finding(path, "pattern-never-matches", format!("pattern {:?} can never match", pattern));
+            }
+            Ok(_) => {}
+        }
+    }
+}
+
+/// async-validator 类型对应的 JSON 值类型，`enum`、`any` 等不限类型时返回 None
+fn rule_json_type(kind: &str) -> Option<&'static str> {
+    match kind {
+        "string" | "email" | "url" | "hex" | "date" | "regexp" => Some("string"),
+        "number" => Some("number"),
+        "integer" => Some("integer"),
+        "boolean" => Some("boolean"),
+        "array" => Some("array"),
+        "object" => Some("object"),
+        _ => None,
+    }
+}
+
+fn matches_type(value: &Value, json_type: &str) -> bool {
+    match json_type {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
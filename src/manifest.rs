@@ -0,0 +1,72 @@
+//! 验证器清单
+
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+
+use crate::keywords::{self, VALIDATION_KEYWORDS};
+use crate::LinkValidator;
+
+/// 清单格式版本，字段发生不兼容变化时递增
+const MANIFEST_VERSION: u32 = 1;
+
+/// 生成验证器清单
+pub(crate) fn build(validator: &LinkValidator) -> Value {
+    let mut fields = 0;
+    let mut constraints = 0;
+    keywords::visit_schemas(&validator.json_schema, &mut |obj| {
+        if let Some(Value::Object(properties)) = obj.get("properties") {
+            fields += properties.len();
+        }
+        constraints += obj.keys().filter(|keyword| VALIDATION_KEYWORDS.contains(&keyword.as_str())).count();
+    });
+
+    json!({
+        "manifest_version": MANIFEST_VERSION,
+        "name": validator.name,
+        "format": validator.format.as_str(),
+        "fingerprint": format!("{:016x}", validator.fingerprint()),
+        "draft": draft(&validator.json_schema),
+        "options": validator.options.to_value(),
+        "rules": {
+            "fields": fields,
+            "constraints": constraints,
+            "definitions": definitions(&validator.json_schema),
+            "document_validators": validator.document_validators.len(),
+        },
+        "custom_keywords": custom_keywords(&validator.json_schema),
+        "warnings": validator.warnings,
+    })
+}
+
+/// schema 中无法识别的关键字（自定义或拼写错误）
+pub(crate) fn custom_keywords(schema: &Value) -> BTreeSet<String> {
+    let mut custom_keywords = BTreeSet::new();
+    keywords::visit_schemas(schema, &mut |obj| {
+        for keyword in obj.keys() {
+            if !keywords::is_known(keyword) {
+                custom_keywords.insert(keyword.clone());
+            }
+        }
+    });
+    custom_keywords
+}
+
+/// 编译使用的 JSON Schema 草案版本（未声明 `$schema` 时为默认的 draft-07）
+pub(crate) fn draft(schema: &Value) -> &'static str {
+    match schema.get("$schema").and_then(Value::as_str) {
+        Some(url) if url.contains("draft-04") => "draft-04",
+        Some(url) if url.contains("draft-06") => "draft-06",
+        Some(url) if url.contains("2019-09") => "2019-09",
+        Some(url) if url.contains("2020-12") => "2020-12",
+        _ => "draft-07",
+    }
+}
+
+/// 顶层可复用定义的数量
+fn definitions(schema: &Value) -> usize {
+    ["definitions", "$defs"]
+        .iter()
+        .filter_map(|k| schema.get(*k).and_then(Value::as_object))
+        .map(|defs| defs.len())
+        .sum()
+}
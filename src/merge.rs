@@ -0,0 +1,85 @@
+//! async-validator 规则合并
+
+use serde_json::{Map, Value};
+
+/// 合并规则时数组的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// 覆盖规则中的数组替换基础规则中的数组（默认）
+    #[default]
+    Replace,
+    /// 数组依次拼接，跳过基础规则中已有的元素；字段的单个规则对象视为只有一条规则的数组
+    Concat,
+}
+
+/// 深度合并两份 async-validator 规则，用于在编译前按表单扩展共享的基础规则
+///
+/// 对象逐键合并（包括嵌套的 `fields`、`defs`、`variants`），同名键以覆盖规则为准；
+/// 数组按 `strategy` 替换或拼接；覆盖规则中值为 `null` 的键会从结果中删除。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{merge_rules, LinkValidator, MergeStrategy};
+/// use serde_json::json;
+///
+/// let base = json!({
+///     "email": {"type": "email", "required": true},
+///     "role": {"type": "enum", "enum": ["user"]}
+/// });
+/// let admin_form = json!({
+///     "role": {"enum": ["admin"]},
+///     "note": {"type": "string", "max": 200}
+/// });
+///
+/// let rules = merge_rules(&base, &admin_form, MergeStrategy::Concat);
+/// assert_eq!(rules["role"]["enum"], json!(["user", "admin"]));
+///
+/// let validator = LinkValidator::new(&rules).unwrap();
+/// assert!(validator.validate(&json!({"email": "a@example.com", "role": "admin"})).is_valid);
+/// ```
+pub fn merge_rules(base: &Value, overlay: &Value, strategy: MergeStrategy) -> Value {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => Value::Object(merge_objects(base, overlay, strategy)),
+        (Value::Array(base), Value::Array(overlay)) => merge_arrays(base, overlay, strategy),
+        // 单个规则对象与规则数组
+        (Value::Object(_), Value::Array(overlay)) if strategy == MergeStrategy::Concat => {
+            merge_arrays(std::slice::from_ref(base), overlay, strategy)
+        }
+        (Value::Array(base), Value::Object(_)) if strategy == MergeStrategy::Concat => {
+            merge_arrays(base, std::slice::from_ref(overlay), strategy)
+        }
+        _ => overlay.clone(),
+    }
+}
+
+fn merge_objects(base: &Map<String, Value>, overlay: &Map<String, Value>, strategy: MergeStrategy) -> Map<String, Value> {
+    let mut merged = base.clone();
+    for (key, value) in overlay {
+        if value.is_null() {
+            merged.remove(key);
+            continue;
+        }
+        let value = match base.get(key) {
+            Some(existing) => merge_rules(existing, value, strategy),
+            None => value.clone(),
+        };
+        merged.insert(key.clone(), value);
+    }
+    merged
+}
+
+fn merge_arrays(base: &[Value], overlay: &[Value], strategy: MergeStrategy) -> Value {
+    match strategy {
+        MergeStrategy::Replace => Value::Array(overlay.to_vec()),
+        MergeStrategy::Concat => {
+            let mut merged = base.to_vec();
+            for value in overlay {
+                if !merged.contains(value) {
+                    merged.push(value.clone());
+                }
+            }
+            Value::Array(merged)
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! async-validator 风格的自定义错误信息（`ValidateOptions::message`）
+//!
+//! 模板按错误码查找，例如 `required`、`string.min`；也接受 async-validator `messages` 对象中的
+//! `enum`、`types.email` 等键。模板中的 `%s` 依次替换为字段路径和规则参数。
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::error::ValidationError;
+
+/// 把 async-validator 的嵌套 `messages` 对象展开为以点连接的键
+pub(crate) fn flatten(value: &Value, prefix: &str, out: &mut BTreeMap<String, String>) -> Result<(), String> {
+    let Value::Object(obj) = value else {
+        return Err(format!("Invalid message for '{}': expected a string or an object", prefix));
+    };
+    for (key, value) in obj {
+        let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match value {
+            Value::String(template) => {
+                out.insert(key, template.clone());
+            }
+            _ => flatten(value, &key, out)?,
+        }
+    }
+    Ok(())
+}
+
+/// 按错误码用模板替换错误信息，没有对应模板时保持不变
+pub(crate) fn apply(error: &mut ValidationError, messages: &BTreeMap<String, String>) {
+    if let Some(template) = template(error, messages) {
+        let mut arguments = vec![field(error)];
+        arguments.extend(arguments_of(error));
+        error.message = format(template, &arguments);
+    }
+}
+
+fn template<'a>(error: &ValidationError, messages: &'a BTreeMap<String, String>) -> Option<&'a str> {
+    if let Some(template) = messages.get(&error.code) {
+        return Some(template);
+    }
+    // async-validator 的键名
+    let alias = match error.code.as_str() {
+        "enum.mismatch" => "enum".to_string(),
+        "type.mismatch" => format!("types.{}", first_expected(error)?),
+        code => format!("types.{}", code.strip_prefix("format.")?),
+    };
+    messages.get(&alias).map(String::as_str)
+}
+
+/// 错误对应字段的点分隔路径（缺少必填字段时为缺少的字段）
+fn field(error: &ValidationError) -> String {
+    error
+        .field_pointer()
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// 字段路径之后的模板参数
+fn arguments_of(error: &ValidationError) -> Vec<String> {
+    let param = |name: &str| error.params.get(name).map(display);
+    match error.code.as_str() {
+        "enum.mismatch" => param("allowed").into_iter().collect(),
+        "type.mismatch" => first_expected(error).map(String::from).into_iter().collect(),
+        "pattern.mismatch" => [error.value.as_ref().map(display), param("pattern")].into_iter().flatten().collect(),
+        code if code.starts_with("format.") => vec![code["format.".len()..].to_string()],
+        _ => param("limit").into_iter().collect(),
+    }
+}
+
+fn first_expected(error: &ValidationError) -> Option<&str> {
+    error.params.get("expected")?.as_array()?.first()?.as_str()
+}
+
+/// 模板参数的显示形式：字符串不加引号，数组以逗号分隔
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(display).collect::<Vec<_>>().join(", "),
+        _ => value.to_string(),
+    }
+}
+
+/// 依次替换模板中的 `%s`，参数不足时保留原样
+fn format(template: &str, arguments: &[String]) -> String {
+    let mut arguments = arguments.iter();
+    let mut parts = template.split("%s");
+    let mut message = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        match arguments.next() {
+            Some(argument) => message.push_str(argument),
+            None => message.push_str("%s"),
+        }
+        message.push_str(part);
+    }
+    message
+}
@@ -0,0 +1,235 @@
+//! 按元 schema 验证 JSON Schema 文档
+
+use jsonschema::JSONSchema;
+use serde_json::{Map, Value};
+use std::sync::OnceLock;
+
+use crate::codes;
+use crate::dates;
+use crate::error::{ErrorCollector, ValidationError};
+use crate::lint::LintFinding;
+use crate::keywords::{self, SUBSCHEMA_ARRAY_KEYWORDS, SUBSCHEMA_KEYWORDS, SUBSCHEMA_MAP_KEYWORDS};
+use crate::{SchemaFormat, ValidateOptions, ValidationResult};
+
+/// JSON Schema 草案版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Draft {
+    /// Draft 4
+    Draft4,
+    /// Draft 6
+    Draft6,
+    /// Draft 7（默认，与编译使用的版本一致）
+    #[default]
+    Draft7,
+}
+
+impl Draft {
+    /// schema 的 `$schema` 声明的草案（未声明或不是 draft-04/06 时为编译使用的 draft-07）
+    pub(crate) fn of(schema: &Value) -> Draft {
+        match schema.get("$schema").and_then(Value::as_str) {
+            Some(url) if url.contains("draft-04") => Draft::Draft4,
+            Some(url) if url.contains("draft-06") => Draft::Draft6,
+            _ => Draft::Draft7,
+        }
+    }
+
+    /// 草案名称，例如 `draft-07`
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Draft::Draft4 => "draft-04",
+            Draft::Draft6 => "draft-06",
+            Draft::Draft7 => "draft-07",
+        }
+    }
+
+    /// 草案顺序
+    fn rank(self) -> u8 {
+        match self {
+            Draft::Draft4 => 4,
+            Draft::Draft6 => 6,
+            Draft::Draft7 => 7,
+        }
+    }
+
+    /// 随库打包的元 schema
+    fn meta_schema(self) -> &'static str {
+        match self {
+            Draft::Draft4 => include_str!("meta_schemas/draft4.json"),
+            Draft::Draft6 => include_str!("meta_schemas/draft6.json"),
+            Draft::Draft7 => include_str!("meta_schemas/draft7.json"),
+        }
+    }
+
+    /// 编译后的元 schema（首次使用时编译）
+    fn validator(self) -> &'static JSONSchema {
+        static VALIDATORS: [OnceLock<JSONSchema>; 3] = [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+        VALIDATORS[self as usize].get_or_init(|| {
+            let meta: Value = serde_json::from_str(self.meta_schema()).expect("bundled meta-schema is valid JSON");
+            JSONSchema::compile(&meta).expect("bundled meta-schema compiles")
+        })
+    }
+}
+
+/// 按元 schema 验证 JSON Schema 文档本身
+///
+/// 除元 schema 中的约束外（例如 `minLength` 必须是非负整数），还会把无法识别的关键字和指定草案不支持的关键字
+/// （例如 draft-04 中的 `if`）报告为 `schema.unknownKeyword` 错误，用于发现编译时被静默忽略的拼写错误。
+/// `x-` 开头的扩展关键字不报告。
+/// 错误使用 JSON Schema 格式，`instancePath` 为 schema 中出错位置的 JSON Pointer。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{validate_meta, Draft};
+/// use serde_json::json;
+///
+/// let result = validate_meta(&json!({
+///     "type": "object",
+///     "properties": {"name": {"type": "string", "minLenght": 3}}
+/// }), Draft::Draft7);
+///
+/// assert!(!result.is_valid);
+/// assert_eq!(result.errors[0]["instancePath"], "/properties/name");
+/// assert_eq!(result.errors[0]["code"], "schema.unknownKeyword");
+/// ```
+pub fn validate_meta(schema: &Value, draft: Draft) -> ValidationResult {
+    let options = ValidateOptions::default();
+    let mut collector = ErrorCollector::new(&options);
+    if let Err(errors) = draft.validator().validate(schema) {
+        for error in errors {
+            collector.push(ValidationError::from(&error));
+        }
+    }
+    for ignored in ignored_keywords(schema, draft) {
+        let message = match ignored.since {
+            Some(since) => format!("Keyword '{}' is not supported by {} (requires {})", ignored.keyword, draft.as_str(), since),
+            None => format!("Unknown keyword '{}'", ignored.keyword),
+        };
+        let error = ValidationError::new(ignored.path, message)
+            .with_code(codes::UNKNOWN_KEYWORD)
+            .with_param("keyword", ignored.keyword);
+        collector.push(error);
+    }
+    collector.finish(SchemaFormat::JsonSchema)
+}
+
+/// 在后续草案中才引入的验证关键字：关键字、引入的草案、草案顺序
+///
+/// 注解关键字和 `$defs`（`$ref` 按 JSON Pointer 解析，不受草案影响）不在此列。
+const LATER_KEYWORDS: &[(&str, &str, u8)] = &[
+    ("const", "draft-06", 6),
+    ("contains", "draft-06", 6),
+    ("propertyNames", "draft-06", 6),
+    ("if", "draft-07", 7),
+    ("then", "draft-07", 7),
+    ("else", "draft-07", 7),
+    ("contentEncoding", "draft-07", 7),
+    ("contentMediaType", "draft-07", 7),
+    ("$anchor", "2019-09", 8),
+    ("$vocabulary", "2019-09", 8),
+    ("$recursiveRef", "2019-09", 8),
+    ("$recursiveAnchor", "2019-09", 8),
+    ("dependentRequired", "2019-09", 8),
+    ("dependentSchemas", "2019-09", 8),
+    ("maxContains", "2019-09", 8),
+    ("minContains", "2019-09", 8),
+    ("unevaluatedItems", "2019-09", 8),
+    ("unevaluatedProperties", "2019-09", 8),
+    ("prefixItems", "2020-12", 9),
+    ("$dynamicRef", "2020-12", 9),
+    ("$dynamicAnchor", "2020-12", 9),
+];
+
+/// 编译时被忽略的关键字
+pub(crate) struct IgnoredKeyword {
+    /// 所在 schema 的 JSON Pointer
+    pub(crate) path: String,
+    pub(crate) keyword: String,
+    /// 关键字引入的草案（`None` 表示无法识别的关键字）
+    pub(crate) since: Option<&'static str>,
+}
+
+impl IgnoredKeyword {
+    /// 转换为 `lint` 的检查结果
+    pub(crate) fn to_finding(&self, draft: Draft) -> LintFinding {
+        let (code, message) = match self.since {
+            Some(since) => (
+                "keyword-not-in-draft",
+                format!("keyword '{}' requires {} and is ignored by {}", self.keyword, since, draft.as_str()),
+            ),
+            None => ("unknown-keyword", format!("unknown keyword '{}' is ignored", self.keyword)),
+        };
+        LintFinding {
+            path: self.path.clone(),
+            code: code.to_string(),
+            message,
+        }
+    }
+}
+
+/// schema 及其子 schema 中无法识别、或不属于指定草案的关键字（`x-` 开头的扩展关键字除外）
+pub(crate) fn ignored_keywords(schema: &Value, draft: Draft) -> Vec<IgnoredKeyword> {
+    let mut ignored = Vec::new();
+    visit_with_path(schema, &mut String::new(), &mut |obj, path| {
+        for keyword in obj.keys() {
+            if keyword.starts_with("x-") || keyword == dates::MINIMUM || keyword == dates::MAXIMUM {
+                continue;
+            }
+            let since = LATER_KEYWORDS
+                .iter()
+                .find(|(name, _, rank)| name == keyword && *rank > draft.rank())
+                .map(|(_, since, _)| *since);
+            if since.is_some() || !keywords::is_known(keyword) {
+                ignored.push(IgnoredKeyword {
+                    path: path.to_string(),
+                    keyword: keyword.clone(),
+                    since,
+                });
+            }
+        }
+    });
+    ignored
+}
+
+/// 依次访问 schema 及其全部子 schema 对象，同时给出其 JSON Pointer
+fn visit_with_path(schema: &Value, path: &mut String, visit: &mut impl FnMut(&Map<String, Value>, &str)) {
+    let Some(obj) = schema.as_object() else {
+        return;
+    };
+    visit(obj, path);
+
+    let len = path.len();
+    for keyword in SUBSCHEMA_KEYWORDS {
+        if let Some(sub) = obj.get(*keyword) {
+            push_segment(path, keyword);
+            visit_with_path(sub, path, visit);
+            path.truncate(len);
+        }
+    }
+    for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+        if let Some(Value::Array(subs)) = obj.get(*keyword) {
+            for (index, sub) in subs.iter().enumerate() {
+                push_segment(path, keyword);
+                push_segment(path, &index.to_string());
+                visit_with_path(sub, path, visit);
+                path.truncate(len);
+            }
+        }
+    }
+    for keyword in SUBSCHEMA_MAP_KEYWORDS {
+        if let Some(Value::Object(subs)) = obj.get(*keyword) {
+            for (name, sub) in subs {
+                push_segment(path, keyword);
+                push_segment(path, name);
+                visit_with_path(sub, path, visit);
+                path.truncate(len);
+            }
+        }
+    }
+}
+
+fn push_segment(path: &mut String, segment: &str) {
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+}
@@ -0,0 +1,108 @@
+//! Schema 精简
+
+use serde_json::Value;
+
+use crate::keywords::{ANNOTATION_KEYWORDS, SUBSCHEMA_ARRAY_KEYWORDS, SUBSCHEMA_KEYWORDS, SUBSCHEMA_MAP_KEYWORDS};
+use crate::{convert_rules, is_async_rules};
+
+/// Schema 精简选项，控制 `minify` 移除哪些约束
+///
+/// 注解（`title`、`description`、`$comment`、`examples`、`default` 等）总是会被移除。
+#[derive(Debug, Clone, Default)]
+pub struct MinifyOptions {
+    /// 移除 `pattern` 约束
+    pub(crate) strip_patterns: bool,
+    /// 移除 `format` 约束
+    pub(crate) strip_formats: bool,
+}
+
+impl MinifyOptions {
+    /// 创建默认精简选项（只移除注解）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 移除正则表达式约束（`pattern`）
+    pub fn strip_patterns(mut self, strip: bool) -> Self {
+        self.strip_patterns = strip;
+        self
+    }
+
+    /// 移除格式约束（`format`，如 email、uri）
+    pub fn strip_formats(mut self, strip: bool) -> Self {
+        self.strip_formats = strip;
+        self
+    }
+}
+
+/// 精简 schema，生成用于边缘节点/WASM 预过滤的轻量 JSON Schema
+///
+/// async-validator 规则会先转换为 JSON Schema。结果只会比原 schema 更宽松：
+/// 能通过完整 schema 的数据一定能通过精简后的 schema，完整验证仍应在源站执行。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{minify, MinifyOptions};
+/// use serde_json::json;
+///
+/// let schema = json!({
+///     "type": "object",
+///     "title": "User",
+///     "properties": {
+///         "email": {"type": "string", "format": "email", "description": "Contact email"},
+///         "code": {"type": "string", "pattern": "^[A-Z]{3}$"}
+///     }
+/// });
+///
+/// let minified = minify(&schema, &MinifyOptions::new().strip_patterns(true)).unwrap();
+/// assert_eq!(minified, json!({
+///     "type": "object",
+///     "properties": {
+///         "email": {"type": "string", "format": "email"},
+///         "code": {"type": "string"}
+///     }
+/// }));
+/// ```
+pub fn minify(schema: &Value, options: &MinifyOptions) -> Result<Value, String> {
+    let mut schema = if is_async_rules(schema) {
+        convert_rules(schema)?.schema
+    } else {
+        schema.clone()
+    };
+    minify_schema(&mut schema, options);
+    Ok(schema)
+}
+
+fn minify_schema(schema: &mut Value, options: &MinifyOptions) {
+    let Some(obj) = schema.as_object_mut() else {
+        return;
+    };
+
+    for keyword in ANNOTATION_KEYWORDS {
+        obj.remove(*keyword);
+    }
+    if options.strip_patterns {
+        obj.remove("pattern");
+    }
+    if options.strip_formats {
+        obj.remove("format");
+    }
+
+    for keyword in SUBSCHEMA_KEYWORDS {
+        if let Some(sub) = obj.get_mut(*keyword) {
+            minify_schema(sub, options);
+        }
+    }
+    for keyword in SUBSCHEMA_ARRAY_KEYWORDS {
+        if let Some(Value::Array(subs)) = obj.get_mut(*keyword) {
+            subs.iter_mut().for_each(|sub| minify_schema(sub, options));
+        }
+    }
+    for keyword in SUBSCHEMA_MAP_KEYWORDS {
+        // dependencies 中的属性名数组不是子 schema，minify_schema 会直接跳过
+        if let Some(Value::Object(subs)) = obj.get_mut(*keyword) {
+            subs.values_mut().for_each(|sub| minify_schema(sub, options));
+        }
+    }
+}
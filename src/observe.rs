@@ -0,0 +1,142 @@
+//! 可观测性：验证事件回调、诊断日志、可选的 `tracing` span 和 `metrics` 指标
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{ConversionWarning, SchemaFormat, Severity, ValidationError};
+
+/// 一次验证完成后传给 `LinkValidator::on_validation` 回调的事件，可用于记录指标
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ValidationEvent<'a> {
+    /// 验证器名称（`LinkValidator::with_name` 设置），用于区分不同的 schema
+    pub name: Option<&'a str>,
+    /// 原始 schema 格式
+    pub format: SchemaFormat,
+    /// 验证耗时（不含验证前的数据规范化）
+    pub duration: Duration,
+    /// 错误数量（受 `fail_fast` / `max_errors` 限制）
+    pub error_count: usize,
+    /// 降级为警告的错误数量
+    pub warning_count: usize,
+    /// 收集到的错误，可按 `code` 统计各类约束的失败次数
+    pub errors: &'a [ValidationError],
+    /// 结果是否来自验证结果缓存（`with_result_cache`），此时 `duration` 为查找缓存的耗时
+    pub cached: bool,
+}
+
+impl ValidationEvent<'_> {
+    /// 验证是否通过
+    pub fn is_valid(&self) -> bool {
+        self.error_count == 0
+    }
+}
+
+type HookFn = dyn Fn(&ValidationEvent<'_>) + Send + Sync;
+
+/// 验证事件回调，在验证器副本之间共享
+#[derive(Clone)]
+pub(crate) struct ValidationHook(Arc<HookFn>);
+
+impl ValidationHook {
+    pub(crate) fn new<F>(f: F) -> Self
+    where
+        F: Fn(&ValidationEvent<'_>) + Send + Sync + 'static,
+    {
+        ValidationHook(Arc::new(f))
+    }
+
+    pub(crate) fn call(&self, event: &ValidationEvent<'_>) {
+        (self.0)(event)
+    }
+}
+
+impl fmt::Debug for ValidationHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ValidationHook")
+    }
+}
+
+/// 已进入的 span，离开作用域时退出（未启用 `tracing` feature 时为空）
+#[cfg(feature = "tracing")]
+pub(crate) type SpanGuard = tracing::span::EnteredSpan;
+
+/// 已进入的 span，离开作用域时退出（未启用 `tracing` feature 时为空）
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct SpanGuard;
+
+/// 进入一个阶段的 span：`detect`、`convert`、`compile` 或 `validate`
+#[cfg(feature = "tracing")]
+pub(crate) fn enter(phase: &'static str, name: Option<&str>) -> SpanGuard {
+    tracing::debug_span!("link_validator", phase, name = name.unwrap_or_default()).entered()
+}
+
+/// 进入一个阶段的 span：`detect`、`convert`、`compile` 或 `validate`
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn enter(_phase: &'static str, _name: Option<&str>) -> SpanGuard {
+    SpanGuard
+}
+
+/// 记录验证完成的 tracing 事件
+#[cfg(feature = "tracing")]
+pub(crate) fn record(event: &ValidationEvent<'_>) {
+    tracing::debug!(
+        duration_us = u64::try_from(event.duration.as_micros()).unwrap_or(u64::MAX),
+        errors = event.error_count,
+        warnings = event.warning_count,
+        cached = event.cached,
+        "validation finished"
+    );
+}
+
+/// 记录验证完成的 tracing 事件
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record(_event: &ValidationEvent<'_>) {}
+
+/// 通过 `metrics` 门面记录验证指标，标签 `schema` 为验证器名称（未命名时为空字符串）
+///
+/// - `validations_total`：验证次数，标签 `result` 为 `valid` 或 `invalid`
+/// - `validation_errors_total`：各错误码的错误次数，标签 `code`
+/// - `validate_duration_seconds`：验证耗时直方图
+/// - `validation_cache_hits_total`：命中验证结果缓存的次数（命中时同样计入以上指标）
+#[cfg(feature = "metrics")]
+pub(crate) fn metrics(event: &ValidationEvent<'_>) {
+    let schema = event.name.unwrap_or_default().to_string();
+    let result = if event.is_valid() { "valid" } else { "invalid" };
+    metrics::counter!("validations_total", "schema" => schema.clone(), "result" => result).increment(1);
+    metrics::histogram!("validate_duration_seconds", "schema" => schema.clone()).record(event.duration.as_secs_f64());
+    if event.cached {
+        metrics::counter!("validation_cache_hits_total", "schema" => schema.clone()).increment(1);
+    }
+    for error in event.errors {
+        metrics::counter!("validation_errors_total", "schema" => schema.clone(), "code" => error.code.clone()).increment(1);
+    }
+}
+
+/// 通过 `metrics` 门面记录验证指标（未启用 `metrics` feature 时为空）
+#[cfg(not(feature = "metrics"))]
+pub(crate) fn metrics(_event: &ValidationEvent<'_>) {}
+
+/// 输出转换警告：默认通过 `log` 门面（target 为 `link_validator`），启用 `tracing` feature 时改为 tracing 事件
+#[cfg(feature = "tracing")]
+pub(crate) fn warning(warning: &ConversionWarning) {
+    let rule = warning.rule.as_deref().unwrap_or_default();
+    let field = warning.field_path.as_str();
+    match warning.severity {
+        Severity::Info => tracing::info!(target: "link_validator", field, rule, "{}", warning.message),
+        Severity::Warning => tracing::warn!(target: "link_validator", field, rule, "{}", warning.message),
+        Severity::Error => tracing::error!(target: "link_validator", field, rule, "{}", warning.message),
+    }
+}
+
+/// 输出转换警告：默认通过 `log` 门面（target 为 `link_validator`），启用 `tracing` feature 时改为 tracing 事件
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn warning(warning: &ConversionWarning) {
+    let level = match warning.severity {
+        Severity::Info => log::Level::Info,
+        Severity::Warning => log::Level::Warn,
+        Severity::Error => log::Level::Error,
+    };
+    log::log!(target: "link_validator", level, "{}", warning.message);
+}
@@ -0,0 +1,227 @@
+//! OpenAPI 3 schema 导入与导出
+
+use serde_json::{json, Map, Value};
+
+use crate::keywords;
+
+/// OpenAPI 组件 schema 引用的前缀
+const COMPONENT_PREFIX: &str = "#/components/schemas/";
+
+/// 只在 OpenAPI 中有意义、转换为 JSON Schema 时移除的关键字
+const OPENAPI_ONLY_KEYWORDS: &[&str] = &["nullable", "discriminator", "xml", "externalDocs", "example"];
+
+/// 从 OpenAPI 文档中提取 schema 并转换为可编译的 JSON Schema（draft-07）
+///
+/// `pointer` 可以是 JSON Pointer（`/components/schemas/User`）、引用形式（`#/components/schemas/User`）
+/// 或组件名称（`User`）。文档中的全部组件 schema 被放入 `definitions`，组件引用随之改写。
+pub(crate) fn extract(spec: &Value, pointer: &str) -> Result<Value, String> {
+    if !spec.is_object() {
+        return Err("OpenAPI document must be an object".to_string());
+    }
+    let path = match pointer.strip_prefix('#').unwrap_or(pointer) {
+        p if p.starts_with('/') => p.to_string(),
+        name => format!("/components/schemas/{}", name.replace('~', "~0").replace('/', "~1")),
+    };
+    let target = spec
+        .pointer(&path)
+        .ok_or_else(|| format!("Schema '{}' not found in OpenAPI document", pointer))?;
+    if !target.is_object() && !target.is_boolean() {
+        return Err(format!("'{}' is not a schema", pointer));
+    }
+
+    let mut definitions = Map::new();
+    if let Some(components) = spec.pointer("/components/schemas").and_then(Value::as_object) {
+        for (name, schema) in components {
+            definitions.insert(name.clone(), convert(schema)?);
+        }
+    }
+
+    let mut schema = match convert(target)? {
+        Value::Object(obj) => obj,
+        Value::Bool(true) => Map::new(),
+        other => return Ok(json!({"allOf": [other], "definitions": definitions})),
+    };
+    // 即使没有组件也保留 definitions，确保按 JSON Schema 格式编译
+    schema.insert("definitions".to_string(), Value::Object(definitions));
+    Ok(Value::Object(schema))
+}
+
+/// 把单个 OpenAPI schema 转换为 JSON Schema
+fn convert(schema: &Value) -> Result<Value, String> {
+    let mut schema = schema.clone();
+    let mut error = None;
+    keywords::visit_schemas_mut(&mut schema, &mut |obj| {
+        if let Err(e) = convert_keywords(obj) {
+            error.get_or_insert(e);
+        }
+    });
+    match error {
+        Some(e) => Err(e),
+        None => Ok(schema),
+    }
+}
+
+/// 转换单个 schema 对象上的 OpenAPI 关键字
+fn convert_keywords(obj: &mut Map<String, Value>) -> Result<(), String> {
+    if let Some(Value::String(reference)) = obj.get_mut("$ref") {
+        match reference.strip_prefix(COMPONENT_PREFIX) {
+            Some(name) => *reference = format!("#/definitions/{}", name),
+            // nullable 引用改写为 anyOf 后会再次被访问
+            None if reference.starts_with("#/definitions/") => {}
+            None => return Err(format!("Unsupported $ref '{}': only {} references are supported", reference, COMPONENT_PREFIX)),
+        }
+    }
+
+    // OpenAPI 3.0 的 exclusiveMinimum/exclusiveMaximum 是布尔值，修饰 minimum/maximum
+    for (exclusive, bound) in [("exclusiveMinimum", "minimum"), ("exclusiveMaximum", "maximum")] {
+        if let Some(Value::Bool(flag)) = obj.get(exclusive).cloned() {
+            obj.remove(exclusive);
+            if flag && let Some(value) = obj.remove(bound) {
+                obj.insert(exclusive.to_string(), value);
+            }
+        }
+    }
+
+    if obj.get("nullable") == Some(&Value::Bool(true)) {
+        make_nullable(obj);
+    }
+    for keyword in OPENAPI_ONLY_KEYWORDS {
+        obj.remove(*keyword);
+    }
+    Ok(())
+}
+
+/// 允许 schema 接受 `null`
+fn make_nullable(obj: &mut Map<String, Value>) {
+    if let Some(reference) = obj.remove("$ref") {
+        let any_of = vec![json!({"$ref": reference}), json!({"type": "null"})];
+        obj.insert("anyOf".to_string(), Value::Array(any_of));
+        return;
+    }
+    match obj.get_mut("type") {
+        Some(Value::String(t)) => {
+            let t = std::mem::take(t);
+            obj.insert("type".to_string(), json!([t, "null"]));
+        }
+        Some(Value::Array(types)) if !types.contains(&json!("null")) => types.push(json!("null")),
+        _ => {}
+    }
+    if let Some(Value::Array(values)) = obj.get_mut("enum")
+        && !values.contains(&Value::Null)
+    {
+        values.push(Value::Null);
+    }
+}
+
+/// OpenAPI 3.0 Schema Object 支持的关键字（`x-` 开头的扩展字段也会保留）
+const OPENAPI_KEYWORDS: &[&str] = &[
+    "$ref", "title", "description", "type", "format", "nullable", "enum", "default", "example",
+    "multipleOf", "maximum", "exclusiveMaximum", "minimum", "exclusiveMinimum",
+    "maxLength", "minLength", "pattern", "maxItems", "minItems", "uniqueItems",
+    "maxProperties", "minProperties", "required", "properties", "additionalProperties", "items",
+    "allOf", "oneOf", "anyOf", "not", "discriminator", "readOnly", "writeOnly", "deprecated",
+];
+
+/// 把编译使用的 JSON Schema 转换为 OpenAPI 3.0 风格的 schema 对象
+///
+/// `type` 中的 `null` 转换为 `nullable`，`const` 转换为单值 `enum`，`examples` 的第一个值作为 `example`，
+/// 数值形式的 `exclusiveMinimum`/`exclusiveMaximum` 转换为布尔形式，`itemsBy` 生成的条件分支转换为
+/// `oneOf` + `discriminator`，其他 OpenAPI 不支持的关键字被移除。
+/// `#/definitions/...` 引用改写为 `#/components/schemas/...`，定义本身需要另行注册为组件。
+pub(crate) fn export(schema: &Value) -> Value {
+    let mut schema = schema.clone();
+    keywords::visit_schemas_mut(&mut schema, &mut export_keywords);
+    schema
+}
+
+/// 转换单个 schema 对象上的关键字
+fn export_keywords(obj: &mut Map<String, Value>) {
+    if let Some(Value::String(reference)) = obj.get_mut("$ref")
+        && let Some(name) = reference.strip_prefix("#/definitions/")
+    {
+        *reference = format!("{}{}", COMPONENT_PREFIX, name);
+    }
+
+    if obj.get("type").is_some_and(Value::is_array)
+        && let Some(Value::Array(types)) = obj.remove("type")
+    {
+        let nullable = types.contains(&json!("null"));
+        let mut types: Vec<Value> = types.into_iter().filter(|t| t != "null").collect();
+        if nullable {
+            obj.insert("nullable".to_string(), Value::Bool(true));
+        }
+        match types.len() {
+            0 => {}
+            1 => {
+                obj.insert("type".to_string(), types.remove(0));
+            }
+            _ => {
+                let any_of = types.into_iter().map(|t| json!({"type": t})).collect();
+                obj.insert("anyOf".to_string(), Value::Array(any_of));
+            }
+        }
+    } else if obj.get("type") == Some(&json!("null")) {
+        obj.remove("type");
+        obj.insert("nullable".to_string(), Value::Bool(true));
+    }
+
+    if let Some(value) = obj.remove("const") {
+        obj.insert("enum".to_string(), Value::Array(vec![value]));
+    }
+    if let Some(Value::Array(mut examples)) = obj.remove("examples")
+        && !examples.is_empty()
+    {
+        obj.entry("example".to_string()).or_insert_with(|| examples.swap_remove(0));
+    }
+
+    for (exclusive, bound) in [("exclusiveMinimum", "minimum"), ("exclusiveMaximum", "maximum")] {
+        if let Some(value) = obj.get(exclusive).filter(|v| v.is_number()).cloned() {
+            obj.insert(bound.to_string(), value);
+            obj.insert(exclusive.to_string(), Value::Bool(true));
+        }
+    }
+
+    export_discriminator(obj);
+
+    // OpenAPI 3.0 的 items 只能是单个 schema
+    if obj.get("items").is_some_and(Value::is_array) {
+        obj.remove("items");
+    }
+    obj.retain(|key, _| OPENAPI_KEYWORDS.contains(&key.as_str()) || key.starts_with("x-"));
+}
+
+/// 把 `itemsBy` 生成的 `allOf` 条件分支转换为 `oneOf` + `discriminator`
+///
+/// 每个分支为 `{"if": {"properties": {字段: {"const": 值}}}, "then": schema}`，
+/// 转换后的分支 schema 中判别字段限定为对应的值。
+fn export_discriminator(obj: &mut Map<String, Value>) {
+    let Some((field, variants)) = keywords::discriminated_branches(obj) else {
+        return;
+    };
+
+    let one_of = variants
+        .into_iter()
+        .map(|(tag, mut then)| {
+            if let Value::Object(then) = &mut then {
+                let properties = then
+                    .entry("properties".to_string())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(properties) = properties {
+                    properties.insert(field.clone(), json!({"enum": [tag]}));
+                }
+                let required = then
+                    .entry("required".to_string())
+                    .or_insert_with(|| Value::Array(Vec::new()));
+                if let Value::Array(required) = required
+                    && !required.contains(&json!(field))
+                {
+                    required.push(json!(field));
+                }
+            }
+            then
+        })
+        .collect();
+    obj.remove("allOf");
+    obj.insert("oneOf".to_string(), Value::Array(one_of));
+    obj.insert("discriminator".to_string(), json!({"propertyName": field}));
+}
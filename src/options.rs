@@ -0,0 +1,899 @@
+//! 验证选项与编译选项
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::coerce::{BooleanTokens, CoercionOptions, NumberFormat};
+use crate::error::Segment;
+use crate::messages;
+use crate::warning::Severity;
+
+/// 验证选项，控制 `LinkValidator::validate_with` 的行为
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{LinkValidator, ValidateOptions};
+/// use serde_json::json;
+///
+/// let schema = json!({
+///     "username": {"type": "string", "required": true, "min": 3},
+///     "email": {"type": "email", "required": true}
+/// });
+/// let validator = LinkValidator::new(&schema).unwrap();
+///
+/// let data = json!({"username": "jo", "email": "invalid-email"});
+/// let options = ValidateOptions::new().fail_fast(true);
+/// let result = validator.validate_with(&data, &options);
+///
+/// assert!(!result.is_valid);
+/// assert_eq!(result.errors.as_array().unwrap().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ValidateOptions {
+    /// 遇到第一个错误即停止验证
+    pub(crate) fail_fast: bool,
+    /// 最多收集的错误数量
+    pub(crate) max_errors: Option<usize>,
+    /// 跳过的验证阶段
+    pub(crate) skipped_phases: Vec<Phase>,
+    /// 前一阶段失败时是否跳过后续阶段
+    pub(crate) short_circuit: bool,
+    /// 客户端版本（配合 `ClientPolicy` 使用）
+    pub(crate) client_version: Option<String>,
+    /// 只验证的顶层字段
+    pub(crate) keys: Option<Vec<String>>,
+    /// 每个字段只保留第一个错误的范围
+    pub(crate) first_fields: FirstFields,
+    /// 错误路径各段的重命名函数
+    pub(crate) rename_path: Option<PathRenamer>,
+    /// 错误对象中是否输出出错的值
+    pub(crate) include_values: bool,
+    /// 错误对象中字符串值的最大长度（字符数）
+    pub(crate) max_value_length: usize,
+    /// 需要脱敏的字段路径（点分隔）
+    pub(crate) redacted: Vec<String>,
+    /// 是否不输出降级为警告的错误
+    pub(crate) omit_warnings: bool,
+    /// 按错误码覆盖错误信息的模板
+    pub(crate) messages: BTreeMap<String, String>,
+}
+
+impl Default for ValidateOptions {
+    fn default() -> Self {
+        ValidateOptions {
+            fail_fast: false,
+            max_errors: None,
+            skipped_phases: Vec::new(),
+            short_circuit: true,
+            client_version: None,
+            keys: None,
+            first_fields: FirstFields::None,
+            rename_path: None,
+            include_values: true,
+            max_value_length: 64,
+            redacted: Vec::new(),
+            omit_warnings: false,
+            messages: BTreeMap::new(),
+        }
+    }
+}
+
+impl ValidateOptions {
+    /// 创建默认验证选项（收集全部错误）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 对应 async-validator 的 `first` 选项，与 `fail_fast` 相同
+    pub fn first(self, first: bool) -> Self {
+        self.fail_fast(first)
+    }
+
+    /// 设置是否在遇到第一个错误时停止验证
+    ///
+    /// 为 `true` 时只返回第一个错误，适合快速拒绝大体积的非法数据；
+    /// 为 `false`（默认）时收集全部错误，适合表单展示。
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// 设置最多收集的错误数量
+    ///
+    /// 对于严重不合法的数据（例如上千个数组元素全部失败），限制错误收集的数量，
+    /// 避免错误处理路径上的内存和时间开销失控。超出限制时 `ValidationResult::truncated` 为 `true`。
+    pub fn max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
+    /// 跳过指定的验证阶段
+    ///
+    /// 可多次调用以跳过多个阶段。跳过 `Phase::Coerce` 时数据不经规范化直接验证。
+    pub fn skip_phase(mut self, phase: Phase) -> Self {
+        if !self.skipped_phases.contains(&phase) {
+            self.skipped_phases.push(phase);
+        }
+        self
+    }
+
+    /// 设置前一阶段产生错误时是否跳过后续阶段
+    ///
+    /// 为 `true`（默认）时，结构不合法的数据不会进入代价较高的文档级验证；
+    /// 为 `false` 时所有启用的阶段都会执行，错误按阶段顺序汇总。
+    pub fn short_circuit(mut self, short_circuit: bool) -> Self {
+        self.short_circuit = short_circuit;
+        self
+    }
+
+    /// 设置发起请求的客户端版本
+    ///
+    /// 验证器设置了 `ClientPolicy` 时，按该版本把匹配字段的错误降级为警告。
+    pub fn client_version(mut self, version: impl Into<String>) -> Self {
+        self.client_version = Some(version.into());
+        self
+    }
+
+    /// 只验证指定的顶层字段，对应 async-validator 的 `validate(data, { keys: [...] })`
+    ///
+    /// 其他顶层字段上的错误（包括缺少必填字段）会被忽略，根节点上的错误（例如数据不是对象）仍会报告。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{LinkValidator, ValidateOptions};
+    /// use serde_json::json;
+    ///
+    /// let schema = json!({
+    ///     "username": {"type": "string", "required": true, "min": 3},
+    ///     "password": {"type": "string", "required": true, "min": 8}
+    /// });
+    /// let validator = LinkValidator::new(&schema).unwrap();
+    ///
+    /// // 表单中只填写了用户名时，只验证该字段
+    /// let options = ValidateOptions::new().keys(["username"]);
+    /// assert!(validator.validate_with(&json!({"username": "john"}), &options).is_valid);
+    /// ```
+    pub fn keys<I, S>(mut self, keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.keys = Some(keys.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 指定顶层字段上的每个字段只保留第一个错误，对应 async-validator 的 `firstFields: [...]`
+    ///
+    /// 同一字段违反多条规则时只报告第一条，与前端表单显示的错误一致；列出的顶层字段下的嵌套字段
+    /// 各自保留第一个错误，缺少必填字段的错误计入缺少的字段。其他字段仍报告全部错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{LinkValidator, ValidateOptions};
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::new(&json!({
+    ///     "username": [{"type": "string", "min": 3}, {"pattern": "^[a-z]+$"}]
+    /// })).unwrap();
+    ///
+    /// let options = ValidateOptions::new().first_fields(["username"]);
+    /// let result = validator.validate_with(&json!({"username": "A"}), &options);
+    /// assert_eq!(result.errors.as_array().unwrap().len(), 1);
+    /// ```
+    pub fn first_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.first_fields = FirstFields::Keys(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// 设置是否所有字段都只保留第一个错误，对应 async-validator 的 `firstFields: true`
+    pub fn first_fields_all(mut self, all: bool) -> Self {
+        self.first_fields = if all { FirstFields::All } else { FirstFields::None };
+        self
+    }
+
+    /// 设置错误路径各段的重命名函数
+    ///
+    /// 只影响 `ValidationResult` 中 `field`/`instancePath` 的输出，例如把下标改为从 1 开始，
+    /// 或把字段名映射为表单控件的名称。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{LinkValidator, Segment, ValidateOptions};
+    /// use serde_json::json;
+    ///
+    /// let schema = json!({
+    ///     "tags": {"type": "array", "fields": {"label": {"type": "string", "max": 3}}}
+    /// });
+    /// let validator = LinkValidator::new(&schema).unwrap();
+    ///
+    /// let options = ValidateOptions::new().rename_path(|segment| match segment {
+    ///     Segment::Index(index) => Segment::Index(index + 1),
+    ///     Segment::Key(key) => Segment::Key(key.to_uppercase()),
+    /// });
+    /// let result = validator.validate_with(&json!({"tags": [{"label": "long"}]}), &options);
+    /// assert_eq!(result.errors[0]["field"], "TAGS.1.LABEL");
+    /// ```
+    pub fn rename_path<F>(mut self, renamer: F) -> Self
+    where
+        F: Fn(&Segment) -> Segment + Send + Sync + 'static,
+    {
+        self.rename_path = Some(PathRenamer(Arc::new(renamer)));
+        self
+    }
+
+    /// 设置错误对象中是否输出出错的值（`value`），默认输出
+    pub fn include_values(mut self, include: bool) -> Self {
+        self.include_values = include;
+        self
+    }
+
+    /// 设置错误对象中字符串值的最大长度（字符数，默认 64），超出部分以省略号代替
+    pub fn max_value_length(mut self, max: usize) -> Self {
+        self.max_value_length = max;
+        self
+    }
+
+    /// 对指定字段（点分隔路径，例如 `auth.token`）及其子字段上的错误脱敏
+    ///
+    /// 与规则中的 `sensitive: true` 效果相同：错误对象的 `value` 和错误信息中的值替换为 `[REDACTED]`。
+    /// 可多次调用。
+    pub fn redact(mut self, path: impl Into<String>) -> Self {
+        self.redacted.push(path.into());
+        self
+    }
+
+    /// 设置是否不输出降级为警告的错误（`ClientPolicy` 降级的错误不再出现在 `ValidationResult::warnings` 中）
+    ///
+    /// 与 async-validator 的 `suppressWarning` 不同：后者只关闭控制台警告，这里会丢弃降级的错误本身。
+    pub fn omit_warnings(mut self, omit: bool) -> Self {
+        self.omit_warnings = omit;
+        self
+    }
+
+    /// 按错误码覆盖错误信息，对应 async-validator 的 `messages`
+    ///
+    /// `code` 为错误码（如 `required`、`string.min`），也接受 async-validator 的键名（`enum`、`types.email`）。
+    /// 模板中的 `%s` 依次替换为字段路径和规则参数（长度、数值界限、枚举值、正则等）。可多次调用。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{LinkValidator, ValidateOptions};
+    /// use serde_json::json;
+    ///
+    /// let validator = LinkValidator::new(&json!({"name": {"type": "string", "min": 3}})).unwrap();
+    /// let options = ValidateOptions::new().message("string.min", "%s must be at least %s characters");
+    /// let result = validator.validate_with(&json!({"name": "al"}), &options);
+    /// assert_eq!(result.errors[0]["message"], "name must be at least 3 characters");
+    /// ```
+    pub fn message(mut self, code: impl Into<String>, template: impl Into<String>) -> Self {
+        self.messages.insert(code.into(), template.into());
+        self
+    }
+
+    /// 由 async-validator 的选项对象创建验证选项
+    ///
+    /// 支持 `first`、`firstFields`（布尔值或字段名数组）、`keys`、`suppressWarning` 和嵌套的 `messages` 对象，
+    /// 便于直接沿用前端的选项。`suppressWarning` 只关闭控制台警告，验证时不输出日志，因此只检查类型。
+    /// 值的类型不正确或包含其他键时返回错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::ValidateOptions;
+    /// use serde_json::json;
+    ///
+    /// let options = ValidateOptions::from_async_options(&json!({
+    ///     "firstFields": true,
+    ///     "suppressWarning": true,
+    ///     "messages": {"required": "%s is required", "string": {"min": "%s is too short"}}
+    /// }));
+    /// assert!(options.is_ok());
+    /// ```
+    pub fn from_async_options(value: &Value) -> Result<Self, String> {
+        let Value::Object(obj) = value else {
+            return Err("Validate options must be an object".to_string());
+        };
+        let invalid = |key: &str, expected: &str| format!("Invalid validate option '{}': expected {}", key, expected);
+        let strings = |key: &str, value: &Value| -> Result<Vec<String>, String> {
+            value
+                .as_array()
+                .and_then(|items| items.iter().map(|item| item.as_str().map(String::from)).collect())
+                .ok_or_else(|| invalid(key, "an array of strings"))
+        };
+        let mut options = ValidateOptions::new();
+        for (key, value) in obj {
+            options = match key.as_str() {
+                "first" => options.first(value.as_bool().ok_or_else(|| invalid(key, "a boolean"))?),
+                "firstFields" => match value {
+                    Value::Bool(all) => options.first_fields_all(*all),
+                    _ => options.first_fields(strings(key, value)?),
+                },
+                "keys" => options.keys(strings(key, value)?),
+                "suppressWarning" => {
+                    value.as_bool().ok_or_else(|| invalid(key, "a boolean"))?;
+                    options
+                }
+                "messages" => {
+                    messages::flatten(value, "", &mut options.messages)?;
+                    options
+                }
+                _ => return Err(format!("Unknown validate option '{}'", key)),
+            };
+        }
+        Ok(options)
+    }
+
+    /// 字段路径（JSON Pointer）是否在 `keys` 限定的范围内
+    pub(crate) fn includes_field(&self, field: &str) -> bool {
+        let Some(keys) = &self.keys else {
+            return true;
+        };
+        let Some(top) = field.strip_prefix('/').and_then(|f| f.split('/').next()) else {
+            return true;
+        };
+        if top.contains('~') {
+            keys.contains(&top.replace("~1", "/").replace("~0", "~"))
+        } else {
+            keys.iter().any(|key| key == top)
+        }
+    }
+
+    /// 字段（JSON Pointer）是否只保留第一个错误
+    pub(crate) fn first_only(&self, field: &str) -> bool {
+        match &self.first_fields {
+            FirstFields::None => false,
+            FirstFields::All => true,
+            FirstFields::Keys(keys) => field
+                .strip_prefix('/')
+                .and_then(|f| f.split('/').next())
+                .is_some_and(|top| keys.iter().any(|key| *key == top.replace("~1", "/").replace("~0", "~"))),
+        }
+    }
+
+    /// 指定阶段是否需要执行
+    pub(crate) fn runs(&self, phase: Phase) -> bool {
+        !self.skipped_phases.contains(&phase)
+    }
+}
+
+/// 只保留第一个错误的字段范围（`ValidateOptions::first_fields`）
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum FirstFields {
+    /// 报告全部错误
+    None,
+    /// 所有字段
+    All,
+    /// 指定的顶层字段
+    Keys(Vec<String>),
+}
+
+/// 错误路径各段的重命名函数（`ValidateOptions::rename_path`）
+#[derive(Clone)]
+pub(crate) struct PathRenamer(Arc<dyn Fn(&Segment) -> Segment + Send + Sync>);
+
+impl PathRenamer {
+    pub(crate) fn rename(&self, segments: Vec<Segment>) -> Vec<Segment> {
+        segments.iter().map(|segment| (self.0)(segment)).collect()
+    }
+}
+
+impl fmt::Debug for PathRenamer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PathRenamer")
+    }
+}
+
+/// 验证阶段，按声明顺序依次执行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Phase {
+    /// 规范化：规则中的转换与编译选项中的强制类型转换
+    Coerce,
+    /// JSON Schema 验证
+    Schema,
+    /// 文档级自定义验证（`register_document_validator`）
+    Document,
+}
+
+/// 编译选项，控制 `LinkValidator::with_options` 生成的验证器
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{CompileOptions, LinkValidator, NumberFormat};
+/// use serde_json::json;
+///
+/// let schema = json!({
+///     "price": {"type": "number", "required": true, "min": 0}
+/// });
+/// let options = CompileOptions::new().number_format(NumberFormat::DecimalComma);
+/// let validator = LinkValidator::with_options(&schema, &options).unwrap();
+///
+/// let (value, result) = validator.validate_and_transform(&json!({"price": "1.234,56"}));
+/// assert!(result.is_valid);
+/// assert_eq!(value["price"], json!(1234.56));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CompileOptions {
+    /// 数字字段的本地化字符串解析格式
+    pub(crate) number_format: Option<NumberFormat>,
+    /// 布尔字段的字符串转换表
+    pub(crate) boolean_tokens: Option<BooleanTokens>,
+    /// 对象属性值为 `null` 时的处理策略
+    pub(crate) null_policy: NullPolicy,
+    /// 为生成的属性填充 `title` 的方式
+    pub(crate) titles: Option<TitleStyle>,
+    /// 将表单元数据同时写入 `title`/`description`
+    pub(crate) form_annotations: bool,
+    /// schema 序列化后的最大字节数
+    pub(crate) max_schema_bytes: Option<usize>,
+    /// schema 中属性的最大总数
+    pub(crate) max_fields: Option<usize>,
+    /// schema 的最大嵌套深度
+    pub(crate) max_depth: Option<usize>,
+    /// 只接受可以线性时间匹配的正则
+    pub(crate) safe_patterns: bool,
+    /// 字符串长度的计数单位
+    pub(crate) length_unit: LengthUnit,
+    /// 编译耗时预算
+    pub(crate) compile_budget: Option<Duration>,
+    /// 规则中无法识别的键的处理策略
+    pub(crate) unknown_rules: UnknownRulePolicy,
+    /// 同一字段多条规则中冲突约束的合并策略
+    pub(crate) merge_strategy: RuleMergeStrategy,
+    /// 必填字段拒绝空字符串和空数组
+    pub(crate) required_rejects_empty: bool,
+    /// 必填字段拒绝去除首尾空白后为空的字符串
+    pub(crate) required_rejects_blank: bool,
+    /// 按规则键覆盖的转换警告严重程度
+    pub(crate) warning_severities: BTreeMap<String, Severity>,
+}
+
+impl CompileOptions {
+    /// 创建默认编译选项（不做任何强制转换）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 启用本地化数字字符串转换
+    ///
+    /// 验证前把 `number`/`integer` 字段上的字符串（如 `"1.234,56"`、`"1,234.56"`）
+    /// 按指定格式解析为数字，适合从电子表格导入的数据。无法解析的字符串保持原样。
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = Some(format);
+        self
+    }
+
+    /// 启用布尔字符串转换
+    ///
+    /// 验证前把 `boolean` 字段上的字符串（如 `"yes"`/`"no"`、`"1"`/`"0"`、`"on"`/`"off"`）
+    /// 按转换表映射为布尔值，适合表单和 CSV 输入。不在转换表中的字符串保持原样。
+    pub fn boolean_tokens(mut self, tokens: BooleanTokens) -> Self {
+        self.boolean_tokens = Some(tokens);
+        self
+    }
+
+    /// 启用表单输入的强制类型转换
+    ///
+    /// 已通过 `number_format` / `boolean_tokens` 设置的转换优先，不会被覆盖。
+    pub fn coercion(mut self, coercion: CoercionOptions) -> Self {
+        if coercion.numbers && self.number_format.is_none() {
+            self.number_format = Some(NumberFormat::Plain);
+        }
+        if coercion.booleans && self.boolean_tokens.is_none() {
+            self.boolean_tokens = Some(BooleanTokens::default());
+        }
+        self
+    }
+
+    /// 设置对象属性值为 `null` 时的处理策略，默认 `NullPolicy::Keep`
+    pub fn null_policy(mut self, policy: NullPolicy) -> Self {
+        self.null_policy = policy;
+        self
+    }
+
+    /// 设置 async-validator 规则中无法识别的键的处理策略，默认 `UnknownRulePolicy::Warn`
+    ///
+    /// 严格的部署可以使用 `UnknownRulePolicy::Error`，让拼写错误的规则（如 `requird`）在编译时失败，
+    /// 而不是被静默忽略。
+    pub fn unknown_rule_policy(mut self, policy: UnknownRulePolicy) -> Self {
+        self.unknown_rules = policy;
+        self
+    }
+
+    /// 设置同一字段的规则数组中冲突约束（如两条规则给出不同的 `min`）的合并策略，默认 `RuleMergeStrategy::LastWins`
+    ///
+    /// 除 `RuleMergeStrategy::Error` 外，每处冲突都会记录为转换警告。
+    pub fn merge_strategy(mut self, strategy: RuleMergeStrategy) -> Self {
+        self.merge_strategy = strategy;
+        self
+    }
+
+    /// 设置某条规则产生的转换警告的严重程度（规则键例如 `validator`、`trigger`、`minDate`）
+    ///
+    /// 默认 `trigger` 为 `Severity::Info`，其他为 `Severity::Warning`，编译时按对应的日志级别输出。
+    /// 任何警告为 `Severity::Error` 时编译失败，返回以 `Conversion warnings treated as errors:` 开头的错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{CompileOptions, LinkValidator, Severity};
+    /// use serde_json::json;
+    ///
+    /// let options = CompileOptions::new().warning_severity("validator", Severity::Error);
+    ///
+    /// let rules = json!({"name": {"type": "string", "trigger": "blur"}});
+    /// assert!(LinkValidator::with_options(&rules, &options).is_ok());
+    ///
+    /// let rules = json!({"name": {"type": "string", "validator": "checkName"}});
+    /// assert!(LinkValidator::with_options(&rules, &options).is_err());
+    /// ```
+    pub fn warning_severity(mut self, rule: impl Into<String>, severity: Severity) -> Self {
+        self.warning_severities.insert(rule.into(), severity);
+        self
+    }
+
+    /// 设置必填字段是否拒绝空字符串和空数组，与 async-validator 的 `required` 一致
+    ///
+    /// 启用后必填的字符串字段生成 `minLength: 1`、数组字段生成 `minItems: 1`。默认只检查字段是否存在。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{CompileOptions, LinkValidator};
+    /// use serde_json::json;
+    ///
+    /// let schema = json!({"name": {"type": "string", "required": true}});
+    /// let options = CompileOptions::new().required_rejects_empty(true);
+    /// let validator = LinkValidator::with_options(&schema, &options).unwrap();
+    ///
+    /// assert!(!validator.validate(&json!({"name": ""})).is_valid);
+    /// ```
+    pub fn required_rejects_empty(mut self, reject: bool) -> Self {
+        self.required_rejects_empty = reject;
+        self
+    }
+
+    /// 设置必填字段是否拒绝去除首尾空白后为空的字符串（如 `"   "`），默认不拒绝
+    ///
+    /// 与 Element Plus 表单中 `required` 配合 `whitespace: true` 的效果一致，错误码为 `required`，
+    /// `field` 为该字段本身。在 schema 验证之后检查，不修改数据（需要去除空白时使用规则中的 `trim`）。
+    /// 同时启用 `required_rejects_empty` 时，字符串字段的空值只报告这一个错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use link_validator::{CompileOptions, LinkValidator};
+    /// use serde_json::json;
+    ///
+    /// let schema = json!({"name": {"type": "string", "required": true}});
+    /// let options = CompileOptions::new().required_rejects_blank(true);
+    /// let validator = LinkValidator::with_options(&schema, &options).unwrap();
+    ///
+    /// let result = validator.validate(&json!({"name": "   "}));
+    /// assert_eq!(result.errors[0]["code"], "required");
+    /// assert_eq!(result.errors[0]["field"], "name");
+    /// ```
+    pub fn required_rejects_blank(mut self, reject: bool) -> Self {
+        self.required_rejects_blank = reject;
+        self
+    }
+
+    /// 设置字符串长度（`minLength`/`maxLength`，即规则中字符串的 `min`/`max`/`len`）的计数单位，
+    /// 默认 `LengthUnit::Chars`
+    ///
+    /// 非默认单位的长度约束在 JSON Schema 验证之后单独检查。
+    pub fn length_unit(mut self, unit: LengthUnit) -> Self {
+        self.length_unit = unit;
+        self
+    }
+
+    /// 为 async-validator 规则生成的每个属性填充 JSON Schema `title`
+    ///
+    /// 已有 `title` 的属性保持不变。表单生成器、文档渲染等下游工具通常依赖 `title` 显示字段名称。
+    pub fn titles(mut self, style: TitleStyle) -> Self {
+        self.titles = Some(style);
+        self
+    }
+
+    /// 将规则中的表单元数据同时输出为 JSON Schema 注解：`label` 写入 `title`，`help` 写入 `description`
+    ///
+    /// 已有 `title`/`description` 的属性保持不变，优先于 `titles` 生成的标题。
+    pub fn form_annotations(mut self, enabled: bool) -> Self {
+        self.form_annotations = enabled;
+        self
+    }
+
+    /// 限制 schema 的大小（紧凑 JSON 序列化后的字节数，从文件加载时为文件大小）
+    ///
+    /// 超出限制时编译返回以 `Schema limit exceeded:` 开头的错误。
+    /// 适合接受用户提交 schema 的服务（例如多租户表单构建器），避免超大或恶意的 schema 占用资源。
+    pub fn max_schema_bytes(mut self, max: usize) -> Self {
+        self.max_schema_bytes = Some(max);
+        self
+    }
+
+    /// 限制 schema 中属性的总数（包含嵌套对象和数组项中的属性）
+    pub fn max_fields(mut self, max: usize) -> Self {
+        self.max_fields = Some(max);
+        self
+    }
+
+    /// 限制 schema 的嵌套深度（JSON 对象和数组的层数，根节点为第 1 层）
+    ///
+    /// 在规则转换之前检查，转换和编译都是递归进行的，过深的 schema 可能耗尽栈空间。
+    pub fn max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+
+    /// 只接受可以线性时间匹配的正则
+    ///
+    /// 拒绝需要回溯匹配的正则（前后查找、反向引用），适合编译用户提交的规则，
+    /// 防止恶意正则造成 ReDoS。检查 `pattern` 和 `patternProperties` 的键（包括 `keyPattern` 生成的正则）。
+    pub fn safe_patterns(mut self) -> Self {
+        self.safe_patterns = true;
+        self
+    }
+
+    /// 设置编译耗时预算
+    ///
+    /// 在规则转换后和编译完成后检查耗时，超出预算时返回错误。正在进行的 JSON Schema 编译无法中断，
+    /// 因此应与 `max_schema_bytes`、`max_fields`、`max_depth` 配合使用。
+    pub fn compile_budget(mut self, budget: Duration) -> Self {
+        self.compile_budget = Some(budget);
+        self
+    }
+
+    /// 资源限制的 JSON 表示，不影响编译结果，因此不包含在 `to_value` 中
+    pub(crate) fn limits_value(&self) -> Value {
+        json!({
+            "max_schema_bytes": self.max_schema_bytes,
+            "max_fields": self.max_fields,
+            "max_depth": self.max_depth,
+            "safe_patterns": self.safe_patterns,
+            "compile_budget_ms": self.compile_budget.map(|d| d.as_millis() as u64),
+        })
+    }
+
+    /// 编译选项的 JSON 表示（键顺序稳定）
+    pub(crate) fn to_value(&self) -> Value {
+        let mut value = json!({
+            "number_format": self.number_format.map(NumberFormat::as_str),
+            "boolean_tokens": self.boolean_tokens.as_ref().map(BooleanTokens::to_value),
+            "null_policy": match self.null_policy {
+                NullPolicy::Keep => "keep",
+                NullPolicy::StripOptional => "strip-optional",
+                NullPolicy::AsMissing => "as-missing",
+            },
+            "titles": self.titles.map(TitleStyle::as_str),
+        });
+        // 默认单位不写入，已有配置的指纹保持不变
+        if self.length_unit != LengthUnit::Chars {
+            value["length_unit"] = json!(self.length_unit.as_str());
+        }
+        if self.form_annotations {
+            value["form_annotations"] = json!(true);
+        }
+        if self.unknown_rules != UnknownRulePolicy::Warn {
+            value["unknown_rules"] = json!(self.unknown_rules.as_str());
+        }
+        if self.merge_strategy != RuleMergeStrategy::LastWins {
+            value["merge_strategy"] = json!(self.merge_strategy.as_str());
+        }
+        if self.required_rejects_empty {
+            value["required_rejects_empty"] = json!(true);
+        }
+        if self.required_rejects_blank {
+            value["required_rejects_blank"] = json!(true);
+        }
+        if !self.warning_severities.is_empty() {
+            let severities: BTreeMap<&str, &str> =
+                self.warning_severities.iter().map(|(rule, severity)| (rule.as_str(), severity.as_str())).collect();
+            value["warning_severities"] = json!(severities);
+        }
+        value
+    }
+}
+
+/// 对象属性值为 `null` 时的处理策略
+///
+/// JS 客户端经常为可选字段显式发送 `null`，而 async-validator 把 `null` 视为未填写。
+///
+/// | 策略 | 可选字段为 `null` | 必填字段为 `null` |
+/// | --- | --- | --- |
+/// | `Keep` | 类型错误 | 类型错误 |
+/// | `StripOptional` | 移除后通过 | 类型错误 |
+/// | `AsMissing` | 移除后通过 | 缺少必填字段错误 |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NullPolicy {
+    /// `null` 作为普通值参与验证（默认）
+    #[default]
+    Keep,
+    /// 验证前移除可选字段上的 `null`，必填字段上的 `null` 保留并按类型验证
+    StripOptional,
+    /// `null` 视为未填写：验证前移除所有值为 `null` 的属性，必填字段因此报缺失错误
+    AsMissing,
+}
+
+/// 字符串长度的计数单位
+///
+/// JSON Schema 的 `minLength`/`maxLength` 按 Unicode 标量值计数，async-validator 按 JavaScript
+/// 字符串长度（UTF-16 码元）计数，两者对 Emoji 等字符的结果不同。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LengthUnit {
+    /// Unicode 标量值（JSON Schema 的定义，默认）
+    #[default]
+    Chars,
+    /// 字素簇，即用户看到的字符（UAX #29 扩展字素簇），例如 `👨‍👩‍👧`、`क्ष` 均计为 1
+    Graphemes,
+    /// UTF-8 字节数，适合与数据库列的字节长度对应
+    Utf8Bytes,
+    /// UTF-16 码元数，与 JavaScript 的 `string.length`（async-validator）一致
+    Utf16,
+}
+
+impl LengthUnit {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            LengthUnit::Chars => "chars",
+            LengthUnit::Graphemes => "graphemes",
+            LengthUnit::Utf8Bytes => "utf8-bytes",
+            LengthUnit::Utf16 => "utf16",
+        }
+    }
+
+    /// 按该单位计算字符串长度
+    pub(crate) fn count(self, s: &str) -> usize {
+        match self {
+            LengthUnit::Chars => s.chars().count(),
+            LengthUnit::Graphemes => s.graphemes(true).count(),
+            LengthUnit::Utf8Bytes => s.len(),
+            LengthUnit::Utf16 => s.encode_utf16().count(),
+        }
+    }
+
+    /// 错误消息中的单位名称
+    pub(crate) fn noun(self) -> &'static str {
+        match self {
+            LengthUnit::Chars | LengthUnit::Graphemes => "characters",
+            LengthUnit::Utf8Bytes => "bytes",
+            LengthUnit::Utf16 => "UTF-16 code units",
+        }
+    }
+}
+
+/// async-validator 规则中无法识别的键（例如拼写错误的 `requird`）的处理策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnknownRulePolicy {
+    /// 静默忽略
+    Ignore,
+    /// 忽略并记录为转换警告（默认）
+    #[default]
+    Warn,
+    /// 编译失败
+    Error,
+}
+
+impl UnknownRulePolicy {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            UnknownRulePolicy::Ignore => "ignore",
+            UnknownRulePolicy::Warn => "warn",
+            UnknownRulePolicy::Error => "error",
+        }
+    }
+}
+
+/// 同一字段的规则数组中冲突约束的合并策略
+///
+/// 例如 `[{"type": "string", "min": 3}, {"min": 5}]` 中两条规则的 `min` 不同。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RuleMergeStrategy {
+    /// 以最后声明的值为准（默认）
+    #[default]
+    LastWins,
+    /// 取最严格的值：`min`/`exclusiveMin`/`minDate` 取最大，`max`/`exclusiveMax`/`maxDate`/`truncate` 取最小，
+    /// `enum` 取交集；其他约束无法比较，以最后声明的值为准
+    Strictest,
+    /// 编译失败
+    Error,
+}
+
+impl RuleMergeStrategy {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            RuleMergeStrategy::LastWins => "last-wins",
+            RuleMergeStrategy::Strictest => "strictest",
+            RuleMergeStrategy::Error => "error",
+        }
+    }
+}
+
+/// 由字段名生成 `title` 的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TitleStyle {
+    /// 直接使用字段名，例如 `firstName` -> `firstName`
+    FieldName,
+    /// 拆分 camelCase、snake_case 和 kebab-case 并首字母大写，例如 `firstName` -> `First name`
+    Humanized,
+}
+
+impl TitleStyle {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            TitleStyle::FieldName => "field-name",
+            TitleStyle::Humanized => "humanized",
+        }
+    }
+
+    /// 由字段名生成标题
+    pub(crate) fn title(self, field: &str) -> String {
+        match self {
+            TitleStyle::FieldName => field.to_string(),
+            TitleStyle::Humanized => humanize(field),
+        }
+    }
+}
+
+/// 把字段名拆分为小写单词并将首字母大写，非 ASCII 字段名（如中文）保持原样
+fn humanize(field: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut prev: Option<char> = None;
+    for c in field.chars() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev = None;
+            continue;
+        }
+        if c.is_ascii_uppercase()
+            && !current.is_empty()
+            && prev.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit())
+        {
+            words.push(std::mem::take(&mut current));
+        }
+        // 缩写后接单词时拆开，例如 `HTTPStatus` -> `HTTP` + `Status`
+        if c.is_ascii_lowercase() && current.len() >= 2 && current.chars().rev().take(2).all(|p| p.is_ascii_uppercase()) {
+            let last = current.pop().unwrap_or_default();
+            words.push(std::mem::replace(&mut current, last.to_string()));
+        }
+        current.push(c);
+        prev = Some(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let sentence = words
+        .iter()
+        .map(|w| if w.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) { w.clone() } else { w.to_ascii_lowercase() })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut chars = sentence.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => field.to_string(),
+    }
+}
@@ -0,0 +1,31 @@
+//! JSON Patch（RFC 6902）应用前的验证
+
+use json_patch::{PatchError, PatchOperation};
+
+use crate::error::ValidationError;
+
+/// 补丁无法应用时的 `patch.apply` 错误
+pub(crate) fn apply_error(error: &PatchError) -> ValidationError {
+    ValidationError::new(error.path.as_str(), format!("Failed to apply patch operation {}: {}", error.operation, error.kind))
+        .with_code("patch.apply")
+        .with_param("op", error.operation)
+}
+
+/// 修改了错误所在位置（或其上下级）的最后一个操作的下标
+///
+/// 缺少必填字段的错误按缺少的字段匹配；`test` 操作不修改数据，不参与匹配；
+/// `move` 同时匹配源位置，移走字段导致的错误也能找到对应的操作。
+pub(crate) fn responsible_op(operations: &[PatchOperation], error: &ValidationError) -> Option<usize> {
+    let path = error.field_pointer();
+    operations.iter().rposition(|operation| match operation {
+        PatchOperation::Test(_) => false,
+        PatchOperation::Move(op) => overlaps(op.path.as_str(), &path) || overlaps(op.from.as_str(), &path),
+        _ => overlaps(operation.path().as_str(), &path),
+    })
+}
+
+/// 两个 JSON Pointer 是否相同或互为上下级
+fn overlaps(a: &str, b: &str) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    long.strip_prefix(short).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
@@ -0,0 +1,53 @@
+//! 正则表达式检查
+//!
+//! `pattern` 在转换时检查能否编译；启用 `CompileOptions::safe_patterns` 后，
+//! 只接受可以线性时间匹配的正则，防止用户提交的规则造成 ReDoS。
+
+use serde_json::Value;
+
+use crate::keywords;
+use crate::options::CompileOptions;
+
+/// 检查正则能否编译（与 JSON Schema 验证使用的引擎一致）
+pub(crate) fn check(pattern: &str) -> Result<(), String> {
+    fancy_regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|e| format!("invalid pattern {:?}: {}", pattern, e))
+}
+
+/// 检查正则能否以线性时间匹配
+///
+/// 前后查找和反向引用需要回溯匹配，会被拒绝；其余正则由有限自动机匹配，
+/// 编译后的大小受 `regex` 默认限制约束。
+pub(crate) fn check_safe(pattern: &str) -> Result<(), String> {
+    if regex_syntax::parse(pattern).is_ok() {
+        return Ok(());
+    }
+    check(pattern)?;
+    Err(format!(
+        "unsafe pattern {:?}: not supported by the linear-time engine (lookaround and backreferences require backtracking)",
+        pattern
+    ))
+}
+
+/// 启用 `safe_patterns` 时检查 schema 中的全部正则（`pattern`、`patternProperties` 的键）
+pub(crate) fn check_schema(options: &CompileOptions, schema: &Value) -> Result<(), String> {
+    if !options.safe_patterns {
+        return Ok(());
+    }
+    let mut result = Ok(());
+    keywords::visit_schemas(schema, &mut |obj| {
+        if result.is_err() {
+            return;
+        }
+        let patterns = obj.get("pattern").and_then(Value::as_str).into_iter();
+        let keys = obj.get("patternProperties").and_then(Value::as_object).into_iter().flat_map(|p| p.keys());
+        for pattern in patterns.chain(keys.map(String::as_str)) {
+            if let Err(e) = check_safe(pattern) {
+                result = Err(format!("Schema limit exceeded: {}", e));
+                return;
+            }
+        }
+    });
+    result
+}
@@ -0,0 +1,121 @@
+//! 按客户端版本降级规则
+
+use jsonschema::error::ValidationErrorKind;
+use std::cmp::Ordering;
+
+/// 客户端版本策略：对旧版本客户端把指定字段的错误降级为警告
+///
+/// 收紧 schema 时，已发布但尚未升级的客户端（例如仍在应用商店审核中的版本）
+/// 可能发送不满足新规则的数据。策略按客户端版本号匹配，降级后的错误出现在
+/// `ValidationResult::warnings` 中，不影响 `is_valid`。
+///
+/// 字段使用与错误路径相同的 JSON Pointer（例如 `/address/zip`），同时匹配其子路径；
+/// 缺少必填字段的错误按缺失字段本身的路径匹配。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{ClientPolicy, LinkValidator, ValidateOptions};
+/// use serde_json::json;
+///
+/// let schema = json!({
+///     "name": {"type": "string", "required": true},
+///     "phone": {"type": "string", "required": true, "pattern": "^\\+"}
+/// });
+/// let validator = LinkValidator::new(&schema)
+///     .unwrap()
+///     .with_client_policy(ClientPolicy::new().downgrade_below("2.0.0", ["/phone"]));
+///
+/// let data = json!({"name": "john"});
+/// let old_client = validator.validate_with(&data, &ValidateOptions::new().client_version("1.9.3"));
+/// assert!(old_client.is_valid);
+/// assert_eq!(old_client.warnings.as_array().unwrap().len(), 1);
+///
+/// let new_client = validator.validate_with(&data, &ValidateOptions::new().client_version("2.1.0"));
+/// assert!(!new_client.is_valid);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientPolicy {
+    downgrades: Vec<Downgrade>,
+}
+
+#[derive(Debug, Clone)]
+struct Downgrade {
+    /// 低于该版本的客户端适用
+    below: String,
+    /// 降级的字段路径
+    fields: Vec<String>,
+}
+
+impl ClientPolicy {
+    /// 创建空策略（不降级任何规则）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 对版本低于 `version` 的客户端，把指定字段的错误降级为警告
+    ///
+    /// 版本号按点分隔的数字逐段比较（`1.10.0` 高于 `1.9.0`），忽略前缀 `v`
+    /// 和 `-`/`+` 之后的预发布与构建信息。无法解析的版本号不会匹配任何降级规则。
+    pub fn downgrade_below<I, S>(mut self, version: &str, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.downgrades.push(Downgrade {
+            below: version.to_string(),
+            fields: fields.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// 指定客户端版本下降级的字段路径
+    pub(crate) fn downgraded_fields(&self, client_version: &str) -> Vec<&str> {
+        let Some(client) = parse_version(client_version) else {
+            return Vec::new();
+        };
+        self.downgrades
+            .iter()
+            .filter(|d| parse_version(&d.below).is_some_and(|below| compare_versions(&client, &below) == Ordering::Less))
+            .flat_map(|d| d.fields.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+/// 错误对应的字段路径是否位于降级字段之下
+pub(crate) fn is_downgraded(fields: &[&str], error_field: &str) -> bool {
+    fields.iter().any(|field| {
+        let field = field.trim_end_matches('/');
+        error_field == field
+            || error_field
+                .strip_prefix(field)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+/// 错误对应的字段路径，缺少必填字段时指向缺失的字段
+pub(crate) fn error_field(error: &jsonschema::ValidationError) -> String {
+    let path = error.instance_path.to_string();
+    match &error.kind {
+        ValidationErrorKind::Required { property } => match property.as_str() {
+            Some(name) => format!("{}/{}", path, name.replace('~', "~0").replace('/', "~1")),
+            None => path,
+        },
+        _ => path,
+    }
+}
+
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    let core = version.split(['-', '+']).next()?;
+    core.split('.').map(|part| part.parse().ok()).collect()
+}
+
+fn compare_versions(a: &[u64], b: &[u64]) -> Ordering {
+    let len = a.len().max(b.len());
+    (0..len)
+        .map(|i| a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0)))
+        .find(|o| o.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
@@ -0,0 +1,316 @@
+//! 导出 protovalidate（`buf.validate`）约束
+
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::codegen::{pascal_case, required, words};
+
+/// 生成 proto3 消息定义，字段约束使用 `buf.validate.field` 选项
+pub(crate) fn export(schema: &Value, message: &str) -> String {
+    let mut exporter = Exporter {
+        names: BTreeSet::new(),
+        messages: Vec::new(),
+        uses_struct: false,
+    };
+    let root = pascal_case(message);
+    let root = if root.is_empty() { "Root".to_string() } else { root };
+    exporter.names.insert(root.clone());
+    exporter.message(schema, &root);
+
+    let mut proto = String::from("// Generated by link-validator. Do not edit.\n\nsyntax = \"proto3\";\n\nimport \"buf/validate/validate.proto\";\n");
+    if exporter.uses_struct {
+        proto.push_str("import \"google/protobuf/struct.proto\";\n");
+    }
+    for message in exporter.messages {
+        proto.push('\n');
+        proto.push_str(&message);
+    }
+    proto
+}
+
+struct Exporter {
+    /// 已使用的消息名称
+    names: BTreeSet<String>,
+    /// 已生成的消息定义
+    messages: Vec<String>,
+    /// 是否使用了 `google.protobuf.Value`
+    uses_struct: bool,
+}
+
+/// 字段类型和约束
+struct FieldType {
+    /// proto 类型，例如 `string`、`repeated int64`
+    name: String,
+    /// `buf.validate.field` 约束的类型名和内容，例如 `("string", "{min_len: 1}")`
+    rules: Vec<(String, String)>,
+}
+
+impl Exporter {
+    fn reserve(&mut self, name: &str) -> String {
+        let mut candidate = name.to_string();
+        let mut index = 2;
+        while !self.names.insert(candidate.clone()) {
+            candidate = format!("{}{}", name, index);
+            index += 1;
+        }
+        candidate
+    }
+
+    /// 生成消息定义，返回消息名称
+    fn message(&mut self, schema: &Value, name: &str) -> String {
+        let index = self.messages.len();
+        self.messages.push(String::new());
+
+        let obj = schema.as_object();
+        let properties = obj.and_then(|o| o.get("properties")).and_then(Value::as_object);
+        let required = obj.map(required).unwrap_or_default();
+        let mut body = String::new();
+        let mut idents = BTreeSet::new();
+        for (number, (key, property)) in properties.into_iter().flatten().enumerate() {
+            let field = self.field_type(property, &format!("{}{}", name, pascal_case(key)));
+            let base = field_name(key);
+            let mut ident = base.clone();
+            let mut suffix = 2;
+            while !idents.insert(ident.clone()) {
+                ident = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+
+            let mut options = Vec::new();
+            if json_name(&ident) != *key {
+                options.push(format!("json_name = {}", quote(key)));
+            }
+            if required.contains(&key.as_str()) {
+                options.push("(buf.validate.field).required = true".to_string());
+            }
+            options.extend(field.rules.iter().map(|(kind, body)| format!("(buf.validate.field).{} = {}", kind, body)));
+
+            let _ = write!(body, "  {} {} = {}", field.name, ident, number + 1);
+            match options.len() {
+                0 => {}
+                1 => {
+                    let _ = write!(body, " [{}]", options[0]);
+                }
+                _ => {
+                    let _ = write!(body, " [\n    {}\n  ]", options.join(",\n    "));
+                }
+            }
+            body.push_str(";\n");
+        }
+        self.messages[index] = format!("message {} {{\n{}}}\n", name, body);
+        name.to_string()
+    }
+
+    fn field_type(&mut self, schema: &Value, hint: &str) -> FieldType {
+        let Some(obj) = schema.as_object() else {
+            return self.any();
+        };
+        let kind = match obj.get("type") {
+            Some(Value::String(t)) => Some(t.as_str()),
+            // 可为 null 的类型按非 null 类型导出
+            Some(Value::Array(ts)) => {
+                let types: Vec<&str> = ts.iter().filter_map(Value::as_str).filter(|t| *t != "null").collect();
+                if types.len() == 1 { Some(types[0]) } else { None }
+            }
+            None if obj.contains_key("properties") => Some("object"),
+            _ => None,
+        };
+
+        match kind {
+            Some("string") => FieldType {
+                name: "string".to_string(),
+                rules: scalar_rules("string", string_rules(obj)),
+            },
+            Some("integer") => FieldType {
+                name: "int64".to_string(),
+                rules: scalar_rules("int64", number_rules(obj)),
+            },
+            Some("number") => FieldType {
+                name: "double".to_string(),
+                rules: scalar_rules("double", number_rules(obj)),
+            },
+            Some("boolean") => FieldType {
+                name: "bool".to_string(),
+                rules: Vec::new(),
+            },
+            Some("array") => self.repeated(obj, hint),
+            Some("object") => match obj.get("properties").and_then(Value::as_object) {
+                Some(_) => {
+                    let name = self.reserve(hint);
+                    let name = self.message(schema, &name);
+                    FieldType { name, rules: Vec::new() }
+                }
+                None => match obj.get("additionalProperties") {
+                    Some(value @ Value::Object(_)) => {
+                        let value = self.field_type(value, &format!("{}Value", hint));
+                        if value.name.starts_with("repeated ") || value.name.starts_with("map<") {
+                            return self.any();
+                        }
+                        let rules = if value.rules.is_empty() {
+                            Vec::new()
+                        } else {
+                            vec![("map".to_string(), format!("{{values: {}}}", nested(&value.rules)))]
+                        };
+                        FieldType {
+                            name: format!("map<string, {}>", value.name),
+                            rules,
+                        }
+                    }
+                    _ => {
+                        self.uses_struct = true;
+                        FieldType {
+                            name: "google.protobuf.Struct".to_string(),
+                            rules: Vec::new(),
+                        }
+                    }
+                },
+            },
+            // 只有 enum 列表时按枚举值的类型导出
+            None if obj.get("enum").and_then(Value::as_array).is_some_and(|v| !v.is_empty() && v.iter().all(Value::is_string)) => FieldType {
+                name: "string".to_string(),
+                rules: scalar_rules("string", string_rules(obj)),
+            },
+            _ => self.any(),
+        }
+    }
+
+    fn repeated(&mut self, obj: &Map<String, Value>, hint: &str) -> FieldType {
+        let item = match obj.get("items") {
+            Some(items @ Value::Object(_)) => self.field_type(items, &format!("{}Item", hint)),
+            _ => self.any(),
+        };
+        // proto 不支持嵌套的 repeated/map，使用 google.protobuf.Value
+        let item = if item.name.starts_with("repeated ") || item.name.starts_with("map<") { self.any() } else { item };
+
+        let mut constraints = Vec::new();
+        for (keyword, name) in [("minItems", "min_items"), ("maxItems", "max_items")] {
+            if let Some(value) = obj.get(keyword) {
+                constraints.push(format!("{}: {}", name, value));
+            }
+        }
+        if obj.get("uniqueItems") == Some(&Value::Bool(true)) {
+            constraints.push("unique: true".to_string());
+        }
+        if !item.rules.is_empty() {
+            constraints.push(format!("items: {}", nested(&item.rules)));
+        }
+        FieldType {
+            name: format!("repeated {}", item.name),
+            rules: if constraints.is_empty() {
+                Vec::new()
+            } else {
+                vec![("repeated".to_string(), format!("{{{}}}", constraints.join(", ")))]
+            },
+        }
+    }
+
+    fn any(&mut self) -> FieldType {
+        self.uses_struct = true;
+        FieldType {
+            name: "google.protobuf.Value".to_string(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// 把标量约束组合为 `(类型, {...})`
+fn scalar_rules(kind: &str, constraints: Vec<String>) -> Vec<(String, String)> {
+    if constraints.is_empty() {
+        Vec::new()
+    } else {
+        vec![(kind.to_string(), format!("{{{}}}", constraints.join(", ")))]
+    }
+}
+
+/// 元素或值约束，使用文本格式的 `{类型: {...}}`
+fn nested(rules: &[(String, String)]) -> String {
+    let fields: Vec<String> = rules.iter().map(|(kind, body)| format!("{}: {}", kind, body)).collect();
+    format!("{{{}}}", fields.join(", "))
+}
+
+fn string_rules(obj: &Map<String, Value>) -> Vec<String> {
+    let mut rules = Vec::new();
+    match (obj.get("minLength"), obj.get("maxLength")) {
+        (Some(a), Some(b)) if a == b => rules.push(format!("len: {}", a)),
+        (a, b) => {
+            if let Some(a) = a {
+                rules.push(format!("min_len: {}", a));
+            }
+            if let Some(b) = b {
+                rules.push(format!("max_len: {}", b));
+            }
+        }
+    }
+    if let Some(pattern) = obj.get("pattern").and_then(Value::as_str) {
+        rules.push(format!("pattern: {}", quote(pattern)));
+    }
+    match obj.get("format").and_then(Value::as_str) {
+        Some("email") => rules.push("email: true".to_string()),
+        Some("uri") => rules.push("uri: true".to_string()),
+        Some("uuid") => rules.push("uuid: true".to_string()),
+        Some("hostname") => rules.push("hostname: true".to_string()),
+        Some("ipv4") => rules.push("ipv4: true".to_string()),
+        Some("ipv6") => rules.push("ipv6: true".to_string()),
+        _ => {}
+    }
+    if let Some(Value::Array(values)) = obj.get("enum")
+        && values.iter().all(Value::is_string)
+    {
+        let values: Vec<String> = values.iter().map(Value::to_string).collect();
+        rules.push(format!("in: [{}]", values.join(", ")));
+    }
+    rules
+}
+
+fn number_rules(obj: &Map<String, Value>) -> Vec<String> {
+    let mut rules = Vec::new();
+    for (keyword, name) in [
+        ("minimum", "gte"),
+        ("exclusiveMinimum", "gt"),
+        ("maximum", "lte"),
+        ("exclusiveMaximum", "lt"),
+    ] {
+        if let Some(value) = obj.get(keyword).filter(|v| v.is_number()) {
+            rules.push(format!("{}: {}", name, value));
+        }
+    }
+    if let Some(Value::Array(values)) = obj.get("enum")
+        && values.iter().all(Value::is_number)
+    {
+        let values: Vec<String> = values.iter().map(Value::to_string).collect();
+        rules.push(format!("in: [{}]", values.join(", ")));
+    }
+    rules
+}
+
+/// proto 字段名（snake_case）
+fn field_name(key: &str) -> String {
+    let name = words(key).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_");
+    match name.chars().next() {
+        None => "field".to_string(),
+        Some(c) if !c.is_ascii_alphabetic() => format!("f_{}", name),
+        Some(_) => name,
+    }
+}
+
+/// proto3 为字段生成的默认 JSON 名称（lowerCamelCase）
+fn json_name(field: &str) -> String {
+    let mut name = String::new();
+    let mut upper = false;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            name.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+fn quote(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+}
@@ -0,0 +1,63 @@
+//! 敏感字段的错误脱敏
+//!
+//! 规则中的 `sensitive: true` 转换为 JSON Schema 的 `writeOnly: true` 注解。验证失败时，
+//! 敏感字段（及其子字段）上的错误不输出出错的值，错误信息中的值替换为占位符，避免密码、令牌进入日志。
+
+use serde_json::Value;
+
+use crate::error::ValidationError;
+use crate::keywords;
+
+/// 替换敏感值的占位符
+pub(crate) const MASK: &str = "[REDACTED]";
+
+/// schema 中是否声明了敏感字段
+pub(crate) fn has_sensitive(schema: &Value) -> bool {
+    let mut found = false;
+    keywords::visit_schemas(schema, &mut |obj| {
+        found |= is_sensitive(obj.get("writeOnly"));
+    });
+    found
+}
+
+/// 数据中敏感字段的路径（JSON Pointer）
+pub(crate) fn sensitive_paths(root: &Value, data: &Value) -> Vec<String> {
+    let mut paths = Vec::new();
+    keywords::walk_instance(root, data, &mut |obj, _, path| {
+        if is_sensitive(obj.get("writeOnly")) && !paths.iter().any(|p: &String| p == path) {
+            paths.push(path.to_string());
+        }
+    });
+    paths
+}
+
+/// 错误是否位于敏感字段上：`pointers` 为 JSON Pointer，`dotted` 为点分隔路径
+pub(crate) fn matches(error: &ValidationError, pointers: &[String], dotted: &[String]) -> bool {
+    let under = |path: &str, prefix: &str, separator: char| {
+        path == prefix || (path.starts_with(prefix) && path[prefix.len()..].starts_with(separator))
+    };
+    if pointers.iter().any(|p| under(&error.path, p, '/')) {
+        return true;
+    }
+    if dotted.is_empty() {
+        return false;
+    }
+    let path = error.dotted_path();
+    dotted.iter().any(|p| under(&path, p, '.'))
+}
+
+/// 隐藏错误中的出错值
+pub(crate) fn apply(error: &mut ValidationError, data: &Value) {
+    let instance = data.pointer(&error.path).or(error.value.as_ref());
+    if let Some(text) = instance.and_then(|v| serde_json::to_string(v).ok()) {
+        error.message = error.message.replace(&text, MASK);
+    }
+    if error.value.is_some() {
+        error.value = Some(Value::String(MASK.to_string()));
+    }
+    error.params.remove("actual");
+}
+
+fn is_sensitive(value: Option<&Value>) -> bool {
+    value == Some(&Value::Bool(true))
+}
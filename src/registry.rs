@@ -0,0 +1,162 @@
+//! 从文件和目录加载 schema
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::{is_async_rules, limits, CompileOptions, LinkValidator};
+
+/// 可以加载的 schema 文件扩展名
+fn is_schema_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("json" | "yaml" | "yml" | "toml")
+    )
+}
+
+/// 读取 schema 文件，按扩展名选择解析方式（其他扩展名按 JSON 解析）
+///
+/// 设置了 `max_schema_bytes` 时先检查文件大小，超出限制的文件不会被读取。
+fn read_schema_file(path: &Path, options: &CompileOptions) -> Result<Value, String> {
+    if options.max_schema_bytes.is_some() {
+        let metadata = fs::metadata(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        limits::check_bytes(options, usize::try_from(metadata.len()).unwrap_or(usize::MAX))
+            .map_err(|e| format!("Failed to load '{}': {}", path.display(), e))?;
+    }
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    let parsed = match path.extension().and_then(|e| e.to_str()) {
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => crate::yaml::from_str(&content),
+        #[cfg(not(feature = "yaml"))]
+        Some("yaml" | "yml") => Err("YAML support requires the `yaml` feature".to_string()),
+        #[cfg(feature = "toml")]
+        Some("toml") => crate::toml::from_str(&content),
+        #[cfg(not(feature = "toml"))]
+        Some("toml") => Err("TOML support requires the `toml` feature".to_string()),
+        _ => serde_json::from_str(&content).map_err(|e| e.to_string()),
+    };
+    parsed.map_err(|e| format!("Failed to parse '{}': {}", path.display(), e))
+}
+
+/// 加载 schema 文件，返回 `$id`（如果有）和编译后的验证器
+///
+/// async-validator 规则中的顶层 `$id` 只用于标识，编译前会被移除。
+pub(crate) fn load_file(path: &Path, options: &CompileOptions) -> Result<(Option<String>, LinkValidator), String> {
+    let mut schema = read_schema_file(path, options)?;
+    let id = schema.get("$id").and_then(Value::as_str).map(String::from);
+
+    if id.is_some()
+        && let Value::Object(obj) = &schema
+    {
+        let mut rules = obj.clone();
+        rules.remove("$id");
+        let rules = Value::Object(rules);
+        if is_async_rules(&rules) {
+            schema = rules;
+        }
+    }
+
+    let validator = LinkValidator::with_options(&schema, options).map_err(|e| format!("Failed to compile '{}': {}", path.display(), e))?;
+    Ok((id, validator))
+}
+
+/// 按名称管理多个验证器
+///
+/// # 示例
+///
+/// ```no_run
+/// use link_validator::SchemaRegistry;
+/// use serde_json::json;
+///
+/// let registry = SchemaRegistry::load_dir("schemas").unwrap();
+/// let validator = registry.get("user").expect("schemas/user.json");
+/// let result = validator.validate(&json!({"name": "john"}));
+/// ```
+#[derive(Debug, Default)]
+pub struct SchemaRegistry {
+    validators: BTreeMap<String, LinkValidator>,
+}
+
+impl SchemaRegistry {
+    /// 创建空的注册表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 加载目录下的全部 schema 文件（`.json`、`.yaml`/`.yml`、`.toml`，不递归子目录）
+    ///
+    /// 每个文件自动检测格式（JSON Schema 或 async-validator 规则）并编译，
+    /// 以顶层 `$id` 注册，没有 `$id` 时使用不含扩展名的文件名，注册名称同时作为验证器名称。
+    /// 任一文件读取、解析或编译失败，或名称重复时返回错误。
+    pub fn load_dir(path: impl AsRef<Path>) -> Result<SchemaRegistry, String> {
+        Self::load_dir_with_options(path, &CompileOptions::default())
+    }
+
+    /// 使用编译选项加载目录下的全部 schema 文件
+    ///
+    /// 编译选项作用于每个文件，其中的资源限制（`max_schema_bytes`、`max_fields`、`compile_budget`）
+    /// 逐个文件检查，任一文件超出限制时返回错误。
+    pub fn load_dir_with_options(path: impl AsRef<Path>, options: &CompileOptions) -> Result<SchemaRegistry, String> {
+        let path = path.as_ref();
+        let entries = fs::read_dir(path)
+            .map_err(|e| format!("Failed to read directory '{}': {}", path.display(), e))?;
+
+        let mut files = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| format!("Failed to read directory '{}': {}", path.display(), e))?;
+            let file = entry.path();
+            if file.is_file() && is_schema_file(&file) {
+                files.push(file);
+            }
+        }
+        // 按文件名排序，保证重复名称的报错稳定
+        files.sort();
+
+        let mut registry = SchemaRegistry::new();
+        for file in files {
+            let (id, validator) = load_file(&file, options)?;
+            let name = match id {
+                Some(id) => id,
+                None => file
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .ok_or_else(|| format!("Invalid file name '{}'", file.display()))?
+                    .to_string(),
+            };
+            if registry.validators.contains_key(&name) {
+                return Err(format!("Duplicate schema name '{}' ({})", name, file.display()));
+            }
+            let validator = validator.with_name(name.clone());
+            registry.insert(name, validator);
+        }
+        Ok(registry)
+    }
+
+    /// 注册验证器，同名验证器会被替换
+    pub fn insert(&mut self, name: impl Into<String>, validator: LinkValidator) {
+        self.validators.insert(name.into(), validator);
+    }
+
+    /// 按名称获取验证器
+    pub fn get(&self, name: &str) -> Option<&LinkValidator> {
+        self.validators.get(name)
+    }
+
+    /// 已注册的名称（按字典序）
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.validators.keys().map(String::as_str)
+    }
+
+    /// 已注册的验证器数量
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// 是否没有注册任何验证器
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+}
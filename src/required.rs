@@ -0,0 +1,70 @@
+//! 必填字段的空值语义
+//!
+//! JSON Schema 的 `required` 只检查键是否存在，async-validator 的 `required` 还会拒绝空字符串和空数组。
+
+use serde_json::{Map, Value};
+
+use crate::error::ValidationError;
+use crate::keywords;
+
+/// 为必填的字符串字段补充 `minLength: 1`、必填的数组字段补充 `minItems: 1`
+///
+/// 已有更严格的下限时保持不变。`strings` 为 `false` 时只处理数组字段（字符串由 `check_blank` 检查）。
+pub(crate) fn reject_empty(schema: &mut Value, strings: bool) {
+    keywords::visit_schemas_mut(schema, &mut |obj| {
+        let Some(Value::Array(required)) = obj.get("required") else {
+            return;
+        };
+        let required: Vec<String> = required.iter().filter_map(Value::as_str).map(str::to_string).collect();
+        let Some(Value::Object(properties)) = obj.get_mut("properties") else {
+            return;
+        };
+        for name in required {
+            if let Some(Value::Object(property)) = properties.get_mut(&name) {
+                if strings && has_type(property, "string") {
+                    raise_minimum(property, "minLength");
+                }
+                if has_type(property, "array") {
+                    raise_minimum(property, "minItems");
+                }
+            }
+        }
+    });
+}
+
+/// `type` 是否为（或包含）指定类型
+fn has_type(schema: &Map<String, Value>, name: &str) -> bool {
+    match schema.get("type") {
+        Some(Value::String(ty)) => ty == name,
+        Some(Value::Array(types)) => types.iter().any(|ty| ty == name),
+        _ => false,
+    }
+}
+
+fn raise_minimum(schema: &mut Map<String, Value>, keyword: &str) {
+    if schema.get(keyword).and_then(Value::as_u64).unwrap_or(0) < 1 {
+        schema.insert(keyword.to_string(), Value::from(1));
+    }
+}
+
+/// 在 schema 验证之后检查必填字段：去除首尾空白后为空的字符串按缺少字段报告（错误码 `required`）
+pub(crate) fn check_blank(root: &Value, data: &Value) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    keywords::walk_instance(root, data, &mut |obj, value, path| {
+        let (Some(Value::Array(required)), Value::Object(fields)) = (obj.get("required"), value) else {
+            return;
+        };
+        for name in required.iter().filter_map(Value::as_str) {
+            if let Some(Value::String(field)) = fields.get(name)
+                && field.trim().is_empty()
+            {
+                let field_path = format!("{}/{}", path, name.replace('~', "~0").replace('/', "~1"));
+                errors.push(ValidationError::new(field_path, format!("\"{}\" is a required property", name))
+                    .with_code("required")
+                    .with_value(field.as_str())
+                    .with_param("property", name));
+            }
+        }
+    });
+    errors
+}
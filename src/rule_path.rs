@@ -0,0 +1,105 @@
+//! 错误对应的 async-validator 规则位置
+//!
+//! 由 JSON Schema 关键字位置（或数据路径）和错误码推出规则中的位置，例如 `user.fields.name.min`，
+//! 便于规则作者从错误直接定位到出错的规则。
+
+use jsonschema::paths::{JSONPointer, PathChunk};
+use serde_json::{Map, Value};
+
+/// 由 JSON Schema 验证错误的关键字位置生成规则位置
+pub(crate) fn from_schema_path(schema_path: &JSONPointer, code: &str, params: &Map<String, Value>) -> String {
+    let mut path = Vec::new();
+    let mut chunks = schema_path.iter().peekable();
+    while let Some(chunk) = chunks.next() {
+        let PathChunk::Keyword(keyword) = chunk else {
+            continue;
+        };
+        match *keyword {
+            "properties" => {
+                if let Some(PathChunk::Property(name)) = chunks.peek() {
+                    push_field(&mut path, name);
+                    chunks.next();
+                }
+            }
+            "additionalProperties" => path.push("valueRule".to_string()),
+            "propertyNames" => path.push("keyPattern".to_string()),
+            "contains" => path.push("contains".to_string()),
+            // 分支下标、定义名称等不对应规则中的位置
+            _ => {}
+        }
+    }
+    if code == "required"
+        && let Some(Value::String(property)) = params.get("property")
+    {
+        push_field(&mut path, property);
+    }
+    finish(path, code)
+}
+
+/// 由数据路径（JSON Pointer）生成规则位置，用于 schema 验证之后单独检查的约束
+pub(crate) fn from_instance_path(instance_path: &str, code: &str) -> String {
+    let mut path = Vec::new();
+    for segment in instance_path.split('/').skip(1) {
+        // 数组元素的规则就是数组字段的 fields
+        if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+            continue;
+        }
+        push_field(&mut path, &segment.replace("~1", "/").replace("~0", "~"));
+    }
+    finish(path, code)
+}
+
+/// 把子树验证得到的规则位置接到子树的数据路径之后
+pub(crate) fn nest(pointer: &str, rule_path: &str, code: &str) -> String {
+    let parent = from_instance_path(pointer, "");
+    if parent.is_empty() {
+        return rule_path.to_string();
+    }
+    if rule_path.is_empty() {
+        return parent;
+    }
+    // 子树本身的规则键（如 `type`）和 `valueRule` 等直接接在字段之后，其余为子字段
+    let first = rule_path.split('.').next().unwrap_or_default();
+    if rule_key(code) == Some(rule_path) || matches!(first, "valueRule" | "keyPattern" | "contains") {
+        format!("{}.{}", parent, rule_path)
+    } else {
+        format!("{}.fields.{}", parent, rule_path)
+    }
+}
+
+fn push_field(path: &mut Vec<String>, name: &str) {
+    if !path.is_empty() {
+        path.push("fields".to_string());
+    }
+    path.push(name.to_string());
+}
+
+fn finish(mut path: Vec<String>, code: &str) -> String {
+    if let Some(key) = rule_key(code) {
+        path.push(key.to_string());
+    }
+    path.join(".")
+}
+
+/// 错误码对应的规则键，联合类型等没有单独规则键的错误返回 `None`
+fn rule_key(code: &str) -> Option<&'static str> {
+    let key = match code {
+        "required" => "required",
+        "type.mismatch" => "type",
+        "enum.mismatch" => "enum",
+        "const.mismatch" => "const",
+        "pattern.mismatch" | "pattern.timeout" => "pattern",
+        "format.date" => "format",
+        "string.min" | "number.min" | "array.min" | "object.min" => "min",
+        "string.max" | "number.max" | "array.max" | "object.max" => "max",
+        "number.exclusiveMin" => "exclusiveMin",
+        "number.exclusiveMax" => "exclusiveMax",
+        "number.multipleOf" => "multipleOf",
+        "array.unique" => "unique",
+        "date.min" => "minDate",
+        "date.max" => "maxDate",
+        _ if code.starts_with("format.") => "type",
+        _ => return None,
+    };
+    Some(key)
+}
@@ -0,0 +1,493 @@
+//! 根据 schema 生成示例数据
+
+use regex_syntax::hir::{Class, Hir, HirKind};
+use serde_json::{json, Map, Value};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::dates;
+use crate::form;
+use crate::keywords;
+
+/// 嵌套超过 `max_depth` 后仍继续展开必填字段的最大层数，防止必填的递归引用无限展开
+const HARD_DEPTH_LIMIT: usize = 32;
+
+/// `generate_sample` 生成不合法数据时的最大重试次数
+pub(crate) const MAX_ATTEMPTS: usize = 16;
+
+/// 未限制重复次数时（`*`、`+`）最多额外重复的次数
+const MAX_EXTRA_REPEAT: u32 = 3;
+
+/// `LinkValidator::generate_sample` 的生成选项
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{LinkValidator, SampleOptions};
+/// use serde_json::json;
+///
+/// let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "min": 2}})).unwrap();
+/// let options = SampleOptions::new().seed(7);
+///
+/// // 相同种子生成相同的数据
+/// assert_eq!(validator.generate_sample(&options), validator.generate_sample(&options));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SampleOptions {
+    seed: Option<u64>,
+    optional_fields: bool,
+    max_depth: usize,
+}
+
+impl Default for SampleOptions {
+    fn default() -> Self {
+        SampleOptions {
+            seed: None,
+            optional_fields: true,
+            max_depth: 4,
+        }
+    }
+}
+
+impl SampleOptions {
+    /// 创建默认生成选项（随机种子、包含可选字段、最大嵌套 4 层）
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 随机种子，设置后每次生成相同的数据
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// 是否生成非必填字段，默认生成
+    pub fn optional_fields(mut self, enabled: bool) -> Self {
+        self.optional_fields = enabled;
+        self
+    }
+
+    /// 最大嵌套层数，超过后只生成必填字段和最少数量的数组元素
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// 选项对应的随机数生成器
+    pub(crate) fn rng(&self) -> Rng {
+        let seed = self.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        Rng(seed)
+    }
+}
+
+/// SplitMix64 伪随机数生成器，保证相同种子生成相同序列
+pub(crate) struct Rng(u64);
+
+impl Rng {
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `0..n` 范围内的随机数，`n` 为 0 时返回 0
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        if n == 0 { 0 } else { self.next_u64() % n }
+    }
+
+    /// `lo..=hi` 范围内的随机整数
+    fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        if hi <= lo {
+            return lo;
+        }
+        let span = (hi as i128 - lo as i128 + 1).min(u64::MAX as i128) as u64;
+        (lo as i128 + self.below(span) as i128) as i64
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.get(self.below(items.len() as u64) as usize)
+    }
+
+    fn word(&mut self, len: usize) -> String {
+        (0..len).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
+
+/// 根据 schema 生成一份数据
+pub(crate) struct Generator<'a> {
+    root: &'a Value,
+    options: &'a SampleOptions,
+    pub(crate) rng: Rng,
+}
+
+impl<'a> Generator<'a> {
+    pub(crate) fn new(root: &'a Value, options: &'a SampleOptions) -> Self {
+        Generator {
+            root,
+            options,
+            rng: options.rng(),
+        }
+    }
+
+    pub(crate) fn generate(&mut self) -> Value {
+        self.value(self.root, 0)
+    }
+
+    fn value(&mut self, schema: &Value, depth: usize) -> Value {
+        let Some(obj) = schema.as_object() else {
+            return match schema {
+                Value::Bool(false) => Value::Null,
+                _ => json!(self.rng.word(6)),
+            };
+        };
+        if depth > self.options.max_depth + HARD_DEPTH_LIMIT {
+            return Value::Null;
+        }
+
+        if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+            let target = reference.strip_prefix('#').and_then(|p| self.root.pointer(p));
+            return match target {
+                Some(target) => self.value(target, depth + 1),
+                None => Value::Null,
+            };
+        }
+        if let Some(value) = obj.get("const") {
+            return value.clone();
+        }
+        if let Some(Value::Array(values)) = obj.get("enum").or_else(|| obj.get(form::ENUM)) {
+            return self.rng.pick(values).cloned().unwrap_or(Value::Null);
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(subs)) = obj.get(keyword)
+                && let Some(sub) = self.rng.pick(subs)
+            {
+                return self.value(sub, depth);
+            }
+        }
+
+        let types: Vec<&str> = match obj.get("type") {
+            Some(Value::String(t)) => vec![t.as_str()],
+            Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => vec![infer_type(obj)],
+        };
+        let non_null: Vec<&str> = types.iter().copied().filter(|t| *t != "null").collect();
+        let kind = match self.rng.pick(&non_null) {
+            Some(kind) => *kind,
+            None if types.contains(&"null") => return Value::Null,
+            None => "string",
+        };
+
+        let mut value = match kind {
+            "string" => json!(self.string(obj)),
+            "integer" => self.integer(obj),
+            "number" => self.number(obj),
+            "boolean" => json!(self.rng.below(2) == 1),
+            "array" => self.array(obj, depth),
+            "object" => self.object(obj, depth),
+            _ => Value::Null,
+        };
+        if let Some(Value::Array(subs)) = obj.get("allOf")
+            && keywords::discriminated_branches(obj).is_none()
+        {
+            for sub in subs {
+                if let (Value::Object(target), Value::Object(extra)) = (&mut value, self.value(sub, depth)) {
+                    target.extend(extra);
+                }
+            }
+        }
+        value
+    }
+
+    pub(crate) fn string(&mut self, obj: &Map<String, Value>) -> String {
+        let min = obj.get("minLength").and_then(Value::as_u64).unwrap_or(0) as usize;
+        let max = obj.get("maxLength").and_then(Value::as_u64).map(|m| m as usize);
+        if let Some(format) = obj.get("format").and_then(Value::as_str) {
+            let (min_date, max_date) = dates::bounds(obj);
+            if matches!(format, "date" | "date-time") && (min_date.is_some() || max_date.is_some()) {
+                return self.date_between(min_date, max_date, format == "date");
+            }
+            if let Some(value) = self.format(format) {
+                return value;
+            }
+        }
+        if let Some(pattern) = obj.get("pattern").and_then(Value::as_str)
+            && let Ok(hir) = regex_syntax::parse(pattern)
+        {
+            // 多次尝试生成满足长度约束的字符串
+            let mut value = String::new();
+            for _ in 0..16 {
+                value.clear();
+                self.regex(&hir, &mut value);
+                let len = value.chars().count();
+                if len >= min && max.is_none_or(|max| len <= max) {
+                    break;
+                }
+            }
+            return value;
+        }
+
+        let max = max.unwrap_or(min + 10).max(min);
+        let lo = min.max(3.min(max));
+        let len = self.rng.between(lo as i64, max.min(lo + 8) as i64) as usize;
+        self.rng.word(len)
+    }
+
+    /// 日期范围内的日期或日期时间，只设置一侧边界时在一年范围内生成
+    fn date_between(&mut self, min: Option<i128>, max: Option<i128>, date_only: bool) -> String {
+        const SECOND: i128 = 1_000_000_000;
+        const YEAR: i128 = 365 * 86_400 * SECOND;
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, min + YEAR),
+            (None, Some(max)) => (max - YEAR, max),
+            (None, None) => (0, YEAR),
+        };
+        let unit = if date_only { 86_400 * SECOND } else { SECOND };
+        let (first, last) = ((min + unit - 1).div_euclid(unit), max.div_euclid(unit));
+        let picked = self.rng.between(first as i64, last as i64) as i128;
+        dates::format(picked * unit, date_only)
+    }
+
+    fn format(&mut self, format: &str) -> Option<String> {
+        let word = self.rng.word(6);
+        let (month, day) = (self.rng.between(1, 12), self.rng.between(1, 28));
+        let (hour, minute, second) = (self.rng.between(0, 23), self.rng.between(0, 59), self.rng.between(0, 59));
+        Some(match format {
+            "email" => format!("{}@example.com", word),
+            "uri" | "url" | "iri" => format!("https://example.com/{}", word),
+            "hostname" | "idn-hostname" => format!("{}.example.com", word),
+            "uuid" => {
+                let hex: String = (0..32).map(|_| format!("{:x}", self.rng.below(16))).collect();
+                format!("{}-{}-4{}-8{}-{}", &hex[..8], &hex[8..12], &hex[13..16], &hex[17..20], &hex[20..])
+            }
+            "date-time" => format!("2024-{:02}-{:02}T{:02}:{:02}:{:02}Z", month, day, hour, minute, second),
+            "date" => format!("2024-{:02}-{:02}", month, day),
+            "time" => format!("{:02}:{:02}:{:02}Z", hour, minute, second),
+            "ipv4" => format!("192.168.{}.{}", self.rng.between(0, 255), self.rng.between(1, 254)),
+            "ipv6" => format!("2001:db8::{:x}", self.rng.between(1, 0xffff)),
+            _ => return None,
+        })
+    }
+
+    /// 生成与正则表达式匹配的字符串，字符类优先选择可打印 ASCII 字符
+    fn regex(&mut self, hir: &Hir, out: &mut String) {
+        match hir.kind() {
+            HirKind::Empty | HirKind::Look(_) => {}
+            HirKind::Literal(literal) => out.push_str(&String::from_utf8_lossy(&literal.0)),
+            HirKind::Class(Class::Unicode(class)) => {
+                let ranges: Vec<(u32, u32)> = class.ranges().iter().map(|r| (r.start() as u32, r.end() as u32)).collect();
+                if let Some(c) = self.class_char(&ranges) {
+                    out.push(c);
+                }
+            }
+            HirKind::Class(Class::Bytes(class)) => {
+                let ranges: Vec<(u32, u32)> = class.ranges().iter().map(|r| (r.start() as u32, r.end() as u32)).collect();
+                if let Some(c) = self.class_char(&ranges) {
+                    out.push(c);
+                }
+            }
+            HirKind::Repetition(repetition) => {
+                let max = repetition.max.unwrap_or(repetition.min + MAX_EXTRA_REPEAT);
+                let max = max.min(repetition.min + MAX_EXTRA_REPEAT);
+                let count = self.rng.between(repetition.min as i64, max as i64);
+                for _ in 0..count {
+                    self.regex(&repetition.sub, out);
+                }
+            }
+            HirKind::Capture(capture) => self.regex(&capture.sub, out),
+            HirKind::Concat(subs) => subs.iter().for_each(|sub| self.regex(sub, out)),
+            HirKind::Alternation(subs) => {
+                if let Some(sub) = self.rng.pick(subs) {
+                    self.regex(sub, out);
+                }
+            }
+        }
+    }
+
+    fn class_char(&mut self, ranges: &[(u32, u32)]) -> Option<char> {
+        let printable: Vec<(u32, u32)> = ranges
+            .iter()
+            .filter_map(|&(start, end)| {
+                let (start, end) = (start.max(0x21), end.min(0x7e));
+                (start <= end).then_some((start, end))
+            })
+            .collect();
+        let ranges = if printable.is_empty() { ranges } else { &printable };
+        let &(start, end) = self.rng.pick(ranges)?;
+        char::from_u32(self.rng.between(start as i64, end as i64) as u32).or_else(|| char::from_u32(start))
+    }
+
+    fn integer(&mut self, obj: &Map<String, Value>) -> Value {
+        let lower = [
+            obj.get("minimum").and_then(Value::as_f64).map(f64::ceil),
+            obj.get("exclusiveMinimum").and_then(Value::as_f64).map(|v| v.floor() + 1.0),
+        ];
+        let upper = [
+            obj.get("maximum").and_then(Value::as_f64).map(f64::floor),
+            obj.get("exclusiveMaximum").and_then(Value::as_f64).map(|v| v.ceil() - 1.0),
+        ];
+        let lo = lower.into_iter().flatten().reduce(f64::max).map(|v| v as i64);
+        let hi = upper.into_iter().flatten().reduce(f64::min).map(|v| v as i64);
+        let (lo, hi) = default_range(lo, hi);
+
+        match obj.get("multipleOf").and_then(Value::as_f64).filter(|m| *m > 0.0) {
+            Some(step) => {
+                let (first, last) = ((lo as f64 / step).ceil() as i64, (hi as f64 / step).floor() as i64);
+                json!((self.rng.between(first, last) as f64 * step) as i64)
+            }
+            None => json!(self.rng.between(lo, hi)),
+        }
+    }
+
+    fn number(&mut self, obj: &Map<String, Value>) -> Value {
+        let minimum = obj.get("minimum").and_then(Value::as_f64);
+        let exclusive_minimum = obj.get("exclusiveMinimum").and_then(Value::as_f64);
+        let maximum = obj.get("maximum").and_then(Value::as_f64);
+        let exclusive_maximum = obj.get("exclusiveMaximum").and_then(Value::as_f64);
+        let lo = [minimum, exclusive_minimum].into_iter().flatten().reduce(f64::max);
+        let hi = [maximum, exclusive_maximum].into_iter().flatten().reduce(f64::min);
+        let (lo, hi) = match (lo, hi) {
+            (Some(lo), Some(hi)) => (lo, hi),
+            (Some(lo), None) => (lo, lo + 100.0),
+            (None, Some(hi)) => (if hi >= 0.0 { 0.0 } else { hi - 100.0 }, hi),
+            (None, None) => (0.0, 100.0),
+        };
+        let inside = |v: f64| {
+            v >= lo && v <= hi && exclusive_minimum.is_none_or(|m| v > m) && exclusive_maximum.is_none_or(|m| v < m)
+        };
+
+        if let Some(step) = obj.get("multipleOf").and_then(Value::as_f64).filter(|m| *m > 0.0) {
+            let (first, last) = ((lo / step).ceil() as i64, (hi / step).floor() as i64);
+            let value = self.rng.between(first, last) as f64 * step;
+            let value = if inside(value) { value } else { (first + 1) as f64 * step };
+            return json!(value);
+        }
+        let fraction = (self.rng.below(98) + 1) as f64 / 100.0;
+        let value = ((lo + (hi - lo) * fraction) * 100.0).round() / 100.0;
+        json!(if inside(value) { value } else { (lo + hi) / 2.0 })
+    }
+
+    fn array(&mut self, obj: &Map<String, Value>, depth: usize) -> Value {
+        let min = obj.get("minItems").and_then(Value::as_u64).unwrap_or(0) as i64;
+        let max = obj.get("maxItems").and_then(Value::as_u64).map(|m| m as i64);
+        let unique = obj.get("uniqueItems") == Some(&Value::Bool(true));
+
+        if let Some(Value::Array(tuple)) = obj.get("items") {
+            return Value::Array(tuple.iter().map(|item| self.value(item, depth + 1)).collect());
+        }
+        let count = if depth >= self.options.max_depth {
+            min
+        } else {
+            let max = max.unwrap_or(min + 3).max(min);
+            self.rng.between(min.max(1.min(max)), max.min(min + 3))
+        };
+        let item_schema = obj.get("items").cloned().unwrap_or(json!({}));
+        let mut items: Vec<Value> = Vec::new();
+        let mut attempts = 0;
+        while (items.len() as i64) < count && attempts < count * 8 {
+            attempts += 1;
+            let item = self.value(&item_schema, depth + 1);
+            if unique && items.contains(&item) {
+                continue;
+            }
+            items.push(item);
+        }
+        // 保证至少有一个元素满足 contains
+        if let Some(contains) = obj.get("contains") {
+            let item = self.value(contains, depth + 1);
+            if unique {
+                items.retain(|i| i != &item);
+            }
+            if max.is_some_and(|max| items.len() as i64 >= max) {
+                items.pop();
+            }
+            let index = self.rng.below(items.len() as u64 + 1) as usize;
+            items.insert(index, item);
+        }
+        Value::Array(items)
+    }
+
+    fn object(&mut self, obj: &Map<String, Value>, depth: usize) -> Value {
+        let required: Vec<&str> = obj
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let include_optional = self.options.optional_fields && depth < self.options.max_depth;
+        let mut result = Map::new();
+
+        if let Some(Value::Object(properties)) = obj.get("properties") {
+            for (key, property) in properties {
+                if include_optional || required.contains(&key.as_str()) {
+                    result.insert(key.clone(), self.value(property, depth + 1));
+                }
+            }
+        } else if let Some(value @ Value::Object(_)) = obj.get("additionalProperties")
+            && include_optional
+        {
+            let min = obj.get("minProperties").and_then(Value::as_i64).unwrap_or(1);
+            let max = obj.get("maxProperties").and_then(Value::as_i64).unwrap_or(min.max(2)).max(min);
+            let count = self.rng.between(min, max.min(min + 2));
+            let mut index = 0;
+            // 键名可能重复，限制尝试次数
+            while (result.len() as i64) < count && index < count * 8 {
+                index += 1;
+                let key = match obj.get("propertyNames").and_then(Value::as_object) {
+                    Some(names) => self.string(names),
+                    None => format!("key{}", index),
+                };
+                let value = self.value(value, depth + 1);
+                result.insert(key, value);
+            }
+        }
+
+        // `itemsBy` 分支：按判别值选择分支并生成分支字段
+        if let Some((field, variants)) = keywords::discriminated_branches(obj) {
+            let index = self.rng.below(variants.len() as u64) as usize;
+            if let Some((tag, variant)) = variants.get(index) {
+                result.insert(field, tag.clone());
+                if let Value::Object(extra) = self.object_value(variant, depth) {
+                    result.extend(extra);
+                }
+            }
+        }
+        Value::Object(result)
+    }
+
+    fn object_value(&mut self, schema: &Value, depth: usize) -> Value {
+        match schema.as_object() {
+            Some(obj) => self.object(obj, depth),
+            None => Value::Object(Map::new()),
+        }
+    }
+}
+
+/// 未声明类型时根据约束关键字推断类型
+pub(crate) fn infer_type(obj: &Map<String, Value>) -> &'static str {
+    let has = |keys: &[&str]| keys.iter().any(|k| obj.contains_key(*k));
+    if has(&["properties", "additionalProperties", "required", "patternProperties"]) {
+        "object"
+    } else if has(&["items", "minItems", "maxItems", "uniqueItems"]) {
+        "array"
+    } else if has(&["minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum", "multipleOf"]) {
+        "number"
+    } else {
+        "string"
+    }
+}
+
+/// 只设置了一侧边界时补全整数范围
+pub(crate) fn default_range(lo: Option<i64>, hi: Option<i64>) -> (i64, i64) {
+    match (lo, hi) {
+        (Some(lo), Some(hi)) => (lo, hi),
+        (Some(lo), None) => (lo, lo.saturating_add(100)),
+        (None, Some(hi)) => (if hi >= 0 { 0 } else { hi.saturating_sub(100) }, hi),
+        (None, None) => (0, 100),
+    }
+}
@@ -0,0 +1,204 @@
+//! 按名称编译和使用多个 schema
+
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+use crate::{convert_rules, is_async_rules, split_definitions, CompileOptions, LinkValidator, ValidationResult};
+
+/// 按名称注册、统一编译多个 schema 的验证器集合
+///
+/// 通过 `define` 声明的定义在集合内共享：async-validator 规则中可以直接用 `$use` 引用，
+/// JSON Schema 中可以用 `{"$ref": "#/$defs/名称"}` 引用。`add` 只记录 schema，
+/// `compile` 统一编译尚未编译的 schema。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::ValidatorSet;
+/// use serde_json::json;
+///
+/// let mut set = ValidatorSet::new();
+/// set.define("address", json!({"city": {"type": "string", "required": true}}));
+/// set.add("user", json!({
+///     "name": {"type": "string", "required": true},
+///     "address": {"type": "object", "$use": "address"}
+/// }));
+/// set.add("shop", json!({"address": {"type": "object", "required": true, "$use": "address"}}));
+/// set.compile().unwrap();
+///
+/// assert!(set.validate("user", &json!({"name": "john", "address": {"city": "Paris"}})).unwrap().is_valid);
+/// assert!(!set.validate("shop", &json!({"address": {}})).unwrap().is_valid);
+/// assert!(set.validate("order", &json!({})).is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct ValidatorSet {
+    options: CompileOptions,
+    /// 共享的定义：名称 -> 字段规则
+    defs: Map<String, Value>,
+    /// 尚未编译的 schema
+    pending: BTreeMap<String, Value>,
+    validators: BTreeMap<String, LinkValidator>,
+}
+
+impl ValidatorSet {
+    /// 创建空的验证器集合
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建使用指定编译选项的验证器集合
+    pub fn with_options(options: &CompileOptions) -> Self {
+        ValidatorSet {
+            options: options.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// 声明共享的定义（字段规则映射，与规则根节点 `defs` 中的定义相同）
+    ///
+    /// 之后编译的 schema 都可以引用该定义；schema 自身 `defs` 中的同名定义优先。
+    pub fn define(&mut self, name: impl Into<String>, fields: Value) -> &mut Self {
+        self.defs.insert(name.into(), fields);
+        self
+    }
+
+    /// 添加 schema（JSON Schema 或 async-validator 规则），在 `compile` 时编译
+    ///
+    /// 同名 schema 会被替换，已编译的验证器在重新编译前仍可使用。
+    pub fn add(&mut self, name: impl Into<String>, schema: Value) -> &mut Self {
+        self.pending.insert(name.into(), schema);
+        self
+    }
+
+    /// 编译全部尚未编译的 schema，验证器名称为注册名称
+    ///
+    /// 任一 schema 编译失败时返回错误，失败的和尚未编译的 schema 保留到下次编译。
+    pub fn compile(&mut self) -> Result<(), String> {
+        let mut shared_schemas: Option<Map<String, Value>> = None;
+        while let Some((name, schema)) = self.pending.pop_first() {
+            let compiled = if is_async_rules(&schema) {
+                compile_rules(&self.options, &self.defs, &name, &schema)
+            } else {
+                let shared = match &shared_schemas {
+                    Some(shared) => shared,
+                    None => shared_schemas.insert(shared_schemas_of(&self.defs)?),
+                };
+                compile_schema(&self.options, shared, &name, &schema)
+            };
+            match compiled {
+                Ok(validator) => {
+                    self.validators.insert(name, validator);
+                }
+                Err(e) => {
+                    self.pending.insert(name, schema);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 使用指定名称的验证器验证数据，名称不存在或尚未编译时返回错误
+    pub fn validate(&self, name: &str, data: &Value) -> Result<ValidationResult, String> {
+        match self.validators.get(name) {
+            Some(validator) => Ok(validator.validate(data)),
+            None if self.pending.contains_key(name) => Err(format!("Schema '{}' is not compiled", name)),
+            None => Err(format!("Unknown schema '{}'", name)),
+        }
+    }
+
+    /// 按名称获取已编译的验证器
+    pub fn get(&self, name: &str) -> Option<&LinkValidator> {
+        self.validators.get(name)
+    }
+
+    /// 已编译的名称（按字典序）
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.validators.keys().map(String::as_str)
+    }
+
+    /// 已编译的验证器数量
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    /// 是否没有已编译的验证器
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+}
+
+/// 编译使用共享定义的 async-validator 规则，验证器名称为注册名称
+pub(crate) fn compile_rules(
+    options: &CompileOptions,
+    defs: &Map<String, Value>,
+    name: &str,
+    rules: &Value,
+) -> Result<LinkValidator, String> {
+    LinkValidator::with_options(&with_shared_rules(defs, rules), options)
+        .map(|validator| validator.with_name(name))
+        .map_err(|e| format!("Failed to compile '{}': {}", name, e))
+}
+
+/// 编译使用共享定义（已转换为 JSON Schema）的 JSON Schema，验证器名称为注册名称
+pub(crate) fn compile_schema(
+    options: &CompileOptions,
+    shared: &Map<String, Value>,
+    name: &str,
+    schema: &Value,
+) -> Result<LinkValidator, String> {
+    LinkValidator::with_options(&with_shared_schemas(schema, shared), options)
+        .map(|validator| validator.with_name(name))
+        .map_err(|e| format!("Failed to compile '{}': {}", name, e))
+}
+
+/// 在 async-validator 规则的 `defs` 中补充共享定义
+fn with_shared_rules(shared: &Map<String, Value>, rules: &Value) -> Value {
+    if shared.is_empty() {
+        return rules.clone();
+    }
+    let (mut defs, rest) = match split_definitions(rules) {
+        Some((defs, rest)) => (defs.clone(), rest),
+        None => (Map::new(), rules.clone()),
+    };
+    for (name, fields) in shared {
+        defs.entry(name.clone()).or_insert_with(|| fields.clone());
+    }
+    let mut rules = rest;
+    if let Value::Object(obj) = &mut rules {
+        obj.insert("defs".to_string(), Value::Object(defs));
+    }
+    rules
+}
+
+/// 共享定义转换后的 JSON Schema
+pub(crate) fn shared_schemas_of(defs: &Map<String, Value>) -> Result<Map<String, Value>, String> {
+    let mut schemas = Map::new();
+    for (name, fields) in defs {
+        let rules = with_shared_rules(defs, fields);
+        let mut conversion =
+            convert_rules(&rules).map_err(|e| format!("Failed to convert definition '{}': {}", name, e))?;
+        // 定义之间的引用指向根节点的 $defs
+        if let Value::Object(obj) = &mut conversion.schema {
+            obj.remove("$defs");
+        }
+        schemas.insert(name.clone(), conversion.schema);
+    }
+    Ok(schemas)
+}
+
+/// 在 JSON Schema 的 `$defs` 中补充共享定义
+fn with_shared_schemas(schema: &Value, shared: &Map<String, Value>) -> Value {
+    let mut schema = schema.clone();
+    if let Value::Object(obj) = &mut schema
+        && !shared.is_empty()
+    {
+        let defs = obj.entry("$defs").or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(defs) = defs {
+            for (name, definition) in shared {
+                defs.entry(name.clone()).or_insert_with(|| definition.clone());
+            }
+        }
+    }
+    schema
+}
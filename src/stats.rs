@@ -0,0 +1,62 @@
+//! 编译和验证耗时统计
+
+use serde_json::Value;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::keywords;
+
+/// 尚未执行过验证
+const NO_VALIDATION: u64 = u64::MAX;
+
+/// 验证器的性能概况，用于判断规则转换、编译和验证中哪一步是瓶颈
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValidatorStats {
+    /// async-validator 规则转换为 JSON Schema 的耗时（JSON Schema 输入为 0）
+    pub conversion_duration: Duration,
+    /// JSON Schema 编译的耗时
+    pub compile_duration: Duration,
+    /// 编译使用的 JSON Schema 中 schema 对象（根节点及全部子 schema）的数量
+    pub schema_nodes: usize,
+    /// 最近一次验证的耗时（不含验证前的数据规范化），尚未验证时为 `None`
+    pub last_validation: Option<Duration>,
+}
+
+/// 验证器内部的统计记录，副本之间共享最近一次验证的耗时
+#[derive(Debug, Clone)]
+pub(crate) struct Stats {
+    conversion: Duration,
+    compile: Duration,
+    nodes: usize,
+    last_validation_nanos: Arc<AtomicU64>,
+}
+
+impl Stats {
+    pub(crate) fn new(conversion: Duration, compile: Duration, schema: &Value) -> Self {
+        let mut nodes = 0;
+        keywords::visit_schemas(schema, &mut |_| nodes += 1);
+        Stats {
+            conversion,
+            compile,
+            nodes,
+            last_validation_nanos: Arc::new(AtomicU64::new(NO_VALIDATION)),
+        }
+    }
+
+    /// 记录一次验证的耗时
+    pub(crate) fn record_validation(&self, elapsed: Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(NO_VALIDATION - 1);
+        self.last_validation_nanos.store(nanos, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ValidatorStats {
+        let nanos = self.last_validation_nanos.load(Ordering::Relaxed);
+        ValidatorStats {
+            conversion_duration: self.conversion,
+            compile_duration: self.compile,
+            schema_nodes: self.nodes,
+            last_validation: (nanos != NO_VALIDATION).then(|| Duration::from_nanos(nanos)),
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! 根据 schema 构造 proptest 策略（`proptest` feature）
+//!
+//! 与 `sample` 覆盖相同的关键字，但生成的是可收缩（shrink）的策略：数值在范围内收缩，
+//! 字符串和数组向最短长度收缩，非必填字段收缩为缺省。`pattern` 使用 proptest 的正则生成器。
+
+use proptest::collection::{btree_map, vec};
+use proptest::option;
+use proptest::prelude::*;
+use proptest::string::{string_regex, string_regex_parsed};
+use proptest::strategy::Union;
+use regex_syntax::hir::{Capture, Class, ClassBytes, ClassBytesRange, ClassUnicode, ClassUnicodeRange, Hir, HirKind, Repetition};
+use serde_json::{json, Map, Value};
+
+use crate::dates;
+use crate::keywords;
+use crate::sample::{default_range, infer_type};
+
+/// 超过该层数后只生成必填字段和最少数量的数组元素
+const MAX_DEPTH: usize = 4;
+
+/// 嵌套超过 `MAX_DEPTH` 后仍继续展开必填字段的最大层数，防止必填的递归引用无限展开
+const HARD_DEPTH_LIMIT: usize = 32;
+
+/// 未限制长度或数量时最多额外生成的字符、元素个数
+const MAX_EXTRA: usize = 8;
+
+/// 整个 schema 的策略
+pub(crate) fn schema(root: &Value) -> BoxedStrategy<Value> {
+    Builder { root }.value(root, 0)
+}
+
+struct Builder<'a> {
+    root: &'a Value,
+}
+
+impl Builder<'_> {
+    fn value(&self, schema: &Value, depth: usize) -> BoxedStrategy<Value> {
+        let Some(obj) = schema.as_object() else {
+            return match schema {
+                Value::Bool(false) => Just(Value::Null).boxed(),
+                _ => words(0, MAX_EXTRA).prop_map(Value::String).boxed(),
+            };
+        };
+        if depth > MAX_DEPTH + HARD_DEPTH_LIMIT {
+            return Just(Value::Null).boxed();
+        }
+
+        if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+            return match reference.strip_prefix('#').and_then(|p| self.root.pointer(p)) {
+                Some(target) => self.value(target, depth + 1),
+                None => Just(Value::Null).boxed(),
+            };
+        }
+        if let Some(value) = obj.get("const") {
+            return Just(value.clone()).boxed();
+        }
+        if let Some(Value::Array(values)) = obj.get("enum")
+            && !values.is_empty()
+        {
+            return proptest::sample::select(values.clone()).boxed();
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(subs)) = obj.get(keyword)
+                && !subs.is_empty()
+            {
+                return Union::new(subs.iter().map(|sub| self.value(sub, depth))).boxed();
+            }
+        }
+
+        let types: Vec<&str> = match obj.get("type") {
+            Some(Value::String(t)) => vec![t.as_str()],
+            Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => vec![infer_type(obj)],
+        };
+        let mut strategies: Vec<BoxedStrategy<Value>> = types
+            .iter()
+            .map(|kind| match *kind {
+                "string" => self.string(obj),
+                "integer" => integer(obj),
+                "number" => number(obj),
+                "boolean" => any::<bool>().prop_map(Value::Bool).boxed(),
+                "array" => self.array(obj, depth),
+                "object" => self.object(obj, depth),
+                _ => Just(Value::Null).boxed(),
+            })
+            .collect();
+        let value = match strategies.len() {
+            0 => Just(Value::Null).boxed(),
+            1 => strategies.remove(0),
+            _ => Union::new(strategies).boxed(),
+        };
+
+        // `allOf` 中的对象 schema 合并到同一个对象中
+        match obj.get("allOf") {
+            Some(Value::Array(subs)) if keywords::discriminated_branches(obj).is_none() => {
+                let subs: Vec<BoxedStrategy<Value>> = subs.iter().map(|sub| self.value(sub, depth)).collect();
+                (value, subs)
+                    .prop_map(|(mut value, subs)| {
+                        for sub in subs {
+                            if let (Value::Object(target), Value::Object(extra)) = (&mut value, sub) {
+                                target.extend(extra);
+                            }
+                        }
+                        value
+                    })
+                    .boxed()
+            }
+            _ => value,
+        }
+    }
+
+    fn string(&self, obj: &Map<String, Value>) -> BoxedStrategy<Value> {
+        let min = obj.get("minLength").and_then(Value::as_u64).unwrap_or(0) as usize;
+        let max = obj.get("maxLength").and_then(Value::as_u64).map(|m| m as usize);
+        if let Some(format) = obj.get("format").and_then(Value::as_str)
+            && let Some(strategy) = format_strategy(format, obj)
+        {
+            return strategy.prop_map(Value::String).boxed();
+        }
+        if let Some(pattern) = obj.get("pattern").and_then(Value::as_str)
+            && let Ok(hir) = regex_syntax::parse(pattern)
+            && let Ok(strategy) = string_regex_parsed(&generator_hir(&hir))
+        {
+            return strategy
+                .prop_filter("string length out of range", move |s| {
+                    let len = s.chars().count();
+                    len >= min && max.is_none_or(|max| len <= max)
+                })
+                .prop_map(Value::String)
+                .boxed();
+        }
+        let max = max.unwrap_or(min + MAX_EXTRA).max(min).min(min + MAX_EXTRA * 8);
+        words(min, max).prop_map(Value::String).boxed()
+    }
+
+    fn array(&self, obj: &Map<String, Value>, depth: usize) -> BoxedStrategy<Value> {
+        if let Some(Value::Array(tuple)) = obj.get("items") {
+            let items: Vec<BoxedStrategy<Value>> = tuple.iter().map(|item| self.value(item, depth + 1)).collect();
+            return items.prop_map(Value::Array).boxed();
+        }
+        let min = obj.get("minItems").and_then(Value::as_u64).unwrap_or(0) as usize;
+        let max = obj.get("maxItems").and_then(Value::as_u64).map(|m| m as usize);
+        let max = if depth >= MAX_DEPTH { min } else { max.unwrap_or(min + 3).max(min).min(min + MAX_EXTRA) };
+        let item = self.value(obj.get("items").unwrap_or(&json!({})), depth + 1);
+
+        let items = match obj.get("contains") {
+            // 插入一个满足 contains 的元素，其余元素少生成一个
+            Some(contains) => {
+                let rest = vec(item, min.saturating_sub(1)..=max.saturating_sub(1).max(min.saturating_sub(1)));
+                (rest, self.value(contains, depth + 1), any::<prop::sample::Index>())
+                    .prop_map(|(mut items, extra, index)| {
+                        items.insert(index.index(items.len() + 1), extra);
+                        items
+                    })
+                    .boxed()
+            }
+            None => vec(item, min..=max).boxed(),
+        };
+        if obj.get("uniqueItems") == Some(&Value::Bool(true)) {
+            items
+                .prop_filter("array items must be unique", |items| {
+                    items.iter().enumerate().all(|(i, item)| !items[..i].contains(item))
+                })
+                .prop_map(Value::Array)
+                .boxed()
+        } else {
+            items.prop_map(Value::Array).boxed()
+        }
+    }
+
+    fn object(&self, obj: &Map<String, Value>, depth: usize) -> BoxedStrategy<Value> {
+        let required: Vec<&str> = obj
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let include_optional = depth < MAX_DEPTH;
+
+        let base = if let Some(Value::Object(properties)) = obj.get("properties") {
+            let fields: Vec<BoxedStrategy<Option<(String, Value)>>> = properties
+                .iter()
+                .filter(|(key, _)| include_optional || required.contains(&key.as_str()))
+                .map(|(key, property)| {
+                    let is_required = required.contains(&key.as_str());
+                    let key = key.clone();
+                    let field = self.value(property, depth + 1).prop_map(move |value| (key.clone(), value));
+                    if is_required {
+                        field.prop_map(Some).boxed()
+                    } else {
+                        option::of(field).boxed()
+                    }
+                })
+                .collect();
+            fields.prop_map(|fields| Value::Object(fields.into_iter().flatten().collect())).boxed()
+        } else if let Some(value @ Value::Object(_)) = obj.get("additionalProperties") {
+            let min = obj.get("minProperties").and_then(Value::as_u64).unwrap_or(0) as usize;
+            let max = obj.get("maxProperties").and_then(Value::as_u64).map(|m| m as usize);
+            let max = if include_optional { max.unwrap_or(min + 2).max(min).min(min + MAX_EXTRA) } else { min };
+            let key = match obj.get("propertyNames").and_then(Value::as_object) {
+                Some(names) => self.string(names).prop_map(|key| key.as_str().unwrap_or_default().to_string()).boxed(),
+                None => words(1, MAX_EXTRA).boxed(),
+            };
+            btree_map(key, self.value(value, depth + 1), min..=max)
+                .prop_map(|entries| Value::Object(entries.into_iter().collect()))
+                .boxed()
+        } else {
+            Just(Value::Object(Map::new())).boxed()
+        };
+
+        // `itemsBy` 分支：选择一个分支，写入判别值并合并分支字段
+        match keywords::discriminated_branches(obj) {
+            Some((field, variants)) if !variants.is_empty() => {
+                let branches = variants.into_iter().map(|(tag, variant)| {
+                    let variant = match variant.as_object() {
+                        Some(variant) => self.object(variant, depth),
+                        None => Just(Value::Object(Map::new())).boxed(),
+                    };
+                    let field = field.clone();
+                    variant.prop_map(move |extra| (field.clone(), tag.clone(), extra)).boxed()
+                });
+                (base, Union::new(branches))
+                    .prop_map(|(mut value, (field, tag, extra))| {
+                        if let (Value::Object(target), Value::Object(extra)) = (&mut value, extra) {
+                            target.insert(field, tag);
+                            target.extend(extra);
+                        }
+                        value
+                    })
+                    .boxed()
+            }
+            _ => base,
+        }
+    }
+}
+
+/// 转换为 proptest 可以生成的正则：去掉锚点和断言（JSON Schema 的 `pattern` 按搜索匹配，
+/// 生成完整匹配的字符串即可），字符类优先使用可打印 ASCII 字符，不限次数的重复最多额外重复 `MAX_EXTRA` 次
+fn generator_hir(hir: &Hir) -> Hir {
+    match hir.kind() {
+        HirKind::Look(_) => Hir::empty(),
+        HirKind::Class(Class::Unicode(class)) => {
+            let mut printable = class.clone();
+            printable.intersect(&ClassUnicode::new([ClassUnicodeRange::new('!', '~')]));
+            Hir::class(Class::Unicode(if printable.ranges().is_empty() { class.clone() } else { printable }))
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            let mut printable = class.clone();
+            printable.intersect(&ClassBytes::new([ClassBytesRange::new(b'!', b'~')]));
+            Hir::class(Class::Bytes(if printable.ranges().is_empty() { class.clone() } else { printable }))
+        }
+        HirKind::Repetition(repetition) => Hir::repetition(Repetition {
+            min: repetition.min,
+            max: Some(repetition.max.unwrap_or(u32::MAX).min(repetition.min + MAX_EXTRA as u32)),
+            greedy: repetition.greedy,
+            sub: Box::new(generator_hir(&repetition.sub)),
+        }),
+        HirKind::Capture(capture) => Hir::capture(Capture {
+            index: capture.index,
+            name: capture.name.clone(),
+            sub: Box::new(generator_hir(&capture.sub)),
+        }),
+        HirKind::Concat(subs) => Hir::concat(subs.iter().map(generator_hir).collect()),
+        HirKind::Alternation(subs) => Hir::alternation(subs.iter().map(generator_hir).collect()),
+        HirKind::Empty | HirKind::Literal(_) => hir.clone(),
+    }
+}
+
+/// 长度在 `min..=max` 之间的 ASCII 字母数字串
+fn words(min: usize, max: usize) -> BoxedStrategy<String> {
+    string_regex(&format!("[a-zA-Z0-9]{{{},{}}}", min, max.max(min)))
+        .expect("alphanumeric pattern is valid")
+        .boxed()
+}
+
+/// 内置格式的字符串策略，未知格式返回 `None`
+fn format_strategy(format: &str, obj: &Map<String, Value>) -> Option<BoxedStrategy<String>> {
+    const SECOND: i128 = 1_000_000_000;
+    const DAY: i128 = 86_400 * SECOND;
+    const YEAR: i128 = 365 * DAY;
+
+    if matches!(format, "date" | "date-time") {
+        let date_only = format == "date";
+        let (min, max) = match dates::bounds(obj) {
+            (Some(min), Some(max)) => (min, max),
+            (Some(min), None) => (min, min + YEAR),
+            (None, Some(max)) => (max - YEAR, max),
+            (None, None) => (0, YEAR),
+        };
+        let unit = if date_only { DAY } else { SECOND };
+        let (first, last) = ((min + unit - 1).div_euclid(unit) as i64, max.div_euclid(unit) as i64);
+        return Some((first..=last.max(first)).prop_map(move |n| dates::format(n as i128 * unit, date_only)).boxed());
+    }
+    let pattern = match format {
+        "email" => "[a-z][a-z0-9]{0,7}@example\\.com",
+        "uri" | "url" | "iri" => "https://example\\.com/[a-z0-9]{0,8}",
+        "hostname" | "idn-hostname" => "[a-z][a-z0-9]{0,7}\\.example\\.com",
+        "uuid" => "[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}",
+        "time" => "([01][0-9]|2[0-3]):[0-5][0-9]:[0-5][0-9]Z",
+        "ipv6" => "2001:db8::[1-9a-f][0-9a-f]{0,3}",
+        "ipv4" => {
+            return Some(
+                any::<[u8; 4]>()
+                    .prop_map(|[a, b, c, d]| format!("{}.{}.{}.{}", a, b, c, d))
+                    .boxed(),
+            );
+        }
+        _ => return None,
+    };
+    Some(string_regex(pattern).expect("format pattern is valid").boxed())
+}
+
+fn integer(obj: &Map<String, Value>) -> BoxedStrategy<Value> {
+    let lower = [
+        obj.get("minimum").and_then(Value::as_f64).map(f64::ceil),
+        obj.get("exclusiveMinimum").and_then(Value::as_f64).map(|v| v.floor() + 1.0),
+    ];
+    let upper = [
+        obj.get("maximum").and_then(Value::as_f64).map(f64::floor),
+        obj.get("exclusiveMaximum").and_then(Value::as_f64).map(|v| v.ceil() - 1.0),
+    ];
+    let lo = lower.into_iter().flatten().reduce(f64::max).map(|v| v as i64);
+    let hi = upper.into_iter().flatten().reduce(f64::min).map(|v| v as i64);
+    let (lo, hi) = default_range(lo, hi);
+
+    match obj.get("multipleOf").and_then(Value::as_f64).filter(|m| *m > 0.0) {
+        Some(step) => {
+            let (first, last) = ((lo as f64 / step).ceil() as i64, (hi as f64 / step).floor() as i64);
+            (first..=last.max(first)).prop_map(move |n| json!((n as f64 * step) as i64)).boxed()
+        }
+        None => (lo..=hi.max(lo)).prop_map(|n| json!(n)).boxed(),
+    }
+}
+
+fn number(obj: &Map<String, Value>) -> BoxedStrategy<Value> {
+    let minimum = obj.get("minimum").and_then(Value::as_f64);
+    let exclusive_minimum = obj.get("exclusiveMinimum").and_then(Value::as_f64);
+    let maximum = obj.get("maximum").and_then(Value::as_f64);
+    let exclusive_maximum = obj.get("exclusiveMaximum").and_then(Value::as_f64);
+    let lo = [minimum, exclusive_minimum].into_iter().flatten().reduce(f64::max);
+    let hi = [maximum, exclusive_maximum].into_iter().flatten().reduce(f64::min);
+    let (lo, hi) = match (lo, hi) {
+        (Some(lo), Some(hi)) => (lo, hi.max(lo)),
+        (Some(lo), None) => (lo, lo + 100.0),
+        (None, Some(hi)) => (if hi >= 0.0 { 0.0 } else { hi - 100.0 }, hi),
+        (None, None) => (0.0, 100.0),
+    };
+
+    if let Some(step) = obj.get("multipleOf").and_then(Value::as_f64).filter(|m| *m > 0.0) {
+        let (first, last) = ((lo / step).ceil() as i64, (hi / step).floor() as i64);
+        return (first..=last.max(first)).prop_map(move |n| json!(n as f64 * step)).boxed();
+    }
+    (lo..=hi)
+        .prop_filter("number outside exclusive bounds", move |v| {
+            exclusive_minimum.is_none_or(|m| *v > m) && exclusive_maximum.is_none_or(|m| *v < m)
+        })
+        .prop_map(|v| json!(v))
+        .boxed()
+}
@@ -0,0 +1,53 @@
+//! 异步流验证
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+use serde_json::Value;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::{LinkValidator, ValidationResult};
+
+pin_project! {
+    /// 逐条验证异步流中数据的适配器，由 `LinkValidator::validate_stream` 创建
+    ///
+    /// 输出顺序与输入一致。验证在轮询时同步执行，单条数据的验证耗时很短，不会长时间占用运行时线程。
+    #[must_use = "streams do nothing unless polled"]
+    #[derive(Debug)]
+    pub struct ValidationStream<S> {
+        validator: LinkValidator,
+        #[pin]
+        inner: S,
+    }
+}
+
+impl<S> ValidationStream<S> {
+    pub(crate) fn new(validator: LinkValidator, inner: S) -> Self {
+        ValidationStream { validator, inner }
+    }
+
+    /// 取回原始数据流
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S> Stream for ValidationStream<S>
+where
+    S: Stream<Item = Value>,
+{
+    type Item = ValidationResult;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match this.inner.poll_next(cx) {
+            Poll::Ready(Some(data)) => Poll::Ready(Some(this.validator.validate(&data))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
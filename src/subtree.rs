@@ -0,0 +1,53 @@
+//! 按数据路径（JSON Pointer）定位子 schema
+
+use serde_json::Value;
+
+use crate::keywords;
+
+/// 跟随 `$ref` 的最大层数，防止循环引用
+const MAX_REF_DEPTH: usize = 64;
+
+/// 数据路径对应的子 schema
+///
+/// 每一段依次查找 `properties`、`items`（数字段）和 `additionalProperties`，沿途跟随本地 `$ref`。
+/// 路径不以 `/` 开头或 schema 中没有对应位置时返回 `None`。
+pub(crate) fn locate<'a>(root: &'a Value, pointer: &str) -> Option<&'a Value> {
+    let mut schema = root;
+    if pointer.is_empty() {
+        return Some(schema);
+    }
+    for segment in pointer.strip_prefix('/')?.split('/') {
+        let segment = segment.replace("~1", "/").replace("~0", "~");
+        for _ in 0..MAX_REF_DEPTH {
+            match keywords::resolve_ref(root, schema) {
+                Some((_, target)) => schema = target,
+                None => break,
+            }
+        }
+        let index = segment.bytes().all(|b| b.is_ascii_digit()).then(|| segment.parse::<usize>().ok()).flatten();
+        schema = if let Some(property) = schema.get("properties").and_then(|p| p.get(&segment)) {
+            property
+        } else if let (Some(index), Some(items)) = (index, schema.get("items")) {
+            match items {
+                Value::Array(tuple) => tuple.get(index)?,
+                _ => items,
+            }
+        } else {
+            schema.get("additionalProperties").filter(|a| a.is_object())?
+        };
+    }
+    Some(schema)
+}
+
+/// 可单独编译的子 schema：带上根节点的定义和草案声明，使其中的 `$ref` 仍然有效
+pub(crate) fn standalone(root: &Value, schema: &Value) -> Value {
+    let mut schema = schema.clone();
+    if let Value::Object(obj) = &mut schema {
+        for keyword in ["$schema", "$defs", "definitions"] {
+            if let Some(value) = root.get(keyword) {
+                obj.entry(keyword.to_string()).or_insert_with(|| value.clone());
+            }
+        }
+    }
+    schema
+}
@@ -0,0 +1,32 @@
+//! TOML 输入支持
+//!
+//! 使用 `toml` crate 直接解析为 `serde_json::Value`，日期时间转换为 RFC 3339 字符串。
+
+use serde_json::Value;
+
+/// `toml` 反序列化日期时间时使用的内部字段名
+const DATETIME_FIELD: &str = "$__toml_private_datetime";
+
+/// 把 TOML 文本解析为 JSON 值
+pub(crate) fn from_str(input: &str) -> Result<Value, String> {
+    let mut value = toml::from_str(input).map_err(|e: toml::de::Error| e.to_string())?;
+    unwrap_datetimes(&mut value);
+    Ok(value)
+}
+
+/// 把 `{"$__toml_private_datetime": "..."}` 还原为字符串
+fn unwrap_datetimes(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if map.len() == 1
+                && let Some(Value::String(s)) = map.get(DATETIME_FIELD)
+            {
+                *value = Value::String(s.clone());
+                return;
+            }
+            map.values_mut().for_each(unwrap_datetimes);
+        }
+        Value::Array(items) => items.iter_mut().for_each(unwrap_datetimes),
+        _ => {}
+    }
+}
@@ -0,0 +1,247 @@
+//! 数据规范化流程
+//!
+//! 在 schema 验证之前，按照规则中声明的转换（例如 `truncate`）以及编译选项中启用的
+//! 强制类型转换对数据副本进行规范化。转换计划在编译时生成，并与 schema 结构一一对应。
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+pub(crate) use link_validator_core::Case;
+use link_validator_core::{RuleTransform, TransformPlan};
+
+use crate::coerce::{self, BooleanTokens, NumberFormat};
+use crate::grapheme;
+use crate::options::{CompileOptions, NullPolicy};
+
+/// 单个字段上的转换操作
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum Transform {
+    /// 去除字符串首尾空白
+    Trim,
+    /// 转换字符串大小写
+    Case(Case),
+    /// 按字素簇截断字符串，最多保留指定数量的字素簇
+    Truncate(usize),
+    /// 把本地化数字字符串解析为数字
+    ParseNumber(NumberFormat),
+    /// 按转换表把字符串映射为布尔值
+    ParseBoolean(BooleanTokens),
+    /// 移除对象中值为 `null` 的属性，`keep` 中列出的属性除外
+    StripNulls { keep: Vec<String> },
+}
+
+impl Transform {
+    fn apply(&self, value: &mut Value) {
+        match self {
+            Transform::Trim => {
+                if let Value::String(s) = value {
+                    let trimmed = s.trim();
+                    if trimmed.len() < s.len() {
+                        *s = trimmed.to_string();
+                    }
+                }
+            }
+            Transform::Case(case) => {
+                if let Value::String(s) = value {
+                    *s = match case {
+                        Case::Lower => s.to_lowercase(),
+                        Case::Upper => s.to_uppercase(),
+                    };
+                }
+            }
+            Transform::Truncate(max) => {
+                if let Value::String(s) = value {
+                    let truncated = grapheme::truncate(s, *max);
+                    if truncated.len() < s.len() {
+                        *value = Value::String(truncated.to_string());
+                    }
+                }
+            }
+            Transform::ParseNumber(format) => {
+                if let Value::String(s) = value
+                    && let Some(number) = coerce::parse_localized_number(s, *format)
+                {
+                    *value = number;
+                }
+            }
+            Transform::ParseBoolean(tokens) => {
+                if let Value::String(s) = value
+                    && let Some(b) = tokens.parse(s)
+                {
+                    *value = Value::Bool(b);
+                }
+            }
+            Transform::StripNulls { keep } => {
+                if let Value::Object(obj) = value {
+                    obj.retain(|key, v| !v.is_null() || keep.contains(key));
+                }
+            }
+        }
+    }
+}
+
+/// 转换计划树，结构与生成的 JSON Schema 的 properties/items 对应
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct TransformNode {
+    /// 作用于当前值的转换
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) ops: Vec<Transform>,
+    /// 对象属性的转换
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) properties: BTreeMap<String, TransformNode>,
+    /// 数组元素的转换
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) items: Option<Box<TransformNode>>,
+}
+
+impl TransformNode {
+    /// 转换计划是否为空
+    pub(crate) fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+            && self.properties.is_empty()
+            && self.items.is_none()
+    }
+
+    /// 根据编译选项和 JSON Schema 中声明的类型，添加强制类型转换和 `null` 处理
+    /// 
+    /// 这些转换排在规则声明的转换之前执行。
+    pub(crate) fn add_option_transforms(&mut self, schema: &Value, options: &CompileOptions) {
+        let mut coercions = Vec::new();
+        if let Some(format) = options.number_format
+            && (schema_has_type(schema, "number") || schema_has_type(schema, "integer"))
+        {
+            coercions.push(Transform::ParseNumber(format));
+        }
+        if let Some(ref tokens) = options.boolean_tokens
+            && schema_has_type(schema, "boolean")
+        {
+            coercions.push(Transform::ParseBoolean(tokens.clone()));
+        }
+        if schema.get("properties").is_some() {
+            match options.null_policy {
+                NullPolicy::Keep => {}
+                NullPolicy::StripOptional => {
+                    let keep = match schema.get("required") {
+                        Some(Value::Array(required)) => required
+                            .iter()
+                            .filter_map(|v| v.as_str().map(String::from))
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    coercions.push(Transform::StripNulls { keep });
+                }
+                NullPolicy::AsMissing => {
+                    coercions.push(Transform::StripNulls { keep: Vec::new() });
+                }
+            }
+        }
+        if !coercions.is_empty() {
+            coercions.append(&mut self.ops);
+            self.ops = coercions;
+        }
+
+        if let Some(Value::Object(properties)) = schema.get("properties") {
+            for (key, property_schema) in properties {
+                let mut node = self.properties.remove(key).unwrap_or_default();
+                node.add_option_transforms(property_schema, options);
+                if !node.is_empty() {
+                    self.properties.insert(key.clone(), node);
+                }
+            }
+        }
+
+        if let Some(items_schema) = schema.get("items").filter(|v| v.is_object()) {
+            let mut node = self.items.take().map(|n| *n).unwrap_or_default();
+            node.add_option_transforms(items_schema, options);
+            if !node.is_empty() {
+                self.items = Some(Box::new(node));
+            }
+        }
+    }
+
+    /// 数据路径（JSON Pointer）处的子转换计划，没有转换时返回空计划
+    pub(crate) fn at(&self, pointer: &str) -> TransformNode {
+        let mut node = self;
+        for segment in pointer.split('/').skip(1) {
+            let child = if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                node.items.as_deref()
+            } else {
+                node.properties.get(&segment.replace("~1", "/").replace("~0", "~"))
+            };
+            match child {
+                Some(child) => node = child,
+                None => return TransformNode::default(),
+            }
+        }
+        node.clone()
+    }
+
+    /// 规范化数据，无转换时直接借用原始数据
+    pub(crate) fn normalize<'a>(&self, data: &'a Value) -> Cow<'a, Value> {
+        if self.is_empty() {
+            Cow::Borrowed(data)
+        } else {
+            let mut value = data.clone();
+            self.apply(&mut value);
+            Cow::Owned(value)
+        }
+    }
+
+    /// 原地应用转换计划
+    pub(crate) fn apply(&self, value: &mut Value) {
+        for op in &self.ops {
+            op.apply(value);
+        }
+
+        match value {
+            Value::Object(obj) => {
+                for (key, node) in &self.properties {
+                    if let Some(child) = obj.get_mut(key) {
+                        node.apply(child);
+                    }
+                }
+            }
+            Value::Array(arr) => {
+                if let Some(ref node) = self.items {
+                    for item in arr.iter_mut() {
+                        node.apply(item);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl From<RuleTransform> for Transform {
+    fn from(transform: RuleTransform) -> Self {
+        match transform {
+            RuleTransform::Trim => Transform::Trim,
+            RuleTransform::Case(case) => Transform::Case(case),
+            RuleTransform::Truncate(max) => Transform::Truncate(max),
+        }
+    }
+}
+
+impl From<TransformPlan> for TransformNode {
+    fn from(plan: TransformPlan) -> Self {
+        TransformNode {
+            ops: plan.ops.into_iter().map(Transform::from).collect(),
+            properties: plan.properties.into_iter().map(|(name, plan)| (name, plan.into())).collect(),
+            items: plan.items.map(|items| Box::new((*items).into())),
+        }
+    }
+}
+
+/// 判断 schema 的 `type` 是否包含指定类型
+fn schema_has_type(schema: &Value, type_name: &str) -> bool {
+    match schema.get("type") {
+        Some(Value::String(t)) => t == type_name,
+        Some(Value::Array(types)) => types.iter().any(|t| t == type_name),
+        _ => false,
+    }
+}
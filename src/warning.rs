@@ -0,0 +1,29 @@
+//! 规则转换警告
+//!
+//! 警告类型由 `link-validator-core` 定义，这里按编译选项调整严重程度。
+
+use std::collections::BTreeMap;
+
+pub use link_validator_core::{ConversionWarning, Severity, WarningKind};
+
+/// 按规则键覆盖警告的严重程度，存在 `Severity::Error` 时返回编译错误
+pub(crate) fn apply_severities(
+    warnings: &mut [ConversionWarning],
+    severities: &BTreeMap<String, Severity>,
+) -> Result<(), String> {
+    for warning in warnings.iter_mut() {
+        if let Some(severity) = warning.rule.as_ref().and_then(|rule| severities.get(rule)) {
+            warning.severity = *severity;
+        }
+    }
+    let errors: Vec<&str> = warnings
+        .iter()
+        .filter(|warning| warning.severity == Severity::Error)
+        .map(|warning| warning.message.as_str())
+        .collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Conversion warnings treated as errors: {}", errors.join("; ")))
+    }
+}
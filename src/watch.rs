@@ -0,0 +1,174 @@
+//! Schema 文件热加载
+
+use notify_debouncer_mini::notify::{RecommendedWatcher, RecursiveMode};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
+use serde_json::Value;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+use crate::{LinkValidator, ValidationResult};
+
+/// 默认的去抖间隔，间隔内的连续修改只触发一次重新加载
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// 文件内容的哈希，读取失败时为 `None`
+fn content_hash(path: &Path) -> Option<u64> {
+    let content = fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// 监视线程与句柄共享的状态
+#[derive(Debug)]
+struct Shared {
+    path: PathBuf,
+    current: RwLock<Arc<LinkValidator>>,
+    generation: AtomicU64,
+    last_error: Mutex<Option<String>>,
+    /// 最近一次加载的文件内容哈希
+    hash: Mutex<Option<u64>>,
+}
+
+impl Shared {
+    /// 重新加载文件，成功时原子替换当前验证器，失败时保留原验证器并记录错误
+    fn reload(&self) -> Result<(), String> {
+        *self.hash.lock().unwrap_or_else(|e| e.into_inner()) = content_hash(&self.path);
+        let result = LinkValidator::from_file(&self.path);
+        let mut last_error = self.last_error.lock().unwrap_or_else(|e| e.into_inner());
+        match result {
+            Ok(validator) => {
+                *self.current.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(validator);
+                self.generation.fetch_add(1, Ordering::SeqCst);
+                *last_error = None;
+                Ok(())
+            }
+            Err(e) => {
+                *last_error = Some(e.clone());
+                Err(e)
+            }
+        }
+    }
+
+    /// 处理去抖后的文件系统事件，文件内容变化时重新加载
+    fn handle(&self, events: DebounceEventResult) {
+        match events {
+            Ok(events) => {
+                let name = self.path.file_name();
+                if !events.iter().any(|event| event.path.file_name() == name) {
+                    return;
+                }
+                let hash = content_hash(&self.path);
+                if hash.is_some() && hash == *self.hash.lock().unwrap_or_else(|e| e.into_inner()) {
+                    return;
+                }
+                // 失败时错误记录在 last_error 中
+                let _ = self.reload();
+            }
+            Err(e) => {
+                *self.last_error.lock().unwrap_or_else(|e| e.into_inner()) = Some(format!("Watch error: {}", e));
+            }
+        }
+    }
+}
+
+/// 监视 schema 文件并在文件变化时自动重新编译的验证器
+///
+/// 通过操作系统的文件通知（`notify` 的 `RecommendedWatcher`）监视文件所在目录，
+/// 去抖间隔内的连续修改合并为一次；文件内容确实变化后才重新编译并原子替换当前验证器，
+/// 长时间运行的服务无需重启即可使用新规则。重新编译失败时继续使用原验证器，
+/// 错误可通过 `last_error` 获取。句柄被丢弃时停止监视。
+///
+/// # 示例
+///
+/// ```no_run
+/// use link_validator::WatchedValidator;
+/// use serde_json::json;
+///
+/// let watched = WatchedValidator::new("schemas/user.json").unwrap();
+/// let result = watched.validate(&json!({"name": "john"}));
+/// ```
+#[derive(Debug)]
+pub struct WatchedValidator {
+    shared: Arc<Shared>,
+    /// 丢弃时停止监视
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl WatchedValidator {
+    /// 加载 schema 文件并开始监视（去抖间隔 200 毫秒）
+    pub fn new(path: impl AsRef<Path>) -> Result<WatchedValidator, String> {
+        Self::with_debounce(path, DEFAULT_DEBOUNCE)
+    }
+
+    /// 加载 schema 文件并按指定去抖间隔监视
+    ///
+    /// 文件格式与 `LinkValidator::from_file` 相同，首次加载失败或无法监视时返回错误。
+    pub fn with_debounce(path: impl AsRef<Path>, debounce: Duration) -> Result<WatchedValidator, String> {
+        let path = path.as_ref().to_path_buf();
+        let hash = content_hash(&path);
+        let validator = LinkValidator::from_file(&path)?;
+
+        let shared = Arc::new(Shared {
+            path,
+            current: RwLock::new(Arc::new(validator)),
+            generation: AtomicU64::new(0),
+            last_error: Mutex::new(None),
+            hash: Mutex::new(hash),
+        });
+
+        // 监视所在目录，编辑器以重命名方式保存文件时同样能收到事件
+        let dir = match shared.path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let handler = shared.clone();
+        let mut debouncer = new_debouncer(debounce, move |events| handler.handle(events))
+            .map_err(|e| format!("Failed to start watcher: {}", e))?;
+        debouncer
+            .watcher()
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Failed to watch '{}': {}", dir.display(), e))?;
+
+        Ok(WatchedValidator {
+            shared,
+            _debouncer: debouncer,
+        })
+    }
+
+    /// 当前使用的验证器
+    ///
+    /// 返回的验证器在替换后仍然有效，正在进行的验证不受热加载影响。
+    pub fn current(&self) -> Arc<LinkValidator> {
+        self.shared.current.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// 使用当前验证器验证数据
+    pub fn validate(&self, data: &Value) -> ValidationResult {
+        self.current().validate(data)
+    }
+
+    /// 立即重新加载文件
+    pub fn reload(&self) -> Result<(), String> {
+        self.shared.reload()
+    }
+
+    /// 验证器被替换的次数
+    pub fn generation(&self) -> u64 {
+        self.shared.generation.load(Ordering::SeqCst)
+    }
+
+    /// 最近一次重新加载的错误，重新加载成功后清除
+    pub fn last_error(&self) -> Option<String> {
+        self.shared.last_error.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// 监视的文件路径
+    pub fn path(&self) -> &Path {
+        &self.shared.path
+    }
+}
@@ -0,0 +1,232 @@
+//! Web 框架集成：验证 HTTP 请求的 JSON 请求体
+
+use http::header::{HeaderValue, CONTENT_TYPE};
+use http::{Request, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+use crate::{LinkValidator, ValidateOptions, ValidationError};
+
+/// 通过验证并反序列化后的请求体
+///
+/// 启用 `axum` / `actix` feature 后可直接作为提取器使用：从应用状态（axum 的 `State`，
+/// 需要 `LinkValidator: FromRef<S>`；actix-web 的 `web::Data<LinkValidator>`）取出验证器，
+/// 验证 JSON 请求体，失败时以 `ValidationRejection` 作为 JSON 响应返回。
+/// 其他框架可以直接调用 `Validated::from_request` 或 `Validated::from_slice`。
+///
+/// # 示例
+///
+/// ```
+/// use link_validator::{LinkValidator, Validated};
+/// use serde::Deserialize;
+/// use serde_json::json;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Signup {
+///     username: String,
+/// }
+///
+/// let validator = LinkValidator::new(&json!({"username": {"type": "string", "required": true, "min": 3}})).unwrap();
+///
+/// let Validated(signup) = Validated::<Signup>::from_slice(&validator, br#"{"username": "john"}"#).unwrap();
+/// assert_eq!(signup.username, "john");
+///
+/// let rejection = Validated::<Signup>::from_slice(&validator, br#"{"username": "jo"}"#).unwrap_err();
+/// assert_eq!(rejection.status(), 422);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Validated<T>(pub T);
+
+impl<T: DeserializeOwned> Validated<T> {
+    /// 解析、规范化并验证 JSON 请求体，通过后反序列化为 `T`
+    ///
+    /// 请求体不是合法 JSON 时返回 400，验证失败或无法反序列化时返回 422。
+    pub fn from_slice(validator: &LinkValidator, body: &[u8]) -> Result<Self, ValidationRejection> {
+        let data: Value = serde_json::from_slice(body).map_err(|e| {
+            ValidationRejection::single(validator, StatusCode::BAD_REQUEST, "request.json", format!("Invalid JSON body: {}", e))
+        })?;
+
+        let (value, result) = validator.validate_and_transform(&data);
+        if !result.is_valid {
+            return Err(ValidationRejection {
+                status: StatusCode::UNPROCESSABLE_ENTITY,
+                errors: result.errors,
+            });
+        }
+
+        serde_json::from_value(value).map(Validated).map_err(|e| {
+            ValidationRejection::single(validator, StatusCode::UNPROCESSABLE_ENTITY, "deserialize", format!("Failed to deserialize: {}", e))
+        })
+    }
+
+    /// 验证 HTTP 请求的请求体，`Content-Type` 不是 JSON 时返回 415
+    pub fn from_request<B: AsRef<[u8]>>(validator: &LinkValidator, request: &Request<B>) -> Result<Self, ValidationRejection> {
+        check_content_type(validator, request.headers().get(CONTENT_TYPE).map(HeaderValue::as_bytes))?;
+        Self::from_slice(validator, request.body().as_ref())
+    }
+}
+
+impl<T> Validated<T> {
+    /// 取出请求体
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Validated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Validated<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// `Content-Type` 不是 JSON（`application/json` 或 `application/*+json`）时返回 415
+fn check_content_type(validator: &LinkValidator, content_type: Option<&[u8]>) -> Result<(), ValidationRejection> {
+    let mime = content_type
+        .and_then(|v| std::str::from_utf8(v).ok())
+        .map(|mime| mime.split(';').next().unwrap_or_default().trim().to_ascii_lowercase());
+    match mime {
+        Some(mime) if mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json")) => Ok(()),
+        _ => Err(ValidationRejection::single(
+            validator,
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "request.contentType",
+            "Expected request with `Content-Type: application/json`",
+        )),
+    }
+}
+
+/// 请求体验证失败时的拒绝响应
+///
+/// 响应体为 `{"errors": [...]}`，错误格式与验证器的 `ValidationResult::errors` 相同
+/// （async-validator 规则为 `{message, field}`）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationRejection {
+    status: StatusCode,
+    errors: Value,
+}
+
+impl ValidationRejection {
+    fn single(validator: &LinkValidator, status: StatusCode, code: &str, message: impl Into<String>) -> Self {
+        let error = ValidationError::new("", message).with_code(code).render(validator.format(), &ValidateOptions::default());
+        ValidationRejection {
+            status,
+            errors: Value::Array(vec![error]),
+        }
+    }
+
+    /// 响应状态码
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// 错误列表（JSON 格式）
+    pub fn errors(&self) -> &Value {
+        &self.errors
+    }
+
+    /// 响应体
+    pub fn body(&self) -> Value {
+        json!({"errors": self.errors})
+    }
+
+    /// 生成 JSON 响应
+    pub fn into_response(self) -> Response<String> {
+        let mut response = Response::new(self.body().to_string());
+        *response.status_mut() = self.status;
+        response
+            .headers_mut()
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+}
+
+impl fmt::Display for ValidationRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.status, self.errors)
+    }
+}
+
+impl std::error::Error for ValidationRejection {}
+
+/// axum 提取器：验证器从 `State` 中取出
+#[cfg(feature = "axum")]
+impl<T, S> axum::extract::FromRequest<S> for Validated<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+    LinkValidator: axum::extract::FromRef<S>,
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(request: axum::extract::Request, state: &S) -> Result<Self, Self::Rejection> {
+        let validator = <LinkValidator as axum::extract::FromRef<S>>::from_ref(state);
+        check_content_type(&validator, request.headers().get(CONTENT_TYPE).map(HeaderValue::as_bytes))?;
+        let body = axum::body::Bytes::from_request(request, state).await.map_err(|e| {
+            ValidationRejection::single(&validator, StatusCode::BAD_REQUEST, "request.body", format!("Failed to read body: {}", e))
+        })?;
+        Self::from_slice(&validator, &body)
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for ValidationRejection {
+    fn into_response(self) -> axum::response::Response {
+        ValidationRejection::into_response(self).map(axum::body::Body::from)
+    }
+}
+
+/// actix-web 提取器：验证器从 `web::Data<LinkValidator>` 中取出，未注册时返回 500
+#[cfg(feature = "actix")]
+impl<T> actix_web::FromRequest for Validated<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    type Error = ValidationRejection;
+    type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(request: &actix_web::HttpRequest, payload: &mut actix_web::dev::Payload) -> Self::Future {
+        use actix_web::http::header;
+
+        let Some(validator) = request.app_data::<actix_web::web::Data<LinkValidator>>().cloned() else {
+            let rejection = ValidationRejection {
+                status: StatusCode::INTERNAL_SERVER_ERROR,
+                errors: json!([{"message": "LinkValidator is not registered with App::app_data(web::Data::new(..))"}]),
+            };
+            return Box::pin(std::future::ready(Err(rejection)));
+        };
+        let content_type = request.headers().get(header::CONTENT_TYPE).map(|v| v.as_bytes());
+        if let Err(rejection) = check_content_type(&validator, content_type) {
+            return Box::pin(std::future::ready(Err(rejection)));
+        }
+        let body = actix_web::web::Bytes::from_request(request, payload);
+        Box::pin(async move {
+            let body = body.await.map_err(|e| {
+                ValidationRejection::single(&validator, StatusCode::BAD_REQUEST, "request.body", format!("Failed to read body: {}", e))
+            })?;
+            Self::from_slice(&validator, &body)
+        })
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::ResponseError for ValidationRejection {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(self.status.as_u16()).unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        actix_web::HttpResponse::build(self.status_code())
+            .content_type("application/json")
+            .body(self.body().to_string())
+    }
+}
@@ -0,0 +1,10 @@
+//! YAML 输入支持
+//!
+//! 使用 `serde_norway`（维护中的 `serde_yaml` 分支）直接解析为 `serde_json::Value`，只接受单个文档。
+
+use serde_json::Value;
+
+/// 把 YAML 文本解析为 JSON 值
+pub(crate) fn from_str(input: &str) -> Result<Value, String> {
+    serde_norway::from_str(input).map_err(|e| e.to_string())
+}
@@ -0,0 +1,346 @@
+//! 导出 Zod schema
+
+use serde_json::{Map, Value};
+
+use link_validator_core::__private::ignore_case_pattern;
+
+use crate::{form, keywords};
+
+/// 缩进单位
+const INDENT: &str = "  ";
+
+/// 生成与 schema 等价的 Zod 表达式
+pub(crate) fn export(schema: &Value) -> String {
+    let definitions = ["definitions", "$defs"]
+        .iter()
+        .filter_map(|k| schema.get(*k).and_then(Value::as_object))
+        .flat_map(|defs| defs.iter())
+        .map(|(name, schema)| (name.as_str(), schema))
+        .collect();
+    let mut exporter = Exporter {
+        definitions,
+        resolving: Vec::new(),
+    };
+    exporter.expr(schema, 0)
+}
+
+struct Exporter<'a> {
+    /// 可被 `$ref` 引用的定义
+    definitions: Vec<(&'a str, &'a Value)>,
+    /// 正在展开的定义，用于识别递归引用
+    resolving: Vec<String>,
+}
+
+impl Exporter<'_> {
+    fn expr(&mut self, schema: &Value, depth: usize) -> String {
+        let Some(obj) = schema.as_object() else {
+            return match schema {
+                Value::Bool(false) => "z.never()".to_string(),
+                _ => "z.any()".to_string(),
+            };
+        };
+
+        if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference, depth);
+        }
+        if let Some(value) = obj.get("const") {
+            return literal(value);
+        }
+        if let Some(Value::Array(values)) = obj.get("enum") {
+            return enumeration(values, obj.get(form::OPTIONS));
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(subs)) = obj.get(keyword) {
+                let options: Vec<String> = subs.iter().map(|s| self.expr(s, depth)).collect();
+                return format!("z.union([{}])", options.join(", "));
+            }
+        }
+
+        let (types, nullable) = match obj.get("type") {
+            Some(Value::String(t)) => (vec![t.as_str()], false),
+            Some(Value::Array(ts)) => {
+                let names: Vec<&str> = ts.iter().filter_map(Value::as_str).collect();
+                let nullable = names.contains(&"null");
+                (names.into_iter().filter(|t| *t != "null").collect(), nullable)
+            }
+            _ if obj.contains_key("properties") => (vec!["object"], false),
+            _ => (Vec::new(), false),
+        };
+
+        let mut expr = match types.as_slice() {
+            ["string"] => string(obj),
+            ["integer"] => format!("z.number().int(){}", number_checks(obj)),
+            ["number"] => format!("z.number(){}", number_checks(obj)),
+            ["boolean"] => "z.boolean()".to_string(),
+            ["array"] => self.array(obj, depth),
+            ["object"] => self.object(obj, depth),
+            [] if nullable => return "z.null()".to_string(),
+            [] => "z.any()".to_string(),
+            many => {
+                let options: Vec<String> = many.iter().map(|t| primitive(t)).collect();
+                format!("z.union([{}])", options.join(", "))
+            }
+        };
+        if let Some(Value::Array(subs)) = obj.get("allOf")
+            && keywords::discriminated_branches(obj).is_none()
+        {
+            for sub in subs {
+                expr = format!("{}.and({})", expr, self.expr(sub, depth));
+            }
+        }
+        if nullable {
+            expr.push_str(".nullable()");
+        }
+        expr
+    }
+
+    /// 展开引用的定义，递归引用使用 `z.any()`
+    fn reference(&mut self, reference: &str, depth: usize) -> String {
+        let name = reference
+            .strip_prefix("#/definitions/")
+            .or_else(|| reference.strip_prefix("#/$defs/"));
+        let Some((name, schema)) = name.and_then(|n| self.definitions.iter().find(|(d, _)| *d == n).copied()) else {
+            return "z.any()".to_string();
+        };
+        if self.resolving.iter().any(|r| r == name) {
+            return "z.any()".to_string();
+        }
+        self.resolving.push(name.to_string());
+        let expr = self.expr(schema, depth);
+        self.resolving.pop();
+        expr
+    }
+
+    fn array(&mut self, obj: &Map<String, Value>, depth: usize) -> String {
+        let item = match obj.get("items") {
+            Some(items @ Value::Object(_)) => self.expr(items, depth),
+            _ => "z.any()".to_string(),
+        };
+        format!("z.array({}){}", item, length_checks(obj, "minItems", "maxItems"))
+    }
+
+    fn object(&mut self, obj: &Map<String, Value>, depth: usize) -> String {
+        if let Some((field, variants)) = keywords::discriminated_branches(obj) {
+            return self.discriminated_union(obj, &field, variants, depth);
+        }
+        let Some(properties) = obj.get("properties").and_then(Value::as_object) else {
+            return match obj.get("additionalProperties") {
+                Some(value @ Value::Object(_)) => format!("z.record({})", self.expr(value, depth)),
+                _ => "z.record(z.any())".to_string(),
+            };
+        };
+
+        let required = required(obj);
+        let entries: Vec<(String, String)> = properties
+            .iter()
+            .map(|(key, property)| {
+                let mut expr = self.expr(property, depth + 1);
+                if !required.contains(&key.as_str()) {
+                    expr.push_str(".optional()");
+                }
+                (key.clone(), expr)
+            })
+            .collect();
+        let mut expr = object_literal(&entries, depth);
+        if obj.get("additionalProperties") == Some(&Value::Bool(false)) {
+            expr.push_str(".strict()");
+        }
+        expr
+    }
+
+    /// `itemsBy` 分支转换为 `z.discriminatedUnion`，每个分支包含公共字段
+    fn discriminated_union(&mut self, obj: &Map<String, Value>, field: &str, variants: Vec<(Value, Value)>, depth: usize) -> String {
+        let common = obj.get("properties").and_then(Value::as_object);
+        let common_required = required(obj);
+        let options: Vec<String> = variants
+            .iter()
+            .map(|(tag, schema)| {
+                let mut entries = vec![(field.to_string(), literal(tag))];
+                let own = schema.get("properties").and_then(Value::as_object);
+                let own_required: Vec<&str> = schema.as_object().map(required).unwrap_or_default();
+                for (key, property) in common.into_iter().flatten().chain(own.into_iter().flatten()) {
+                    if key == field {
+                        continue;
+                    }
+                    // 分支规则覆盖同名的公共字段
+                    entries.retain(|(k, _)| k != key);
+                    let mut expr = self.expr(property, depth + 2);
+                    if !common_required.contains(&key.as_str()) && !own_required.contains(&key.as_str()) {
+                        expr.push_str(".optional()");
+                    }
+                    entries.push((key.clone(), expr));
+                }
+                object_literal(&entries, depth + 1)
+            })
+            .collect();
+
+        let pad = INDENT.repeat(depth + 1);
+        let mut expr = format!("z.discriminatedUnion({}, [\n", quote(field));
+        for option in options {
+            expr.push_str(&format!("{}{},\n", pad, option));
+        }
+        expr.push_str(&INDENT.repeat(depth));
+        expr.push_str("])");
+        expr
+    }
+}
+
+/// 多行对象字面量 `z.object({...})`
+fn object_literal(entries: &[(String, String)], depth: usize) -> String {
+    if entries.is_empty() {
+        return "z.object({})".to_string();
+    }
+    let pad = INDENT.repeat(depth + 1);
+    let mut expr = String::from("z.object({\n");
+    for (key, value) in entries {
+        expr.push_str(&format!("{}{}: {},\n", pad, property_key(key), value));
+    }
+    expr.push_str(&INDENT.repeat(depth));
+    expr.push_str("})");
+    expr
+}
+
+fn string(obj: &Map<String, Value>) -> String {
+    let mut expr = String::from("z.string()");
+    match obj.get("format").and_then(Value::as_str) {
+        Some("email") => expr.push_str(".email()"),
+        Some("uri" | "url") => expr.push_str(".url()"),
+        Some("uuid") => expr.push_str(".uuid()"),
+        Some("date-time") => expr.push_str(".datetime()"),
+        Some("ipv4") => expr.push_str(".ip({ version: \"v4\" })"),
+        Some("ipv6") => expr.push_str(".ip({ version: \"v6\" })"),
+        _ => {}
+    }
+    expr.push_str(&length_checks(obj, "minLength", "maxLength"));
+    // ignoreCase 枚举：JavaScript 正则不支持 `(?i:...)`，按 x-enum 中的取值改用 `i` 标志
+    let ignore_case = obj.get(form::ENUM).and_then(Value::as_array).map(|values| ignore_case_pattern(values));
+    if let Some(pattern) = &ignore_case {
+        expr.push_str(&format!(".regex({}i)", regex_literal(&pattern.replacen("(?i:", "(?:", 1))));
+    }
+    if let Some(pattern) = obj.get("pattern").and_then(Value::as_str)
+        && ignore_case.as_deref() != Some(pattern)
+    {
+        expr.push_str(&format!(".regex({})", regex_literal(pattern)));
+    }
+    expr
+}
+
+/// 长度约束，上下限相同时使用 `.length()`
+fn length_checks(obj: &Map<String, Value>, min: &str, max: &str) -> String {
+    match (obj.get(min), obj.get(max)) {
+        (Some(a), Some(b)) if a == b => format!(".length({})", a),
+        (a, b) => {
+            let mut checks = String::new();
+            if let Some(a) = a {
+                checks.push_str(&format!(".min({})", a));
+            }
+            if let Some(b) = b {
+                checks.push_str(&format!(".max({})", b));
+            }
+            checks
+        }
+    }
+}
+
+fn number_checks(obj: &Map<String, Value>) -> String {
+    let mut checks = String::new();
+    for (keyword, method) in [
+        ("minimum", "gte"),
+        ("exclusiveMinimum", "gt"),
+        ("maximum", "lte"),
+        ("exclusiveMaximum", "lt"),
+        ("multipleOf", "multipleOf"),
+    ] {
+        if let Some(value) = obj.get(keyword).filter(|v| v.is_number()) {
+            checks.push_str(&format!(".{}({})", method, value));
+        }
+    }
+    checks
+}
+
+fn primitive(name: &str) -> String {
+    match name {
+        "string" => "z.string()",
+        "integer" => "z.number().int()",
+        "number" => "z.number()",
+        "boolean" => "z.boolean()",
+        "array" => "z.array(z.any())",
+        "object" => "z.record(z.any())",
+        _ => "z.any()",
+    }
+    .to_string()
+}
+
+/// 字符串枚举使用 `z.enum`，其他值使用字面量联合；带标签的选项输出为 `.describe(标签)`
+fn enumeration(values: &[Value], options: Option<&Value>) -> String {
+    let label = |value: &Value| {
+        options?.as_array()?.iter().find(|option| option.get("value") == Some(value))?.get("label")?.as_str()
+    };
+    if values.iter().any(|value| label(value).is_some()) {
+        let items: Vec<String> = values
+            .iter()
+            .map(|value| match label(value) {
+                Some(label) => format!("{}.describe({})", literal(value), Value::from(label)),
+                None => literal(value),
+            })
+            .collect();
+        return format!("z.union([{}])", items.join(", "));
+    }
+    match values {
+        [] => "z.never()".to_string(),
+        [value] => literal(value),
+        _ if values.iter().all(Value::is_string) => {
+            let items: Vec<String> = values.iter().map(Value::to_string).collect();
+            format!("z.enum([{}])", items.join(", "))
+        }
+        _ => {
+            let items: Vec<String> = values.iter().map(literal).collect();
+            format!("z.union([{}])", items.join(", "))
+        }
+    }
+}
+
+fn literal(value: &Value) -> String {
+    match value {
+        Value::Null => "z.null()".to_string(),
+        Value::Array(_) | Value::Object(_) => "z.any()".to_string(),
+        other => format!("z.literal({})", other),
+    }
+}
+
+fn required(obj: &Map<String, Value>) -> Vec<&str> {
+    obj.get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+/// 合法的 JavaScript 标识符直接作为键，否则使用字符串
+fn property_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier { key.to_string() } else { quote(key) }
+}
+
+fn quote(value: &str) -> String {
+    Value::String(value.to_string()).to_string()
+}
+
+/// 正则表达式字面量，转义未转义的 `/` 和换行
+fn regex_literal(pattern: &str) -> String {
+    let mut literal = String::from("/");
+    let mut escaped = false;
+    for c in pattern.chars() {
+        match c {
+            '/' if !escaped => literal.push_str("\\/"),
+            '\n' => literal.push_str("\\n"),
+            c => literal.push(c),
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    literal.push('/');
+    literal
+}
@@ -0,0 +1,67 @@
+//! Array rule tests for link-validator
+
+use link_validator::{LinkValidator, SampleOptions};
+use serde_json::json;
+
+#[test]
+fn test_unique_items() {
+    let validator = LinkValidator::new(&json!({
+        "tags": {"type": "array", "unique": true}
+    }))
+    .unwrap();
+
+    assert_eq!(validator.json_schema()["properties"]["tags"]["uniqueItems"], json!(true));
+    assert!(validator.validate(&json!({"tags": ["a", "b"]})).is_valid);
+    let result = validator.validate(&json!({"tags": ["a", "b", "a"]}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], json!("tags"));
+}
+
+#[test]
+fn test_contains_rule() {
+    let validator = LinkValidator::new(&json!({
+        "members": {
+            "type": "array",
+            "fields": {"name": {"type": "string", "required": true}, "role": {"type": "string"}},
+            "contains": {"type": "object", "fields": {"role": {"type": "enum", "enum": ["admin"], "required": true}}}
+        }
+    }))
+    .unwrap();
+
+    let contains = &validator.json_schema()["properties"]["members"]["contains"];
+    assert_eq!(contains["properties"]["role"]["enum"], json!(["admin"]));
+    assert_eq!(contains["required"], json!(["role"]));
+
+    let data = json!({"members": [{"name": "a", "role": "user"}, {"name": "b", "role": "admin"}]});
+    assert!(validator.validate(&data).is_valid);
+    let data = json!({"members": [{"name": "a", "role": "user"}]});
+    assert!(!validator.validate(&data).is_valid);
+}
+
+#[test]
+fn test_contains_scalar_rule() {
+    let validator = LinkValidator::new(&json!({
+        "scores": {"type": "array", "unique": true, "contains": {"type": "integer", "min": 90}}
+    }))
+    .unwrap();
+
+    assert!(validator.validate(&json!({"scores": [60, 95]})).is_valid);
+    assert!(!validator.validate(&json!({"scores": [60, 70]})).is_valid);
+
+    for seed in 0..20 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(validator.validate(&sample).is_valid, "{}", sample);
+    }
+}
+
+#[test]
+fn test_array_rules_on_other_types() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "unique": true, "contains": {"type": "string"}}
+    }))
+    .unwrap();
+
+    assert!(validator.warnings().iter().any(|w| w.message.contains("unique rule only supported for array type")));
+    assert!(validator.warnings().iter().any(|w| w.message.contains("contains rule only supported for array type")));
+    assert!(validator.json_schema()["properties"]["name"].get("uniqueItems").is_none());
+}
@@ -1,6 +1,6 @@
 //! Basic functionality tests for link-validator
 
-use link_validator::LinkValidator;
+use link_validator::{LinkValidator, SchemaFormat};
 use serde_json::json;
 
 #[test]
@@ -196,3 +196,22 @@ fn test_min_max_field_differences() {
         assert!(error.get("field").is_none());
     }
 }
+
+#[test]
+fn test_converted_schema_and_warnings() {
+    let schema = json!({
+        "username": {"type": "string", "required": true, "min": 3, "trigger": "blur"}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+    assert_eq!(validator.format(), SchemaFormat::AsyncValidator);
+    assert_eq!(validator.json_schema()["properties"]["username"]["minLength"], 3);
+    assert_eq!(validator.json_schema()["required"], json!(["username"]));
+    assert_eq!(validator.warnings().len(), 1);
+
+    let json_schema = json!({"type": "object", "properties": {"a": {"type": "string"}}});
+    let validator = LinkValidator::new(&json_schema).expect("Compilation failed");
+    assert_eq!(validator.format(), SchemaFormat::JsonSchema);
+    assert_eq!(validator.json_schema(), &json_schema);
+    assert!(validator.warnings().is_empty());
+}
@@ -0,0 +1,74 @@
+//! Batch validation tests for link-validator
+
+use link_validator::{BatchSummary, LinkValidator};
+use serde_json::json;
+
+fn validator() -> LinkValidator {
+    let schema = json!({
+        "id": {"type": "integer", "required": true},
+        "name": {"type": "string", "required": true, "min": 2}
+    });
+    LinkValidator::new(&schema).expect("Compilation failed")
+}
+
+#[cfg(feature = "rayon")]
+fn records(count: usize) -> Vec<serde_json::Value> {
+    (0..count)
+        .map(|i| {
+            if i % 7 == 0 {
+                json!({"id": i, "name": "x"})
+            } else {
+                json!({"id": i, "name": format!("user-{}", i)})
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_validate_batch_per_item_results() {
+    let validator = validator();
+    let items = vec![json!({"id": 1, "name": "john"}), json!({"id": "2"}), json!({"id": 3, "name": "jane"})];
+
+    let results = validator.validate_batch(&items);
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_valid);
+    assert!(!results[1].is_valid);
+    assert!(results[2].is_valid);
+
+    let summary = BatchSummary::from_results(&results);
+    assert_eq!(summary.total, 3);
+    assert_eq!(summary.valid, 2);
+    assert_eq!(summary.invalid, 1);
+    assert_eq!(summary.error_count, results[1].errors.as_array().unwrap().len());
+    assert_eq!(summary.invalid_indices, vec![1]);
+    assert!(!summary.all_valid());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parallel_matches_sequential() {
+    let validator = validator();
+    let items = records(2000);
+
+    let sequential = validator.validate_batch(&items);
+    let parallel = validator.validate_batch_parallel(&items);
+    assert_eq!(sequential.len(), parallel.len());
+    for (a, b) in sequential.iter().zip(&parallel) {
+        assert_eq!(a.is_valid, b.is_valid);
+        assert_eq!(a.errors, b.errors);
+    }
+
+    let summary = BatchSummary::from_results(&parallel);
+    assert_eq!(summary.invalid, 2000usize.div_ceil(7));
+    assert!(summary.invalid_indices.iter().all(|i| i % 7 == 0));
+    assert!(validator.validate_batch_parallel(&[]).is_empty());
+}
+
+#[test]
+fn test_empty_batch() {
+    assert!(validator().validate_batch(&[]).is_empty());
+
+    let summary = BatchSummary::from_results(&[]);
+    assert_eq!(summary, BatchSummary::default());
+    assert!(summary.all_valid());
+}
@@ -0,0 +1,63 @@
+//! Dry-run check tests for link-validator
+
+use link_validator::{check, SchemaFormat};
+use serde_json::json;
+
+#[test]
+fn test_clean_rules() {
+    let report = check(&json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "age": {"type": "integer", "min": 0}
+    }));
+    assert_eq!(report.format, SchemaFormat::AsyncValidator);
+    assert!(report.valid);
+    assert!(report.is_clean());
+    assert_eq!(report.error, None);
+}
+
+#[test]
+fn test_unsupported_rules_are_reported() {
+    let report = check(&json!({
+        "name": {"type": "string", "trigger": "blur", "colour": "red"}
+    }));
+    assert!(report.valid);
+    assert!(!report.is_clean());
+    let warnings: Vec<&str> = report.warnings.iter().map(|w| w.message.as_str()).collect();
+    assert_eq!(warnings, ["Field 'name': trigger option not supported", "Field 'name': unsupported rule 'colour'"]);
+}
+
+#[test]
+fn test_json_schema_unknown_keywords() {
+    let report = check(&json!({
+        "type": "object",
+        "properties": {"name": {"type": "string", "minLenght": 3}}
+    }));
+    assert_eq!(report.format, SchemaFormat::JsonSchema);
+    assert!(report.valid);
+    assert_eq!(report.unknown_keywords, vec!["minLenght"]);
+    assert!(!report.is_clean());
+}
+
+#[test]
+fn test_compile_failure() {
+    let report = check(&json!({
+        "code": {"type": "string", "pattern": "(unclosed"}
+    }));
+    assert!(!report.valid);
+    assert!(report.error.is_some());
+    assert!(report.warnings.is_empty());
+
+    let report = check(&json!({"type": "object", "properties": {"n": {"type": "integer", "minimum": "one"}}}));
+    assert_eq!(report.format, SchemaFormat::JsonSchema);
+    assert!(!report.valid);
+    assert!(report.error.is_some());
+}
+
+#[test]
+fn test_report_serializes() {
+    let report = check(&json!({"name": {"type": "string"}}));
+    assert_eq!(
+        serde_json::to_value(&report).unwrap(),
+        json!({"format": "async-validator", "valid": true, "warnings": [], "unknown_keywords": []})
+    );
+}
@@ -0,0 +1,68 @@
+//! CLI tests for link-validator (requires the `cli` feature)
+
+#![cfg(feature = "cli")]
+
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+fn write_temp(name: &str, value: &Value) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("link-validator-cli-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, value.to_string()).unwrap();
+    path
+}
+
+fn run(args: &[&str]) -> (i32, Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_link-validator"))
+        .args(args)
+        .output()
+        .expect("Failed to run CLI");
+    let stdout: Value = serde_json::from_slice(&output.stdout).expect("Output is not JSON");
+    (output.status.code().unwrap(), stdout)
+}
+
+#[test]
+fn test_cli_convert_and_check() {
+    let rules = write_temp("rules.json", &json!({
+        "username": {"type": "string", "required": true, "min": 3}
+    }));
+    let rules = rules.to_str().unwrap();
+
+    let (code, output) = run(&["convert", rules]);
+    assert_eq!(code, 0);
+    assert_eq!(output["format"], "async-validator");
+    assert_eq!(output["schema"]["properties"]["username"]["minLength"], 3);
+
+    let (code, output) = run(&["check", rules]);
+    assert_eq!(code, 0);
+    assert_eq!(output["valid"], true);
+
+    let invalid = write_temp("invalid.json", &json!("not a schema"));
+    let (code, output) = run(&["check", invalid.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert_eq!(output["valid"], false);
+}
+
+#[test]
+fn test_cli_validate_exit_codes() {
+    let schema = write_temp("schema.json", &json!({
+        "email": {"type": "email", "required": true}
+    }));
+    let good = write_temp("good.json", &json!({"email": "john@example.com"}));
+    let bad = write_temp("bad.json", &json!({"email": "invalid-email"}));
+    let schema = schema.to_str().unwrap();
+
+    let (code, output) = run(&["validate", "--schema", schema, "--data", good.to_str().unwrap()]);
+    assert_eq!(code, 0);
+    assert_eq!(output["valid"], true);
+
+    let (code, output) = run(&["validate", "--schema", schema, "--data", bad.to_str().unwrap()]);
+    assert_eq!(code, 1);
+    assert_eq!(output["errors"][0]["field"], "email");
+
+    let (code, _) = run(&["validate", "--schema", schema, "--data", "/nonexistent/data.json"]);
+    assert_eq!(code, 2);
+}
@@ -0,0 +1,61 @@
+//! Client policy tests for link-validator
+
+use link_validator::{ClientPolicy, LinkValidator, ValidateOptions, ValidationError};
+use serde_json::{json, Value};
+
+fn validator() -> LinkValidator {
+    let schema = json!({
+        "name": {"type": "string", "required": true},
+        "phone": {"type": "string", "required": true},
+        "address": {
+            "type": "object",
+            "fields": {
+                "zip": {"type": "string", "len": 6}
+            }
+        }
+    });
+    let policy = ClientPolicy::new()
+        .downgrade_below("2.0.0", ["/phone"])
+        .downgrade_below("1.5", ["/address"]);
+    LinkValidator::new(&schema).unwrap().with_client_policy(policy)
+}
+
+fn validate(validator: &LinkValidator, data: &Value, version: &str) -> (usize, usize) {
+    let result = validator.validate_with(data, &ValidateOptions::new().client_version(version));
+    assert_eq!(result.is_valid, result.errors.as_array().unwrap().is_empty());
+    (result.errors.as_array().unwrap().len(), result.warnings.as_array().unwrap().len())
+}
+
+#[test]
+fn test_downgrade_by_client_version() {
+    let validator = validator();
+    let data = json!({"name": "john", "address": {"zip": "123"}});
+
+    assert_eq!(validate(&validator, &data, "1.4.9"), (0, 2));
+    assert_eq!(validate(&validator, &data, "v1.9.0-beta.1"), (1, 1));
+    assert_eq!(validate(&validator, &data, "1.10"), (1, 1));
+    assert_eq!(validate(&validator, &data, "2.0.0"), (2, 0));
+    // 无法解析的版本号不降级
+    assert_eq!(validate(&validator, &data, "unknown"), (2, 0));
+
+    // 未指定客户端版本时不降级
+    let result = validator.validate(&data);
+    assert_eq!(result.errors.as_array().unwrap().len(), 2);
+    assert!(result.warnings.as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_downgrade_matches_whole_segments() {
+    let schema = json!({
+        "phone": {"type": "string"},
+        "phoneExt": {"type": "string"}
+    });
+    let mut validator = LinkValidator::new(&schema).unwrap();
+    validator.register_document_validator(|_: &Value| vec![ValidationError::new("/phone", "Phone is blocked")]);
+    let validator = validator.with_client_policy(ClientPolicy::new().downgrade_below("3", ["/phone"]));
+
+    let options = ValidateOptions::new().client_version("2").short_circuit(false);
+    let result = validator.validate_with(&json!({"phone": "1", "phoneExt": 2}), &options);
+    assert_eq!(result.errors[0]["field"], "phoneExt");
+    assert_eq!(result.warnings[0]["field"], "phone");
+}
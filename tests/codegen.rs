@@ -0,0 +1,90 @@
+//! Code generation tests for link-validator
+
+use link_validator::{CodegenOptions, LinkValidator};
+use serde_json::json;
+
+#[test]
+fn test_generate_structs_from_rules() {
+    let rules = json!({
+        "userName": {"type": "string", "required": true},
+        "age": {"type": "integer"},
+        "score": {"type": "number"},
+        "active": {"type": "boolean", "required": true},
+        "type": {"type": "string"},
+        "role": {"type": "enum", "enum": ["admin", "read-only"], "required": true},
+        "address": {
+            "type": "object",
+            "fields": {"zipCode": {"type": "string", "required": true}}
+        },
+        "tags": {"type": "array", "fields": {"label": {"type": "string"}}}
+    });
+    let validator = LinkValidator::new(&rules).unwrap().with_name("user-profile");
+    let code = validator.generate_rust_types(&CodegenOptions::new().derive("PartialEq"));
+
+    assert!(code.starts_with("// Generated by link-validator. Do not edit."));
+    assert!(code.contains("#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]\npub struct UserProfile {"), "{}", code);
+    assert!(code.contains("    #[serde(rename = \"userName\")]\n    pub user_name: String,"), "{}", code);
+    assert!(code.contains("    #[serde(default, skip_serializing_if = \"Option::is_none\")]\n    pub age: Option<i64>,"), "{}", code);
+    assert!(code.contains("pub score: Option<f64>,"));
+    assert!(code.contains("pub active: bool,"));
+    assert!(code.contains("pub r#type: Option<String>,"), "{}", code);
+    assert!(code.contains("pub role: UserProfileRole,"), "{}", code);
+    assert!(code.contains("pub enum UserProfileRole {\n    #[serde(rename = \"admin\")]\n    Admin,\n    #[serde(rename = \"read-only\")]\n    ReadOnly,\n}"), "{}", code);
+    assert!(code.contains("pub address: Option<UserProfileAddress>,"));
+    assert!(code.contains("pub struct UserProfileAddress {\n    #[serde(rename = \"zipCode\")]\n    pub zip_code: String,\n}"), "{}", code);
+    assert!(code.contains("pub tags: Option<Vec<UserProfileTagsItem>>,"), "{}", code);
+
+    // 顶层结构体排在最前面
+    assert!(code.find("pub struct UserProfile {").unwrap() < code.find("pub struct UserProfileAddress").unwrap());
+}
+
+#[test]
+fn test_generate_from_json_schema() {
+    let schema = json!({
+        "type": "object",
+        "additionalProperties": false,
+        "required": ["owner"],
+        "properties": {
+            "owner": {"$ref": "#/definitions/Person", "description": "Account owner"},
+            "nickname": {"type": ["string", "null"]},
+            "labels": {"type": "object", "additionalProperties": {"type": "integer"}},
+            "extra": {}
+        },
+        "definitions": {
+            "Person": {"type": "object", "properties": {"name": {"type": "string"}}}
+        }
+    });
+    let code = LinkValidator::new(&schema).unwrap().generate_rust_types(&CodegenOptions::new().root_name("account"));
+
+    assert!(code.contains("#[serde(deny_unknown_fields)]\npub struct Account {"), "{}", code);
+    assert!(code.contains("    /// Account owner\n    pub owner: Person,"), "{}", code);
+    assert!(code.contains("pub nickname: Option<String>,"), "{}", code);
+    assert!(code.contains("pub labels: Option<std::collections::BTreeMap<String, i64>>,"), "{}", code);
+    assert!(code.contains("pub extra: Option<serde_json::Value>,"), "{}", code);
+    assert!(code.contains("pub struct Person {"), "{}", code);
+}
+
+#[test]
+fn test_generate_tagged_enum_for_items_by() {
+    let rules = json!({
+        "blocks": {
+            "type": "array",
+            "required": true,
+            "fields": {"id": {"type": "string", "required": true}},
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "text": {"body": {"type": "string", "required": true}},
+                    "image": {"src": {"type": "string", "required": true}}
+                }
+            }
+        }
+    });
+    let code = LinkValidator::new(&rules).unwrap().generate_rust_types(&CodegenOptions::new().root_name("Page"));
+
+    assert!(code.contains("pub blocks: Vec<PageBlocksItem>,"), "{}", code);
+    assert!(code.contains("#[serde(tag = \"kind\")]\npub enum PageBlocksItem {"), "{}", code);
+    assert!(code.contains("    #[serde(rename = \"text\")]\n    Text(PageBlocksItemText),"), "{}", code);
+    assert!(code.contains("pub struct PageBlocksItemText {\n    pub body: String,\n    pub id: String,\n}"), "{}", code);
+    assert!(!code.contains("pub kind:"), "{}", code);
+}
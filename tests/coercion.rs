@@ -0,0 +1,147 @@
+//! Coercion option tests for link-validator
+
+use link_validator::{BooleanTokens, CoercionOptions, CompileOptions, LinkValidator, NumberFormat};
+use serde_json::json;
+
+#[test]
+fn test_decimal_comma_number_format() {
+    let schema = json!({
+        "price": {"type": "number", "required": true, "min": 0},
+        "quantity": {"type": "integer", "required": true}
+    });
+
+    let options = CompileOptions::new().number_format(NumberFormat::DecimalComma);
+    let validator = LinkValidator::with_options(&schema, &options).expect("Compilation failed");
+
+    let (value, result) = validator.validate_and_transform(&json!({
+        "price": "1.234,56",
+        "quantity": "12.000"
+    }));
+    assert!(result.is_valid);
+    assert_eq!(value["price"], json!(1234.56));
+    assert_eq!(value["quantity"], json!(12000));
+}
+
+#[test]
+fn test_decimal_point_number_format_json_schema() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "amounts": {"type": "array", "items": {"type": "number"}}
+        }
+    });
+
+    let options = CompileOptions::new().number_format(NumberFormat::DecimalPoint);
+    let validator = LinkValidator::with_options(&schema, &options).expect("Compilation failed");
+
+    let (value, result) = validator.validate_and_transform(&json!({
+        "amounts": ["1,234.56", "-7", "1 000 000", 3]
+    }));
+    assert!(result.is_valid);
+    assert_eq!(value["amounts"], json!([1234.56, -7, 1000000, 3]));
+}
+
+#[test]
+fn test_malformed_numbers_are_left_untouched() {
+    let schema = json!({
+        "price": {"type": "number", "required": true}
+    });
+
+    let options = CompileOptions::new().number_format(NumberFormat::DecimalPoint);
+    let validator = LinkValidator::with_options(&schema, &options).expect("Compilation failed");
+
+    // 千位分组错误或包含非法字符时不做转换，仍然按类型报错
+    for input in ["1,5", "12abc", "1e5", ""] {
+        let result = validator.validate(&json!({"price": input}));
+        assert!(!result.is_valid, "{} should not be coerced", input);
+    }
+
+    // 未启用转换时字符串不被接受
+    let strict = LinkValidator::new(&schema).expect("Compilation failed");
+    assert!(!strict.validate(&json!({"price": "1,234.56"})).is_valid);
+}
+
+#[test]
+fn test_boolean_tokens_default_table() {
+    let schema = json!({
+        "subscribe": {"type": "boolean", "required": true},
+        "agree": {"type": "boolean", "required": true},
+        "remember": {"type": "boolean"}
+    });
+
+    let options = CompileOptions::new().boolean_tokens(BooleanTokens::default());
+    let validator = LinkValidator::with_options(&schema, &options).expect("Compilation failed");
+
+    let (value, result) = validator.validate_and_transform(&json!({
+        "subscribe": "Yes",
+        "agree": "0",
+        "remember": "on"
+    }));
+    assert!(result.is_valid);
+    assert_eq!(value, json!({"subscribe": true, "agree": false, "remember": true}));
+
+    // 不在转换表中的字符串仍然报类型错误
+    assert!(!validator.validate(&json!({"subscribe": "maybe", "agree": true})).is_valid);
+}
+
+#[test]
+fn test_boolean_tokens_custom_table() {
+    let schema = json!({
+        "active": {"type": "boolean", "required": true}
+    });
+
+    let tokens = BooleanTokens::new(["是", "Y"], ["否", "N"]);
+    let options = CompileOptions::new().boolean_tokens(tokens);
+    let validator = LinkValidator::with_options(&schema, &options).expect("Compilation failed");
+
+    assert!(validator.validate(&json!({"active": "是"})).is_valid);
+    assert!(validator.validate(&json!({"active": "n"})).is_valid);
+    // 自定义转换表替换默认值
+    assert!(!validator.validate(&json!({"active": "yes"})).is_valid);
+}
+
+#[test]
+fn test_form_coercion_options() {
+    let schema = json!({
+        "age": {"type": "integer", "required": true, "min": 18},
+        "price": {"type": "number"},
+        "subscribe": {"type": "boolean"},
+        "code": {"type": "string"}
+    });
+    let coercion = CoercionOptions::new().coerce_numbers(true).coerce_booleans(true);
+    let validator = LinkValidator::with_options(&schema, &CompileOptions::new().coercion(coercion)).unwrap();
+
+    let (value, result) = validator.validate_and_transform(&json!({
+        "age": " 42 ",
+        "price": "-1.5e2",
+        "subscribe": "on",
+        "code": "007"
+    }));
+    assert!(result.is_valid);
+    assert_eq!(value, json!({"age": 42, "price": -150.0, "subscribe": true, "code": "007"}));
+
+    assert!(validator.validate(&json!({"age": "42"})).is_valid);
+    for age in ["17", "42.5", "1,000", "inf", "NaN", "0x10", ""] {
+        assert!(!validator.validate(&json!({"age": age})).is_valid, "{} should be rejected", age);
+    }
+}
+
+#[test]
+fn test_coercion_keeps_explicit_formats() {
+    let schema = json!({"price": {"type": "number"}, "flag": {"type": "boolean"}});
+    let options = CompileOptions::new()
+        .number_format(NumberFormat::DecimalComma)
+        .boolean_tokens(BooleanTokens::new(["ja"], ["nein"]))
+        .coercion(CoercionOptions::new().coerce_numbers(true).coerce_booleans(true));
+    let validator = LinkValidator::with_options(&schema, &options).unwrap();
+
+    let (value, _) = validator.validate_and_transform(&json!({"price": "1.234,5", "flag": "ja"}));
+    assert_eq!(value, json!({"price": 1234.5, "flag": true}));
+    assert!(!validator.validate(&json!({"flag": "on"})).is_valid);
+
+    // 只启用数字转换时布尔字段保持严格
+    let numbers_only = CompileOptions::new().coercion(CoercionOptions::new().coerce_numbers(true));
+    let validator = LinkValidator::with_options(&schema, &numbers_only).unwrap();
+    assert!(validator.validate(&json!({"price": "3"})).is_valid);
+    assert!(!validator.validate(&json!({"flag": "true"})).is_valid);
+}
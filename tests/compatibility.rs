@@ -0,0 +1,123 @@
+//! Backward-compatibility tests for link-validator
+
+use link_validator::{is_backward_compatible, CompatibilityReport};
+use serde_json::json;
+
+fn reasons(report: &CompatibilityReport) -> Vec<String> {
+    report.issues.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_identical_and_relaxed_rules_are_compatible() {
+    let old = json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 20},
+        "age": {"type": "integer", "min": 0, "max": 150},
+        "role": {"type": "enum", "enum": ["admin", "user"]}
+    });
+    assert!(is_backward_compatible(&old, &old).unwrap().is_compatible());
+
+    let relaxed = json!({
+        "name": {"type": "string", "min": 1, "max": 50},
+        "age": {"type": "number", "min": -1},
+        "role": {"type": "enum", "enum": ["admin", "user", "guest"]}
+    });
+    let report = is_backward_compatible(&old, &relaxed).unwrap();
+    assert!(report.is_compatible(), "{:?}", reasons(&report));
+    assert!(report.issues.is_empty());
+}
+
+#[test]
+fn test_tightened_rules_are_reported_per_keyword() {
+    let old = json!({
+        "name": {"type": "string", "max": 20},
+        "age": {"type": "number", "min": 0},
+        "role": {"type": "enum", "enum": ["admin", "user"]}
+    });
+    let new = json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 10, "pattern": "^[a-z]+$"},
+        "age": {"type": "integer", "min": 18},
+        "role": {"type": "enum", "enum": ["admin"]}
+    });
+    let report = is_backward_compatible(&old, &new).unwrap();
+
+    assert!(!report.compatible);
+    assert_eq!(
+        reasons(&report),
+        vec![
+            "required: field 'name' became required",
+            "/properties/age type: type 'number' no longer accepted",
+            "/properties/age minimum: minimum tightened from 0 to 18",
+            "/properties/name minLength: minLength 2 added",
+            "/properties/name maxLength: maxLength lowered from 20 to 10",
+            "/properties/name pattern: pattern \"^[a-z]+$\" added",
+            "/properties/role enum: value \"user\" removed from enum",
+        ]
+    );
+}
+
+#[test]
+fn test_json_schema_bounds_and_properties() {
+    let old = json!({
+        "type": "object",
+        "properties": {
+            "score": {"type": "number", "minimum": 0, "maximum": 1},
+            "tags": {"type": "array", "items": {"type": "string"}},
+            "legacy": {"type": "string"}
+        }
+    });
+    let new = json!({
+        "type": "object",
+        "properties": {
+            "score": {"type": "number", "exclusiveMinimum": 0, "maximum": 1},
+            "tags": {"type": "array", "items": {"type": "string", "maxLength": 5}, "uniqueItems": true},
+            "extra": {"type": "integer"}
+        },
+        "additionalProperties": false
+    });
+    let report = is_backward_compatible(&old, &new).unwrap();
+
+    assert_eq!(
+        reasons(&report),
+        vec![
+            "/properties/extra type: type restricted to integer",
+            "/properties/score exclusiveMinimum: minimum 0 became exclusive",
+            "/properties/tags uniqueItems: items must now be unique",
+            "/properties/tags/items maxLength: maxLength 5 added",
+            "additionalProperties: property 'legacy' removed while additional properties are not allowed",
+            "/additionalProperties false: schema no longer accepts any value",
+        ]
+    );
+}
+
+#[test]
+fn test_closed_object_allows_new_properties() {
+    let old = json!({"type": "object", "properties": {"a": {"type": "string"}}, "additionalProperties": false});
+    let new = json!({
+        "type": "object",
+        "properties": {"a": {"type": "string"}, "b": {"type": "integer"}},
+        "additionalProperties": false
+    });
+    assert!(is_backward_compatible(&old, &new).unwrap().is_compatible());
+}
+
+#[test]
+fn test_unverifiable_changes_are_incompatible() {
+    let old = json!({"type": "object", "properties": {"v": {"anyOf": [{"type": "string"}]}}});
+    let new = json!({"type": "object", "properties": {"v": {"anyOf": [{"type": "string"}, {"type": "null"}]}}});
+    let report = is_backward_compatible(&old, &new).unwrap();
+
+    assert_eq!(reasons(&report), vec!["/properties/v anyOf: changes to anyOf cannot be verified"]);
+}
+
+#[test]
+fn test_report_serializes() {
+    let report = is_backward_compatible(&json!({"a": {"type": "string"}}), &json!({"a": {"type": "number"}})).unwrap();
+    let value = serde_json::to_value(&report).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "compatible": false,
+            "issues": [{"path": "/properties/a", "keyword": "type", "reason": "type 'string' no longer accepted"}]
+        })
+    );
+}
@@ -0,0 +1,66 @@
+//! Concurrency tests for link-validator
+
+use link_validator::{LinkValidator, ValidationError};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+fn assert_send_sync<T: Send + Sync + Clone + 'static>() {}
+
+#[test]
+fn test_validator_is_shareable() {
+    assert_send_sync::<LinkValidator>();
+}
+
+#[test]
+fn test_concurrent_validate() {
+    let schema = json!({
+        "id": {"type": "integer", "required": true, "min": 0},
+        "name": {"type": "string", "required": true, "max": 8}
+    });
+    let validator = Arc::new(LinkValidator::new(&schema).unwrap().with_result_cache(64, Duration::from_secs(60)));
+
+    let handles: Vec<_> = (0..8)
+        .map(|t| {
+            let validator = validator.clone();
+            thread::spawn(move || {
+                for i in 0..200 {
+                    let valid = json!({"id": i % 16, "name": format!("user{}", t)});
+                    let invalid = json!({"id": -1, "name": "much too long"});
+                    assert!(validator.validate(&valid).is_valid);
+                    assert_eq!(validator.validate(&invalid).errors.as_array().unwrap().len(), 2);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert!(validator.result_cache_len() <= 64);
+}
+
+#[test]
+fn test_clones_share_compiled_state() {
+    let schema = json!({"total": {"type": "number", "required": true}});
+    let base = LinkValidator::new(&schema).unwrap().with_result_cache(16, Duration::from_secs(60));
+
+    let data = json!({"total": 5});
+    let shared = base.clone();
+    assert!(shared.validate(&data).is_valid);
+    // 结果缓存在副本之间共享
+    assert_eq!(base.result_cache_len(), 1);
+
+    // 在副本上注册文档级验证器只影响该副本，也不会使用其他副本缓存的结果
+    let mut strict = base.clone();
+    strict.register_document_validator(|data: &Value| {
+        if data["total"].as_f64().unwrap_or(0.0) > 3.0 {
+            vec![ValidationError::new("/total", "total too large")]
+        } else {
+            Vec::new()
+        }
+    });
+    assert!(!strict.validate(&data).is_valid);
+    assert!(base.validate(&data).is_valid);
+    assert_eq!(base.json_schema(), strict.json_schema());
+}
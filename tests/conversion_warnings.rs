@@ -0,0 +1,72 @@
+//! Structured conversion warning tests for link-validator
+
+use link_validator::{CompileOptions, ConversionWarning, LinkValidator, Severity};
+use serde_json::json;
+
+fn rules() -> serde_json::Value {
+    json!({
+        "name": {"type": "string", "trigger": "blur"},
+        "address": {
+            "type": "object",
+            "fields": {"city": {"type": "string", "validator": "checkCity"}}
+        },
+        "lines": {"type": "array", "fields": {"sku": {"type": "string", "whitespace": true}}}
+    })
+}
+
+fn find<'a>(validator: &'a LinkValidator, rule: &str) -> &'a ConversionWarning {
+    validator.warnings().iter().find(|w| w.rule.as_deref() == Some(rule)).unwrap()
+}
+
+#[test]
+fn test_warning_fields() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+
+    let trigger = find(&validator, "trigger");
+    assert_eq!(trigger.field_path, "name");
+    assert_eq!(trigger.severity, Severity::Info);
+    assert_eq!(trigger.message, "Field 'name': trigger option not supported");
+    assert_eq!(trigger.to_string(), trigger.message);
+
+    let validator_rule = find(&validator, "validator");
+    assert_eq!(validator_rule.field_path, "address.city");
+    assert_eq!(validator_rule.severity, Severity::Warning);
+
+    assert_eq!(find(&validator, "whitespace").field_path, "lines[].sku");
+}
+
+#[test]
+fn test_severity_overrides() {
+    let options = CompileOptions::new()
+        .warning_severity("whitespace", Severity::Info)
+        .warning_severity("trigger", Severity::Warning);
+    let validator = LinkValidator::with_options(&rules(), &options).unwrap();
+    assert_eq!(find(&validator, "whitespace").severity, Severity::Info);
+    assert_eq!(find(&validator, "trigger").severity, Severity::Warning);
+}
+
+#[test]
+fn test_severity_error_fails_compilation() {
+    let options = CompileOptions::new().warning_severity("validator", Severity::Error);
+    let error = LinkValidator::with_options(&rules(), &options).unwrap_err();
+    assert_eq!(error, "Conversion warnings treated as errors: Field 'city': validator function not supported");
+
+    // JSON Schema 中被忽略的关键字以关键字为规则键
+    let options = CompileOptions::new().warning_severity("minLenght", Severity::Error);
+    let schema = json!({"type": "object", "properties": {"name": {"type": "string", "minLenght": 3}}});
+    assert!(LinkValidator::with_options(&schema, &options).is_err());
+}
+
+#[test]
+fn test_warning_serialization() {
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "trigger": "blur"}})).unwrap();
+    assert_eq!(
+        serde_json::to_value(validator.warnings()).unwrap(),
+        json!([{
+            "field_path": "name",
+            "rule": "trigger",
+            "severity": "info",
+            "message": "Field 'name': trigger option not supported"
+        }])
+    );
+}
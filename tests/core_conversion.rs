@@ -0,0 +1,69 @@
+//! no_std core conversion tests for link-validator
+
+use link_validator::LinkValidator;
+use link_validator_core::{Case, RuleTransform, Severity};
+use serde_json::json;
+
+#[test]
+fn test_schema_matches_validator() {
+    let rules = json!({
+        "name": {"type": "string", "required": true, "min": 2},
+        "tags": {"type": "array", "defaultField": {"type": "string"}},
+        "address": {"type": "object", "fields": {"city": {"type": "string", "required": true}}},
+    });
+    let conversion = link_validator_core::convert(&rules).unwrap();
+    let validator = LinkValidator::new(&rules).unwrap();
+    assert_eq!(&conversion.schema, validator.json_schema());
+}
+
+#[test]
+fn test_warnings_and_transforms() {
+    let conversion = link_validator_core::convert(&json!({
+        "email": {"type": "email", "trim": true, "case": "lower", "trigger": "blur"},
+        "code": {"type": "string", "validator": "custom"},
+    }))
+    .unwrap();
+
+    let mut rules: Vec<_> = conversion.warnings.iter().map(|w| (w.field_path.as_str(), w.rule.as_deref(), w.severity)).collect();
+    rules.sort();
+    assert_eq!(rules, [("code", Some("validator"), Severity::Warning), ("email", Some("trigger"), Severity::Info)]);
+    assert_eq!(conversion.transforms.properties["email"].ops, [RuleTransform::Trim, RuleTransform::Case(Case::Lower)]);
+}
+
+#[test]
+fn test_pattern_check() {
+    let rules = json!({"code": {"type": "string", "pattern": "("}});
+    assert!(link_validator_core::convert(&rules).is_ok());
+
+    let error = link_validator_core::convert_with(&rules, &|pattern| Err(format!("bad pattern {}", pattern))).unwrap_err();
+    assert!(error.contains("bad pattern ("), "{}", error);
+    assert!(LinkValidator::new(&rules).is_err());
+}
+
+#[test]
+fn test_union_root_and_definitions() {
+    let union = link_validator_core::convert(&json!({
+        "discriminator": "kind",
+        "variants": {"a": {"x": {"type": "string"}}, "b": {"y": {"type": "number"}}},
+    }))
+    .unwrap();
+    assert!(union.schema.get("oneOf").is_some());
+
+    let error = link_validator_core::convert(&json!({
+        "defs": {"node": {"next": {"$use": "missing"}}},
+        "root": {"$use": "node"},
+    }))
+    .unwrap_err();
+    assert!(error.contains("Unknown definition 'missing'"), "{}", error);
+}
+
+#[test]
+fn test_convert_value() {
+    let rule = json!({"type": "string", "required": true});
+    let conversion = link_validator_core::convert_value_with(&rule, &|_| Ok(())).unwrap();
+    assert_eq!(conversion.schema, json!({"type": "string"}));
+
+    // convert 不把 `$value` 字段当作根节点规则
+    let conversion = link_validator_core::convert(&json!({"$value": rule})).unwrap();
+    assert_eq!(conversion.schema["required"], json!(["$value"]));
+}
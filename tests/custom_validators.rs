@@ -0,0 +1,194 @@
+//! Custom validator tests for link-validator
+
+use link_validator::{LinkValidator, Phase, ValidateOptions, ValidationError};
+use serde_json::{json, Value};
+
+fn order_validator() -> LinkValidator {
+    let schema = json!({
+        "total": {"type": "number", "required": true},
+        "lines": {
+            "type": "array",
+            "required": true,
+            "fields": {
+                "amount": {"type": "number", "required": true}
+            }
+        }
+    });
+
+    let mut validator = LinkValidator::new(&schema).expect("Compilation failed");
+    validator.register_document_validator(|data: &Value| {
+        let sum: f64 = data["lines"]
+            .as_array()
+            .map(|lines| lines.iter().filter_map(|l| l["amount"].as_f64()).sum())
+            .unwrap_or_default();
+        if data["total"].as_f64() == Some(sum) {
+            vec![]
+        } else {
+            vec![ValidationError::new("/total", "total must equal the sum of line amounts")]
+        }
+    });
+    validator
+}
+
+#[test]
+fn test_document_validator_reports_through_result() {
+    let validator = order_validator();
+
+    let valid = json!({"total": 30, "lines": [{"amount": 10}, {"amount": 20}]});
+    assert!(validator.validate(&valid).is_valid);
+
+    let invalid = json!({"total": 31, "lines": [{"amount": 10}, {"amount": 20}]});
+    let result = validator.validate(&invalid);
+    assert!(!result.is_valid);
+    assert_eq!(result.errors, json!([{
+        "message": "total must equal the sum of line amounts",
+        "field": "total",
+        "code": "custom"
+    }]));
+}
+
+#[test]
+fn test_document_validator_skipped_when_schema_fails() {
+    let validator = order_validator();
+
+    // schema 验证失败时不执行文档级验证
+    let data = json!({"total": 31, "lines": [{"amount": "10"}]});
+    let result = validator.validate(&data);
+    assert!(!result.is_valid);
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["field"], "lines.0.amount");
+}
+
+#[test]
+fn test_multiple_document_validators_respect_options() {
+    let schema = json!({
+        "start": {"type": "integer", "required": true},
+        "end": {"type": "integer", "required": true}
+    });
+
+    let mut validator = LinkValidator::new(&schema).expect("Compilation failed");
+    validator.register_document_validator(|data: &Value| {
+        if data["start"].as_i64() > data["end"].as_i64() {
+            vec![ValidationError::new("/start", "start must not be after end")]
+        } else {
+            vec![]
+        }
+    });
+    validator.register_document_validator(|data: &Value| {
+        if data["start"] == data["end"] {
+            vec![]
+        } else {
+            vec![ValidationError::new("", "range must be a single day")]
+        }
+    });
+
+    let data = json!({"start": 5, "end": 1});
+    assert_eq!(validator.validate(&data).errors.as_array().unwrap().len(), 2);
+
+    let result = validator.validate_with(&data, &ValidateOptions::new().fail_fast(true));
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_phase_short_circuit_control() {
+    let validator = order_validator();
+    let data = json!({"total": 31, "lines": [{"amount": 10}, {"amount": "20"}]});
+
+    // 默认短路：schema 失败后不执行文档级验证
+    assert_eq!(validator.validate(&data).errors.as_array().unwrap().len(), 1);
+
+    // 关闭短路后文档级验证同样执行，错误按阶段顺序汇总
+    let options = ValidateOptions::new().short_circuit(false);
+    let result = validator.validate_with(&data, &options);
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["field"], "lines.1.amount");
+    assert_eq!(errors[1]["field"], "total");
+}
+
+#[test]
+fn test_skip_phases() {
+    let validator = order_validator();
+    let data = json!({"total": 31, "lines": [{"amount": 10}, {"amount": 20}]});
+
+    let options = ValidateOptions::new().skip_phase(Phase::Document);
+    assert!(validator.validate_with(&data, &options).is_valid);
+
+    let structurally_invalid = json!({"total": "31"});
+    let options = ValidateOptions::new()
+        .skip_phase(Phase::Schema)
+        .skip_phase(Phase::Document);
+    assert!(validator.validate_with(&structurally_invalid, &options).is_valid);
+}
+
+#[test]
+fn test_nested_validate_inside_document_validator() {
+    let item = LinkValidator::new(&json!({"sku": {"type": "string", "required": true}})).unwrap();
+
+    let mut validator = LinkValidator::new(&json!({"item": {"type": "object", "required": true}})).unwrap();
+    validator.register_document_validator(move |data: &Value| {
+        let result = item.validate(&data["item"]);
+        if result.is_valid {
+            Vec::new()
+        } else {
+            vec![ValidationError::new("/item", format!("invalid item: {}", result.errors[0]["message"]))]
+        }
+    });
+
+    for _ in 0..3 {
+        assert!(validator.validate(&json!({"item": {"sku": "A-1"}})).is_valid);
+
+        let result = validator.validate(&json!({"item": {}}));
+        assert!(!result.is_valid);
+        assert_eq!(result.errors.as_array().unwrap().len(), 1);
+        assert_eq!(result.errors[0]["field"], "item");
+
+        let result = validator.validate(&json!({}));
+        assert_eq!(result.errors.as_array().unwrap().len(), 1);
+    }
+}
+
+struct Account {
+    premium: bool,
+}
+
+fn cart_validator() -> LinkValidator {
+    let mut validator = LinkValidator::new(&json!({"lines": {"type": "array", "required": true}})).unwrap();
+    validator.register_context_validator(|data: &Value, account: &Account| {
+        let count = data["lines"].as_array().map_or(0, Vec::len);
+        if count > 5 && !account.premium {
+            vec![ValidationError::new("/lines", "at most 5 lines unless premium").with_code("lines.premium")]
+        } else {
+            vec![]
+        }
+    });
+    validator
+}
+
+#[test]
+fn test_context_validator() {
+    let validator = cart_validator();
+    let data = json!({"lines": [1, 2, 3, 4, 5, 6]});
+
+    assert!(validator.validate_with_context(&data, &Account { premium: true }).is_valid);
+    let result = validator.validate_with_context(&data, &Account { premium: false });
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "lines");
+    assert_eq!(result.errors[0]["code"], "lines.premium");
+
+    assert!(validator.validate_with_context(&json!({"lines": [1]}), &Account { premium: false }).is_valid);
+}
+
+#[test]
+fn test_context_validator_requires_matching_context() {
+    let validator = cart_validator();
+    let data = json!({"lines": [1, 2, 3, 4, 5, 6]});
+
+    // 没有上下文或上下文类型不同时不执行
+    assert!(validator.validate(&data).is_valid);
+    assert!(validator.validate_with_context(&data, &"premium").is_valid);
+
+    // schema 阶段照常执行
+    assert!(!validator.validate_with_context(&json!({}), &Account { premium: true }).is_valid);
+}
@@ -0,0 +1,89 @@
+//! Date rule tests for link-validator
+
+use link_validator::{LinkValidator, SampleOptions};
+use serde_json::json;
+
+#[test]
+fn test_date_only_format() {
+    let validator = LinkValidator::new(&json!({
+        "birthday": {"type": "date", "format": "date", "required": true}
+    }))
+    .unwrap();
+
+    assert_eq!(validator.json_schema()["properties"]["birthday"]["format"], json!("date"));
+    assert!(validator.validate(&json!({"birthday": "2000-02-29"})).is_valid);
+    assert!(!validator.validate(&json!({"birthday": "2000-02-29T10:00:00Z"})).is_valid);
+    assert!(!validator.validate(&json!({"birthday": "2001-02-29"})).is_valid);
+
+    // 默认仍为日期时间
+    let validator = LinkValidator::new(&json!({"at": {"type": "date"}})).unwrap();
+    assert_eq!(validator.json_schema()["properties"]["at"]["format"], json!("date-time"));
+}
+
+#[test]
+fn test_min_and_max_date() {
+    let validator = LinkValidator::new(&json!({
+        "birthday": {"type": "date", "format": "date", "minDate": "1900-01-01", "maxDate": "2010-12-31"}
+    }))
+    .unwrap();
+    assert_eq!(validator.json_schema()["properties"]["birthday"]["formatMinimum"], json!("1900-01-01"));
+    assert_eq!(validator.json_schema()["properties"]["birthday"]["formatMaximum"], json!("2010-12-31"));
+
+    assert!(validator.validate(&json!({"birthday": "1990-05-17"})).is_valid);
+    assert!(validator.validate(&json!({"birthday": "2010-12-31"})).is_valid);
+    assert!(validator.validate(&json!({})).is_valid);
+
+    let result = validator.validate(&json!({"birthday": "1899-12-31"}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], json!("birthday"));
+    assert_eq!(result.errors[0]["message"], json!("\"1899-12-31\" is earlier than the minimum date 1900-01-01"));
+
+    let result = validator.validate(&json!({"birthday": "2011-01-01"}));
+    assert_eq!(result.errors[0]["message"], json!("\"2011-01-01\" is later than the maximum date 2010-12-31"));
+}
+
+#[test]
+fn test_date_time_bounds_with_offsets() {
+    let validator = LinkValidator::new(&json!({
+        "slots": {
+            "type": "array",
+            "fields": {"start": {"type": "date", "minDate": "2024-03-01T09:00:00Z", "maxDate": "2024-03-01"}}
+        }
+    }))
+    .unwrap();
+
+    // 只有日期的上限包含当天全部时间；时区偏移换算为 UTC 后比较
+    let data = json!({"slots": [
+        {"start": "2024-03-01T09:00:00Z"},
+        {"start": "2024-03-01T23:59:59.999Z"},
+        {"start": "2024-03-01T10:30:00+01:00"}
+    ]});
+    assert!(validator.validate(&data).is_valid, "{:?}", validator.validate(&data).errors);
+
+    let data = json!({"slots": [{"start": "2024-03-01T09:30:00+01:00"}, {"start": "2024-03-02T00:00:00Z"}]});
+    let result = validator.validate(&data);
+    let fields: Vec<_> = result.errors.as_array().unwrap().iter().map(|e| e["field"].clone()).collect();
+    assert_eq!(fields, vec![json!("slots.0.start"), json!("slots.1.start")]);
+}
+
+#[test]
+fn test_invalid_date_bounds() {
+    let error = LinkValidator::new(&json!({"day": {"type": "date", "minDate": "yesterday"}})).unwrap_err();
+    assert!(error.contains("minDate must be a date"), "{}", error);
+
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "maxDate": "2024-01-01"}})).unwrap();
+    assert!(validator.warnings().iter().any(|w| w.message.contains("maxDate rule only supported for date type")));
+}
+
+#[test]
+fn test_samples_respect_date_bounds() {
+    let validator = LinkValidator::new(&json!({
+        "day": {"type": "date", "format": "date", "required": true, "minDate": "2024-02-27", "maxDate": "2024-03-02"},
+        "at": {"type": "date", "required": true, "minDate": "2030-01-01T12:00:00+08:00"}
+    }))
+    .unwrap();
+    for seed in 0..50 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(validator.validate(&sample).is_valid, "{} {:?}", sample, validator.validate(&sample).errors);
+    }
+}
@@ -0,0 +1,112 @@
+//! Recursive definition tests for link-validator
+
+use link_validator::{lint, LinkValidator, SampleOptions};
+use serde_json::json;
+
+fn thread_rules() -> serde_json::Value {
+    json!({
+        "defs": {
+            "comment": {
+                "author": {"type": "string", "required": true},
+                "text": {"type": "string", "required": true, "max": 20},
+                "replies": {"type": "array", "$use": "comment"}
+            }
+        },
+        "title": {"type": "string", "required": true},
+        "root": {"type": "object", "required": true, "$use": "comment"}
+    })
+}
+
+#[test]
+fn test_recursive_definition() {
+    let validator = LinkValidator::new(&thread_rules()).unwrap();
+
+    let schema = validator.json_schema();
+    assert_eq!(schema["properties"]["root"], json!({"$ref": "#/$defs/comment"}));
+    assert_eq!(schema["$defs"]["comment"]["properties"]["replies"]["items"], json!({"$ref": "#/$defs/comment"}));
+    assert!(schema["properties"].get("defs").is_none());
+
+    let data = json!({
+        "title": "t",
+        "root": {
+            "author": "a",
+            "text": "hello",
+            "replies": [{"author": "b", "text": "hi", "replies": [{"author": "c", "text": "yo"}]}]
+        }
+    });
+    assert!(validator.validate(&data).is_valid);
+
+    let data = json!({
+        "title": "t",
+        "root": {"author": "a", "text": "hello", "replies": [{"author": "b", "text": "hi", "replies": [{"text": "yo"}]}]}
+    });
+    let result = validator.validate(&data);
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], json!("root.replies.0.replies.0"));
+}
+
+#[test]
+fn test_use_with_nullable_and_constraints() {
+    let validator = LinkValidator::new(&json!({
+        "defs": {"node": {"value": {"type": "integer", "required": true}, "next": {"nullable": true, "$use": "node"}}},
+        "head": {"$use": "node", "required": true}
+    }))
+    .unwrap();
+
+    let next = &validator.json_schema()["$defs"]["node"]["properties"]["next"];
+    assert_eq!(next, &json!({"anyOf": [{"$ref": "#/$defs/node"}, {"type": "null"}]}));
+
+    assert!(validator.validate(&json!({"head": {"value": 1, "next": {"value": 2, "next": null}}})).is_valid);
+    assert!(!validator.validate(&json!({"head": {"value": 1, "next": {"value": "2"}}})).is_valid);
+
+    for seed in 0..20 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(validator.validate(&sample).is_valid, "{}", sample);
+    }
+}
+
+#[test]
+fn test_definition_errors() {
+    let error = LinkValidator::new(&json!({
+        "defs": {"a": {"x": {"type": "string"}}},
+        "item": {"$use": "b"}
+    }))
+    .unwrap_err();
+    assert!(error.contains("Unknown definition 'b'"), "{}", error);
+
+    // 必填的循环引用没有有限的合法数据
+    let error = LinkValidator::new(&json!({
+        "defs": {
+            "a": {"b": {"required": true, "$use": "b"}},
+            "b": {"inner": {"type": "object", "required": true, "fields": {"a": {"required": true, "$use": "a"}}}}
+        },
+        "root": {"$use": "a"}
+    }))
+    .unwrap_err();
+    assert!(error.contains("requires itself: a -> b -> a"), "{}", error);
+
+    let error = LinkValidator::new(&json!({
+        "defs": {"a": {"x": {"type": "string"}}},
+        "item": {"$use": "a", "fields": {"y": {"type": "string"}}}
+    }))
+    .unwrap_err();
+    assert!(error.contains("$use cannot be combined with fields"), "{}", error);
+}
+
+#[test]
+fn test_field_named_defs() {
+    // defs 不是字段规则映射时仍是普通字段
+    let validator = LinkValidator::new(&json!({"defs": {"type": "string", "required": true}})).unwrap();
+    assert!(validator.validate(&json!({"defs": "x"})).is_valid);
+    assert!(!validator.validate(&json!({})).is_valid);
+}
+
+#[test]
+fn test_lint_checks_definitions() {
+    let findings = lint(&json!({
+        "defs": {"comment": {"text": {"type": "string", "min": 5, "max": 1}}},
+        "root": {"$use": "comment"}
+    }));
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].path, "defs.comment.text");
+}
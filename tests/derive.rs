@@ -0,0 +1,224 @@
+//! Derive macro tests for link-validator (requires the `derive` feature)
+
+#![cfg(feature = "derive")]
+#![allow(dead_code)]
+
+use link_validator::LinkSchema;
+use serde_json::json;
+
+#[derive(LinkSchema)]
+struct Address {
+    #[rule(required)]
+    city: String,
+    #[rule(len = 6, pattern = "^[0-9]+$")]
+    zip: Option<String>,
+}
+
+#[derive(LinkSchema)]
+struct SignUp {
+    #[rule(required, min = 3, max = 20)]
+    username: String,
+    #[rule(required, type = "email")]
+    email: String,
+    #[rule(min = 0, max = 150)]
+    age: Option<u32>,
+    #[rule(enum = ["admin", "user"])]
+    role: String,
+    #[rule(nested, required)]
+    address: Address,
+    #[rule(nested)]
+    previous: Vec<Address>,
+    #[rule(rename = "type")]
+    kind: bool,
+    #[rule(skip)]
+    internal: std::time::Duration,
+}
+
+#[test]
+fn test_derive_generates_rules() {
+    assert_eq!(SignUp::rules(), json!({
+        "username": {"type": "string", "required": true, "min": 3, "max": 20},
+        "email": {"type": "email", "required": true},
+        "age": {"type": "integer", "min": 0, "max": 150},
+        "role": {"type": "string", "enum": ["admin", "user"]},
+        "address": {
+            "type": "object",
+            "required": true,
+            "fields": {
+                "city": {"type": "string", "required": true},
+                "zip": {"type": "string", "len": 6, "pattern": "^[0-9]+$"}
+            }
+        },
+        "previous": {
+            "type": "array",
+            "fields": {
+                "city": {"type": "string", "required": true},
+                "zip": {"type": "string", "len": 6, "pattern": "^[0-9]+$"}
+            }
+        },
+        "type": {"type": "boolean"}
+    }));
+}
+
+#[test]
+fn test_derive_builds_validator() {
+    let validator = SignUp::validator().expect("Compilation failed");
+
+    let valid = json!({
+        "username": "john",
+        "email": "john@example.com",
+        "role": "user",
+        "address": {"city": "Shanghai", "zip": "200000"}
+    });
+    assert!(validator.validate(&valid).is_valid);
+
+    let invalid = json!({
+        "username": "jo",
+        "email": "john@example.com",
+        "role": "root",
+        "address": {"zip": "20"}
+    });
+    let result = validator.validate(&invalid);
+    assert!(!result.is_valid);
+    assert_eq!(result.errors.as_array().unwrap().len(), 4);
+}
+
+#[derive(LinkSchema)]
+struct Booking {
+    #[rule(required, type = "date", format = "date", min_date = "2024-01-01", max_date = "2024-12-31")]
+    day: String,
+}
+
+#[test]
+fn test_derive_date_rules() {
+    assert_eq!(Booking::rules(), json!({
+        "day": {"type": "date", "required": true, "format": "date", "minDate": "2024-01-01", "maxDate": "2024-12-31"}
+    }));
+    let validator = Booking::validator().unwrap();
+    assert!(validator.validate(&json!({"day": "2024-06-01"})).is_valid);
+    assert!(!validator.validate(&json!({"day": "2025-01-01"})).is_valid);
+}
+
+#[derive(LinkSchema)]
+struct LineItem {
+    #[rule(multiple_of = 0.5, positive)]
+    price: f64,
+    #[rule(non_negative, exclusive_max = 1000)]
+    quantity: u32,
+}
+
+#[test]
+fn test_derive_numeric_rules() {
+    let rules = LineItem::rules();
+    assert_eq!(rules["price"]["multipleOf"], json!(0.5));
+    assert_eq!(rules["price"]["positive"], json!(true));
+    assert_eq!(rules["quantity"]["nonNegative"], json!(true));
+
+    let validator = LineItem::validator().unwrap();
+    assert!(validator.validate(&json!({"price": 9.5, "quantity": 3})).is_valid);
+    assert!(!validator.validate(&json!({"price": 0, "quantity": 3})).is_valid);
+    assert!(!validator.validate(&json!({"price": 1, "quantity": 1000})).is_valid);
+}
+
+#[derive(LinkSchema)]
+struct Tagged {
+    #[rule(unique)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_derive_unique_items() {
+    assert_eq!(Tagged::rules()["tags"]["unique"], json!(true));
+    let validator = Tagged::validator().unwrap();
+    assert!(validator.validate(&json!({"tags": ["a", "b"]})).is_valid);
+    assert!(!validator.validate(&json!({"tags": ["a", "a"]})).is_valid);
+}
+
+#[derive(LinkSchema)]
+struct Contact {
+    #[rule(nullable, type = "email")]
+    email: Option<String>,
+}
+
+#[test]
+fn test_derive_nullable() {
+    assert_eq!(Contact::rules()["email"]["nullable"], json!(true));
+    let validator = Contact::validator().unwrap();
+    assert!(validator.validate(&json!({"email": null})).is_valid);
+    assert!(!validator.validate(&json!({"email": "nope"})).is_valid);
+}
+
+#[derive(LinkSchema)]
+struct Envelope {
+    #[rule(required, const = "v2")]
+    version: String,
+}
+
+#[test]
+fn test_derive_const() {
+    assert_eq!(Envelope::rules()["version"]["const"], json!("v2"));
+    let validator = Envelope::validator().unwrap();
+    assert!(validator.validate(&json!({"version": "v2"})).is_valid);
+    assert!(!validator.validate(&json!({"version": "v1"})).is_valid);
+}
+
+#[derive(LinkSchema)]
+struct Credentials {
+    #[rule(required, sensitive, min = 8)]
+    password: String,
+}
+
+#[test]
+fn test_derive_sensitive() {
+    assert_eq!(Credentials::rules()["password"]["sensitive"], json!(true));
+    let validator = Credentials::validator().unwrap();
+    let result = validator.validate(&json!({"password": "hunter2"}));
+    assert_eq!(result.errors[0]["value"], "[REDACTED]");
+}
+
+#[derive(LinkSchema)]
+struct Article {
+    #[rule(required, groups = ["update"])]
+    id: Option<String>,
+    #[rule(required)]
+    title: String,
+}
+
+#[test]
+fn test_derive_groups() {
+    assert_eq!(Article::rules()["id"]["groups"], json!(["update"]));
+    let validator = Article::validator().unwrap();
+    let data = json!({"title": "hello"});
+    assert!(validator.validate(&data).is_valid);
+    assert!(validator.validate_with_group(&data, "create").is_err());
+    assert!(!validator.validate_with_group(&data, "update").unwrap().is_valid);
+}
+
+#[derive(LinkSchema)]
+struct ContactForm {
+    #[rule(required, label = "Email", placeholder = "name@example.com", order = 1)]
+    email: String,
+}
+
+#[test]
+fn test_derive_form_metadata() {
+    let rules = ContactForm::rules();
+    assert_eq!(rules["email"]["label"], json!("Email"));
+    assert_eq!(rules["email"]["order"], json!(1));
+    let info = ContactForm::validator().unwrap().schema_info();
+    assert_eq!(info.fields[0].placeholder.as_deref(), Some("name@example.com"));
+}
+
+#[derive(LinkSchema)]
+struct Documented {
+    #[rule(required, title = "Name", description = "Public display name")]
+    name: String,
+}
+
+#[test]
+fn test_derive_title_and_description() {
+    let validator = Documented::validator().unwrap();
+    let name = &validator.json_schema()["properties"]["name"];
+    assert_eq!(name["title"], "Name");
+    assert_eq!(name["description"], "Public display name");
+}
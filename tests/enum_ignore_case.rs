@@ -0,0 +1,86 @@
+//! Case-insensitive enum tests for link-validator
+
+use link_validator::{LinkValidator, SampleOptions};
+use serde_json::json;
+
+#[test]
+fn test_ignore_case_matches_any_case() {
+    let validator = LinkValidator::new(&json!({
+        "status": {"type": "enum", "enum": ["active", "inactive"], "ignoreCase": true, "required": true}
+    }))
+    .unwrap();
+    assert!(validator.warnings().is_empty());
+    assert_eq!(validator.json_schema()["properties"]["status"]["pattern"], "^(?i:active|inactive)$");
+
+    for status in ["active", "Active", "INACTIVE"] {
+        assert!(validator.validate(&json!({"status": status})).is_valid, "{}", status);
+    }
+    for status in [json!("activ"), json!("active "), json!("pending"), json!(1)] {
+        assert!(!validator.validate(&json!({"status": status})).is_valid, "{}", status);
+    }
+}
+
+#[test]
+fn test_ignore_case_escapes_values() {
+    let validator = LinkValidator::new(&json!({
+        "plan": {"type": "enum", "enum": ["a.b", "c+", "Straße"], "ignoreCase": true}
+    }))
+    .unwrap();
+    assert!(validator.validate(&json!({"plan": "A.B"})).is_valid);
+    assert!(validator.validate(&json!({"plan": "C+"})).is_valid);
+    assert!(validator.validate(&json!({"plan": "STRAßE"})).is_valid);
+    assert!(!validator.validate(&json!({"plan": "axb"})).is_valid);
+    assert!(!validator.validate(&json!({"plan": "cc"})).is_valid);
+}
+
+#[test]
+fn test_ignore_case_with_pattern_and_nullable() {
+    let validator = LinkValidator::new(&json!({
+        "code": {"type": "string", "enum": ["ab", "cd"], "pattern": "^[a-z]+$", "ignoreCase": true, "nullable": true}
+    }))
+    .unwrap();
+    assert!(validator.validate(&json!({"code": "ab"})).is_valid);
+    assert!(validator.validate(&json!({"code": null})).is_valid);
+    // 两个 pattern 都需要满足
+    assert!(!validator.validate(&json!({"code": "AB"})).is_valid);
+}
+
+#[test]
+fn test_ignore_case_warnings() {
+    let validator = LinkValidator::new(&json!({
+        "level": {"type": "enum", "enum": [1, "two"], "ignoreCase": true},
+        "name": {"type": "string", "ignoreCase": true}
+    }))
+    .unwrap();
+    let mut messages: Vec<&str> = validator.warnings().iter().map(|w| w.message.as_str()).collect();
+    messages.sort();
+    assert_eq!(
+        messages,
+        ["Field 'level': ignoreCase requires string enum values", "Field 'name': ignoreCase requires an 'enum' list"]
+    );
+    assert!(!validator.validate(&json!({"level": "TWO"})).is_valid);
+}
+
+#[test]
+fn test_ignore_case_keeps_values() {
+    let validator = LinkValidator::new(&json!({
+        "status": {"type": "enum", "enum": ["active", "a/b"], "ignoreCase": true, "required": true}
+    }))
+    .unwrap();
+    let property = &validator.json_schema()["properties"]["status"];
+    assert!(property.get("enum").is_none());
+    assert_eq!(property["x-enum"], json!(["active", "a/b"]));
+
+    let info = validator.schema_info();
+    assert_eq!(info.fields[0].constraints["x-enum"], json!(["active", "a/b"]));
+    assert_eq!(validator.to_openapi_schema()["properties"]["status"]["x-enum"], json!(["active", "a/b"]));
+    // JavaScript 正则不支持 (?i:...)，改用 i 标志
+    let zod = validator.to_zod();
+    assert!(zod.contains(r".regex(/^(?:active|a\/b)$/i)"), "{}", zod);
+    assert!(!zod.contains("(?i:"), "{}", zod);
+
+    for seed in 0..5 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(["active", "a/b"].contains(&sample["status"].as_str().unwrap()), "{}", sample);
+    }
+}
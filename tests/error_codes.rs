@@ -0,0 +1,92 @@
+//! Error code tests for link-validator
+
+use link_validator::{LinkValidator, ValidationError, ERROR_CODES};
+use serde_json::{json, Value};
+
+fn codes(validator: &LinkValidator, data: Value) -> Vec<String> {
+    let result = validator.validate(&data);
+    let mut codes: Vec<String> = result
+        .errors
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["code"].as_str().unwrap().to_string())
+        .collect();
+    codes.sort();
+    codes
+}
+
+fn registered(code: &str) -> bool {
+    ERROR_CODES.contains(&code) || (code.starts_with("format.") && ERROR_CODES.contains(&"format.<name>"))
+}
+
+#[test]
+fn test_rule_error_codes() {
+    let validator = LinkValidator::new(&json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "bio": {"type": "string", "max": 5},
+        "email": {"type": "email"},
+        "age": {"type": "integer", "min": 18},
+        "role": {"type": "enum", "enum": ["admin", "user"]},
+        "tags": {"type": "array", "min": 1, "unique": true},
+        "zip": {"type": "string", "pattern": "^[0-9]+$"}
+    }))
+    .unwrap();
+
+    assert_eq!(codes(&validator, json!({})), vec!["required"]);
+    assert_eq!(
+        codes(&validator, json!({"username": "jo", "bio": "too long", "email": "x", "age": 3, "role": "root", "tags": [], "zip": "ab"})),
+        vec!["array.min", "enum.mismatch", "format.email", "number.min", "pattern.mismatch", "string.max", "string.min"]
+    );
+    assert_eq!(codes(&validator, json!({"username": 1, "tags": [1, 1]})), vec!["array.unique", "type.mismatch"]);
+}
+
+#[test]
+fn test_post_schema_error_codes() {
+    let validator = LinkValidator::new(&json!({
+        "birthday": {"type": "date", "format": "date", "minDate": "1900-01-01", "maxDate": "2010-12-31"}
+    }))
+    .unwrap();
+    assert_eq!(codes(&validator, json!({"birthday": "1800-01-01"})), vec!["date.min"]);
+    assert_eq!(codes(&validator, json!({"birthday": "2020-01-01"})), vec!["date.max"]);
+}
+
+#[test]
+fn test_json_schema_error_codes() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"kind": {"const": "a"}, "n": {"exclusiveMinimum": 0, "multipleOf": 2}},
+        "additionalProperties": false
+    }))
+    .unwrap();
+    let result = validator.validate(&json!({"kind": "b", "n": -1, "extra": true}));
+    let mut codes: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["code"].as_str().unwrap()).collect();
+    codes.sort();
+    assert_eq!(codes, vec!["const.mismatch", "number.exclusiveMin", "number.multipleOf", "object.additionalProperties"]);
+}
+
+#[test]
+fn test_custom_error_codes() {
+    let mut validator = LinkValidator::new(&json!({"total": {"type": "number"}})).unwrap();
+    validator.register_document_validator(|_| {
+        vec![
+            ValidationError::new("/total", "mismatch"),
+            ValidationError::new("/total", "too large").with_code("order.total"),
+        ]
+    });
+    assert_eq!(codes(&validator, json!({"total": 1})), vec!["custom", "order.total"]);
+}
+
+#[test]
+fn test_codes_are_registered() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "len": 2},
+        "url": {"type": "url"},
+        "score": {"type": "number", "max": 1}
+    }))
+    .unwrap();
+    let result = validator.validate(&json!({"name": "abc", "url": "nope", "score": 2}));
+    for error in result.errors.as_array().unwrap() {
+        assert!(registered(error["code"].as_str().unwrap()), "{}", error);
+    }
+}
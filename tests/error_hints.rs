@@ -0,0 +1,58 @@
+//! Error hint tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::{json, Value};
+
+fn hints(schema: &Value, data: &Value) -> Vec<Value> {
+    let validator = LinkValidator::new(schema).unwrap();
+    let result = validator.validate(data);
+    result.errors.as_array().unwrap().iter().map(|e| e["hint"].clone()).collect()
+}
+
+#[test]
+fn test_nearest_enum_value() {
+    let schema = json!({"status": {"type": "enum", "enum": ["active", "inactive", "banned"]}});
+    assert_eq!(hints(&schema, &json!({"status": "inactve"})), vec![json!("did you mean 'inactive'?")]);
+    assert_eq!(hints(&schema, &json!({"status": "Banned!"})), vec![json!("did you mean 'banned'?")]);
+    // 没有足够接近的候选时不提示
+    assert_eq!(hints(&schema, &json!({"status": "deleted"})), vec![Value::Null]);
+}
+
+#[test]
+fn test_misspelled_required_property() {
+    let schema = json!({
+        "username": {"type": "string", "required": true},
+        "email": {"type": "string"}
+    });
+    assert_eq!(
+        hints(&schema, &json!({"usernmae": "ada", "email": "a@example.com"})),
+        vec![json!("unknown property 'usernmae', closest declared property 'username'")]
+    );
+    assert_eq!(hints(&schema, &json!({"nickname": "ada"})), vec![Value::Null]);
+}
+
+#[test]
+fn test_declared_properties_are_not_typos() {
+    let schema = json!({
+        "a1": {"type": "string", "required": true},
+        "a2": {"type": "string", "required": true}
+    });
+    assert_eq!(hints(&schema, &json!({"a1": "x"})), vec![Value::Null]);
+}
+
+#[test]
+fn test_nested_json_schema_hint() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "user": {
+                "type": "object",
+                "properties": {"country": {"type": "string"}},
+                "required": ["country"]
+            }
+        }
+    });
+    let validator = LinkValidator::new(&schema).unwrap();
+    let result = validator.validate(&json!({"user": {"Country": "NZ"}}));
+    assert_eq!(result.errors[0]["hint"], "unknown property 'Country', closest declared property 'country'");
+}
@@ -0,0 +1,90 @@
+//! Error path tests for link-validator
+
+use link_validator::{LinkValidator, Segment, ValidateOptions, ValidationError};
+use serde::Deserialize;
+use serde_json::json;
+
+fn validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "user": {
+            "type": "object",
+            "fields": {
+                "profile": {"type": "object", "fields": {"name": {"type": "string", "min": 3}}}
+            }
+        },
+        "tags": {"type": "array", "fields": {"label": {"type": "string", "max": 3}}}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_dotted_field_paths() {
+    let data = json!({"user": {"profile": {"name": "jo"}}, "tags": [{"label": "a"}, {"label": "b"}, {"label": "long"}]});
+    let result = validator().validate(&data);
+    let mut fields: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["field"].as_str().unwrap()).collect();
+    fields.sort();
+    assert_eq!(fields, vec!["tags.2.label", "user.profile.name"]);
+}
+
+#[test]
+fn test_json_schema_keeps_instance_path() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"tags": {"type": "array", "items": {"type": "string"}}}
+    }))
+    .unwrap();
+    let result = validator.validate(&json!({"tags": ["a", 1]}));
+    assert_eq!(result.errors[0]["instancePath"], "/tags/1");
+}
+
+#[test]
+fn test_path_segments() {
+    let error = ValidationError::new("/user/tags/2/a~1b", "bad");
+    assert_eq!(
+        error.path_segments(),
+        vec![
+            Segment::Key("user".to_string()),
+            Segment::Key("tags".to_string()),
+            Segment::Index(2),
+            Segment::Key("a/b".to_string()),
+        ]
+    );
+    assert_eq!(error.dotted_path(), "user.tags.2.a/b");
+    assert!(ValidationError::new("", "bad").path_segments().is_empty());
+    assert_eq!(ValidationError::new("/01", "bad").path_segments(), vec![Segment::Key("01".to_string())]);
+}
+
+#[test]
+fn test_typed_error_segments() {
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Form {
+        user: serde_json::Value,
+    }
+
+    let errors = validator().validate_into::<Form>(&json!({"user": {"profile": {"name": "jo"}}})).unwrap_err();
+    assert_eq!(
+        errors.errors[0].path_segments(),
+        vec![Segment::Key("user".to_string()), Segment::Key("profile".to_string()), Segment::Key("name".to_string())]
+    );
+}
+
+#[test]
+fn test_rename_path() {
+    let options = ValidateOptions::new().rename_path(|segment| match segment {
+        Segment::Index(index) => Segment::Index(index + 1),
+        Segment::Key(key) if key == "label" => Segment::Key("标签".to_string()),
+        other => other.clone(),
+    });
+    let result = validator().validate_with(&json!({"tags": [{"label": "long"}]}), &options);
+    assert_eq!(result.errors[0]["field"], "tags.1.标签");
+
+    // JSON Schema 格式同样应用重命名
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"tags": {"type": "array", "items": {"type": "string"}}}
+    }))
+    .unwrap();
+    let result = validator.validate_with(&json!({"tags": ["a", 1]}), &options);
+    assert_eq!(result.errors[0]["instancePath"], "/tags/2");
+}
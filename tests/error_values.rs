@@ -0,0 +1,93 @@
+//! Error value and parameter tests for link-validator
+
+use link_validator::{LinkValidator, ValidateOptions, ValidationError};
+use serde_json::json;
+
+fn validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "username": {"type": "string", "required": true, "min": 5},
+        "age": {"type": "integer", "max": 120},
+        "role": {"type": "enum", "enum": ["admin", "user"]},
+        "tags": {"type": "array", "max": 1}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_value_and_params() {
+    let result = validator().validate(&json!({"username": "joe", "age": 130, "role": "root", "tags": [1, 2, 3]}));
+    let errors = result.errors.as_array().unwrap();
+    let error = |field: &str| errors.iter().find(|e| e["field"] == field).unwrap().clone();
+
+    let username = error("username");
+    assert_eq!(username["value"], "joe");
+    assert_eq!(username["params"], json!({"limit": 5, "actual": 3}));
+
+    let age = error("age");
+    assert_eq!(age["value"], 130);
+    assert_eq!(age["params"], json!({"limit": 120}));
+
+    let role = error("role");
+    assert_eq!(role["params"], json!({"allowed": ["admin", "user"]}));
+
+    // 数组和对象只输出参数
+    let tags = error("tags");
+    assert!(tags.get("value").is_none());
+    assert_eq!(tags["params"], json!({"limit": 1, "actual": 3}));
+}
+
+#[test]
+fn test_required_has_property_param() {
+    let result = validator().validate(&json!({}));
+    let error = &result.errors[0];
+    assert_eq!(error["code"], "required");
+    assert!(error.get("value").is_none());
+    assert_eq!(error["params"], json!({"property": "username"}));
+}
+
+#[test]
+fn test_value_truncation_and_opt_out() {
+    let long = "x".repeat(100);
+    let validator = LinkValidator::new(&json!({"code": {"type": "string", "max": 10}})).unwrap();
+
+    let result = validator.validate(&json!({"code": long}));
+    assert_eq!(result.errors[0]["value"], format!("{}…", "x".repeat(64)));
+
+    let options = ValidateOptions::new().max_value_length(4);
+    let result = validator.validate_with(&json!({"code": long}), &options);
+    assert_eq!(result.errors[0]["value"], "xxxx…");
+
+    let options = ValidateOptions::new().include_values(false);
+    let result = validator.validate_with(&json!({"code": long}), &options);
+    assert!(result.errors[0].get("value").is_none());
+    assert_eq!(result.errors[0]["params"], json!({"limit": 10, "actual": 100}));
+}
+
+#[test]
+fn test_typed_errors_carry_value_and_params() {
+    let errors = validator().validate_into::<serde_json::Value>(&json!({"username": "joe"})).unwrap_err();
+    assert_eq!(errors.errors[0].value, Some(json!("joe")));
+    assert_eq!(errors.errors[0].params["limit"], 5);
+}
+
+#[test]
+fn test_custom_error_value_and_params() {
+    let mut validator = LinkValidator::new(&json!({"total": {"type": "number"}})).unwrap();
+    validator.register_document_validator(|data| {
+        vec![ValidationError::new("/total", "total too large").with_value(data["total"].clone()).with_param("limit", 10)]
+    });
+    let result = validator.validate(&json!({"total": 12}));
+    assert_eq!(result.errors[0]["value"], 12);
+    assert_eq!(result.errors[0]["params"], json!({"limit": 10}));
+}
+
+#[test]
+fn test_date_params() {
+    let validator = LinkValidator::new(&json!({
+        "day": {"type": "date", "format": "date", "maxDate": "2010-12-31"}
+    }))
+    .unwrap();
+    let result = validator.validate(&json!({"day": "2020-01-01"}));
+    assert_eq!(result.errors[0]["value"], "2020-01-01");
+    assert_eq!(result.errors[0]["params"], json!({"limit": "2010-12-31"}));
+}
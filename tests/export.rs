@@ -0,0 +1,86 @@
+//! Compiled validator export tests for link-validator
+
+use link_validator::{CompileOptions, LengthUnit, LinkValidator, NullPolicy, NumberFormat};
+use serde_json::{json, Value};
+
+fn round_trip(validator: &LinkValidator) -> LinkValidator {
+    LinkValidator::import_compiled(&validator.export_compiled()).expect("Import failed")
+}
+
+#[test]
+fn test_round_trip_keeps_rules_and_transforms() {
+    let options = CompileOptions::new()
+        .number_format(NumberFormat::DecimalComma)
+        .null_policy(NullPolicy::StripOptional)
+        .length_unit(LengthUnit::Utf8Bytes);
+    let validator = LinkValidator::with_options(
+        &json!({
+            "name": {"type": "string", "required": true, "trim": true, "max": 6},
+            "price": {"type": "number", "min": 0},
+            "note": {"type": "string"},
+            "birthday": {"type": "date", "format": "date", "maxDate": "2010-12-31"},
+            "password": {"type": "string", "min": 8, "sensitive": true},
+            "extra": {"type": "string", "trigger": "blur"}
+        }),
+        &options,
+    )
+    .unwrap();
+    let restored = round_trip(&validator);
+
+    assert_eq!(restored.json_schema(), validator.json_schema());
+    assert_eq!(restored.warnings(), validator.warnings());
+    assert_eq!(restored.manifest()["fingerprint"], validator.manifest()["fingerprint"]);
+
+    let data = json!({"name": "  中文  ", "price": "1.234,5", "note": null});
+    let (value, result) = restored.validate_and_transform(&data);
+    assert!(result.is_valid, "{}", result.errors);
+    assert_eq!(value["name"], "中文");
+    assert_eq!(value["price"], json!(1234.5));
+
+    // 按字节计数的长度、日期范围和敏感字段在恢复后仍然生效
+    let result = restored.validate(&json!({"name": "中文字", "birthday": "2020-01-01", "password": "short"}));
+    let codes: Vec<&Value> = result.errors.as_array().unwrap().iter().map(|e| &e["code"]).collect();
+    assert_eq!(codes, [&json!("date.max"), &json!("string.max"), &json!("string.min")]);
+    assert_eq!(result.errors[2]["value"], "[REDACTED]");
+}
+
+#[test]
+fn test_round_trip_json_schema_and_groups() {
+    let schema = json!({"type": "object", "required": ["id"], "properties": {"id": {"type": "integer"}}});
+    let restored = round_trip(&LinkValidator::new(&schema).unwrap());
+    assert_eq!(restored.format(), link_validator::SchemaFormat::JsonSchema);
+    assert_eq!(restored.validate(&json!({})).errors[0]["instancePath"], "");
+
+    let validator = LinkValidator::new(&json!({
+        "id": [{"type": "string"}, {"required": true, "groups": ["update"]}]
+    }))
+    .unwrap();
+    let restored = round_trip(&validator);
+    assert_eq!(restored.groups(), vec!["update"]);
+    assert!(restored.validate(&json!({})).is_valid);
+    assert!(!restored.validate_with_group(&json!({}), "update").unwrap().is_valid);
+}
+
+#[test]
+fn test_round_trip_keeps_blank_required_check() {
+    let options = CompileOptions::new().required_rejects_blank(true);
+    let validator = LinkValidator::with_options(&json!({"name": {"type": "string", "required": true}}), &options).unwrap();
+    let restored = round_trip(&validator);
+
+    let result = restored.validate(&json!({"name": "  "}));
+    assert_eq!(result.errors[0]["code"], "required");
+    assert_eq!(restored.manifest()["fingerprint"], validator.manifest()["fingerprint"]);
+}
+
+#[test]
+fn test_import_errors() {
+    let error = LinkValidator::import_compiled(b"not json").unwrap_err();
+    assert!(error.starts_with("Failed to import compiled validator"));
+
+    let validator = LinkValidator::new(&json!({"id": {"type": "integer"}})).unwrap();
+    let mut exported: Value = serde_json::from_slice(&validator.export_compiled()).unwrap();
+    assert_eq!(exported["version"], 2);
+    exported["version"] = json!(1);
+    let error = LinkValidator::import_compiled(&serde_json::to_vec(&exported).unwrap()).unwrap_err();
+    assert_eq!(error, "Unsupported compiled validator version 1");
+}
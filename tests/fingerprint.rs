@@ -0,0 +1,96 @@
+//! Schema fingerprint tests for link-validator
+
+use link_validator::{schemas_equivalent, CompileOptions, LengthUnit, LinkValidator};
+use serde_json::json;
+
+#[test]
+fn test_fingerprint_ignores_annotations_and_order() {
+    let a = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "title": "Name"},
+        "email": {"type": "email", "required": true}
+    }))
+    .unwrap();
+    let b = LinkValidator::new(&json!({
+        "type": "object",
+        "description": "Signup form",
+        "properties": {
+            "email": {"type": "string", "format": "email"},
+            "name": {"type": "string"}
+        },
+        "required": ["name", "email"]
+    }))
+    .unwrap();
+    assert_eq!(a.fingerprint(), b.fingerprint());
+    assert_eq!(a.sha256(), b.sha256());
+    assert!(schemas_equivalent(&a, &b));
+}
+
+#[test]
+fn test_different_constraints_differ() {
+    let a = LinkValidator::new(&json!({"name": {"type": "string", "min": 3}})).unwrap();
+    let b = LinkValidator::new(&json!({"name": {"type": "string", "min": 4}})).unwrap();
+    assert_ne!(a.fingerprint(), b.fingerprint());
+    assert_ne!(a.sha256(), b.sha256());
+    assert!(!schemas_equivalent(&a, &b));
+
+    // 数据规范化会改变验证结果，同样参与比较
+    let trimmed = LinkValidator::new(&json!({"name": {"type": "string", "min": 3, "trim": true}})).unwrap();
+    assert!(!schemas_equivalent(&a, &trimmed));
+}
+
+#[test]
+fn test_sha256_is_stable() {
+    let validator = LinkValidator::new(&json!({"type": "object"})).unwrap();
+    let sha = validator.sha256();
+    assert_eq!(sha.len(), 64);
+    assert!(sha.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase()));
+    assert_eq!(sha, validator.clone().sha256());
+    assert_eq!(validator.fingerprint(), LinkValidator::new(&json!({"type": "object"})).unwrap().fingerprint());
+}
+
+#[test]
+fn test_behavioral_options_differ() {
+    let schema = json!({"name": {"type": "string", "required": true, "max": 4}});
+    let plain = LinkValidator::new(&schema).unwrap();
+    let options = CompileOptions::new().required_rejects_blank(true).length_unit(LengthUnit::Utf8Bytes);
+    let strict = LinkValidator::with_options(&schema, &options).unwrap();
+    assert_eq!(plain.json_schema(), strict.json_schema());
+    assert_ne!(plain.fingerprint(), strict.fingerprint());
+    assert_ne!(plain.sha256(), strict.sha256());
+    assert!(!schemas_equivalent(&plain, &strict));
+
+    for options in [
+        CompileOptions::new().required_rejects_blank(true),
+        CompileOptions::new().length_unit(LengthUnit::Utf8Bytes),
+    ] {
+        let validator = LinkValidator::with_options(&schema, &options).unwrap();
+        assert!(!schemas_equivalent(&plain, &validator));
+    }
+
+    // 不影响验证结果的选项不参与
+    let titled = LinkValidator::with_options(&schema, &CompileOptions::new().form_annotations(true)).unwrap();
+    assert_eq!(plain.fingerprint(), titled.fingerprint());
+}
+
+#[test]
+fn test_draft_participates() {
+    let schema = |draft: &str| {
+        json!({
+            "$schema": draft,
+            "type": "object",
+            "properties": {"a": {"const": 1}}
+        })
+    };
+    // draft-04 没有 const 关键字
+    let draft4 = LinkValidator::new(&schema("http://json-schema.org/draft-04/schema#")).unwrap();
+    let draft7 = LinkValidator::new(&schema("http://json-schema.org/draft-07/schema#")).unwrap();
+    assert!(draft4.validate(&json!({"a": 2})).is_valid);
+    assert!(!draft7.validate(&json!({"a": 2})).is_valid);
+    assert_ne!(draft4.fingerprint(), draft7.fingerprint());
+    assert_ne!(draft4.sha256(), draft7.sha256());
+    assert!(!schemas_equivalent(&draft4, &draft7));
+
+    // 未声明 `$schema` 时按默认的 draft-07 处理
+    let default = LinkValidator::new(&json!({"type": "object", "properties": {"a": {"const": 1}}})).unwrap();
+    assert!(schemas_equivalent(&default, &draft7));
+}
@@ -0,0 +1,75 @@
+//! Form data tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+    items.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+fn order_validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "page": {"type": "integer", "min": 1},
+        "active": {"type": "boolean"},
+        "customer": {"type": "object", "fields": {"name": {"type": "string", "required": true}}},
+        "lines": {
+            "type": "array",
+            "required": true,
+            "fields": {"sku": {"type": "string", "required": true}, "qty": {"type": "integer", "min": 1}}
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_nested_keys_and_coercion() {
+    let validator = order_validator();
+    let result = validator.validate_form(&pairs(&[
+        ("page", "2"),
+        ("active", "on"),
+        ("customer.name", "Ada"),
+        ("lines[0].sku", "A-1"),
+        ("lines[0][qty]", "3"),
+        ("lines[1].sku", "B-2"),
+    ]));
+    assert!(result.is_valid, "{:?}", result.errors);
+
+    let result = validator.validate_form(&pairs(&[("page", "0"), ("lines[0].sku", "A-1"), ("lines[0].qty", "x")]));
+    assert!(!result.is_valid);
+    let fields: Vec<&str> = result.errors.as_array().unwrap().iter().filter_map(|e| e["field"].as_str()).collect();
+    assert!(fields.contains(&"page"), "{:?}", fields);
+    assert!(fields.contains(&"lines.0.qty"), "{:?}", fields);
+}
+
+#[test]
+fn test_repeated_keys_for_arrays() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {
+            "tags": {"type": "array", "items": {"type": "string"}},
+            "ids": {"type": "array", "items": {"type": "integer"}},
+            "name": {"type": "string"}
+        }
+    }))
+    .unwrap();
+    assert!(validator.validate_form(&pairs(&[("tags", "a"), ("tags", "b"), ("ids[]", "1"), ("ids[]", "2")])).is_valid);
+    assert!(validator.validate_form(&pairs(&[("tags", "only")])).is_valid);
+    assert!(!validator.validate_form(&pairs(&[("ids", "x")])).is_valid);
+    // 非数组字段重复时报告类型错误
+    assert!(!validator.validate_form(&pairs(&[("name", "a"), ("name", "b")])).is_valid);
+}
+
+#[test]
+fn test_invalid_keys() {
+    let validator = order_validator();
+    for key in ["", "[0]", "lines[0", "customer..name", "lines[99].sku"] {
+        let result = validator.validate_form(&pairs(&[(key, "x")]));
+        assert_eq!(result.errors[0]["code"], "parse.form", "{}", key);
+        assert_eq!(result.errors[0]["params"]["key"], key);
+    }
+
+    let result = validator.validate_form(&pairs(&[("customer", "x"), ("customer.name", "Ada")]));
+    assert_eq!(result.errors[0]["code"], "parse.form");
+    let result = validator.validate_form(&pairs(&[("customer.name", "Ada"), ("customer", "x")]));
+    assert_eq!(result.errors[0]["code"], "parse.form");
+}
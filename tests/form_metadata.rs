@@ -0,0 +1,87 @@
+//! Form metadata tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, TitleStyle};
+use serde_json::json;
+
+fn rules() -> serde_json::Value {
+    json!({
+        "email": {
+            "type": "email",
+            "required": true,
+            "label": "Email",
+            "placeholder": "name@example.com",
+            "help": "We never share your email",
+            "order": 2
+        },
+        "name": {"type": "string", "label": "Full name", "order": 1},
+        "age": {"type": "integer", "min": 0}
+    })
+}
+
+#[test]
+fn test_metadata_does_not_affect_validation() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+    assert!(validator.validate(&json!({"email": "a@example.com", "name": "Ann"})).is_valid);
+    let result = validator.validate(&json!({"email": "not-an-email"}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_metadata_is_preserved_as_annotations() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+    let email = &validator.json_schema()["properties"]["email"];
+    assert_eq!(email["x-label"], json!("Email"));
+    assert_eq!(email["x-placeholder"], json!("name@example.com"));
+    assert_eq!(email["x-help"], json!("We never share your email"));
+    assert_eq!(email["x-order"], json!(2));
+    assert!(email.get("title").is_none());
+
+    let manifest = validator.manifest();
+    assert_eq!(manifest["custom_keywords"], json!([]));
+}
+
+#[test]
+fn test_schema_info_exposes_metadata_in_order() {
+    let info = LinkValidator::new(&rules()).unwrap().schema_info();
+    let names: Vec<&str> = info.fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["name", "email", "age"]);
+
+    let email = &info.fields[1];
+    assert_eq!(email.label.as_deref(), Some("Email"));
+    assert_eq!(email.placeholder.as_deref(), Some("name@example.com"));
+    assert_eq!(email.help.as_deref(), Some("We never share your email"));
+    assert_eq!(email.order, Some(2));
+    assert!(email.constraints.get("x-label").is_none());
+    assert_eq!(info.fields[2].label, None);
+}
+
+#[test]
+fn test_form_annotations_fill_title_and_description() {
+    let options = CompileOptions::new().form_annotations(true).titles(TitleStyle::Humanized);
+    let validator = LinkValidator::with_options(&rules(), &options).unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["email"]["title"], json!("Email"));
+    assert_eq!(properties["email"]["description"], json!("We never share your email"));
+    assert_eq!(properties["name"]["title"], json!("Full name"));
+    assert_eq!(properties["age"]["title"], json!("Age"));
+    assert!(properties["name"].get("description").is_none());
+}
+
+#[test]
+fn test_metadata_in_nested_fields_and_rule_arrays() {
+    let validator = LinkValidator::new(&json!({
+        "address": {
+            "type": "object",
+            "fields": {
+                "city": [{"type": "string", "required": true}, {"label": "City", "order": 1}]
+            }
+        }
+    }))
+    .unwrap();
+    let info = validator.schema_info();
+    let city = &info.fields[0].fields[0];
+    assert_eq!(city.path, "address.city");
+    assert_eq!(city.label.as_deref(), Some("City"));
+    assert_eq!(city.order, Some(1));
+}
@@ -0,0 +1,94 @@
+//! Validation group tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+fn validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "id": [
+            {"type": "string"},
+            {"required": true, "groups": ["update"]}
+        ],
+        "name": [
+            {"type": "string", "min": 3},
+            {"required": true, "groups": ["create"]}
+        ],
+        "password": {"type": "string", "required": true, "min": 8, "groups": ["create", "reset"]}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_group_names() {
+    assert_eq!(validator().groups(), vec!["create", "reset", "update"]);
+    assert!(LinkValidator::new(&json!({"a": {"type": "string"}})).unwrap().groups().is_empty());
+}
+
+#[test]
+fn test_requiredness_per_group() {
+    let validator = validator();
+
+    let create = json!({"name": "widget", "password": "long enough"});
+    assert!(validator.validate_with_group(&create, "create").unwrap().is_valid);
+    assert!(!validator.validate_with_group(&create, "update").unwrap().is_valid);
+
+    let update = json!({"id": "w-1"});
+    assert!(validator.validate_with_group(&update, "update").unwrap().is_valid);
+    let result = validator.validate_with_group(&update, "create").unwrap();
+    let mut missing: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["params"]["property"].as_str().unwrap()).collect();
+    missing.sort();
+    assert_eq!(missing, vec!["name", "password"]);
+
+    assert!(!validator.validate_with_group(&json!({"password": "short"}), "reset").unwrap().is_valid);
+}
+
+#[test]
+fn test_ungrouped_rules_always_apply() {
+    let validator = validator();
+    // 不带分组只使用未声明分组的规则
+    assert!(validator.validate(&json!({})).is_valid);
+    assert!(!validator.validate(&json!({"name": "ab"})).is_valid);
+    assert!(!validator.validate_with_group(&json!({"id": "x", "name": "ab"}), "update").unwrap().is_valid);
+    assert!(!validator.validate_with_group(&json!({"id": 1}), "update").unwrap().is_valid);
+    assert!(validator.json_schema()["required"].as_array().is_none_or(Vec::is_empty));
+}
+
+#[test]
+fn test_nested_group_rules() {
+    let validator = LinkValidator::new(&json!({
+        "profile": {
+            "type": "object",
+            "fields": {
+                "email": [{"type": "email"}, {"required": true, "groups": ["signup"]}]
+            }
+        }
+    }))
+    .unwrap();
+    let data = json!({"profile": {}});
+    assert!(validator.validate(&data).is_valid);
+    assert!(!validator.validate_with_group(&data, "signup").unwrap().is_valid);
+    assert!(!validator.validate(&json!({"profile": {"email": "nope"}})).is_valid);
+}
+
+#[test]
+fn test_document_validators_apply_to_groups() {
+    let mut validator = validator();
+    validator.register_document_validator(|data| {
+        if data.get("id").is_some() && data.get("password").is_some() {
+            vec![link_validator::ValidationError::new("/password", "password cannot be changed on update")]
+        } else {
+            vec![]
+        }
+    });
+    let result = validator.validate_with_group(&json!({"id": "w-1", "password": "long enough"}), "update").unwrap();
+    assert_eq!(result.errors[0]["code"], "custom");
+}
+
+#[test]
+fn test_unknown_and_invalid_groups() {
+    let error = validator().validate_with_group(&json!({}), "delete").unwrap_err();
+    assert_eq!(error, "Unknown validation group 'delete'");
+
+    let error = LinkValidator::new(&json!({"a": {"type": "string", "groups": "create"}})).unwrap_err();
+    assert!(error.contains("groups must be a non-empty array of group names"));
+}
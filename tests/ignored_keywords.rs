@@ -0,0 +1,88 @@
+//! Ignored keyword detection tests for link-validator
+
+use link_validator::{lint, validate_meta, Draft, LinkValidator};
+use serde_json::json;
+
+fn messages(validator: &LinkValidator) -> Vec<&str> {
+    validator.warnings().iter().map(|w| w.message.as_str()).collect()
+}
+
+#[test]
+fn test_misspelled_keyword_warning() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"name": {"type": "string", "minLenght": 3, "x-widget": "text"}}
+    }))
+    .unwrap();
+    assert_eq!(
+        messages(&validator),
+        ["/properties/name [unknown-keyword] unknown keyword 'minLenght' is ignored"]
+    );
+    // 拼错的约束没有生效
+    assert!(validator.validate(&json!({"name": "a"})).is_valid);
+}
+
+#[test]
+fn test_keyword_from_later_draft() {
+    let validator = LinkValidator::new(&json!({
+        "$schema": "http://json-schema.org/draft-04/schema#",
+        "type": "object",
+        "properties": {"kind": {"const": "a"}},
+        "dependentRequired": {"a": ["b"]}
+    }))
+    .unwrap();
+    assert_eq!(
+        messages(&validator),
+        [
+            "[keyword-not-in-draft] keyword 'dependentRequired' requires 2019-09 and is ignored by draft-04",
+            "/properties/kind [keyword-not-in-draft] keyword 'const' requires draft-06 and is ignored by draft-04",
+        ]
+    );
+}
+
+#[test]
+fn test_clean_schema_has_no_warnings() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "$defs": {"id": {"type": "integer"}},
+        "properties": {
+            "id": {"$ref": "#/$defs/id"},
+            "kind": {"enum": ["a", "b"], "title": "Kind"},
+            "birthday": {"type": "string", "format": "date", "formatMaximum": "2020-01-01"}
+        },
+        "if": {"properties": {"kind": {"const": "a"}}},
+        "then": {"required": ["id"]}
+    }))
+    .unwrap();
+    assert!(validator.warnings().is_empty(), "{:?}", validator.warnings());
+}
+
+#[test]
+fn test_lint_reports_ignored_keywords() {
+    let findings = lint(&json!({
+        "type": "object",
+        "properties": {"tags": {"type": "array", "prefixItems": [{"type": "string"}], "itmes": {}}}
+    }));
+    let codes: Vec<(&str, &str)> = findings.iter().map(|f| (f.path.as_str(), f.code.as_str())).collect();
+    assert_eq!(
+        codes,
+        vec![("/properties/tags", "unknown-keyword"), ("/properties/tags", "keyword-not-in-draft")]
+    );
+}
+
+#[test]
+fn test_validate_meta_uses_requested_draft() {
+    let schema = json!({"type": "object", "if": {"required": ["a"]}, "then": {"required": ["b"]}});
+    assert!(validate_meta(&schema, Draft::Draft7).is_valid);
+
+    let result = validate_meta(&schema, Draft::Draft4);
+    let messages: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["message"].as_str().unwrap()).collect();
+    assert_eq!(
+        messages,
+        [
+            "Keyword 'if' is not supported by draft-04 (requires draft-07)",
+            "Keyword 'then' is not supported by draft-04 (requires draft-07)",
+        ]
+    );
+    assert_eq!(Draft::Draft4.as_str(), "draft-04");
+}
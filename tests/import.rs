@@ -0,0 +1,111 @@
+//! Joi / Yup import tests for link-validator
+
+use link_validator::{LinkValidator, SchemaFormat};
+use serde_json::json;
+
+#[test]
+fn test_from_joi_json() {
+    let description = json!({
+        "type": "object",
+        "keys": {
+            "username": {
+                "type": "string",
+                "flags": {"presence": "required"},
+                "rules": [
+                    {"name": "min", "args": {"limit": 3}},
+                    {"name": "max", "args": {"limit": 20}},
+                    {"name": "pattern", "args": {"regex": "/^[a-z0-9]+$/i"}}
+                ]
+            },
+            "email": {"type": "string", "rules": [{"name": "email", "args": {"options": {}}}]},
+            "age": {"type": "number", "rules": [{"name": "integer"}, {"name": "min", "args": {"limit": 0}}]},
+            "role": {"type": "string", "flags": {"only": true}, "allow": ["admin", "user"]},
+            "address": {
+                "type": "object",
+                "keys": {"city": {"type": "string", "flags": {"presence": "required"}}}
+            },
+            "items": {
+                "type": "array",
+                "items": [{"type": "object", "keys": {"sku": {"type": "string", "flags": {"presence": "required"}}}}]
+            },
+            "token": {"type": "string", "rules": [{"name": "guid"}]}
+        }
+    });
+    let validator = LinkValidator::from_joi_json(&description).unwrap();
+    assert_eq!(validator.format(), SchemaFormat::AsyncValidator);
+
+    let valid = json!({
+        "username": "John42",
+        "email": "john@example.com",
+        "age": 30,
+        "role": "admin",
+        "address": {"city": "Berlin"},
+        "items": [{"sku": "A-1"}]
+    });
+    assert!(validator.validate(&valid).is_valid);
+
+    for (field, value) in [
+        ("username", json!("jo")),
+        ("username", json!("john doe")),
+        ("email", json!("not-an-email")),
+        ("age", json!(1.5)),
+        ("role", json!("guest")),
+        ("address", json!({})),
+        ("items", json!([{}])),
+    ] {
+        let mut data = valid.clone();
+        data[field] = value;
+        assert!(!validator.validate(&data).is_valid, "{} should be invalid", field);
+    }
+    assert!(!validator.validate(&json!({})).is_valid);
+    assert!(validator.warnings().iter().any(|w| w.message.contains("token") && w.message.contains("guid")));
+}
+
+#[test]
+fn test_from_yup_json() {
+    let description = json!({
+        "type": "object",
+        "fields": {
+            "name": {
+                "type": "string",
+                "optional": false,
+                "tests": [
+                    {"name": "required"},
+                    {"name": "min", "params": {"min": 2}},
+                    {"name": "matches", "params": {"regex": "/^[A-Z]/"}}
+                ]
+            },
+            "website": {"type": "string", "optional": true, "tests": [{"name": "url"}]},
+            "count": {"type": "number", "tests": [{"name": "integer"}, {"name": "max", "params": {"max": 10}}]},
+            "size": {"type": "string", "oneOf": ["s", "m", "l"]},
+            "lines": {
+                "type": "array",
+                "innerType": {"type": "object", "fields": {"qty": {"type": "number", "optional": false}}}
+            },
+            "tags": {"type": "array", "innerType": {"type": "string"}}
+        }
+    });
+    let validator = LinkValidator::from_yup_json(&description).unwrap();
+
+    let valid = json!({"name": "Alice", "website": "https://example.com", "count": 3, "size": "m", "lines": [{"qty": 1}]});
+    assert!(validator.validate(&valid).is_valid);
+
+    for (field, value) in [
+        ("name", json!("alice")),
+        ("website", json!("not a url")),
+        ("count", json!(11)),
+        ("size", json!("xl")),
+        ("lines", json!([{}])),
+    ] {
+        let mut data = valid.clone();
+        data[field] = value;
+        assert!(!validator.validate(&data).is_valid, "{} should be invalid", field);
+    }
+    assert!(validator.warnings().iter().any(|w| w.message.contains("tags")));
+}
+
+#[test]
+fn test_import_requires_object() {
+    assert!(LinkValidator::from_joi_json(&json!({"type": "string"})).is_err());
+    assert!(LinkValidator::from_yup_json(&json!({"fields": {}})).is_err());
+}
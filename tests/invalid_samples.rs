@@ -0,0 +1,147 @@
+//! Negative sample generation tests for link-validator
+
+use link_validator::{InvalidSample, LinkValidator};
+use serde_json::json;
+
+fn tags(samples: &[InvalidSample]) -> Vec<(String, String)> {
+    samples.iter().map(|s| (s.path.clone(), s.keyword.clone())).collect()
+}
+
+fn assert_all_invalid(validator: &LinkValidator, samples: &[InvalidSample]) {
+    for sample in samples {
+        let result = validator.validate(&sample.data);
+        assert!(!result.is_valid, "{} {} should fail: {}", sample.path, sample.keyword, sample.data);
+    }
+}
+
+#[test]
+fn test_one_violation_per_constraint() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 5},
+        "code": {"type": "string", "pattern": "^[A-Z]{3}$"},
+        "email": {"type": "email"},
+        "age": {"type": "integer", "min": 18, "max": 60},
+        "role": {"type": "enum", "enum": ["admin", "user"]}
+    }))
+    .unwrap();
+    let samples = validator.generate_invalid_samples(100);
+    let tags = tags(&samples);
+
+    for expected in [
+        ("", "type"),
+        ("", "required"),
+        ("/name", "type"),
+        ("/name", "minLength"),
+        ("/name", "maxLength"),
+        ("/code", "pattern"),
+        ("/email", "format"),
+        ("/age", "type"),
+        ("/age", "minimum"),
+        ("/age", "maximum"),
+        ("/role", "enum"),
+    ] {
+        assert!(
+            tags.contains(&(expected.0.to_string(), expected.1.to_string())),
+            "missing {:?} in {:?}",
+            expected,
+            tags
+        );
+    }
+    assert_all_invalid(&validator, &samples);
+
+    let short = samples.iter().find(|s| s.keyword == "minLength").unwrap();
+    assert_eq!(short.data["name"].as_str().unwrap().chars().count(), 1);
+    assert_eq!(short.description, "string shorter than minLength 2");
+}
+
+#[test]
+fn test_missing_required_field_is_only_change() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "email": {"type": "email", "required": true}
+    }))
+    .unwrap();
+    let samples = validator.generate_invalid_samples(100);
+    let missing: Vec<&InvalidSample> = samples.iter().filter(|s| s.keyword == "required").collect();
+
+    assert_eq!(missing.len(), 2);
+    assert!(missing.iter().any(|s| s.data.get("name").is_none() && s.data.get("email").is_some()));
+    assert!(missing.iter().any(|s| s.data.get("email").is_none() && s.data.get("name").is_some()));
+}
+
+#[test]
+fn test_nested_and_array_constraints() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {
+            "ids": {"type": "array", "items": {"type": "integer", "minimum": 1}, "minItems": 2, "maxItems": 4, "uniqueItems": true},
+            "profile": {
+                "type": "object",
+                "properties": {"bio": {"type": "string", "maxLength": 3}},
+                "required": ["bio"],
+                "additionalProperties": false
+            }
+        },
+        "required": ["ids", "profile"]
+    }))
+    .unwrap();
+    let samples = validator.generate_invalid_samples(100);
+    let tags = tags(&samples);
+
+    for expected in [
+        ("/ids", "minItems"),
+        ("/ids", "uniqueItems"),
+        ("/ids/0", "minimum"),
+        ("/profile", "required"),
+        ("/profile", "additionalProperties"),
+        ("/profile/bio", "maxLength"),
+    ] {
+        assert!(
+            tags.contains(&(expected.0.to_string(), expected.1.to_string())),
+            "missing {:?} in {:?}",
+            expected,
+            tags
+        );
+    }
+    // 同一约束在多个数组元素中只生成一次
+    assert_eq!(samples.iter().filter(|s| s.keyword == "minimum").count(), 1);
+    assert_all_invalid(&validator, &samples);
+}
+
+#[test]
+fn test_items_by_branch_constraints() {
+    let validator = LinkValidator::new(&json!({
+        "blocks": {
+            "type": "array",
+            "required": true,
+            "min": 6,
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "text": {"body": {"type": "string", "required": true}},
+                    "image": {"width": {"type": "integer", "min": 1}}
+                }
+            }
+        }
+    }))
+    .unwrap();
+    let samples = validator.generate_invalid_samples(100);
+    let keywords: Vec<&str> = samples.iter().map(|s| s.keyword.as_str()).collect();
+
+    assert!(keywords.contains(&"enum"), "{:?}", keywords);
+    assert!(keywords.contains(&"minimum"), "{:?}", keywords);
+    assert!(samples.iter().any(|s| s.keyword == "required" && s.path.starts_with("/blocks/")));
+    assert_all_invalid(&validator, &samples);
+}
+
+#[test]
+fn test_limit_and_determinism() {
+    let validator = LinkValidator::new(&json!({
+        "a": {"type": "string", "required": true, "min": 1},
+        "b": {"type": "integer", "required": true, "max": 3}
+    }))
+    .unwrap();
+    assert_eq!(validator.generate_invalid_samples(2).len(), 2);
+    assert!(validator.generate_invalid_samples(0).is_empty());
+    assert_eq!(validator.generate_invalid_samples(50), validator.generate_invalid_samples(50));
+}
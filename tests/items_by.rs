@@ -0,0 +1,90 @@
+//! Discriminated array items tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+fn blocks_validator() -> LinkValidator {
+    let schema = json!({
+        "blocks": {
+            "type": "array",
+            "required": true,
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "text": {"body": {"type": "string", "required": true}},
+                    "image": {
+                        "src": {"type": "string", "required": true},
+                        "width": {"type": "integer", "min": 1}
+                    }
+                }
+            }
+        }
+    });
+    LinkValidator::new(&schema).expect("Compilation failed")
+}
+
+#[test]
+fn test_items_selected_by_discriminator() {
+    let validator = blocks_validator();
+
+    let data = json!({
+        "blocks": [
+            {"kind": "text", "body": "hello"},
+            {"kind": "image", "src": "a.png", "width": 100}
+        ]
+    });
+    assert!(validator.validate(&data).is_valid);
+
+    // image 元素不需要 body，text 元素不需要 src
+    let missing = json!({"blocks": [{"kind": "image", "body": "hello"}]});
+    let result = validator.validate(&missing);
+    assert!(!result.is_valid);
+    let errors = result.errors.as_array().unwrap();
+    assert!(errors.iter().any(|e| e["field"] == "blocks.0" && e["message"].as_str().unwrap().contains("src")));
+
+    let invalid = json!({"blocks": [{"kind": "text", "body": "ok"}, {"kind": "image", "src": "a.png", "width": 0}]});
+    let result = validator.validate(&invalid);
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "blocks.1.width");
+}
+
+#[test]
+fn test_discriminator_required_and_restricted() {
+    let validator = blocks_validator();
+
+    assert!(!validator.validate(&json!({"blocks": [{"body": "hello"}]})).is_valid);
+    assert!(!validator.validate(&json!({"blocks": [{"kind": "video"}]})).is_valid);
+
+    let items = &validator.json_schema()["properties"]["blocks"]["items"];
+    assert_eq!(items["required"], json!(["kind"]));
+    assert_eq!(items["allOf"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_items_by_with_common_fields() {
+    let schema = json!({
+        "blocks": {
+            "type": "array",
+            "fields": {"id": {"type": "string", "required": true}},
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {"text": {"body": {"type": "string", "required": true}}}
+            }
+        }
+    });
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    assert!(validator.validate(&json!({"blocks": [{"id": "1", "kind": "text", "body": "x"}]})).is_valid);
+    assert!(!validator.validate(&json!({"blocks": [{"kind": "text", "body": "x"}]})).is_valid);
+}
+
+#[test]
+fn test_invalid_items_by() {
+    let schema = json!({"blocks": {"type": "array", "itemsBy": {"mapping": {}}}});
+    let err = LinkValidator::new(&schema).unwrap_err();
+    assert!(err.contains("itemsBy"));
+
+    let schema = json!({"blocks": {"type": "string", "itemsBy": {"field": "kind", "mapping": {"a": {}}}}});
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+    assert!(validator.warnings().iter().any(|w| w.message.contains("itemsBy")));
+}
@@ -0,0 +1,112 @@
+//! JSON Patch validation tests for link-validator
+#![cfg(feature = "patch")]
+
+use json_patch::Patch;
+use link_validator::LinkValidator;
+use serde_json::{from_value, json, Value};
+
+fn document_validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "title": {"type": "string", "required": true, "max": 10},
+        "tags": {"type": "array", "max": 2, "fields": {"name": {"type": "string", "required": true}}},
+        "owner": {"type": "object", "fields": {"email": {"type": "email", "required": true}}}
+    }))
+    .unwrap()
+}
+
+fn patch(ops: Value) -> Patch {
+    from_value(ops).unwrap()
+}
+
+fn ops_by_field(result: &link_validator::ValidationResult) -> Vec<(String, Value)> {
+    result
+        .errors
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| (e["field"].as_str().unwrap_or_default().to_string(), e["params"]["op"].clone()))
+        .collect()
+}
+
+#[test]
+fn test_valid_patch() {
+    let validator = document_validator();
+    let current = json!({"title": "draft", "tags": []});
+    let result = validator.validate_patch(
+        &current,
+        &patch(json!([
+            {"op": "replace", "path": "/title", "value": "final"},
+            {"op": "add", "path": "/tags/-", "value": {"name": "rust"}}
+        ])),
+    );
+    assert!(result.is_valid, "{:?}", result.errors);
+    // 当前文档保持不变
+    assert_eq!(current["title"], "draft");
+}
+
+#[test]
+fn test_errors_point_to_ops() {
+    let validator = document_validator();
+    let current = json!({"title": "draft", "tags": [], "owner": {"email": "a@example.com"}});
+    let result = validator.validate_patch(
+        &current,
+        &patch(json!([
+            {"op": "test", "path": "/title", "value": "draft"},
+            {"op": "add", "path": "/tags/0", "value": {}},
+            {"op": "replace", "path": "/owner/email", "value": "nope"},
+            {"op": "replace", "path": "/title", "value": "a much longer title"}
+        ])),
+    );
+    assert!(!result.is_valid);
+    let mut ops = ops_by_field(&result);
+    ops.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        ops,
+        vec![
+            ("owner.email".to_string(), json!(2)),
+            ("tags.0".to_string(), json!(1)),
+            ("title".to_string(), json!(3)),
+        ]
+    );
+}
+
+#[test]
+fn test_removed_and_moved_fields() {
+    let validator = document_validator();
+    let current = json!({"title": "draft", "owner": {"email": "a@example.com"}});
+    // 缺少必填字段的错误位于上级对象，按缺少的字段匹配操作
+    let result = validator.validate_patch(&current, &patch(json!([
+        {"op": "add", "path": "/tags", "value": []},
+        {"op": "remove", "path": "/title"}
+    ])));
+    assert_eq!(ops_by_field(&result), vec![(String::new(), json!(1))]);
+
+    let result = validator.validate_patch(
+        &current,
+        &patch(json!([{"op": "move", "from": "/owner/email", "path": "/contact"}])),
+    );
+    assert_eq!(ops_by_field(&result), vec![("owner".to_string(), json!(0))]);
+}
+
+#[test]
+fn test_existing_errors_have_no_op() {
+    let validator = document_validator();
+    let current = json!({"title": "a much longer title"});
+    let result = validator.validate_patch(&current, &patch(json!([{"op": "add", "path": "/tags", "value": []}])));
+    assert_eq!(ops_by_field(&result), vec![("title".to_string(), Value::Null)]);
+}
+
+#[test]
+fn test_patch_that_cannot_apply() {
+    let validator = document_validator();
+    let result = validator.validate_patch(
+        &json!({"title": "draft"}),
+        &patch(json!([
+            {"op": "replace", "path": "/title", "value": "x"},
+            {"op": "remove", "path": "/missing"}
+        ])),
+    );
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["code"], "patch.apply");
+    assert_eq!(result.errors[0]["params"]["op"], 1);
+}
@@ -0,0 +1,62 @@
+//! Labeled enum tests for link-validator
+
+use link_validator::{EnumOption, LinkValidator};
+use serde_json::json;
+
+fn validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "gender": {
+            "type": "enum",
+            "required": true,
+            "enum": [{"value": 1, "label": "男"}, {"value": 2, "label": "女"}, {"value": 0}]
+        }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_values_used_for_validation() {
+    let validator = validator();
+    assert!(validator.warnings().is_empty());
+    assert_eq!(validator.json_schema()["properties"]["gender"]["enum"], json!([1, 2, 0]));
+
+    assert!(validator.validate(&json!({"gender": 1})).is_valid);
+    assert!(validator.validate(&json!({"gender": 0})).is_valid);
+    assert!(!validator.validate(&json!({"gender": 3})).is_valid);
+    assert!(!validator.validate(&json!({"gender": {"value": 1, "label": "男"}})).is_valid);
+}
+
+#[test]
+fn test_labels_in_schema_info() {
+    let info = validator().schema_info();
+    assert_eq!(
+        info.fields[0].options,
+        [
+            EnumOption { value: json!(1), label: Some("男".to_string()) },
+            EnumOption { value: json!(2), label: Some("女".to_string()) },
+            EnumOption { value: json!(0), label: None },
+        ]
+    );
+}
+
+#[test]
+fn test_labels_in_zod_export() {
+    let zod = validator().to_zod();
+    assert!(zod.contains(r#"z.union([z.literal(1).describe("男"), z.literal(2).describe("女"), z.literal(0)])"#), "{}", zod);
+}
+
+#[test]
+fn test_nullable_and_plain_enums() {
+    let validator = LinkValidator::new(&json!({
+        "level": {"type": "enum", "nullable": true, "enum": [{"value": "low", "label": "Low"}, "high"]},
+        "shape": {"type": "enum", "enum": [{"value": 1, "extra": true}]}
+    }))
+    .unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["level"]["enum"], json!(["low", "high", null]));
+    assert_eq!(properties["level"]["x-options"], json!([{"value": "low", "label": "Low"}, {"value": "high"}]));
+    // 含其他键的对象是普通枚举值
+    assert_eq!(properties["shape"]["enum"], json!([{"value": 1, "extra": true}]));
+    assert!(properties["shape"].get("x-options").is_none());
+    assert!(validator.validate(&json!({"level": null})).is_valid);
+}
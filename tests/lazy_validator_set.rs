@@ -0,0 +1,69 @@
+//! Lazy validator set tests for link-validator
+
+use link_validator::{CompileOptions, LazyValidatorSet, NumberFormat};
+use serde_json::json;
+use std::sync::Arc;
+
+#[test]
+fn test_compiles_on_first_use() {
+    let mut set = LazyValidatorSet::new();
+    set.add("user", json!({"name": {"type": "string", "required": true}}));
+    set.add("event", json!({"type": "object", "required": ["id"]}));
+    assert_eq!(set.len(), 2);
+    assert_eq!(set.names().collect::<Vec<_>>(), vec!["event", "user"]);
+    assert!(!set.is_compiled("user"));
+
+    assert!(set.validate("user", &json!({"name": "john"})).unwrap().is_valid);
+    assert!(set.is_compiled("user"));
+    assert!(!set.is_compiled("event"));
+    assert_eq!(set.get("user").unwrap().name(), Some("user"));
+
+    assert!(!set.validate("event", &json!({})).unwrap().is_valid);
+    assert_eq!(set.validate("order", &json!({})).unwrap_err(), "Unknown schema 'order'");
+}
+
+#[test]
+fn test_shared_definitions() {
+    let mut set = LazyValidatorSet::new();
+    set.add("shop", json!({"address": {"type": "object", "required": true, "$use": "address"}}));
+    set.add("place", json!({"type": "object", "properties": {"address": {"$ref": "#/$defs/address"}}}));
+    set.define("address", json!({"city": {"type": "string", "required": true}}));
+
+    assert!(!set.validate("shop", &json!({"address": {}})).unwrap().is_valid);
+    assert!(!set.validate("place", &json!({"address": {}})).unwrap().is_valid);
+    assert!(set.validate("place", &json!({"address": {"city": "Paris"}})).unwrap().is_valid);
+
+    // 新的定义使缓存失效
+    set.define("address", json!({"city": {"type": "string"}}));
+    assert!(!set.is_compiled("shop"));
+    assert!(set.validate("shop", &json!({"address": {}})).unwrap().is_valid);
+}
+
+#[test]
+fn test_compile_errors_are_cached() {
+    let mut set = LazyValidatorSet::new();
+    set.add("broken", json!({"code": {"type": "string", "pattern": "(unclosed"}}));
+    let error = set.validate("broken", &json!({})).unwrap_err();
+    assert!(error.starts_with("Failed to compile 'broken'"));
+    assert!(set.is_compiled("broken"));
+    assert_eq!(set.get("broken").unwrap_err(), error);
+
+    set.add("broken", json!({"code": {"type": "string"}}));
+    assert!(set.validate("broken", &json!({"code": "x"})).unwrap().is_valid);
+}
+
+#[test]
+fn test_options_and_concurrent_use() {
+    let options = CompileOptions::new().number_format(NumberFormat::DecimalComma);
+    let mut set = LazyValidatorSet::with_options(&options);
+    set.add("price", json!({"amount": {"type": "number", "min": 0}}));
+    let set = Arc::new(set);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let set = Arc::clone(&set);
+            std::thread::spawn(move || set.validate("price", &json!({"amount": "1.234,5"})).unwrap().is_valid)
+        })
+        .collect();
+    assert!(handles.into_iter().all(|h| h.join().unwrap()));
+}
@@ -0,0 +1,91 @@
+//! String length unit tests for link-validator
+
+use link_validator::{CompileOptions, LengthUnit, LinkValidator};
+use serde_json::json;
+
+fn compile(rules: serde_json::Value, unit: LengthUnit) -> LinkValidator {
+    LinkValidator::with_options(&rules, &CompileOptions::new().length_unit(unit)).unwrap()
+}
+
+#[test]
+fn test_default_counts_scalar_values() {
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "max": 2}})).unwrap();
+    assert!(validator.validate(&json!({"name": "é"})).is_valid);
+    // "e" + 组合重音符是两个标量值
+    assert!(validator.validate(&json!({"name": "e\u{301}"})).is_valid);
+    assert!(!validator.validate(&json!({"name": "e\u{301}x"})).is_valid);
+}
+
+#[test]
+fn test_graphemes() {
+    let validator = compile(json!({"name": {"type": "string", "min": 2, "max": 3}}), LengthUnit::Graphemes);
+    assert!(validator.validate(&json!({"name": "e\u{301}x\u{301}y"})).is_valid);
+    assert!(validator.validate(&json!({"name": "👨‍👩‍👧👍"})).is_valid);
+
+    let result = validator.validate(&json!({"name": "👨‍👩‍👧"}));
+    assert!(!result.is_valid);
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]["message"].as_str().unwrap().contains("shorter than 2 characters"));
+    assert!(!validator.validate(&json!({"name": "abcd"})).is_valid);
+}
+
+#[test]
+fn test_graphemes_in_indic_and_southeast_asian_scripts() {
+    let validator = compile(json!({"name": {"type": "string", "len": 2}}), LengthUnit::Graphemes);
+    // 每个字符串都是两个用户可见的字符，按标量值计数都超过 2
+    for name in ["நிலா", "ກີບ", "क्षत्र", "ক্ষমা", "ที่นี่"] {
+        assert!(validator.validate(&json!({"name": name})).is_valid, "{}", name);
+    }
+    assert!(!validator.validate(&json!({"name": "क्षत्रिय"})).is_valid);
+}
+
+#[test]
+fn test_utf16_matches_javascript_length() {
+    let validator = compile(json!({"code": {"type": "string", "len": 2}}), LengthUnit::Utf16);
+    assert!(validator.validate(&json!({"code": "😀"})).is_valid);
+    assert!(validator.validate(&json!({"code": "ab"})).is_valid);
+
+    let result = validator.validate(&json!({"code": "😀😀"}));
+    assert!(!result.is_valid);
+    let errors = result.errors.as_array().unwrap();
+    assert!(errors[0]["message"].as_str().unwrap().contains("longer than 2 UTF-16 code units"));
+}
+
+#[test]
+fn test_utf8_bytes() {
+    let validator = compile(json!({"title": {"type": "string", "max": 6}}), LengthUnit::Utf8Bytes);
+    assert!(validator.validate(&json!({"title": "中文"})).is_valid);
+    let result = validator.validate(&json!({"title": "中文字"}));
+    assert!(!result.is_valid);
+    assert!(result.errors.as_array().unwrap()[0]["message"].as_str().unwrap().contains("longer than 6 bytes"));
+}
+
+#[test]
+fn test_nested_and_array_strings() {
+    let validator = compile(
+        json!({
+            "tags": {"type": "array", "fields": {"label": {"type": "string", "max": 4}}},
+            "profile": {"type": "object", "fields": {"nick": {"type": "string", "max": 4}}}
+        }),
+        LengthUnit::Utf8Bytes,
+    );
+    assert!(validator.validate(&json!({"tags": [{"label": "ab"}], "profile": {"nick": "abcd"}})).is_valid);
+    let result = validator.validate(&json!({"tags": [{"label": "ab"}, {"label": "中文"}], "profile": {"nick": "中文"}}));
+    assert!(!result.is_valid);
+    let mut fields: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["field"].as_str().unwrap()).collect();
+    fields.sort();
+    assert_eq!(fields, vec!["profile.nick", "tags.1.label"]);
+}
+
+#[test]
+fn test_json_schema_input() {
+    let schema = json!({
+        "type": "object",
+        "properties": {"name": {"type": "string", "maxLength": 1}}
+    });
+    let validator = LinkValidator::with_options(&schema, &CompileOptions::new().length_unit(LengthUnit::Graphemes)).unwrap();
+    assert_eq!(validator.json_schema()["properties"]["name"]["maxLength"], json!(1));
+    assert!(validator.validate(&json!({"name": "👨‍👩‍👧"})).is_valid);
+    assert!(!validator.validate(&json!({"name": "ab"})).is_valid);
+}
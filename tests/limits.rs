@@ -0,0 +1,118 @@
+//! Schema limit tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, SchemaRegistry, ValidatorCache};
+use serde_json::{json, Map, Value};
+use std::fs;
+use std::time::Duration;
+
+fn wide_rules(fields: usize) -> Value {
+    let mut rules = Map::new();
+    for i in 0..fields {
+        rules.insert(format!("field{}", i), json!({"type": "string", "required": true}));
+    }
+    Value::Object(rules)
+}
+
+#[test]
+fn test_max_schema_bytes() {
+    let schema = wide_rules(50);
+    let size = serde_json::to_string(&schema).unwrap().len();
+
+    let err = LinkValidator::with_options(&schema, &CompileOptions::new().max_schema_bytes(size - 1)).unwrap_err();
+    assert!(err.starts_with("Schema limit exceeded:"), "{}", err);
+    assert!(err.contains("max_schema_bytes"), "{}", err);
+
+    assert!(LinkValidator::with_options(&schema, &CompileOptions::new().max_schema_bytes(size)).is_ok());
+}
+
+#[test]
+fn test_max_fields() {
+    let options = CompileOptions::new().max_fields(10);
+    assert!(LinkValidator::with_options(&wide_rules(10), &options).is_ok());
+
+    let err = LinkValidator::with_options(&wide_rules(11), &options).unwrap_err();
+    assert!(err.contains("11 fields (max_fields 10)"), "{}", err);
+
+    // 嵌套对象和 JSON Schema 中的属性同样计数
+    let nested = json!({"user": {"type": "object", "fields": wide_rules(10)}});
+    assert!(LinkValidator::with_options(&nested, &options).is_err());
+
+    let json_schema = json!({"type": "object", "properties": {"a": {"type": "object", "properties": {"b": {}, "c": {}}}}});
+    assert!(LinkValidator::with_options(&json_schema, &CompileOptions::new().max_fields(2)).is_err());
+    assert!(LinkValidator::with_options(&json_schema, &CompileOptions::new().max_fields(3)).is_ok());
+}
+
+#[test]
+fn test_compile_budget() {
+    let err = LinkValidator::with_options(&wide_rules(500), &CompileOptions::new().compile_budget(Duration::ZERO)).unwrap_err();
+    assert!(err.contains("compile_budget"), "{}", err);
+
+    assert!(LinkValidator::with_options(&wide_rules(5), &CompileOptions::new().compile_budget(Duration::from_secs(60))).is_ok());
+}
+
+#[test]
+fn test_registry_limits() {
+    let dir = std::env::temp_dir().join(format!("link-validator-limits-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("small.json"), wide_rules(2).to_string()).unwrap();
+    fs::write(dir.join("large.json"), wide_rules(200).to_string()).unwrap();
+
+    let err = SchemaRegistry::load_dir_with_options(&dir, &CompileOptions::new().max_schema_bytes(1024)).unwrap_err();
+    assert!(err.contains("large.json") && err.contains("Schema limit exceeded"), "{}", err);
+
+    let err = SchemaRegistry::load_dir_with_options(&dir, &CompileOptions::new().max_fields(100)).unwrap_err();
+    assert!(err.contains("max_fields"), "{}", err);
+
+    let registry = SchemaRegistry::load_dir_with_options(&dir, &CompileOptions::new().max_fields(200)).unwrap();
+    assert_eq!(registry.len(), 2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_validator_cache_respects_limits() {
+    let cache = ValidatorCache::new(10);
+    let schema = wide_rules(20);
+    assert!(cache.get_or_compile(&schema).is_ok());
+    assert!(cache.get_or_compile_with(&schema, &CompileOptions::new().max_fields(5)).is_err());
+}
+
+fn nested_rules(levels: usize) -> Value {
+    let mut rule = json!({"type": "string"});
+    for _ in 0..levels {
+        rule = json!({"type": "object", "fields": {"child": rule}});
+    }
+    json!({"root": rule})
+}
+
+#[test]
+fn test_max_depth() {
+    // 根节点和字段规则共 2 层，每层 object 规则再增加 2 层（fields 和子字段规则）
+    let schema = nested_rules(3);
+    let err = LinkValidator::with_options(&schema, &CompileOptions::new().max_depth(7)).unwrap_err();
+    assert!(err.starts_with("Schema limit exceeded:"), "{}", err);
+    assert!(err.contains("nesting depth 8 (max_depth 7)"), "{}", err);
+    assert!(LinkValidator::with_options(&schema, &CompileOptions::new().max_depth(8)).is_ok());
+}
+
+#[test]
+fn test_max_depth_rejects_before_conversion() {
+    // 深度远超转换递归能承受的规则也能直接拒绝
+    let mut rule = json!({"type": "string"});
+    for _ in 0..100_000 {
+        let mut fields = Map::new();
+        fields.insert("child".to_string(), rule);
+        let mut parent = Map::new();
+        parent.insert("type".to_string(), json!("object"));
+        parent.insert("fields".to_string(), Value::Object(fields));
+        rule = Value::Object(parent);
+    }
+    let mut root = Map::new();
+    root.insert("root".to_string(), rule);
+    let schema = Value::Object(root);
+    let err = LinkValidator::with_options(&schema, &CompileOptions::new().max_depth(64)).unwrap_err();
+    assert!(err.contains("max_depth 64"), "{}", err);
+    // 深层嵌套的 Value 递归析构可能栈溢出，测试中直接泄漏
+    std::mem::forget(schema);
+}
@@ -0,0 +1,57 @@
+//! Compile-time schema macro tests for link-validator (requires the `derive` feature)
+
+#![cfg(feature = "derive")]
+
+use link_validator::{link_schema, LinkValidator, SchemaFormat};
+use serde_json::{json, Value};
+
+const SIGNUP: &str = link_schema!({
+    "username": {"type": "string", "required": true, "min": 3, "pattern": "^[a-z]+$"},
+    "age": [{"type": "integer"}, {"min": -1, "max": 150}],
+    "role": {"type": "enum", "enum": ["admin", "member"]},
+    "address": {"type": "object", "fields": {"zip": {"type": "string", "len": 6}}},
+    "score": {"type": "number", "multipleOf": 0.5, "nullable": true},
+});
+
+#[test]
+fn test_constant_matches_runtime_conversion() {
+    let runtime = LinkValidator::new(&json!({
+        "username": {"type": "string", "required": true, "min": 3, "pattern": "^[a-z]+$"},
+        "age": [{"type": "integer"}, {"min": -1, "max": 150}],
+        "role": {"type": "enum", "enum": ["admin", "member"]},
+        "address": {"type": "object", "fields": {"zip": {"type": "string", "len": 6}}},
+        "score": {"type": "number", "multipleOf": 0.5, "nullable": true}
+    }))
+    .unwrap();
+    let constant: Value = serde_json::from_str(SIGNUP).unwrap();
+    assert_eq!(constant["x-link-schema"], 1);
+    assert_eq!(LinkValidator::from_link_schema(SIGNUP).unwrap().json_schema(), runtime.json_schema());
+}
+
+#[test]
+fn test_validator_from_constant() {
+    let validator = LinkValidator::from_link_schema(SIGNUP).unwrap();
+    assert_eq!(validator.format(), SchemaFormat::AsyncValidator);
+    assert!(validator.validate(&json!({"username": "ada", "age": -1, "address": {"zip": "123456"}})).is_valid);
+
+    let result = validator.validate(&json!({"username": "A", "age": -2, "role": "guest"}));
+    assert!(!result.is_valid);
+    let fields: Vec<&str> = result.errors.as_array().unwrap().iter().filter_map(|e| e["field"].as_str()).collect();
+    assert!(fields.contains(&"username") && fields.contains(&"age") && fields.contains(&"role"), "{:?}", fields);
+    assert!(result.errors.as_array().unwrap().iter().all(|e| e["rulePath"].is_string()));
+}
+
+#[test]
+fn test_invalid_constant() {
+    assert!(LinkValidator::from_link_schema("{").is_err());
+}
+
+#[test]
+fn test_rejects_unmarked_schema() {
+    // 普通 JSON Schema 没有宏写入的标记
+    let error = LinkValidator::from_link_schema(r#"{"type":"object","required":["name"]}"#).unwrap_err();
+    assert!(error.contains("link_schema!"), "{}", error);
+
+    let error = LinkValidator::from_link_schema(r#"{"type":"object","x-link-schema":99}"#).unwrap_err();
+    assert!(error.contains("version 99"), "{}", error);
+}
@@ -0,0 +1,111 @@
+//! Lint tests for link-validator
+
+use link_validator::{lint, LintFinding};
+use serde_json::json;
+
+fn codes(findings: &[LintFinding]) -> Vec<(String, String)> {
+    findings.iter().map(|f| (f.path.clone(), f.code.clone())).collect()
+}
+
+fn pair(path: &str, code: &str) -> (String, String) {
+    (path.to_string(), code.to_string())
+}
+
+#[test]
+fn test_clean_rules_have_no_findings() {
+    let rules = json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 20, "pattern": "^[a-z]+$"},
+        "role": {"type": "enum", "enum": ["admin", "user"]},
+        "address": {"type": "object", "fields": {"city": {"type": "string", "len": 4}}}
+    });
+    assert!(lint(&rules).is_empty(), "{:?}", lint(&rules));
+}
+
+#[test]
+fn test_rule_findings() {
+    let rules = json!({
+        "name": [{"type": "string", "min": 10}, {"max": 5}],
+        "code": {"type": "string", "len": 6, "min": 2},
+        "level": {"type": "integer", "enum": [1, "2", 3.5]},
+        "tag": {"type": "string", "pattern": "[a-"},
+        "never": {"type": "string", "pattern": "[^\\s\\S]"},
+        "kind": {"type": "text"},
+        "meta": {"type": "string", "fields": {"a": {"type": "string"}}},
+        "blocks": {"type": "object", "itemsBy": {"field": "kind", "mapping": {}}},
+        "address": {"type": "object", "fields": {"zip": {"type": "string", "min": 6, "max": 5}}},
+        "lines": {"type": "array", "fields": {"qty": {"type": "int"}}}
+    });
+    let findings = codes(&lint(&rules));
+
+    for expected in [
+        pair("name", "min-greater-than-max"),
+        pair("code", "len-with-min-max"),
+        pair("level", "enum-type-mismatch"),
+        pair("tag", "invalid-pattern"),
+        pair("never", "pattern-never-matches"),
+        pair("kind", "unknown-type"),
+        pair("meta", "unreachable-branch"),
+        pair("blocks", "unreachable-branch"),
+        pair("address.zip", "min-greater-than-max"),
+        pair("lines[].qty", "unknown-type"),
+    ] {
+        assert!(findings.contains(&expected), "missing {:?} in {:?}", expected, findings);
+    }
+    // "2" 和 3.5 都不是整数
+    assert_eq!(findings.iter().filter(|f| f.1 == "enum-type-mismatch").count(), 2);
+}
+
+#[test]
+fn test_json_schema_findings() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string", "minLength": 5, "maxLength": 2},
+            "age": {"type": "integer", "minimum": 10, "maximum": 1},
+            "status": {"type": "string", "enum": ["on", 1]},
+            "flag": {"type": "boolean", "const": "yes"},
+            "id": {"type": "uuid"},
+            "look": {"type": "string", "pattern": "^(?=a)a$"},
+            "cond": {"then": {"required": ["x"]}},
+            "always": {"if": true, "then": {}, "else": {"minimum": 1}},
+            "items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {"kind": {"enum": ["a"]}},
+                    "allOf": [
+                        {"if": {"properties": {"kind": {"const": "a"}}}, "then": {}},
+                        {"if": {"properties": {"kind": {"const": "b"}}}, "then": {}}
+                    ]
+                }
+            }
+        }
+    });
+    let findings = codes(&lint(&schema));
+
+    assert_eq!(
+        findings,
+        vec![
+            pair("/properties/age", "min-greater-than-max"),
+            pair("/properties/always", "unreachable-branch"),
+            pair("/properties/cond", "unreachable-branch"),
+            pair("/properties/flag", "enum-type-mismatch"),
+            pair("/properties/id", "unknown-type"),
+            pair("/properties/items/items/allOf/1", "unreachable-branch"),
+            pair("/properties/name", "min-greater-than-max"),
+            pair("/properties/status", "enum-type-mismatch"),
+        ]
+    );
+}
+
+#[test]
+fn test_finding_display() {
+    let findings = lint(&json!({"age": {"type": "number", "min": 3, "max": 1}}));
+    assert_eq!(findings[0].to_string(), "age [min-greater-than-max] min 3 is greater than max 1");
+}
+
+#[test]
+fn test_const_type_mismatch() {
+    let findings = lint(&json!({"version": {"type": "string", "const": 2}}));
+    assert_eq!(codes(&findings), vec![pair("version", "enum-type-mismatch")]);
+}
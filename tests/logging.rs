@@ -0,0 +1,46 @@
+//! Diagnostic logging tests for link-validator
+#![cfg(not(feature = "tracing"))]
+
+use link_validator::LinkValidator;
+use log::{Level, Metadata, Record};
+use serde_json::json;
+use std::sync::Mutex;
+
+/// 记录 `link_validator` 输出的日志
+struct Capture(Mutex<Vec<(Level, String)>>);
+
+impl log::Log for Capture {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.target() == "link_validator"
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.0.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+#[test]
+fn test_warnings_go_through_log() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Trace);
+
+    LinkValidator::new(&json!({
+        "name": {"type": "string", "trigger": "blur", "validator": "checkName"}
+    }))
+    .unwrap();
+
+    let records = LOGGER.0.lock().unwrap();
+    assert_eq!(
+        *records,
+        [
+            (Level::Warn, "Field 'name': validator function not supported".to_string()),
+            (Level::Info, "Field 'name': trigger option not supported".to_string()),
+        ]
+    );
+}
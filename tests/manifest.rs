@@ -0,0 +1,70 @@
+//! Manifest tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, NullPolicy, NumberFormat, ValidationError};
+use serde_json::{json, Value};
+
+#[test]
+fn test_manifest_contents() {
+    let schema = json!({
+        "$schema": "http://json-schema.org/draft-06/schema#",
+        "type": "object",
+        "required": ["id"],
+        "properties": {
+            "id": {"type": "integer", "minimum": 1},
+            "tags": {"type": "array", "items": {"type": "string", "x-widget": "chips"}}
+        },
+        "definitions": {"money": {"type": "number", "x-currency": "EUR"}}
+    });
+    let options = CompileOptions::new()
+        .number_format(NumberFormat::DecimalComma)
+        .null_policy(NullPolicy::AsMissing);
+    let mut validator = LinkValidator::with_options(&schema, &options).unwrap();
+    validator.register_document_validator(|_: &Value| Vec::<ValidationError>::new());
+
+    let manifest = validator.with_name("order").manifest();
+    let fingerprint = manifest["fingerprint"].clone();
+    assert_eq!(manifest, json!({
+        "manifest_version": 1,
+        "name": "order",
+        "format": "json-schema",
+        "fingerprint": fingerprint,
+        "draft": "draft-06",
+        "options": {
+            "number_format": "decimal-comma",
+            "boolean_tokens": null,
+            "null_policy": "as-missing",
+            "titles": null
+        },
+        "rules": {
+            "fields": 2,
+            "constraints": 7,
+            "definitions": 1,
+            "document_validators": 1
+        },
+        "custom_keywords": ["x-currency", "x-widget"],
+        "warnings": []
+    }));
+}
+
+#[test]
+fn test_fingerprint_tracks_configuration() {
+    let schema = json!({"name": {"type": "string", "required": true}});
+    let a = LinkValidator::new(&schema).unwrap().manifest();
+    let b = LinkValidator::new(&schema).unwrap().with_name("other").manifest();
+    assert_eq!(a["fingerprint"], b["fingerprint"]);
+    assert_eq!(a["name"], Value::Null);
+    assert_eq!(a["draft"], "draft-07");
+
+    let stricter = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "min": 2}})).unwrap();
+    assert_ne!(a["fingerprint"], stricter.manifest()["fingerprint"]);
+
+    let options = CompileOptions::new().null_policy(NullPolicy::StripOptional);
+    let with_options = LinkValidator::with_options(&schema, &options).unwrap();
+    assert_ne!(a["fingerprint"], with_options.manifest()["fingerprint"]);
+}
+
+#[test]
+fn test_fingerprint_matches_validator() {
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}})).unwrap();
+    assert_eq!(validator.manifest()["fingerprint"], format!("{:016x}", validator.fingerprint()));
+}
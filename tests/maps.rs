@@ -0,0 +1,100 @@
+//! Map rule tests for link-validator
+
+use link_validator::{LinkValidator, SampleOptions};
+use serde_json::json;
+
+#[test]
+fn test_key_pattern_and_value_rule() {
+    let validator = LinkValidator::new(&json!({
+        "prices": {"type": "object", "required": true, "keyPattern": "^[A-Z]{3}$", "valueRule": {"type": "number", "min": 0}}
+    }))
+    .unwrap();
+
+    let schema = &validator.json_schema()["properties"]["prices"];
+    assert_eq!(schema["propertyNames"], json!({"pattern": "^[A-Z]{3}$"}));
+    assert_eq!(schema["additionalProperties"], json!({"type": "number", "minimum": 0}));
+
+    assert!(validator.validate(&json!({"prices": {"USD": 1.5, "CNY": 10}})).is_valid);
+    assert!(validator.validate(&json!({"prices": {}})).is_valid);
+
+    let result = validator.validate(&json!({"prices": {"usd": 1.5}}));
+    assert!(!result.is_valid);
+    let result = validator.validate(&json!({"prices": {"EUR": -1}}));
+    assert_eq!(result.errors[0]["field"], json!("prices.EUR"));
+}
+
+#[test]
+fn test_value_rule_with_fields() {
+    let validator = LinkValidator::new(&json!({
+        "labels": {
+            "type": "object",
+            "fields": {"default": {"type": "string", "required": true}},
+            "valueRule": {"type": "string", "max": 5}
+        }
+    }))
+    .unwrap();
+
+    assert!(validator.validate(&json!({"labels": {"default": "a long default", "zh": "标签"}})).is_valid);
+    assert!(!validator.validate(&json!({"labels": {"default": "x", "en": "too long"}})).is_valid);
+    assert!(!validator.validate(&json!({"labels": {"zh": "标签"}})).is_valid);
+}
+
+#[test]
+fn test_nested_value_rule() {
+    let validator = LinkValidator::new(&json!({
+        "inventory": {
+            "type": "object",
+            "keyPattern": "^[a-z]+$",
+            "valueRule": {"type": "object", "fields": {"qty": {"type": "integer", "required": true, "min": 0}}}
+        }
+    }))
+    .unwrap();
+
+    assert!(validator.validate(&json!({"inventory": {"apple": {"qty": 3}}})).is_valid);
+    let result = validator.validate(&json!({"inventory": {"apple": {"qty": -1}}}));
+    assert_eq!(result.errors[0]["field"], json!("inventory.apple.qty"));
+
+    for seed in 0..20 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(validator.validate(&sample).is_valid, "{}", sample);
+    }
+}
+
+#[test]
+fn test_map_rules_on_other_types() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "keyPattern": "^a$"}
+    }))
+    .unwrap();
+    assert!(validator.warnings().iter().any(|w| w.message.contains("keyPattern rule only supported for object type")));
+}
+
+#[test]
+fn test_object_min_and_max_properties() {
+    let validator = LinkValidator::new(&json!({
+        "labels": {"type": "object", "min": 1, "max": 2, "valueRule": {"type": "string"}}
+    }))
+    .unwrap();
+
+    let schema = &validator.json_schema()["properties"]["labels"];
+    assert_eq!(schema["minProperties"], json!(1));
+    assert_eq!(schema["maxProperties"], json!(2));
+    assert!(schema.get("minimum").is_none());
+
+    assert!(validator.validate(&json!({"labels": {"en": "a"}})).is_valid);
+    assert!(!validator.validate(&json!({"labels": {}})).is_valid);
+    assert!(!validator.validate(&json!({"labels": {"en": "a", "zh": "b", "ja": "c"}})).is_valid);
+}
+
+#[test]
+fn test_samples_respect_property_counts() {
+    let validator = LinkValidator::new(&json!({
+        "scores": {"type": "object", "required": true, "min": 3, "max": 4, "keyPattern": "^[a-z]{4}$", "valueRule": {"type": "integer"}}
+    }))
+    .unwrap();
+
+    for seed in 0..20 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(validator.validate(&sample).is_valid, "{}", sample);
+    }
+}
@@ -0,0 +1,84 @@
+//! Rule merging tests for link-validator
+
+use link_validator::{merge_rules, LinkValidator, MergeStrategy};
+use serde_json::json;
+
+fn base() -> serde_json::Value {
+    json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "role": {"type": "enum", "enum": ["user", "guest"]},
+        "tags": [{"type": "array"}, {"max": 5}],
+        "address": {
+            "type": "object",
+            "fields": {
+                "city": {"type": "string", "required": true},
+                "zip": {"type": "string", "pattern": "^[0-9]{5}$"}
+            }
+        }
+    })
+}
+
+#[test]
+fn test_overlay_wins_and_deep_merges() {
+    let overlay = json!({
+        "username": {"min": 5},
+        "address": {"fields": {"zip": {"required": true}, "country": {"type": "string"}}},
+        "email": {"type": "email"}
+    });
+    let merged = merge_rules(&base(), &overlay, MergeStrategy::Replace);
+
+    assert_eq!(merged["username"], json!({"type": "string", "required": true, "min": 5}));
+    assert_eq!(merged["address"]["fields"]["zip"], json!({"type": "string", "pattern": "^[0-9]{5}$", "required": true}));
+    assert_eq!(merged["address"]["fields"]["city"]["required"], json!(true));
+    assert_eq!(merged["address"]["fields"]["country"], json!({"type": "string"}));
+    assert_eq!(merged["email"], json!({"type": "email"}));
+
+    // 输入不被修改
+    assert_eq!(base()["username"]["min"], json!(3));
+}
+
+#[test]
+fn test_array_strategies() {
+    let overlay = json!({
+        "role": {"enum": ["admin", "user"]},
+        "tags": [{"unique": true}]
+    });
+
+    let replaced = merge_rules(&base(), &overlay, MergeStrategy::Replace);
+    assert_eq!(replaced["role"]["enum"], json!(["admin", "user"]));
+    assert_eq!(replaced["tags"], json!([{"unique": true}]));
+
+    let concatenated = merge_rules(&base(), &overlay, MergeStrategy::Concat);
+    assert_eq!(concatenated["role"]["enum"], json!(["user", "guest", "admin"]));
+    assert_eq!(concatenated["tags"], json!([{"type": "array"}, {"max": 5}, {"unique": true}]));
+}
+
+#[test]
+fn test_single_rule_and_rule_array() {
+    let overlay = json!({"username": [{"pattern": "^[a-z]+$"}]});
+    let merged = merge_rules(&base(), &overlay, MergeStrategy::Concat);
+    assert_eq!(
+        merged["username"],
+        json!([{"type": "string", "required": true, "min": 3}, {"pattern": "^[a-z]+$"}])
+    );
+
+    let merged = merge_rules(&base(), &overlay, MergeStrategy::Replace);
+    assert_eq!(merged["username"], json!([{"pattern": "^[a-z]+$"}]));
+}
+
+#[test]
+fn test_null_removes_keys() {
+    let overlay = json!({"role": null, "username": {"required": null}});
+    let merged = merge_rules(&base(), &overlay, MergeStrategy::Replace);
+    assert!(merged.get("role").is_none());
+    assert_eq!(merged["username"], json!({"type": "string", "min": 3}));
+}
+
+#[test]
+fn test_merged_rules_compile() {
+    let overlay = json!({"username": {"max": 8}, "address": {"fields": {"zip": {"required": true}}}});
+    let validator = LinkValidator::new(&merge_rules(&base(), &overlay, MergeStrategy::Concat)).unwrap();
+    assert!(validator.validate(&json!({"username": "alice", "address": {"city": "X", "zip": "12345"}})).is_valid);
+    assert!(!validator.validate(&json!({"username": "alice_the_great"})).is_valid);
+    assert!(!validator.validate(&json!({"username": "alice", "address": {"city": "X"}})).is_valid);
+}
@@ -0,0 +1,74 @@
+//! Meta-schema validation tests for link-validator
+
+use link_validator::{validate_meta, Draft, SchemaFormat};
+use serde_json::json;
+
+#[test]
+fn test_valid_schema() {
+    let result = validate_meta(
+        &json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": {"type": "string", "minLength": 1, "x-label": "Name", "x-widget": "text"},
+                "tags": {"type": "array", "items": {"type": "string"}}
+            }
+        }),
+        Draft::Draft7,
+    );
+    assert!(result.is_valid, "{}", result.errors);
+    assert_eq!(result.format, SchemaFormat::JsonSchema);
+}
+
+#[test]
+fn test_meta_schema_violations() {
+    let result = validate_meta(
+        &json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": -1},
+                "kind": {"type": "strnig"}
+            }
+        }),
+        Draft::Draft7,
+    );
+    assert!(!result.is_valid);
+    let paths: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["instancePath"].as_str().unwrap()).collect();
+    assert!(paths.contains(&"/properties/name/minLength"));
+    assert!(paths.iter().any(|p| p.starts_with("/properties/kind/type")));
+}
+
+#[test]
+fn test_unknown_keywords_are_reported() {
+    let result = validate_meta(
+        &json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLenght": 3},
+                "a/b": {"type": "array", "items": {"maxItem": 2}}
+            },
+            "anyOf": [{"requierd": ["name"]}]
+        }),
+        Draft::default(),
+    );
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 3);
+    assert!(errors.iter().all(|e| e["code"] == "schema.unknownKeyword"));
+    let found: Vec<(&str, &str)> = errors
+        .iter()
+        .map(|e| (e["instancePath"].as_str().unwrap(), e["params"]["keyword"].as_str().unwrap()))
+        .collect();
+    assert!(found.contains(&("/properties/name", "minLenght")));
+    assert!(found.contains(&("/properties/a~1b/items", "maxItem")));
+    assert!(found.contains(&("/anyOf/0", "requierd")));
+    assert!(errors.iter().any(|e| e["message"] == "Unknown keyword 'minLenght'"));
+}
+
+#[test]
+fn test_draft4_meta_schema() {
+    let schema = json!({"type": "integer", "exclusiveMinimum": true, "minimum": 0});
+    assert!(validate_meta(&schema, Draft::Draft4).is_valid);
+    assert!(!validate_meta(&schema, Draft::Draft7).is_valid);
+    assert!(!validate_meta(&schema, Draft::Draft6).is_valid);
+}
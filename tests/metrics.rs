@@ -0,0 +1,112 @@
+//! Metrics tests for link-validator (requires the `metrics` feature)
+
+#![cfg(feature = "metrics")]
+
+use link_validator::LinkValidator;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use serde_json::json;
+use std::time::Duration;
+
+type Labels = Vec<(String, String)>;
+
+/// 按指标名和标签（按名称排序）整理快照
+fn recorded(validate: impl FnOnce()) -> Vec<(String, Labels, DebugValue)> {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, validate);
+
+    let mut entries: Vec<_> = snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let key = key.key();
+            let mut labels: Labels =
+                key.labels().map(|label| (label.key().to_string(), label.value().to_string())).collect();
+            labels.sort();
+            (key.name().to_string(), labels, value)
+        })
+        .collect();
+    entries.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+    entries
+}
+
+fn labels(pairs: &[(&str, &str)]) -> Labels {
+    pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn test_counters_and_histogram() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "age": {"type": "integer", "min": 0}
+    }))
+    .unwrap()
+    .with_name("user");
+
+    let entries = recorded(|| {
+        validator.validate(&json!({"name": "ann", "age": 3}));
+        validator.validate(&json!({"name": "bob"}));
+        validator.validate(&json!({"age": -1}));
+    });
+
+    let counters: Vec<(&str, Labels, u64)> = entries
+        .iter()
+        .filter_map(|(name, labels, value)| match value {
+            DebugValue::Counter(count) => Some((name.as_str(), labels.clone(), *count)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        counters,
+        vec![
+            ("validation_errors_total", labels(&[("code", "number.min"), ("schema", "user")]), 1),
+            ("validation_errors_total", labels(&[("code", "required"), ("schema", "user")]), 1),
+            ("validations_total", labels(&[("result", "invalid"), ("schema", "user")]), 1),
+            ("validations_total", labels(&[("result", "valid"), ("schema", "user")]), 2),
+        ]
+    );
+
+    let durations: Vec<usize> = entries
+        .iter()
+        .filter_map(|(name, labels_, value)| match value {
+            DebugValue::Histogram(samples) if name == "validate_duration_seconds" => {
+                assert_eq!(*labels_, labels(&[("schema", "user")]));
+                Some(samples.len())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(durations, vec![3]);
+}
+
+#[test]
+fn test_unnamed_schema_label() {
+    let validator = LinkValidator::new(&json!({"id": {"type": "integer"}})).unwrap();
+    let entries = recorded(|| {
+        validator.validate_str(r#"{"id": "x"}"#);
+    });
+    assert!(entries.iter().any(|(name, labels_, _)| name == "validations_total"
+        && *labels_ == labels(&[("result", "invalid"), ("schema", "")])));
+}
+
+#[test]
+fn test_cache_hits_recorded() {
+    let validator = LinkValidator::new(&json!({"id": {"type": "integer"}}))
+        .unwrap()
+        .with_name("cached")
+        .with_result_cache(10, Duration::from_secs(60));
+    let entries = recorded(|| {
+        validator.validate(&json!({"id": "x"}));
+        validator.validate(&json!({"id": "x"}));
+    });
+
+    let counter = |name: &str, expected: Labels| {
+        entries.iter().find_map(|(n, l, value)| match value {
+            DebugValue::Counter(count) if n == name && *l == expected => Some(*count),
+            _ => None,
+        })
+    };
+    assert_eq!(counter("validations_total", labels(&[("result", "invalid"), ("schema", "cached")])), Some(2));
+    assert_eq!(counter("validation_cache_hits_total", labels(&[("schema", "cached")])), Some(1));
+}
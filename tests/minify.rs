@@ -0,0 +1,54 @@
+//! Schema minification tests for link-validator
+
+use link_validator::{minify, LinkValidator, MinifyOptions};
+use serde_json::json;
+
+#[test]
+fn test_minify_strips_nested_annotations() {
+    let schema = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "$comment": "user payload",
+        "type": "object",
+        "properties": {
+            // 名为 title 的属性不是注解
+            "title": {"type": "string", "description": "Post title", "default": ""},
+            "tags": {
+                "type": "array",
+                "items": {"type": "string", "examples": ["rust"], "pattern": "^[a-z]+$"}
+            }
+        },
+        "anyOf": [{"required": ["title"], "title": "has title"}]
+    });
+
+    let minified = minify(&schema, &MinifyOptions::new()).expect("Minify failed");
+    assert_eq!(minified, json!({
+        "type": "object",
+        "properties": {
+            "title": {"type": "string"},
+            "tags": {"type": "array", "items": {"type": "string", "pattern": "^[a-z]+$"}}
+        },
+        "anyOf": [{"required": ["title"]}]
+    }));
+}
+
+#[test]
+fn test_minify_async_rules_as_prefilter() {
+    let rules = json!({
+        "email": {"type": "email", "required": true},
+        "code": {"type": "string", "pattern": "^[A-Z]{3}$", "required": true}
+    });
+
+    let options = MinifyOptions::new().strip_patterns(true).strip_formats(true);
+    let minified = minify(&rules, &options).expect("Minify failed");
+    let text = minified.to_string();
+    assert!(!text.contains("pattern"));
+    assert!(!text.contains("format"));
+
+    // 精简后的 schema 更宽松，但仍保留类型和必填约束
+    let full = LinkValidator::new(&rules).expect("Compilation failed");
+    let edge = LinkValidator::new(&minified).expect("Compilation failed");
+    let data = json!({"email": "not-an-email", "code": "abc"});
+    assert!(!full.validate(&data).is_valid);
+    assert!(edge.validate(&data).is_valid);
+    assert!(!edge.validate(&json!({"email": "a@b.co"})).is_valid);
+}
@@ -0,0 +1,92 @@
+//! Null policy tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, NullPolicy};
+use serde_json::json;
+
+fn rules() -> serde_json::Value {
+    json!({
+        "name": {"type": "string", "required": true},
+        "nickname": {"type": "string"},
+        "profile": {
+            "type": "object",
+            "fields": {
+                "bio": {"type": "string"}
+            }
+        }
+    })
+}
+
+#[test]
+fn test_keep_policy_rejects_null() {
+    let validator = LinkValidator::new(&rules()).expect("Compilation failed");
+
+    let result = validator.validate(&json!({"name": "John", "nickname": null}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "nickname");
+}
+
+#[test]
+fn test_strip_optional_policy() {
+    let options = CompileOptions::new().null_policy(NullPolicy::StripOptional);
+    let validator = LinkValidator::with_options(&rules(), &options).expect("Compilation failed");
+
+    let data = json!({"name": "John", "nickname": null, "profile": {"bio": null}});
+    let (value, result) = validator.validate_and_transform(&data);
+    assert!(result.is_valid);
+    assert_eq!(value, json!({"name": "John", "profile": {}}));
+
+    // 必填字段上的 null 保留并报类型错误
+    let result = validator.validate(&json!({"name": null}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "name");
+}
+
+#[test]
+fn test_as_missing_policy() {
+    let options = CompileOptions::new().null_policy(NullPolicy::AsMissing);
+    let validator = LinkValidator::with_options(&rules(), &options).expect("Compilation failed");
+
+    assert!(validator.validate(&json!({"name": "John", "nickname": null})).is_valid);
+
+    // 必填字段上的 null 视为缺失
+    let result = validator.validate(&json!({"name": null}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "");
+    assert!(result.errors[0]["message"].as_str().unwrap().contains("required"));
+}
+
+#[test]
+fn test_nullable_rule() {
+    let validator = LinkValidator::new(&json!({
+        "nickname": {"type": "string", "nullable": true, "min": 2},
+        "level": {"type": "enum", "enum": ["low", "high"], "nullable": true},
+        "profile": {"type": "object", "required": true, "nullable": true, "fields": {"bio": {"type": "string"}}},
+        "age": {"type": "integer"}
+    }))
+    .unwrap();
+
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["nickname"]["type"], json!(["string", "null"]));
+    assert_eq!(properties["level"]["enum"], json!(["low", "high", null]));
+    assert_eq!(properties["profile"]["type"], json!(["object", "null"]));
+
+    assert!(validator.validate(&json!({"nickname": null, "level": null, "profile": null})).is_valid);
+    assert!(validator.validate(&json!({"nickname": "Al", "level": "low", "profile": {"bio": "hi"}})).is_valid);
+    assert!(!validator.validate(&json!({"nickname": "A", "profile": null})).is_valid);
+    assert!(!validator.validate(&json!({"profile": {"bio": null}})).is_valid);
+    // 未声明 nullable 的字段仍拒绝 null
+    assert!(!validator.validate(&json!({"profile": null, "age": null})).is_valid);
+    // nullable 不影响必填检查
+    assert!(!validator.validate(&json!({})).is_valid);
+}
+
+#[test]
+fn test_nullable_required_with_as_missing_policy() {
+    let options = CompileOptions::new().null_policy(NullPolicy::AsMissing);
+    let validator = LinkValidator::with_options(
+        &json!({"name": {"type": "string", "required": true, "nullable": true}}),
+        &options,
+    )
+    .unwrap();
+    assert!(!validator.validate(&json!({"name": null})).is_valid);
+}
@@ -0,0 +1,72 @@
+//! Numeric rule tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+#[test]
+fn test_multiple_of() {
+    let validator = LinkValidator::new(&json!({
+        "price": {"type": "number", "multipleOf": 0.25},
+        "quantity": {"type": "integer", "multipleOf": 5}
+    }))
+    .unwrap();
+
+    assert_eq!(validator.json_schema()["properties"]["price"]["multipleOf"], json!(0.25));
+    assert!(validator.validate(&json!({"price": 19.75, "quantity": 15})).is_valid);
+    assert!(!validator.validate(&json!({"price": 19.8})).is_valid);
+    assert!(!validator.validate(&json!({"quantity": 12})).is_valid);
+}
+
+#[test]
+fn test_exclusive_bounds() {
+    let validator = LinkValidator::new(&json!({
+        "ratio": {"type": "number", "exclusiveMin": 0, "exclusiveMax": 1}
+    }))
+    .unwrap();
+
+    let schema = &validator.json_schema()["properties"]["ratio"];
+    assert_eq!(schema["exclusiveMinimum"], json!(0));
+    assert_eq!(schema["exclusiveMaximum"], json!(1));
+    assert!(validator.validate(&json!({"ratio": 0.5})).is_valid);
+    assert!(!validator.validate(&json!({"ratio": 0})).is_valid);
+    assert!(!validator.validate(&json!({"ratio": 1})).is_valid);
+}
+
+#[test]
+fn test_sign_presets() {
+    let validator = LinkValidator::new(&json!({
+        "amount": {"type": "number", "positive": true},
+        "stock": {"type": "integer", "nonNegative": true},
+        "refund": {"type": "number", "negative": true},
+        "offset": {"type": "integer", "nonPositive": true},
+        "age": {"type": "integer", "min": 18, "nonNegative": true}
+    }))
+    .unwrap();
+
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["amount"]["exclusiveMinimum"], json!(0));
+    assert_eq!(properties["stock"]["minimum"], json!(0));
+    assert_eq!(properties["refund"]["exclusiveMaximum"], json!(0));
+    assert_eq!(properties["offset"]["maximum"], json!(0));
+    // 已有更严格的下限时保留
+    assert_eq!(properties["age"]["minimum"], json!(18));
+
+    assert!(validator.validate(&json!({"amount": 0.01, "stock": 0, "refund": -1, "offset": 0, "age": 18})).is_valid);
+    assert!(!validator.validate(&json!({"amount": 0})).is_valid);
+    assert!(!validator.validate(&json!({"stock": -1})).is_valid);
+    assert!(!validator.validate(&json!({"refund": 0})).is_valid);
+    assert!(!validator.validate(&json!({"offset": 1})).is_valid);
+}
+
+#[test]
+fn test_invalid_numeric_rules() {
+    let error = LinkValidator::new(&json!({"price": {"type": "number", "multipleOf": 0}})).unwrap_err();
+    assert!(error.contains("multipleOf must be greater than 0"), "{}", error);
+
+    let error = LinkValidator::new(&json!({"price": {"type": "number", "exclusiveMin": "0"}})).unwrap_err();
+    assert!(error.contains("exclusiveMin must be a number"), "{}", error);
+
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "positive": true}})).unwrap();
+    assert!(validator.warnings().iter().any(|w| w.message.contains("positive rule only supported for number and integer types")));
+    assert!(validator.json_schema()["properties"]["name"].get("exclusiveMinimum").is_none());
+}
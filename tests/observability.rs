@@ -0,0 +1,84 @@
+//! Validation event tests for link-validator
+
+use link_validator::{LinkValidator, SchemaFormat, ValidateOptions};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+type Recorded = Arc<Mutex<Vec<(Option<String>, SchemaFormat, usize, bool)>>>;
+
+fn recording_validator() -> (LinkValidator, Recorded) {
+    let recorded: Recorded = Arc::default();
+    let sink = recorded.clone();
+    let mut validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "age": {"type": "integer", "min": 0}
+    }))
+    .unwrap()
+    .with_name("user");
+    validator.on_validation(move |event| {
+        sink.lock().unwrap().push((
+            event.name.map(str::to_string),
+            event.format,
+            event.error_count,
+            event.is_valid(),
+        ));
+    });
+    (validator, recorded)
+}
+
+#[test]
+fn test_event_per_validation() {
+    let (validator, recorded) = recording_validator();
+    validator.validate(&json!({"name": "ann", "age": 3}));
+    validator.validate(&json!({"age": -1}));
+
+    let events = recorded.lock().unwrap();
+    assert_eq!(
+        *events,
+        vec![
+            (Some("user".to_string()), SchemaFormat::AsyncValidator, 0, true),
+            (Some("user".to_string()), SchemaFormat::AsyncValidator, 2, false),
+        ]
+    );
+}
+
+#[test]
+fn test_event_respects_fail_fast() {
+    let (validator, recorded) = recording_validator();
+    validator.validate_with(&json!({"age": -1}), &ValidateOptions::new().fail_fast(true));
+    assert_eq!(recorded.lock().unwrap()[0].2, 1);
+}
+
+#[test]
+fn test_clones_share_hook() {
+    let (validator, recorded) = recording_validator();
+    let copy = validator.clone();
+    copy.validate(&json!({"name": "ann"}));
+    assert_eq!(recorded.lock().unwrap().len(), 1);
+}
+
+#[test]
+fn test_event_for_typed_entry_points() {
+    let (validator, recorded) = recording_validator();
+    validator.validate_str(r#"{"name": 1}"#);
+    assert_eq!(recorded.lock().unwrap()[0].2, 1);
+}
+
+#[test]
+fn test_event_error_codes() {
+    let codes: Arc<Mutex<Vec<String>>> = Arc::default();
+    let sink = codes.clone();
+    let mut validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "age": {"type": "integer", "min": 0}
+    }))
+    .unwrap();
+    validator.on_validation(move |event| {
+        sink.lock().unwrap().extend(event.errors.iter().map(|error| error.code.clone()));
+    });
+
+    validator.validate(&json!({"age": -1}));
+    let mut codes = codes.lock().unwrap().clone();
+    codes.sort();
+    assert_eq!(codes, vec!["number.min", "required"]);
+}
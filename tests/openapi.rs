@@ -0,0 +1,155 @@
+//! OpenAPI tests for link-validator
+
+use link_validator::{LinkValidator, SchemaFormat};
+use serde_json::{json, Value};
+
+fn spec() -> Value {
+    json!({
+        "openapi": "3.0.3",
+        "info": {"title": "Shop", "version": "1.0.0"},
+        "paths": {},
+        "components": {
+            "schemas": {
+                "Address": {
+                    "type": "object",
+                    "required": ["city"],
+                    "properties": {"city": {"type": "string", "example": "Berlin"}}
+                },
+                "Order": {
+                    "type": "object",
+                    "required": ["id", "total"],
+                    "discriminator": {"propertyName": "kind"},
+                    "properties": {
+                        "id": {"type": "integer"},
+                        "total": {"type": "number", "minimum": 0, "exclusiveMinimum": true},
+                        "note": {"type": "string", "nullable": true},
+                        "status": {"type": "string", "enum": ["open", "closed"], "nullable": true},
+                        "shipping": {"$ref": "#/components/schemas/Address"},
+                        "billing": {"$ref": "#/components/schemas/Address", "nullable": true},
+                        "example": {"type": "string"}
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[test]
+fn test_from_openapi_component() {
+    let validator = LinkValidator::from_openapi(&spec(), "#/components/schemas/Order").unwrap();
+    assert_eq!(validator.format(), SchemaFormat::JsonSchema);
+
+    let order = json!({
+        "id": 1,
+        "total": 9.5,
+        "note": null,
+        "status": null,
+        "shipping": {"city": "Berlin"},
+        "billing": null,
+        "example": "property named like an OpenAPI keyword"
+    });
+    assert!(validator.validate(&order).is_valid);
+
+    // 布尔形式的 exclusiveMinimum
+    assert!(!validator.validate(&json!({"id": 1, "total": 0})).is_valid);
+    // 引用的组件同样生效
+    assert!(!validator.validate(&json!({"id": 1, "total": 1, "shipping": {}})).is_valid);
+    assert!(!validator.validate(&json!({"id": 1, "total": 1, "billing": {}})).is_valid);
+    assert!(!validator.validate(&json!({"id": 1, "total": 1, "status": "lost"})).is_valid);
+
+    let order_schema = &validator.json_schema()["properties"];
+    assert_eq!(order_schema["note"]["type"], json!(["string", "null"]));
+    assert!(validator.json_schema().get("discriminator").is_none());
+    assert!(validator.json_schema()["properties"].get("example").is_some());
+}
+
+#[test]
+fn test_pointer_forms() {
+    let spec = spec();
+    for pointer in ["Address", "/components/schemas/Address", "#/components/schemas/Address"] {
+        let validator = LinkValidator::from_openapi(&spec, pointer).unwrap();
+        assert!(validator.validate(&json!({"city": "Paris"})).is_valid);
+        assert!(!validator.validate(&json!({})).is_valid);
+    }
+
+    let err = LinkValidator::from_openapi(&spec, "Missing").unwrap_err();
+    assert!(err.contains("not found"), "{}", err);
+}
+
+#[test]
+fn test_unsupported_reference() {
+    let spec = json!({
+        "components": {"schemas": {"User": {"$ref": "https://example.com/user.json"}}}
+    });
+    let err = LinkValidator::from_openapi(&spec, "User").unwrap_err();
+    assert!(err.contains("Unsupported $ref"), "{}", err);
+}
+
+#[test]
+fn test_to_openapi_schema_from_rules() {
+    let rules = json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 20},
+        "email": {"type": "email"},
+        "callback": {"type": "method"},
+        "blocks": {
+            "type": "array",
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "text": {"body": {"type": "string", "required": true}},
+                    "image": {"src": {"type": "url", "required": true}}
+                }
+            }
+        }
+    });
+    let validator = LinkValidator::new(&rules).unwrap();
+    let schema = validator.to_openapi_schema();
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["required"], json!(["name"]));
+    assert_eq!(schema["properties"]["name"], json!({"type": "string", "minLength": 2, "maxLength": 20}));
+    assert_eq!(schema["properties"]["email"]["format"], "email");
+    assert!(schema["properties"]["callback"].get("instanceof").is_none());
+
+    let items = &schema["properties"]["blocks"]["items"];
+    assert!(items.get("allOf").is_none());
+    assert_eq!(items["discriminator"], json!({"propertyName": "kind"}));
+    let variants = items["oneOf"].as_array().unwrap();
+    assert_eq!(variants.len(), 2);
+    let text = variants.iter().find(|v| v["properties"]["kind"]["enum"] == json!(["text"])).unwrap();
+    assert_eq!(text["required"], json!(["body", "kind"]));
+}
+
+#[test]
+fn test_to_openapi_schema_from_json_schema() {
+    let schema = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": {
+            "nickname": {"type": ["string", "null"], "examples": ["neo"]},
+            "score": {"type": "number", "exclusiveMinimum": 0},
+            "version": {"const": 2},
+            "owner": {"$ref": "#/definitions/User"},
+            "tags": {"type": "array", "items": {"type": "string"}, "contains": {"const": "a"}}
+        },
+        "patternProperties": {"^x-": {}},
+        "definitions": {"User": {"type": "object"}}
+    });
+    let openapi = LinkValidator::new(&schema).unwrap().to_openapi_schema();
+    let properties = &openapi["properties"];
+
+    assert_eq!(properties["nickname"], json!({"type": "string", "nullable": true, "example": "neo"}));
+    assert_eq!(properties["score"], json!({"type": "number", "minimum": 0, "exclusiveMinimum": true}));
+    assert_eq!(properties["version"], json!({"enum": [2]}));
+    assert_eq!(properties["owner"]["$ref"], "#/components/schemas/User");
+    assert!(properties["tags"].get("contains").is_none());
+    for keyword in ["$schema", "patternProperties", "definitions"] {
+        assert!(openapi.get(keyword).is_none(), "{}", keyword);
+    }
+
+    // 导出结果可以重新导入
+    let spec = json!({"components": {"schemas": {"User": {"type": "object"}, "Profile": openapi}}});
+    let validator = LinkValidator::from_openapi(&spec, "Profile").unwrap();
+    assert!(validator.validate(&json!({"nickname": null, "score": 1, "version": 2})).is_valid);
+    assert!(!validator.validate(&json!({"score": 0})).is_valid);
+}
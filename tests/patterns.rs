@@ -0,0 +1,54 @@
+//! Pattern safety tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator};
+use serde_json::json;
+
+#[test]
+fn test_invalid_pattern_reports_field() {
+    let err = LinkValidator::new(&json!({"code": {"type": "string", "pattern": "^[a-z"}})).unwrap_err();
+    assert!(err.contains("Field 'code': invalid pattern \"^[a-z\""), "{}", err);
+
+    let err = LinkValidator::new(&json!({"tags": {"type": "object", "keyPattern": "(a"}})).unwrap_err();
+    assert!(err.contains("Field 'tags': keyPattern: invalid pattern"), "{}", err);
+}
+
+#[test]
+fn test_backtracking_patterns_allowed_by_default() {
+    let validator = LinkValidator::new(&json!({"word": {"type": "string", "pattern": "^(\\w)\\1$"}})).unwrap();
+    assert!(validator.validate(&json!({"word": "aa"})).is_valid);
+    assert!(!validator.validate(&json!({"word": "ab"})).is_valid);
+}
+
+#[test]
+fn test_safe_patterns() {
+    let options = CompileOptions::new().safe_patterns();
+
+    let err = LinkValidator::with_options(&json!({"word": {"type": "string", "pattern": "^(\\w)\\1$"}}), &options).unwrap_err();
+    assert!(err.starts_with("Schema limit exceeded:"), "{}", err);
+    assert!(err.contains("require backtracking"), "{}", err);
+
+    let err = LinkValidator::with_options(&json!({"password": {"type": "string", "pattern": "^(?=.*[0-9]).{8,}$"}}), &options).unwrap_err();
+    assert!(err.contains("require backtracking"), "{}", err);
+
+    // 编译后超出大小限制的正则在转换时报告
+    let err = LinkValidator::new(&json!({"x": {"type": "string", "pattern": "\\w{1000}{1000}"}})).unwrap_err();
+    assert!(err.contains("Field 'x': invalid pattern"), "{}", err);
+
+    // 嵌套量词在线性引擎中是安全的
+    let validator = LinkValidator::with_options(&json!({"x": {"type": "string", "pattern": "^(a+)+$"}}), &options).unwrap();
+    assert!(!validator.validate(&json!({"x": format!("{}b", "a".repeat(64))})).is_valid);
+}
+
+#[test]
+fn test_safe_patterns_check_json_schema() {
+    let options = CompileOptions::new().safe_patterns();
+    let schema = json!({
+        "type": "object",
+        "patternProperties": {"^(?!x)": {"type": "string"}}
+    });
+    let err = LinkValidator::with_options(&schema, &options).unwrap_err();
+    assert!(err.contains("require backtracking"), "{}", err);
+
+    let schema = json!({"type": "object", "properties": {"a": {"type": "string", "pattern": "^[a-z]+$"}}});
+    assert!(LinkValidator::with_options(&schema, &options).is_ok());
+}
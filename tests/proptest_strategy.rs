@@ -0,0 +1,125 @@
+//! Proptest strategy tests for link-validator (requires the `proptest` feature)
+
+#![cfg(feature = "proptest")]
+
+use link_validator::LinkValidator;
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use serde_json::{json, Value};
+
+fn rules_validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 5},
+        "code": {"type": "string", "required": true, "pattern": "^[A-Z]{3}-\\d{2,4}$"},
+        "email": {"type": "email", "required": true},
+        "site": {"type": "url"},
+        "age": {"type": "integer", "min": 18, "max": 60},
+        "score": {"type": "number", "min": 0, "max": 1},
+        "agree": {"type": "boolean", "required": true},
+        "role": {"type": "enum", "enum": ["admin", "user"], "required": true},
+        "tags": {"type": "array", "min": 1, "max": 3},
+        "address": {
+            "type": "object",
+            "required": true,
+            "fields": {
+                "city": {"type": "string", "required": true, "len": 4},
+                "zip": {"type": "string", "pattern": "^\\d{5}$"}
+            }
+        },
+        "lines": {
+            "type": "array",
+            "required": true,
+            "min": 1,
+            "fields": {"sku": {"type": "string", "required": true}, "qty": {"type": "integer", "min": 1}}
+        }
+    }))
+    .unwrap()
+}
+
+fn json_schema_validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "string", "format": "uuid"},
+            "created": {"type": "string", "format": "date-time"},
+            "price": {"type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 10, "multipleOf": 0.5},
+            "even": {"type": "integer", "minimum": -9, "maximum": 9, "multipleOf": 2},
+            "ids": {"type": "array", "items": {"type": "integer", "minimum": 1, "maximum": 5}, "minItems": 3, "uniqueItems": true},
+            "labels": {"type": "object", "additionalProperties": {"type": "string", "maxLength": 3}},
+            "note": {"type": ["string", "null"]},
+            "kind": {"const": "order"},
+            "tree": {"$ref": "#/definitions/node"}
+        },
+        "required": ["id", "price", "tree"],
+        "additionalProperties": false,
+        "definitions": {
+            "node": {
+                "type": "object",
+                "properties": {
+                    "value": {"type": "integer"},
+                    "children": {"type": "array", "items": {"$ref": "#/definitions/node"}}
+                },
+                "required": ["value"]
+            }
+        }
+    }))
+    .unwrap()
+}
+
+proptest! {
+    #![proptest_config(Config::with_cases(64))]
+
+    #[test]
+    fn test_rules_strategy_is_valid(value in rules_validator().arbitrary_valid()) {
+        let result = rules_validator().validate(&value);
+        prop_assert!(result.is_valid, "{} {:?}", value, result.errors);
+    }
+
+    #[test]
+    fn test_json_schema_strategy_is_valid(value in json_schema_validator().arbitrary_valid()) {
+        let result = json_schema_validator().validate(&value);
+        prop_assert!(result.is_valid, "{} {:?}", value, result.errors);
+    }
+}
+
+#[test]
+fn test_items_by_branches() {
+    let validator = LinkValidator::new(&json!({
+        "blocks": {
+            "type": "array",
+            "required": true,
+            "min": 2,
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "text": {"body": {"type": "string", "required": true, "min": 1}},
+                    "image": {"src": {"type": "url", "required": true}}
+                }
+            }
+        }
+    }))
+    .unwrap();
+    let mut runner = TestRunner::deterministic();
+    runner
+        .run(&validator.arbitrary_valid(), |value| {
+            prop_assert!(validator.validate(&value).is_valid, "{}", value);
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_failures_shrink_to_boundary() {
+    let validator = LinkValidator::new(&json!({"age": {"type": "integer", "required": true, "min": 18, "max": 60}})).unwrap();
+    let mut runner = TestRunner::deterministic();
+    let result = runner.run(&validator.arbitrary_valid(), |value: Value| {
+        if value["age"].as_i64().unwrap() >= 40 {
+            return Err(TestCaseError::fail("too old"));
+        }
+        Ok(())
+    });
+    match result {
+        Err(TestError::Fail(_, value)) => assert_eq!(value, json!({"age": 40})),
+        other => panic!("expected a failure, got {:?}", other),
+    }
+}
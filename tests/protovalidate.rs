@@ -0,0 +1,81 @@
+//! Protovalidate export tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+#[test]
+fn test_rules_to_protovalidate() {
+    let rules = json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 20},
+        "code": {"type": "string", "len": 6, "pattern": "^[A-Z]+$"},
+        "email": {"type": "email"},
+        "age": {"type": "integer", "min": 0, "max": 150},
+        "price": {"type": "number"},
+        "agree": {"type": "boolean"},
+        "role": {"type": "enum", "enum": ["admin", "user"]},
+        "tags": {"type": "array", "max": 3}
+    });
+    let proto = LinkValidator::new(&rules).unwrap().to_protovalidate("user");
+
+    assert!(proto.contains("syntax = \"proto3\";\n"), "{}", proto);
+    assert!(proto.contains("import \"buf/validate/validate.proto\";\n"), "{}", proto);
+    assert!(proto.contains("message User {\n"), "{}", proto);
+    assert!(proto.contains("  string name = 5 [\n    (buf.validate.field).required = true,\n    (buf.validate.field).string = {min_len: 2, max_len: 20}\n  ];"), "{}", proto);
+    assert!(proto.contains("string code = 3 [(buf.validate.field).string = {len: 6, pattern: \"^[A-Z]+$\"}];"), "{}", proto);
+    assert!(proto.contains("string email = 4 [(buf.validate.field).string = {email: true}];"), "{}", proto);
+    assert!(proto.contains("int64 age = 1 [(buf.validate.field).int64 = {gte: 0, lte: 150}];"), "{}", proto);
+    assert!(proto.contains("  double price = 6;"), "{}", proto);
+    assert!(proto.contains("  bool agree = 2;"), "{}", proto);
+    assert!(proto.contains("[(buf.validate.field).string = {in: [\"admin\", \"user\"]}];"), "{}", proto);
+    assert!(proto.contains("repeated google.protobuf.Value tags = "), "{}", proto);
+    assert!(proto.contains("(buf.validate.field).repeated = {max_items: 3}"), "{}", proto);
+    assert!(proto.contains("import \"google/protobuf/struct.proto\";\n"), "{}", proto);
+}
+
+#[test]
+fn test_nested_messages_and_json_names() {
+    let rules = json!({
+        "address": {
+            "type": "object",
+            "required": true,
+            "fields": {"zipCode": {"type": "string", "len": 5}, "street-name": {"type": "string"}}
+        },
+        "lines": {
+            "type": "array",
+            "min": 1,
+            "fields": {"sku": {"type": "string", "required": true}}
+        }
+    });
+    let proto = LinkValidator::new(&rules).unwrap().to_protovalidate("Order");
+
+    assert!(proto.contains("message Order {\n"), "{}", proto);
+    assert!(proto.contains("  OrderAddress address = 1 [(buf.validate.field).required = true];"), "{}", proto);
+    assert!(proto.contains("message OrderAddress {\n"), "{}", proto);
+    assert!(proto.contains("string zip_code = "), "{}", proto);
+    assert!(proto.contains("string street_name = 1 [json_name = \"street-name\"];"), "{}", proto);
+    assert!(proto.contains("  repeated OrderLinesItem lines = 2 [(buf.validate.field).repeated = {min_items: 1}];"), "{}", proto);
+    assert!(proto.contains("message OrderLinesItem {\n  string sku = 1 [(buf.validate.field).required = true];\n}"), "{}", proto);
+    assert!(!proto.contains("google/protobuf/struct.proto"), "{}", proto);
+}
+
+#[test]
+fn test_json_schema_to_protovalidate() {
+    let schema = json!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "string", "format": "uuid"},
+            "score": {"type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 1},
+            "labels": {"type": "object", "additionalProperties": {"type": "string", "maxLength": 10}},
+            "ids": {"type": "array", "items": {"type": "integer", "minimum": 1}, "uniqueItems": true},
+            "note": {"type": ["string", "null"]}
+        },
+        "required": ["id"]
+    });
+    let proto = LinkValidator::new(&schema).unwrap().to_protovalidate("Record");
+
+    assert!(proto.contains("string id = 1 [\n    (buf.validate.field).required = true,\n    (buf.validate.field).string = {uuid: true}\n  ];"), "{}", proto);
+    assert!(proto.contains("double score = 5 [(buf.validate.field).double = {gt: 0, lt: 1}];"), "{}", proto);
+    assert!(proto.contains("map<string, string> labels = 3 [(buf.validate.field).map = {values: {string: {max_len: 10}}}];"), "{}", proto);
+    assert!(proto.contains("repeated int64 ids = 2 [(buf.validate.field).repeated = {unique: true, items: {int64: {gte: 1}}}];"), "{}", proto);
+    assert!(proto.contains("  string note = 4;"), "{}", proto);
+}
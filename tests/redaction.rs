@@ -0,0 +1,99 @@
+//! Sensitive field redaction tests for link-validator
+
+use link_validator::{LinkValidator, ValidateOptions};
+use serde_json::json;
+
+fn validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "username": {"type": "string", "min": 5},
+        "password": {"type": "string", "required": true, "min": 8, "sensitive": true},
+        "auth": {
+            "type": "object",
+            "sensitive": true,
+            "fields": {"token": {"type": "string", "pattern": "^tk_"}}
+        },
+        "keys": {"type": "array", "fields": {"secret": {"type": "string", "max": 4, "sensitive": true}}}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_sensitive_rule_compiles_to_write_only() {
+    let validator = validator();
+    assert_eq!(validator.json_schema()["properties"]["password"]["writeOnly"], json!(true));
+    assert!(validator.warnings().is_empty());
+}
+
+#[test]
+fn test_sensitive_values_are_masked() {
+    let result = validator().validate(&json!({"username": "joe", "password": "hunter2"}));
+    let errors = result.errors.as_array().unwrap();
+
+    let password = errors.iter().find(|e| e["field"] == "password").unwrap();
+    assert_eq!(password["value"], "[REDACTED]");
+    assert!(!password["message"].as_str().unwrap().contains("hunter2"));
+    assert!(password["params"].get("actual").is_none());
+    assert_eq!(password["params"]["limit"], 8);
+
+    // 非敏感字段不受影响
+    let username = errors.iter().find(|e| e["field"] == "username").unwrap();
+    assert_eq!(username["value"], "joe");
+    assert!(username["message"].as_str().unwrap().contains("joe"));
+}
+
+#[test]
+fn test_nested_and_array_fields() {
+    let data = json!({"password": "long enough", "auth": {"token": "secret-token"}, "keys": [{"secret": "abcdef"}]});
+    let result = validator().validate(&data);
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    for error in errors {
+        assert_eq!(error["value"], "[REDACTED]");
+        let message = error["message"].as_str().unwrap();
+        assert!(!message.contains("secret-token") && !message.contains("abcdef"), "{}", message);
+    }
+}
+
+#[test]
+fn test_typed_errors_are_masked() {
+    let errors = validator().validate_into::<serde_json::Value>(&json!({"password": "short"})).unwrap_err();
+    assert!(!errors.to_string().contains("\"short\""));
+    assert_eq!(errors.errors[0].value, Some(json!("[REDACTED]")));
+}
+
+#[test]
+fn test_redact_option() {
+    let validator = LinkValidator::new(&json!({
+        "profile": {"type": "object", "fields": {"ssn": {"type": "string", "len": 9}}},
+        "name": {"type": "string", "min": 3}
+    }))
+    .unwrap();
+    let data = json!({"profile": {"ssn": "123-45-678"}, "name": "al"});
+
+    let result = validator.validate(&data);
+    assert!(result.errors.as_array().unwrap().iter().any(|e| e["value"] == "123-45-678"));
+
+    let options = ValidateOptions::new().redact("profile");
+    let result = validator.validate_with(&data, &options);
+    let errors = result.errors.as_array().unwrap();
+    let ssn = errors.iter().find(|e| e["field"] == "profile.ssn").unwrap();
+    assert_eq!(ssn["value"], "[REDACTED]");
+    let name = errors.iter().find(|e| e["field"] == "name").unwrap();
+    assert_eq!(name["value"], "al");
+
+    // 只匹配完整的路径段
+    let options = ValidateOptions::new().redact("prof");
+    let result = validator.validate_with(&data, &options);
+    assert!(result.errors.as_array().unwrap().iter().any(|e| e["value"] == "123-45-678"));
+}
+
+#[test]
+fn test_json_schema_write_only() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"secret": {"type": "string", "writeOnly": true, "minLength": 10}}
+    }))
+    .unwrap();
+    let result = validator.validate(&json!({"secret": "abc"}));
+    assert_eq!(result.errors[0]["value"], "[REDACTED]");
+}
@@ -0,0 +1,81 @@
+//! Schema file loading tests for link-validator
+
+use link_validator::{LinkValidator, SchemaFormat, SchemaRegistry};
+use serde_json::json;
+use std::fs;
+use std::path::PathBuf;
+
+/// 为每个测试创建独立的临时目录
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("link-validator-registry-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_from_file() {
+    let dir = temp_dir("file");
+    let path = dir.join("user.json");
+    fs::write(&path, r#"{"name": {"type": "string", "required": true}}"#).unwrap();
+
+    let validator = LinkValidator::from_file(&path).expect("Load failed");
+    assert_eq!(validator.format(), SchemaFormat::AsyncValidator);
+    assert!(!validator.validate(&json!({})).is_valid);
+
+    let err = LinkValidator::from_file(dir.join("missing.json")).unwrap_err();
+    assert!(err.starts_with("Failed to read"), "{}", err);
+
+    fs::write(dir.join("broken.json"), "{").unwrap();
+    let err = LinkValidator::from_file(dir.join("broken.json")).unwrap_err();
+    assert!(err.starts_with("Failed to parse"), "{}", err);
+}
+
+#[test]
+fn test_load_dir_by_id_and_filename() {
+    let dir = temp_dir("dir");
+    fs::write(dir.join("user.json"), r#"{"name": {"type": "string", "required": true}}"#).unwrap();
+    fs::write(
+        dir.join("order.json"),
+        r#"{"$id": "https://example.com/order", "type": "object", "properties": {"total": {"type": "number"}}}"#,
+    ).unwrap();
+    // async-validator 规则中的 $id 只用于注册
+    fs::write(dir.join("address.json"), r#"{"$id": "addr", "city": {"type": "string", "required": true}}"#).unwrap();
+    fs::write(dir.join("README.md"), "ignored").unwrap();
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    fs::write(dir.join("nested").join("skipped.json"), "{").unwrap();
+
+    let registry = SchemaRegistry::load_dir(&dir).expect("Load failed");
+    assert_eq!(registry.len(), 3);
+    assert_eq!(registry.names().collect::<Vec<_>>(), ["addr", "https://example.com/order", "user"]);
+
+    let order = registry.get("https://example.com/order").unwrap();
+    assert_eq!(order.format(), SchemaFormat::JsonSchema);
+    assert!(!order.validate(&json!({"total": "1"})).is_valid);
+
+    let address = registry.get("addr").unwrap();
+    assert_eq!(address.format(), SchemaFormat::AsyncValidator);
+    assert!(address.validate(&json!({"city": "Paris"})).is_valid);
+    assert!(registry.get("user").is_some());
+}
+
+#[test]
+fn test_load_dir_errors() {
+    let dir = temp_dir("dup");
+    fs::write(dir.join("a.json"), r#"{"$id": "b", "name": {"type": "string"}}"#).unwrap();
+    fs::write(dir.join("b.json"), r#"{"name": {"type": "string"}}"#).unwrap();
+    let err = SchemaRegistry::load_dir(&dir).unwrap_err();
+    assert!(err.contains("Duplicate schema name 'b'"), "{}", err);
+
+    let err = SchemaRegistry::load_dir(dir.join("missing")).unwrap_err();
+    assert!(err.starts_with("Failed to read directory"), "{}", err);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn test_load_yaml_file() {
+    let dir = temp_dir("yaml");
+    fs::write(dir.join("profile.yaml"), "nickname:\n  type: string\n  max: 4\n").unwrap();
+    let registry = SchemaRegistry::load_dir(&dir).expect("Load failed");
+    assert!(!registry.get("profile").unwrap().validate(&json!({"nickname": "toolong"})).is_valid);
+}
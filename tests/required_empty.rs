@@ -0,0 +1,104 @@
+//! Required empty and blank value tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator};
+use serde_json::json;
+
+fn rules() -> serde_json::Value {
+    json!({
+        "name": {"type": "string", "required": true},
+        "email": {"type": "email", "required": true},
+        "tags": {"type": "array", "required": true},
+        "note": {"type": "string"},
+        "code": {"type": "string", "required": true, "min": 3}
+    })
+}
+
+fn options() -> CompileOptions {
+    CompileOptions::new().required_rejects_empty(true)
+}
+
+#[test]
+fn test_empty_allowed_by_default() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+    let data = json!({"name": "", "email": "a@b.co", "tags": [], "code": "abc"});
+    assert!(validator.validate(&data).is_valid);
+}
+
+#[test]
+fn test_required_rejects_empty() {
+    let validator = LinkValidator::with_options(&rules(), &options()).unwrap();
+    let schema = validator.json_schema();
+    assert_eq!(schema["properties"]["name"]["minLength"], 1);
+    assert_eq!(schema["properties"]["email"]["minLength"], 1);
+    assert_eq!(schema["properties"]["tags"]["minItems"], 1);
+    assert_eq!(schema["properties"]["code"]["minLength"], 3);
+    assert!(schema["properties"]["note"].get("minLength").is_none());
+
+    let result = validator.validate(&json!({"name": "", "email": "a@b.co", "tags": [], "note": "", "code": "abc"}));
+    let fields: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["field"].as_str().unwrap()).collect();
+    assert_eq!(fields, ["name", "tags"]);
+
+    assert!(validator.validate(&json!({"name": "a", "email": "a@b.co", "tags": [1], "code": "abc"})).is_valid);
+}
+
+#[test]
+fn test_nested_and_nullable_fields() {
+    let rules = json!({
+        "address": {
+            "type": "object",
+            "required": true,
+            "fields": {"city": {"type": "string", "required": true, "nullable": true}}
+        }
+    });
+    let validator = LinkValidator::with_options(&rules, &options()).unwrap();
+    assert!(!validator.validate(&json!({"address": {"city": ""}})).is_valid);
+    assert!(validator.validate(&json!({"address": {"city": "Paris"}})).is_valid);
+}
+
+#[test]
+fn test_required_rejects_blank() {
+    let options = CompileOptions::new().required_rejects_blank(true);
+    let validator = LinkValidator::with_options(&rules(), &options).unwrap();
+
+    let result = validator.validate(&json!({"name": " \t", "email": "a@b.co", "tags": [], "note": " ", "code": "abc"}));
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+    assert_eq!(result.errors[0]["field"], "name");
+    assert_eq!(result.errors[0]["code"], "required");
+    assert_eq!(result.errors[0]["params"]["property"], "name");
+
+    assert!(validator.validate(&json!({"name": " a ", "email": "a@b.co", "tags": [], "code": "abc"})).is_valid);
+}
+
+#[test]
+fn test_blank_and_empty_report_required_once() {
+    let options = CompileOptions::new().required_rejects_empty(true).required_rejects_blank(true);
+    let validator = LinkValidator::with_options(&rules(), &options).unwrap();
+    assert!(validator.json_schema()["properties"]["name"].get("minLength").is_none());
+
+    let result = validator.validate(&json!({"name": "", "email": "a@b.co", "tags": [], "code": "abc"}));
+    let codes: Vec<(&str, &str)> = result
+        .errors
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| (e["field"].as_str().unwrap(), e["code"].as_str().unwrap()))
+        .collect();
+    assert_eq!(codes, [("tags", "array.min"), ("name", "required")]);
+}
+
+#[test]
+fn test_blank_after_trim_transform() {
+    let rules = json!({
+        "profile": {"type": "object", "fields": {"nickname": {"type": "string", "required": true, "trim": true}}},
+        "tags": {"type": "array", "fields": {"label": {"type": "string", "required": true}}}
+    });
+    let options = CompileOptions::new().required_rejects_blank(true);
+    let validator = LinkValidator::with_options(&rules, &options).unwrap();
+
+    let data = json!({"profile": {"nickname": "  "}, "tags": [{"label": " ok "}, {"label": "  "}]});
+    let (value, result) = validator.validate_and_transform(&data);
+    assert_eq!(value["profile"]["nickname"], "");
+    let fields: Vec<&str> = result.errors.as_array().unwrap().iter().map(|e| e["field"].as_str().unwrap()).collect();
+    assert_eq!(fields, ["profile.nickname", "tags.1.label"]);
+    assert!(result.errors.as_array().unwrap().iter().all(|e| e["code"] == "required"));
+}
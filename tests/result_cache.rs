@@ -0,0 +1,101 @@
+//! Result cache tests for link-validator
+
+use link_validator::{LinkValidator, ValidationError};
+use serde_json::{json, Value};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// 构建带调用计数的验证器，用于判断是否真正执行了验证
+fn counting_validator(capacity: usize, ttl: Duration) -> (LinkValidator, Arc<AtomicUsize>) {
+    let schema = json!({"name": {"type": "string", "required": true}});
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = calls.clone();
+
+    let mut validator = LinkValidator::new(&schema).expect("Compilation failed");
+    validator.register_document_validator(move |_: &Value| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Vec::<ValidationError>::new()
+    });
+    (validator.with_result_cache(capacity, ttl), calls)
+}
+
+#[test]
+fn test_cache_hit_skips_revalidation() {
+    let (validator, calls) = counting_validator(10, Duration::from_secs(60));
+
+    let data = json!({"name": "john"});
+    for _ in 0..5 {
+        assert!(validator.validate(&data).is_valid);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(validator.result_cache_len(), 1);
+
+    // 失败结果同样缓存
+    let invalid = json!({"name": 1});
+    let first = validator.validate(&invalid);
+    let second = validator.validate(&invalid);
+    assert!(!second.is_valid);
+    assert_eq!(first.errors, second.errors);
+
+    validator.clear_result_cache();
+    assert_eq!(validator.result_cache_len(), 0);
+}
+
+#[test]
+fn test_cache_is_bounded() {
+    let (validator, calls) = counting_validator(2, Duration::from_secs(60));
+
+    let a = json!({"name": "a"});
+    let b = json!({"name": "b"});
+    let c = json!({"name": "c"});
+
+    validator.validate(&a);
+    validator.validate(&b);
+    validator.validate(&a); // a 最近使用过
+    validator.validate(&c); // 淘汰 b
+    assert_eq!(validator.result_cache_len(), 2);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    validator.validate(&a);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    validator.validate(&b);
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_cache_entries_expire() {
+    let (validator, calls) = counting_validator(10, Duration::from_millis(20));
+
+    let data = json!({"name": "john"});
+    validator.validate(&data);
+    thread::sleep(Duration::from_millis(40));
+    validator.validate(&data);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_cache_hit_emits_event() {
+    let (mut validator, _) = counting_validator(10, Duration::from_secs(60));
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let recorded = events.clone();
+    validator.on_validation(move |event| {
+        recorded.lock().unwrap().push((event.cached, event.error_count, event.errors.first().map(|e| e.code.clone())));
+    });
+
+    let invalid = json!({"name": 1});
+    validator.validate(&invalid);
+    validator.validate(&invalid);
+    assert_eq!(validator.result_cache_len(), 1);
+
+    // 命中缓存同样触发回调，错误与首次验证一致
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 2);
+    assert!(!events[0].0);
+    assert!(events[1].0);
+    assert_eq!(events[0].1, 1);
+    assert_eq!(events[0].1, events[1].1);
+    assert_eq!(events[0].2, events[1].2);
+    assert!(validator.stats().last_validation.is_some());
+}
@@ -0,0 +1,98 @@
+//! Result serialization tests for link-validator
+
+use link_validator::{LinkValidator, SchemaFormat, Segment, ValidationError, ValidationErrors, ValidationResult};
+use serde_json::json;
+
+#[test]
+fn test_result_layout() {
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "min": 3}})).unwrap();
+    let result = validator.validate(&json!({"name": "al"}));
+    assert_eq!(result.format, SchemaFormat::AsyncValidator);
+
+    let value = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "version": 1,
+            "format": "async-validator",
+            "valid": false,
+            "truncated": false,
+            "errors": [{
+                "message": "\"al\" is shorter than 3 characters",
+                "field": "name",
+                "code": "string.min",
+                "value": "al",
+                "params": {"limit": 3, "actual": 2},
+                "rulePath": "name.min"
+            }],
+            "warnings": []
+        })
+    );
+}
+
+#[test]
+fn test_json_schema_result_round_trip() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"age": {"type": "integer"}}
+    }))
+    .unwrap();
+    let result = validator.validate(&json!({"age": "x"}));
+    let text = serde_json::to_string(&result).unwrap();
+    assert!(text.contains("\"format\":\"json-schema\""));
+
+    let restored: ValidationResult = serde_json::from_str(&text).unwrap();
+    assert_eq!(restored.format, SchemaFormat::JsonSchema);
+    assert!(!restored.is_valid);
+    assert_eq!(restored.errors, result.errors);
+    assert_eq!(restored.errors[0]["instancePath"], "/age");
+}
+
+#[test]
+fn test_rejects_unknown_version() {
+    let error = serde_json::from_value::<ValidationResult>(json!({
+        "version": 2,
+        "format": "async-validator",
+        "valid": true,
+        "errors": []
+    }))
+    .unwrap_err();
+    assert!(error.to_string().contains("Unsupported validation result version 2"));
+
+    // truncated 和 warnings 可以省略
+    let result: ValidationResult = serde_json::from_value(json!({
+        "version": 1,
+        "format": "async-validator",
+        "valid": true,
+        "errors": []
+    }))
+    .unwrap();
+    assert!(result.is_valid && !result.truncated);
+    assert_eq!(result.warnings, json!([]));
+}
+
+#[test]
+fn test_typed_errors_round_trip() {
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true, "min": 3}})).unwrap();
+    let errors = validator.validate_into::<serde_json::Value>(&json!({"name": "al"})).unwrap_err();
+
+    let value = serde_json::to_value(&errors).unwrap();
+    assert_eq!(value["errors"][0]["path"], "/name");
+    assert_eq!(value["errors"][0]["code"], "string.min");
+
+    let restored: ValidationErrors = serde_json::from_value(value).unwrap();
+    assert_eq!(restored, errors);
+
+    // 省略的字段使用默认值
+    let error: ValidationError = serde_json::from_value(json!({"path": "", "message": "bad"})).unwrap();
+    assert_eq!(error, ValidationError::new("", "bad"));
+    assert!(serde_json::to_value(&error).unwrap().get("value").is_none());
+}
+
+#[test]
+fn test_segment_serialization() {
+    let segments = vec![Segment::Key("tags".to_string()), Segment::Index(2)];
+    let value = serde_json::to_value(&segments).unwrap();
+    assert_eq!(value, json!(["tags", 2]));
+    assert_eq!(serde_json::from_value::<Vec<Segment>>(value).unwrap(), segments);
+}
@@ -0,0 +1,60 @@
+//! Result grouping and summary tests for link-validator
+
+use link_validator::{LinkValidator, ValidateOptions};
+use serde_json::json;
+
+fn signup_validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "username": [{"type": "string", "required": true, "min": 3}, {"pattern": "^[a-z]+$"}],
+        "role": {"type": "enum", "enum": ["admin", "member"]},
+        "tags": {"type": "array", "fields": {"name": {"type": "string", "max": 3}}}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_grouped_by_field() {
+    let validator = signup_validator();
+    let result = validator.validate(&json!({"username": "A", "role": "membr", "tags": [{"name": "rust"}]}));
+    let groups = result.grouped_by_field();
+    assert_eq!(groups.len(), 3);
+    let codes: Vec<&str> = groups["username"].iter().map(|e| e.code.as_str()).collect();
+    assert!(codes.contains(&"string.min") && codes.contains(&"pattern.mismatch"), "{:?}", codes);
+
+    let role = &groups["role"][0];
+    assert_eq!(role.path, "/role");
+    assert_eq!(role.hint.as_deref(), Some("did you mean 'member'?"));
+    assert_eq!(role.rule_path.as_deref(), Some("role.enum"));
+    assert_eq!(groups["tags.0.name"][0].path, "/tags/0/name");
+}
+
+#[test]
+fn test_grouped_json_schema_paths() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"age": {"type": "integer", "minimum": 0}},
+        "required": ["name"]
+    }))
+    .unwrap();
+    let groups = validator.validate(&json!({"age": -1})).grouped_by_field();
+    assert_eq!(groups[""][0].code, "required");
+    assert_eq!(groups["/age"][0].code, "number.min");
+    assert!(validator.validate(&json!({"name": "x"})).grouped_by_field().is_empty());
+}
+
+#[test]
+fn test_summary() {
+    let validator = signup_validator();
+    assert_eq!(validator.validate(&json!({"username": "ada"})).summary(), "Validation passed");
+
+    let summary = validator.validate(&json!({"username": "ada", "role": "membr"})).summary();
+    let lines: Vec<&str> = summary.lines().collect();
+    assert_eq!(lines[0], "Validation failed with 1 error:");
+    assert!(lines[1].starts_with("- role: "), "{}", summary);
+    assert!(lines[1].ends_with("(did you mean 'member'?)"), "{}", summary);
+
+    let options = ValidateOptions::new().max_errors(1);
+    let summary = validator.validate_with(&json!({"username": "A", "role": "x"}), &options).summary();
+    assert!(summary.starts_with("Validation failed with 1 error:"), "{}", summary);
+    assert!(summary.ends_with("(more errors omitted)"), "{}", summary);
+}
@@ -0,0 +1,63 @@
+//! Root value rule tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+#[test]
+fn test_scalar_email() {
+    let validator = LinkValidator::for_value(&json!({"type": "email"})).unwrap();
+    assert_eq!(validator.json_schema(), &json!({"type": "string", "format": "email"}));
+    assert!(validator.validate(&json!("alice@example.com")).is_valid);
+
+    let result = validator.validate(&json!("not-an-email"));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "");
+}
+
+#[test]
+fn test_rule_array() {
+    let validator = LinkValidator::for_value(&json!([{"type": "integer"}, {"min": 1, "max": 10}])).unwrap();
+    assert!(validator.validate(&json!(5)).is_valid);
+    assert!(!validator.validate(&json!(11)).is_valid);
+    assert!(!validator.validate(&json!("5")).is_valid);
+}
+
+#[test]
+fn test_root_transforms() {
+    let validator = LinkValidator::for_value(&json!({"type": "string", "trim": true, "case": "lower", "min": 2})).unwrap();
+    let (value, result) = validator.validate_and_transform(&json!("  AB "));
+    assert!(result.is_valid);
+    assert_eq!(value, json!("ab"));
+}
+
+#[test]
+fn test_root_object_rule() {
+    let validator = LinkValidator::for_value(&json!({
+        "type": "object",
+        "fields": {"name": {"type": "string", "required": true}}
+    }))
+    .unwrap();
+    assert!(validator.validate(&json!({"name": "ann"})).is_valid);
+
+    let result = validator.validate(&json!({"name": 1}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "name");
+}
+
+#[test]
+fn test_invalid_root_rule() {
+    assert!(LinkValidator::for_value(&json!("email")).is_err());
+    assert!(LinkValidator::for_value(&json!([])).is_err());
+}
+
+#[test]
+fn test_value_field_name_in_field_map() {
+    // 只有 for_value 把规则当作根节点，普通规则中的 `$value` 就是一个字段名
+    let validator = LinkValidator::new(&json!({"$value": {"type": "string", "required": true}})).unwrap();
+    assert!(validator.validate(&json!({"$value": "x"})).is_valid);
+    assert!(!validator.validate(&json!("x")).is_valid);
+
+    let result = validator.validate(&json!({}));
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["params"], json!({"property": "$value"}));
+}
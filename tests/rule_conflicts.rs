@@ -0,0 +1,87 @@
+//! Rule conflict tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, RuleMergeStrategy};
+use serde_json::{Value, json};
+
+fn rules() -> Value {
+    json!({
+        "name": [{"type": "string", "min": 3, "max": 20}, {"min": 5, "max": 10}],
+        "role": [{"type": "enum", "enum": ["admin", "user", "guest"]}, {"enum": ["user", "guest", "bot"]}],
+        "start": [{"type": "date", "minDate": "2024-03-01"}, {"minDate": "2024-01-01"}],
+        "tags": {
+            "type": "object",
+            "fields": {"label": [{"type": "string", "max": 8}, {"max": 4}]}
+        }
+    })
+}
+
+fn compile(strategy: RuleMergeStrategy) -> Result<LinkValidator, String> {
+    LinkValidator::with_options(&rules(), &CompileOptions::new().merge_strategy(strategy))
+}
+
+#[test]
+fn test_last_wins_by_default() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["name"]["minLength"], 5);
+    assert_eq!(properties["name"]["maxLength"], 10);
+    assert_eq!(properties["role"]["enum"], json!(["user", "guest", "bot"]));
+    assert_eq!(properties["start"]["formatMinimum"], "2024-01-01");
+    assert_eq!(properties["tags"]["properties"]["label"]["maxLength"], 4);
+
+    let warnings: Vec<&str> = validator.warnings().iter().map(|w| w.message.as_str()).collect();
+    assert!(warnings.contains(&"Field 'name': conflicting 'min' rules (3, 5), using 5"));
+    assert!(warnings.contains(&"Field 'label': conflicting 'max' rules (8, 4), using 4"));
+}
+
+#[test]
+fn test_strictest() {
+    let validator = compile(RuleMergeStrategy::Strictest).unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["name"]["minLength"], 5);
+    assert_eq!(properties["name"]["maxLength"], 10);
+    assert_eq!(properties["role"]["enum"], json!(["user", "guest"]));
+    assert_eq!(properties["start"]["formatMinimum"], "2024-03-01");
+    assert_eq!(properties["tags"]["properties"]["label"]["maxLength"], 4);
+    let warning = "Field 'start': conflicting 'minDate' rules (\"2024-03-01\", \"2024-01-01\"), using \"2024-03-01\"";
+    assert!(validator.warnings().iter().any(|w| w.message == warning));
+
+    assert!(!validator.validate(&json!({"role": "bot"})).is_valid);
+    assert!(validator.validate(&json!({"role": "guest"})).is_valid);
+}
+
+#[test]
+fn test_strictest_date_only_max_covers_whole_day() {
+    let rules = json!({
+        "end": [{"type": "date", "maxDate": "2024-06-01"}, {"maxDate": "2024-06-01T12:00:00Z"}]
+    });
+    let options = CompileOptions::new().merge_strategy(RuleMergeStrategy::Strictest);
+    let validator = LinkValidator::with_options(&rules, &options).unwrap();
+    assert_eq!(validator.json_schema()["properties"]["end"]["formatMaximum"], "2024-06-01T12:00:00Z");
+}
+
+#[test]
+fn test_error_on_conflict() {
+    let error = compile(RuleMergeStrategy::Error).unwrap_err();
+    assert!(error.starts_with("Conflicting rules:"));
+    assert!(error.contains("Field 'name': conflicting 'min' rules (3, 5)"));
+}
+
+#[test]
+fn test_identical_values_are_not_conflicts() {
+    let rules = json!({"name": [{"type": "string", "required": true, "message": "Required"}, {"type": "string", "min": 2, "message": "Too short"}]});
+    let options = CompileOptions::new().merge_strategy(RuleMergeStrategy::Error);
+    let validator = LinkValidator::with_options(&rules, &options).unwrap();
+    assert!(validator.warnings().is_empty());
+}
+
+#[test]
+fn test_groups_are_not_conflicts() {
+    let rules = json!({
+        "name": [{"type": "string", "max": 10}, {"max": 5, "groups": ["compact"]}]
+    });
+    let validator = LinkValidator::new(&rules).unwrap();
+    assert!(validator.warnings().is_empty());
+    assert!(validator.validate(&json!({"name": "abcdefgh"})).is_valid);
+    assert!(!validator.validate_with_group(&json!({"name": "abcdefgh"}), "compact").unwrap().is_valid);
+}
@@ -0,0 +1,71 @@
+//! Rule order tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+#[test]
+fn test_min_before_type() {
+    let validator = LinkValidator::new(&json!({"name": [{"min": 3}, {"type": "string"}]})).unwrap();
+    let name = &validator.json_schema()["properties"]["name"];
+    assert_eq!(name["minLength"], 3);
+    assert!(name.get("minimum").is_none());
+
+    assert!(!validator.validate(&json!({"name": "al"})).is_valid);
+    assert!(validator.validate(&json!({"name": "alice"})).is_valid);
+}
+
+#[test]
+fn test_max_and_len_before_type() {
+    let validator = LinkValidator::new(&json!({
+        "tags": [{"max": 2}, {"type": "array"}],
+        "code": [{"len": 4}, {"type": "string"}]
+    }))
+    .unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["tags"]["maxItems"], 2);
+    assert_eq!(properties["code"]["minLength"], 4);
+    assert_eq!(properties["code"]["maxLength"], 4);
+    assert!(!validator.warnings().iter().any(|w| w.message.contains("len rule")));
+}
+
+#[test]
+fn test_numeric_rules_before_type() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string"},
+        "price": [{"positive": true, "multipleOf": 0.5}, {"type": "number"}]
+    }))
+    .unwrap();
+    let price = &validator.json_schema()["properties"]["price"];
+    assert_eq!(price["exclusiveMinimum"], 0);
+    assert_eq!(price["multipleOf"], 0.5);
+    assert!(validator.warnings().is_empty());
+}
+
+#[test]
+fn test_type_specific_rules_in_separate_rule() {
+    let validator = LinkValidator::new(&json!({
+        "ids": [{"type": "array"}, {"unique": true}],
+        "labels": [{"type": "object"}, {"keyPattern": "^[a-z]+$"}],
+        "start": [{"minDate": "2024-01-01"}, {"type": "date", "format": "date"}],
+        "email": [{"trim": true}, {"type": "email"}]
+    }))
+    .unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["ids"]["uniqueItems"], true);
+    assert_eq!(properties["labels"]["propertyNames"]["pattern"], "^[a-z]+$");
+    assert_eq!(properties["start"]["format"], "date");
+    assert!(validator.warnings().is_empty(), "{:?}", validator.warnings());
+
+    let (value, result) = validator.validate_and_transform(&json!({"email": "  a@b.co "}));
+    assert!(result.is_valid);
+    assert_eq!(value["email"], "a@b.co");
+}
+
+#[test]
+fn test_last_declared_type_wins() {
+    let validator = LinkValidator::new(&json!({"count": [{"type": "string"}, {"min": 1}, {"type": "integer"}]})).unwrap();
+    let count = &validator.json_schema()["properties"]["count"];
+    assert_eq!(count["type"], "integer");
+    assert_eq!(count["minimum"], 1);
+    assert!(count.get("minLength").is_none());
+}
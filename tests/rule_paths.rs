@@ -0,0 +1,84 @@
+//! Rule path tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::{Value, json};
+
+fn rule_paths(rules: &Value, data: &Value) -> Vec<(String, String)> {
+    let validator = LinkValidator::new(rules).unwrap();
+    let result = validator.validate(data);
+    result
+        .errors
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| {
+            (
+                error["field"].as_str().unwrap_or_default().to_string(),
+                error["rulePath"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_nested_rule_path() {
+    let rules = json!({
+        "user": {
+            "type": "object",
+            "fields": {"name": {"type": "string", "min": 3}}
+        }
+    });
+    let paths = rule_paths(&rules, &json!({"user": {"name": "ab"}}));
+    assert_eq!(paths, vec![("user.name".to_string(), "user.fields.name.min".to_string())]);
+}
+
+#[test]
+fn test_required_rule_path() {
+    let rules = json!({
+        "user": {
+            "type": "object",
+            "fields": {"email": {"type": "email", "required": true}}
+        }
+    });
+    let paths = rule_paths(&rules, &json!({"user": {}}));
+    assert_eq!(paths[0].1, "user.fields.email.required");
+
+    let paths = rule_paths(&rules, &json!({"user": {"email": "nope"}}));
+    assert_eq!(paths[0].1, "user.fields.email.type");
+}
+
+#[test]
+fn test_array_item_rule_path() {
+    let rules = json!({
+        "tags": {
+            "type": "array",
+            "fields": {"label": {"type": "string", "pattern": "^[a-z]+$"}}
+        }
+    });
+    let paths = rule_paths(&rules, &json!({"tags": [{"label": "ok"}, {"label": "NO"}]}));
+    assert_eq!(paths[0].1, "tags.fields.label.pattern");
+}
+
+#[test]
+fn test_post_schema_rule_path() {
+    let rules = json!({
+        "period": {
+            "type": "object",
+            "fields": {"start": {"type": "date", "minDate": "2024-01-01T00:00:00Z"}}
+        }
+    });
+    let paths = rule_paths(&rules, &json!({"period": {"start": "2023-06-01T00:00:00Z"}}));
+    assert_eq!(paths[0].1, "period.fields.start.minDate");
+}
+
+#[test]
+fn test_json_schema_has_no_rule_path() {
+    let schema = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": {"name": {"type": "string", "minLength": 3}}
+    });
+    let validator = LinkValidator::new(&schema).unwrap();
+    let result = validator.validate(&json!({"name": "ab"}));
+    assert!(result.errors[0].get("rulePath").is_none());
+}
@@ -0,0 +1,130 @@
+//! Sample generation tests for link-validator
+
+use link_validator::{LinkValidator, SampleOptions};
+use serde_json::json;
+
+fn assert_samples_valid(validator: &LinkValidator) {
+    for seed in 0..200 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        let result = validator.validate(&sample);
+        assert!(result.is_valid, "seed {}: {} {:?}", seed, sample, result.errors);
+    }
+}
+
+#[test]
+fn test_samples_satisfy_rules() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 5},
+        "code": {"type": "string", "required": true, "pattern": "^[A-Z]{3}-\\d{2,4}$"},
+        "email": {"type": "email", "required": true},
+        "site": {"type": "url"},
+        "age": {"type": "integer", "min": 18, "max": 60},
+        "score": {"type": "number", "min": 0, "max": 1},
+        "agree": {"type": "boolean", "required": true},
+        "role": {"type": "enum", "enum": ["admin", "user"], "required": true},
+        "tags": {"type": "array", "min": 1, "max": 3},
+        "address": {
+            "type": "object",
+            "required": true,
+            "fields": {
+                "city": {"type": "string", "required": true, "len": 4},
+                "zip": {"type": "string", "pattern": "^\\d{5}$"}
+            }
+        },
+        "lines": {
+            "type": "array",
+            "required": true,
+            "min": 1,
+            "fields": {"sku": {"type": "string", "required": true}, "qty": {"type": "integer", "min": 1}}
+        }
+    }))
+    .unwrap();
+    assert_samples_valid(&validator);
+}
+
+#[test]
+fn test_samples_satisfy_json_schema() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {
+            "id": {"type": "string", "format": "uuid"},
+            "created": {"type": "string", "format": "date-time"},
+            "price": {"type": "number", "exclusiveMinimum": 0, "exclusiveMaximum": 10, "multipleOf": 0.5},
+            "even": {"type": "integer", "minimum": -9, "maximum": 9, "multipleOf": 2},
+            "ids": {"type": "array", "items": {"type": "integer", "minimum": 1, "maximum": 5}, "minItems": 3, "uniqueItems": true},
+            "labels": {"type": "object", "additionalProperties": {"type": "string", "maxLength": 3}},
+            "note": {"type": ["string", "null"]},
+            "kind": {"const": "order"},
+            "tree": {"$ref": "#/definitions/node"}
+        },
+        "required": ["id", "price", "tree"],
+        "additionalProperties": false,
+        "definitions": {
+            "node": {
+                "type": "object",
+                "properties": {
+                    "value": {"type": "integer"},
+                    "children": {"type": "array", "items": {"$ref": "#/definitions/node"}}
+                },
+                "required": ["value"]
+            }
+        }
+    }))
+    .unwrap();
+    assert_samples_valid(&validator);
+}
+
+#[test]
+fn test_samples_select_items_by_branch() {
+    let validator = LinkValidator::new(&json!({
+        "blocks": {
+            "type": "array",
+            "required": true,
+            "min": 4,
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "text": {"body": {"type": "string", "required": true}},
+                    "image": {"src": {"type": "url", "required": true}, "width": {"type": "integer", "min": 1}}
+                }
+            }
+        }
+    }))
+    .unwrap();
+    assert_samples_valid(&validator);
+}
+
+#[test]
+fn test_seeded_samples_are_deterministic() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "age": {"type": "integer"}
+    }))
+    .unwrap();
+    let first = validator.generate_sample(&SampleOptions::new().seed(1));
+    assert_eq!(first, validator.generate_sample(&SampleOptions::new().seed(1)));
+
+    let different = (2..20).any(|seed| validator.generate_sample(&SampleOptions::new().seed(seed)) != first);
+    assert!(different);
+}
+
+#[test]
+fn test_optional_fields_and_depth() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "nickname": {"type": "string"},
+        "profile": {"type": "object", "fields": {"bio": {"type": "string"}}}
+    }))
+    .unwrap();
+
+    let sample = validator.generate_sample(&SampleOptions::new().seed(3).optional_fields(false));
+    assert_eq!(sample.as_object().unwrap().keys().collect::<Vec<_>>(), vec!["name"]);
+
+    let sample = validator.generate_sample(&SampleOptions::new().seed(3));
+    assert!(sample.get("nickname").is_some());
+    assert!(sample.pointer("/profile/bio").is_some());
+
+    // 超过最大层数后不再生成可选字段
+    let sample = validator.generate_sample(&SampleOptions::new().seed(3).max_depth(1));
+    assert_eq!(sample["profile"], json!({}));
+}
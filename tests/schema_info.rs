@@ -0,0 +1,117 @@
+//! Schema introspection tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, SchemaFormat, TitleStyle};
+use serde_json::json;
+
+#[test]
+fn test_fields_types_and_constraints() {
+    let validator = LinkValidator::new(&json!({
+        "username": {"type": "string", "required": true, "min": 3, "max": 20, "pattern": "^[a-z]+$"},
+        "age": {"type": "integer", "min": 18},
+        "email": {"type": "email", "nullable": true},
+        "role": {"type": "enum", "enum": ["admin", "user"]},
+        "birthday": {"type": "date", "format": "date", "maxDate": "2010-12-31"}
+    }))
+    .unwrap();
+    let info = validator.schema_info();
+    assert_eq!(info.format, SchemaFormat::AsyncValidator);
+    let field = |name: &str| info.fields.iter().find(|f| f.name == name).unwrap().clone();
+
+    let username = field("username");
+    assert!(username.required);
+    assert_eq!(username.path, "username");
+    assert_eq!(
+        username.constraints,
+        json!({"minLength": 3, "maxLength": 20, "pattern": "^[a-z]+$"}).as_object().unwrap().clone()
+    );
+
+    let age = field("age");
+    assert!(!age.required);
+    assert_eq!(age.types, vec!["integer"]);
+    assert_eq!(age.constraints["minimum"], 18);
+
+    let email = field("email");
+    assert!(email.nullable);
+    assert_eq!(email.types, vec!["string"]);
+    assert_eq!(email.format.as_deref(), Some("email"));
+
+    assert_eq!(field("role").constraints["enum"], json!(["admin", "user"]));
+    assert_eq!(field("birthday").constraints["formatMaximum"], "2010-12-31");
+}
+
+#[test]
+fn test_nesting_and_arrays() {
+    let validator = LinkValidator::new(&json!({
+        "profile": {
+            "type": "object",
+            "required": true,
+            "fields": {"address": {"type": "object", "fields": {"city": {"type": "string", "required": true}}}}
+        },
+        "lines": {"type": "array", "fields": {"sku": {"type": "string", "required": true}}}
+    }))
+    .unwrap();
+    let info = validator.schema_info();
+
+    let profile = info.fields.iter().find(|f| f.name == "profile").unwrap();
+    let city = &profile.fields[0].fields[0];
+    assert_eq!(city.path, "profile.address.city");
+    assert!(city.required);
+
+    let lines = info.fields.iter().find(|f| f.name == "lines").unwrap();
+    let items = lines.items.as_ref().unwrap();
+    assert_eq!(items.path, "lines.*");
+    assert_eq!(items.fields[0].path, "lines.*.sku");
+}
+
+#[test]
+fn test_definitions_and_references() {
+    let validator = LinkValidator::new(&json!({
+        "defs": {
+            "comment": {
+                "text": {"type": "string", "required": true},
+                "replies": {"type": "array", "$use": "comment"}
+            }
+        },
+        "root": {"type": "object", "nullable": true, "$use": "comment"}
+    }))
+    .unwrap();
+    let info = validator.schema_info();
+
+    let root = &info.fields[0];
+    assert_eq!(root.reference.as_deref(), Some("comment"));
+    assert!(root.nullable);
+    let comment = &info.definitions["comment"];
+    let replies = comment.iter().find(|f| f.name == "replies").unwrap();
+    assert_eq!(replies.reference.as_deref(), Some("comment"));
+    assert!(replies.items.is_none());
+}
+
+#[test]
+fn test_json_schema_input_and_titles() {
+    let schema = json!({
+        "type": "object",
+        "required": ["id"],
+        "properties": {
+            "id": {"type": "integer", "description": "Identifier"},
+            "note": {"type": ["string", "null"], "maxLength": 10}
+        }
+    });
+    let info = LinkValidator::new(&schema).unwrap().schema_info();
+    assert_eq!(info.format, SchemaFormat::JsonSchema);
+    assert_eq!(info.fields[0].description.as_deref(), Some("Identifier"));
+    assert!(info.fields[0].required);
+    assert!(info.fields[1].nullable);
+
+    let options = CompileOptions::new().titles(TitleStyle::Humanized);
+    let validator = LinkValidator::with_options(&json!({"firstName": {"type": "string"}}), &options).unwrap();
+    assert_eq!(validator.schema_info().fields[0].title.as_deref(), Some("First name"));
+}
+
+#[test]
+fn test_serializes_to_json() {
+    let validator = LinkValidator::new(&json!({"tags": {"type": "array", "max": 3}})).unwrap();
+    let value = serde_json::to_value(validator.schema_info()).unwrap();
+    assert_eq!(value["format"], "async-validator");
+    assert_eq!(value["fields"][0]["constraints"], json!({"maxItems": 3}));
+    assert!(value["fields"][0].get("items").is_none());
+}
@@ -0,0 +1,63 @@
+//! simd-json parsing tests for link-validator (requires the `simd` feature)
+
+#![cfg(feature = "simd")]
+
+use link_validator::LinkValidator;
+use serde_json::{json, Value};
+
+const DOCUMENTS: &[&str] = &[
+    r#"{"name": "Keyboard", "price": 19.99, "tags": ["a", "b"], "stock": 3}"#,
+    r#"{"name": "Pc", "price": -1, "tags": [], "stock": 1.5}"#,
+    r#"{"name": "键盘 \"mini\"\n", "price": 1e2, "tags": ["a", "a"], "stock": 18446744073709551615}"#,
+    r#"{"name": null, "dimensions": {"width": 0, "height": -3}}"#,
+    r#"[1, 2, 3]"#,
+    r#""text""#,
+    "{\n  \"name\": \"Mouse\",\n  \"price\": 0.5\n}",
+];
+
+fn validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "min": 3, "max": 20},
+        "price": {"type": "number", "required": true, "min": 0},
+        "tags": {"type": "array", "unique": true, "max": 3},
+        "stock": {"type": "integer", "min": 0},
+        "dimensions": {"type": "object", "fields": {"width": {"type": "number", "positive": true}, "height": {"type": "number", "min": 0}}}
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_matches_serde_json_path() {
+    let validator = validator();
+    for document in DOCUMENTS {
+        let data: Value = serde_json::from_str(document).unwrap();
+        let expected = validator.validate(&data);
+
+        let from_str = validator.validate_str(document);
+        assert_eq!(from_str.is_valid, expected.is_valid, "{}", document);
+        assert_eq!(from_str.errors, expected.errors, "{}", document);
+
+        let from_slice = validator.validate_slice(document.as_bytes());
+        assert_eq!(from_slice.errors, expected.errors, "{}", document);
+
+        let from_bytes = validator.validate_bytes(&mut document.as_bytes().to_vec());
+        assert_eq!(from_bytes.errors, expected.errors, "{}", document);
+    }
+}
+
+#[test]
+fn test_parse_errors() {
+    let validator = validator();
+    for document in ["{\n  \"name\": }", "{\"name\": \"x\"", "", "[1, 2,]"] {
+        let result = validator.validate_str(document);
+        assert!(!result.is_valid, "{}", document);
+        assert_eq!(result.errors.as_array().unwrap().len(), 1);
+        assert_eq!(result.errors[0]["code"], "parse.json", "{}", document);
+
+        let result = validator.validate_bytes(&mut document.as_bytes().to_vec());
+        assert_eq!(result.errors[0]["code"], "parse.json", "{}", document);
+    }
+
+    let result = validator.validate_str("{\n  \"name\": }");
+    assert_eq!(result.errors[0]["params"], json!({"line": 2, "column": 11}));
+}
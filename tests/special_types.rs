@@ -19,6 +19,27 @@ fn test_enum_validation() {
     assert!(result.is_valid);
 }
 
+#[test]
+fn test_enum_type() {
+    let schema = json!({
+        "role": {"type": "enum", "enum": ["admin", "member"], "required": true},
+        "level": {"type": "enum", "enum": [1, 2, "max"]}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+    assert!(validator.warnings().is_empty());
+    assert_eq!(validator.json_schema()["properties"]["role"], json!({"enum": ["admin", "member"]}));
+
+    assert!(validator.validate(&json!({"role": "admin", "level": "max"})).is_valid);
+
+    // 只报告枚举错误，不附带类型错误
+    let result = validator.validate(&json!({"role": 1, "level": 3}));
+    assert_eq!(result.errors.as_array().unwrap().len(), 2);
+
+    let missing_list = LinkValidator::new(&json!({"role": {"type": "enum"}})).unwrap();
+    assert_eq!(missing_list.warnings()[0].message, "Field 'role': type 'enum' requires an 'enum' list");
+}
+
 #[test]
 fn test_array_rules_format() {
     let schema = json!({
@@ -84,4 +105,25 @@ fn test_unsupported_rules_warning() {
     assert!(result.is_valid);
     // Note: We can't easily test stderr output in this context
     // In a real test, we might use a testing framework that captures stderr
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_const_rule() {
+    let validator = LinkValidator::new(&json!({
+        "version": {"type": "string", "required": true, "const": "v2"},
+        "flags": {"const": 3},
+        "mode": {"type": "string", "const": "fast", "nullable": true}
+    }))
+    .unwrap();
+
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["version"]["const"], json!("v2"));
+    assert_eq!(properties["flags"], json!({"const": 3}));
+    assert_eq!(properties["mode"]["enum"], json!(["fast", null]));
+
+    assert!(validator.validate(&json!({"version": "v2", "flags": 3, "mode": null})).is_valid);
+    assert!(validator.validate(&json!({"version": "v2", "mode": "fast"})).is_valid);
+    assert!(!validator.validate(&json!({"version": "v1"})).is_valid);
+    assert!(!validator.validate(&json!({"version": "v2", "flags": 4})).is_valid);
+    assert!(!validator.validate(&json!({"version": "v2", "mode": "slow"})).is_valid);
+}
@@ -0,0 +1,46 @@
+//! Validator statistics tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+use std::time::Duration;
+
+#[test]
+fn test_rule_stats() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true},
+        "address": {"type": "object", "fields": {"city": {"type": "string"}}}
+    }))
+    .unwrap();
+    let stats = validator.stats();
+    // 根节点、name、address、address.city
+    assert_eq!(stats.schema_nodes, 4);
+    assert!(stats.compile_duration > Duration::ZERO);
+    assert_eq!(stats.last_validation, None);
+}
+
+#[test]
+fn test_json_schema_has_no_conversion() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"id": {"type": "integer"}, "tags": {"type": "array", "items": {"type": "string"}}}
+    }))
+    .unwrap();
+    let stats = validator.stats();
+    assert_eq!(stats.conversion_duration, Duration::ZERO);
+    assert_eq!(stats.schema_nodes, 4);
+}
+
+#[test]
+fn test_last_validation_is_recorded() {
+    let validator = LinkValidator::new(&json!({"id": {"type": "integer", "required": true}})).unwrap();
+    let copy = validator.clone();
+
+    assert!(!validator.validate(&json!({})).is_valid);
+    let first = validator.stats().last_validation.unwrap();
+    assert!(first > Duration::ZERO);
+    // 副本共享统计
+    assert_eq!(copy.stats().last_validation, Some(first));
+
+    validator.validate_into::<serde_json::Value>(&json!({"id": 1})).unwrap();
+    assert!(validator.stats().last_validation.is_some());
+}
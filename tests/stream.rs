@@ -0,0 +1,48 @@
+//! Async stream validation tests for link-validator
+#![cfg(feature = "tokio")]
+
+use futures_util::{stream, StreamExt};
+use link_validator::LinkValidator;
+use serde_json::json;
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn test_validate_stream_preserves_order() {
+    let validator = LinkValidator::new(&json!({"id": {"type": "integer", "required": true}})).unwrap();
+    let items: Vec<_> = (0..100).map(|i| if i % 3 == 0 { json!({"id": "bad"}) } else { json!({"id": i}) }).collect();
+
+    let results: Vec<_> = block_on(validator.validate_stream(stream::iter(items)).collect());
+    assert_eq!(results.len(), 100);
+    for (i, result) in results.iter().enumerate() {
+        assert_eq!(result.is_valid, i % 3 != 0);
+    }
+}
+
+#[test]
+fn test_validate_stream_across_tasks() {
+    let validator = LinkValidator::new(&json!({"name": {"type": "string", "required": true}})).unwrap();
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let results: Vec<_> = runtime.block_on(async move {
+        let producer = tokio::spawn(async move {
+            for data in [json!({"name": "a"}), json!({}), json!({"name": "c"})] {
+                tx.send(data).unwrap();
+                tokio::task::yield_now().await;
+            }
+        });
+        let messages = stream::unfold(rx, |mut rx| async move { rx.recv().await.map(|v| (v, rx)) });
+        let results = validator.validate_stream(messages).collect::<Vec<_>>().await;
+        producer.await.unwrap();
+        results
+    });
+
+    let valid: Vec<bool> = results.iter().map(|r| r.is_valid).collect();
+    assert_eq!(valid, vec![true, false, true]);
+}
@@ -0,0 +1,96 @@
+//! Subtree validation tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::{Value, json};
+
+fn profile_validator() -> LinkValidator {
+    LinkValidator::new(&json!({
+        "user": {
+            "type": "object",
+            "fields": {
+                "profile": {
+                    "type": "object",
+                    "fields": {
+                        "nickname": {"type": "string", "min": 3, "required": true, "trim": true},
+                        "age": {"type": "integer", "min": 0}
+                    }
+                }
+            }
+        },
+        "lines": {"type": "array", "fields": {"qty": {"type": "integer", "min": 1}}}
+    }))
+    .unwrap()
+}
+
+fn fields(errors: &Value, key: &str) -> Vec<String> {
+    errors.as_array().unwrap().iter().map(|e| e[key].as_str().unwrap_or_default().to_string()).collect()
+}
+
+#[test]
+fn test_validate_nested_object() {
+    let validator = profile_validator();
+    let result = validator.validate_at("/user/profile", &json!({"nickname": "Ada", "age": 30})).unwrap();
+    assert!(result.is_valid, "{:?}", result.errors);
+
+    let result = validator.validate_at("/user/profile", &json!({"nickname": "Ada", "age": -1})).unwrap();
+    assert!(!result.is_valid);
+    assert_eq!(fields(&result.errors, "field"), vec!["user.profile.age"]);
+    assert_eq!(fields(&result.errors, "rulePath"), vec!["user.fields.profile.fields.age.min"]);
+}
+
+#[test]
+fn test_required_and_leaf_errors() {
+    let validator = profile_validator();
+    let result = validator.validate_at("/user/profile", &json!({})).unwrap();
+    assert_eq!(fields(&result.errors, "rulePath"), vec!["user.fields.profile.fields.nickname.required"]);
+
+    let result = validator.validate_at("/user/profile/age", &json!("old")).unwrap();
+    assert_eq!(fields(&result.errors, "field"), vec!["user.profile.age"]);
+    assert_eq!(fields(&result.errors, "rulePath"), vec!["user.fields.profile.fields.age.type"]);
+}
+
+#[test]
+fn test_transforms_apply_to_subtree() {
+    let validator = profile_validator();
+    // 去掉空白后长度不足
+    let result = validator.validate_at("/user/profile/nickname", &json!("  ab  ")).unwrap();
+    assert!(!result.is_valid);
+    let result = validator.validate_at("/user/profile/nickname", &json!("  abc  ")).unwrap();
+    assert!(result.is_valid, "{:?}", result.errors);
+}
+
+#[test]
+fn test_array_item_pointer() {
+    let validator = profile_validator();
+    let result = validator.validate_at("/lines/2", &json!({"qty": 0})).unwrap();
+    assert_eq!(fields(&result.errors, "field"), vec!["lines.2.qty"]);
+    assert!(validator.validate_at("/lines/0", &json!({"qty": 5})).unwrap().is_valid);
+}
+
+#[test]
+fn test_json_schema_refs() {
+    let validator = LinkValidator::new(&json!({
+        "type": "object",
+        "properties": {"home": {"$ref": "#/$defs/address"}},
+        "$defs": {
+            "address": {
+                "type": "object",
+                "properties": {"city": {"type": "string", "minLength": 1}, "zip": {"$ref": "#/$defs/zip"}}
+            },
+            "zip": {"type": "string", "pattern": "^[0-9]{5}$"}
+        }
+    }))
+    .unwrap();
+    let result = validator.validate_at("/home", &json!({"city": "", "zip": "12345"})).unwrap();
+    assert!(!result.is_valid);
+    assert!(!validator.validate_at("/home/zip", &json!("abc")).unwrap().is_valid);
+    assert!(validator.validate_at("/home/zip", &json!("12345")).unwrap().is_valid);
+}
+
+#[test]
+fn test_whole_document_and_invalid_pointers() {
+    let validator = profile_validator();
+    assert!(validator.validate_at("", &json!({"user": {"profile": {"nickname": "Ada"}}})).unwrap().is_valid);
+    assert!(validator.validate_at("/user/unknown", &json!({})).is_err());
+    assert!(validator.validate_at("user/profile", &json!({})).is_err());
+}
@@ -0,0 +1,91 @@
+//! Generated title tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, TitleStyle};
+use serde_json::json;
+
+fn schema() -> serde_json::Value {
+    json!({
+        "firstName": {"type": "string", "required": true},
+        "user_id": {"type": "integer"},
+        "HTTPStatus": {"type": "integer"},
+        "手机号": {"type": "string"},
+        "address": {
+            "type": "object",
+            "fields": {"zip-code": {"type": "string"}}
+        },
+        "tags": {
+            "type": "array",
+            "fields": {"tagName": {"type": "string"}}
+        }
+    })
+}
+
+#[test]
+fn test_field_name_titles() {
+    let options = CompileOptions::new().titles(TitleStyle::FieldName);
+    let validator = LinkValidator::with_options(&schema(), &options).unwrap();
+    let properties = &validator.json_schema()["properties"];
+
+    assert_eq!(properties["firstName"]["title"], "firstName");
+    assert_eq!(properties["address"]["properties"]["zip-code"]["title"], "zip-code");
+    assert_eq!(properties["tags"]["items"]["properties"]["tagName"]["title"], "tagName");
+}
+
+#[test]
+fn test_humanized_titles() {
+    let options = CompileOptions::new().titles(TitleStyle::Humanized);
+    let validator = LinkValidator::with_options(&schema(), &options).unwrap();
+    let properties = &validator.json_schema()["properties"];
+
+    assert_eq!(properties["firstName"]["title"], "First name");
+    assert_eq!(properties["user_id"]["title"], "User id");
+    assert_eq!(properties["HTTPStatus"]["title"], "HTTP status");
+    assert_eq!(properties["手机号"]["title"], "手机号");
+    assert_eq!(properties["address"]["title"], "Address");
+    assert_eq!(properties["address"]["properties"]["zip-code"]["title"], "Zip code");
+    assert_eq!(properties["tags"]["items"]["properties"]["tagName"]["title"], "Tag name");
+
+    assert!(validator.validate(&json!({"firstName": "john"})).is_valid);
+}
+
+#[test]
+fn test_titles_disabled_by_default() {
+    let validator = LinkValidator::new(&schema()).unwrap();
+    assert!(validator.json_schema()["properties"]["firstName"].get("title").is_none());
+}
+
+#[test]
+fn test_title_and_description_rules() {
+    let validator = LinkValidator::new(&json!({
+        "email": {"type": "email", "required": true, "title": "Email", "description": "Login address"},
+        "age": [{"type": "integer"}, {"min": 18, "message": "Must be an adult"}],
+        "nick": {"type": "string", "message": "Invalid nick", "description": "Display name"},
+        "address": {
+            "type": "object",
+            "fields": {"city": {"type": "string", "title": "City"}}
+        }
+    }))
+    .unwrap();
+    let properties = &validator.json_schema()["properties"];
+
+    assert_eq!(properties["email"]["title"], "Email");
+    assert_eq!(properties["email"]["description"], "Login address");
+    assert_eq!(properties["age"]["description"], "Must be an adult");
+    assert_eq!(properties["nick"]["description"], "Display name");
+    assert_eq!(properties["address"]["properties"]["city"]["title"], "City");
+    assert!(validator.warnings().is_empty());
+    assert!(validator.validate(&json!({"email": "a@example.com", "age": 20})).is_valid);
+}
+
+#[test]
+fn test_explicit_title_wins_over_generated() {
+    let options = CompileOptions::new().titles(TitleStyle::Humanized);
+    let validator = LinkValidator::with_options(
+        &json!({"firstName": {"type": "string", "title": "Given name"}, "lastName": {"type": "string"}}),
+        &options,
+    )
+    .unwrap();
+    let properties = &validator.json_schema()["properties"];
+    assert_eq!(properties["firstName"]["title"], "Given name");
+    assert_eq!(properties["lastName"]["title"], "Last name");
+}
@@ -0,0 +1,123 @@
+//! TOML input tests for link-validator
+#![cfg(feature = "toml")]
+
+use link_validator::{LinkValidator, SchemaFormat};
+use serde_json::json;
+
+#[test]
+fn test_toml_rules_validate_config() {
+    let validator = LinkValidator::from_toml_str(r#"
+# 服务配置规则
+[server]
+type = "object"
+required = true
+
+[server.fields.host]
+type = "string"
+required = true
+
+[server.fields.port]
+type = "integer"
+min = 1
+max = 65535
+
+[upstreams]
+type = "array"
+max = 4
+"#).expect("Compilation failed");
+    assert_eq!(validator.format(), SchemaFormat::AsyncValidator);
+
+    let config = r#"
+upstreams = ["http://a.internal"]
+
+[server]
+host = "0.0.0.0"
+port = 8_080
+"#;
+    let result = validator.validate_toml_str(config).unwrap();
+    assert!(result.is_valid, "{}", result.errors);
+
+    let result = validator.validate_toml_str("[server]\nhost = \"localhost\"\nport = 70000\n").unwrap();
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "server.port");
+}
+
+#[test]
+fn test_toml_values() {
+    // JSON Schema 忽略未知关键字，用 json_schema() 检查解析结果
+    let validator = LinkValidator::from_toml_str(r#"
+[definitions.data]
+bare = "tab\there \u00E9"
+literal = 'C:\Users\nobody'
+"quoted key" = 1
+dotted.inner = true
+hex = 0xff
+neg = -17
+float = 6.626e-34
+date = 1979-05-27
+datetime = 1979-05-27 07:32:00Z
+time = 07:32:00
+multi = """
+first \
+  second
+third"""
+raw = '''
+keep \n as is'''
+nested = [ [1, 2], ["a"], ]
+multiline = [
+  1,  # 注释
+  2,
+]
+inline = { x = 1, y.z = "w" }
+
+[[definitions.data.items]]
+name = "one"
+
+[[definitions.data.items]]
+name = "two"
+[definitions.data.items.extra]
+flag = false
+"#).expect("Compilation failed");
+
+    assert_eq!(validator.json_schema()["definitions"]["data"], json!({
+        "bare": "tab\there é",
+        "literal": "C:\\Users\\nobody",
+        "quoted key": 1,
+        "dotted": {"inner": true},
+        "hex": 255,
+        "neg": -17,
+        "float": 6.626e-34,
+        "date": "1979-05-27",
+        "datetime": "1979-05-27T07:32:00Z",
+        "time": "07:32:00",
+        "multi": "first second\nthird",
+        "raw": "keep \\n as is",
+        "nested": [[1, 2], ["a"]],
+        "multiline": [1, 2],
+        "inline": {"x": 1, "y": {"z": "w"}},
+        "items": [{"name": "one"}, {"name": "two", "extra": {"flag": false}}]
+    }));
+}
+
+#[test]
+fn test_toml_errors() {
+    let cases = [
+        ("a = 1\na = 2\n", "duplicate key `a`"),
+        ("[t]\n[t]\n", "duplicate key `t`"),
+        ("a = \"open\n", "invalid basic string"),
+        ("a = 1 2\n", "line 1, column 7"),
+        ("a = 1\n[a]\n", "invalid table header"),
+        ("a = 01\n", "line 1, column 6"),
+    ];
+    for (input, expected) in cases {
+        let err = LinkValidator::from_toml_str(input).unwrap_err();
+        assert!(err.starts_with("Failed to parse TOML"), "{}", err);
+        assert!(err.contains(expected), "{:?}: {}", input, err);
+    }
+}
+
+#[test]
+fn test_toml_non_finite_floats_become_null() {
+    let validator = LinkValidator::from_toml_str("[definitions.data]\na = nan\nb = -inf\n").expect("Compilation failed");
+    assert_eq!(validator.json_schema()["definitions"]["data"], json!({"a": null, "b": null}));
+}
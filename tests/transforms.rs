@@ -0,0 +1,156 @@
+//! Normalization transform tests for link-validator
+
+use link_validator::{LinkValidator, Phase, ValidateOptions};
+use serde_json::json;
+
+#[test]
+fn test_truncate_at_grapheme_boundaries() {
+    let schema = json!({
+        "nickname": {"type": "string", "truncate": 3},
+        "flag": {"type": "string", "truncate": 1},
+        "note": {"type": "string", "truncate": 2}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let data = json!({
+        "nickname": "你好👨‍👩‍👧世界",
+        "flag": "🇨🇳🇺🇸",
+        "note": "e\u{0301}e\u{0301}e\u{0301}"
+    });
+
+    let (value, result) = validator.validate_and_transform(&data);
+    assert!(result.is_valid);
+    assert_eq!(value["nickname"], "你好👨‍👩‍👧");
+    assert_eq!(value["flag"], "🇨🇳");
+    assert_eq!(value["note"], "e\u{0301}e\u{0301}");
+
+    // 原始数据保持不变
+    assert_eq!(data["flag"], "🇨🇳🇺🇸");
+}
+
+#[test]
+fn test_truncate_keeps_indic_and_southeast_asian_clusters() {
+    let validator = LinkValidator::new(&json!({"text": {"type": "string", "truncate": 1}})).expect("Compilation failed");
+    let truncate = |text: &str| validator.validate_and_transform(&json!({"text": text})).0["text"].clone();
+
+    // 泰米尔文：辅音与元音符号
+    assert_eq!(truncate("நிலா"), "நி");
+    // 老挝文：辅音与元音符号
+    assert_eq!(truncate("ກີບ"), "ກີ");
+    // 天城文和孟加拉文：以 virama 连接的连字
+    assert_eq!(truncate("क्षत्रिय"), "क्ष");
+    assert_eq!(truncate("ক্ষমা"), "ক্ষ");
+    // 泰文：辅音与上方元音、声调符号
+    assert_eq!(truncate("ที่นี่"), "ที่");
+    // 韩文字母组合
+    assert_eq!(truncate("\u{1100}\u{1161}\u{11A8}가"), "\u{1100}\u{1161}\u{11A8}");
+}
+
+#[test]
+fn test_truncate_runs_before_validation() {
+    let schema = json!({
+        "title": [
+            {"type": "string", "required": true, "max": 5},
+            {"truncate": 5}
+        ]
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    // 截断后满足 max 限制
+    let data = json!({"title": "a very long title"});
+    assert!(validator.validate(&data).is_valid);
+}
+
+#[test]
+fn test_truncate_nested_fields() {
+    let schema = json!({
+        "user": {
+            "type": "object",
+            "fields": {
+                "bio": {"type": "string", "truncate": 2}
+            }
+        },
+        "tags": {
+            "type": "array",
+            "fields": {
+                "name": {"type": "string", "truncate": 1}
+            }
+        }
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let data = json!({
+        "user": {"bio": "👍🏽👍🏽👍🏽"},
+        "tags": [{"name": "ab"}, {"name": "中文"}]
+    });
+
+    let (value, result) = validator.validate_and_transform(&data);
+    assert!(result.is_valid);
+    assert_eq!(value["user"]["bio"], "👍🏽👍🏽");
+    assert_eq!(value["tags"][0]["name"], "a");
+    assert_eq!(value["tags"][1]["name"], "中");
+}
+
+#[test]
+fn test_skip_coerce_phase() {
+    let schema = json!({
+        "title": {"type": "string", "max": 3, "truncate": 3}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+    let data = json!({"title": "abcdef"});
+    assert!(validator.validate(&data).is_valid);
+
+    // 跳过规范化阶段时直接验证原始数据
+    let options = ValidateOptions::new().skip_phase(Phase::Coerce);
+    assert!(!validator.validate_with(&data, &options).is_valid);
+}
+
+#[test]
+fn test_trim_and_case() {
+    let schema = json!({
+        "email": {"type": "email", "required": true, "trim": true, "case": "lower"},
+        "code": {"type": "string", "len": 3, "trim": true, "case": "upper"},
+        "tags": {"type": "array", "fields": {"name": {"type": "string", "trim": true, "enum": ["rust", "go"]}}}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+    let data = json!({
+        "email": "  Alice@Example.COM ",
+        "code": " cny\t",
+        "tags": [{"name": " rust "}, {"name": "go"}]
+    });
+
+    let (value, result) = validator.validate_and_transform(&data);
+    assert!(result.is_valid, "{:?}", result.errors);
+    assert_eq!(value["email"], "alice@example.com");
+    assert_eq!(value["code"], "CNY");
+    assert_eq!(value["tags"][0]["name"], "rust");
+
+    // 验证的是转换后的值
+    assert!(validator.validate(&json!({"email": "a@b.co", "code": " usd "})).is_valid);
+    assert!(!validator.validate(&json!({"email": "a@b.co", "code": " us "})).is_valid);
+}
+
+#[test]
+fn test_trim_runs_before_truncate() {
+    let validator = LinkValidator::new(&json!({
+        "title": {"type": "string", "truncate": 3, "trim": true}
+    }))
+    .expect("Compilation failed");
+
+    let (value, _) = validator.validate_and_transform(&json!({"title": "   abcdef"}));
+    assert_eq!(value["title"], "abc");
+}
+
+#[test]
+fn test_invalid_case_rule() {
+    let error = LinkValidator::new(&json!({"code": {"type": "string", "case": "title"}})).unwrap_err();
+    assert!(error.contains("case must be 'lower' or 'upper'"), "{}", error);
+
+    let validator = LinkValidator::new(&json!({"count": {"type": "integer", "trim": true}})).unwrap();
+    assert!(validator.warnings().iter().any(|w| w.message.contains("trim rule only supported for string types")));
+}
@@ -0,0 +1,117 @@
+//! Typed validation tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, NumberFormat};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+struct Product {
+    name: String,
+    price: f64,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_validate_into_struct() {
+    let schema = json!({
+        "name": {"type": "string", "required": true, "truncate": 5},
+        "price": {"type": "number", "required": true, "min": 0},
+        "tags": {"type": "array", "required": true}
+    });
+
+    let options = CompileOptions::new().number_format(NumberFormat::DecimalComma);
+    let validator = LinkValidator::with_options(&schema, &options).expect("Compilation failed");
+
+    // 转换后的数据被反序列化
+    let product: Product = validator
+        .validate_into(&json!({"name": "Keyboard", "price": "1.299,90", "tags": ["pc"]}))
+        .expect("Validation failed");
+    assert_eq!(product, Product {
+        name: "Keybo".to_string(),
+        price: 1299.9,
+        tags: vec!["pc".to_string()],
+    });
+}
+
+#[test]
+fn test_validate_into_errors() {
+    let schema = json!({
+        "name": {"type": "string", "required": true},
+        "price": {"type": "number", "required": true, "min": 0},
+        "tags": {"type": "array"}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let errors = validator
+        .validate_into::<Product>(&json!({"name": 1, "price": -1, "tags": []}))
+        .unwrap_err();
+    assert_eq!(errors.errors.len(), 2);
+    assert!(errors.to_string().starts_with("Validation failed"));
+
+    // schema 通过但结构体要求的字段缺失时报告反序列化错误
+    let errors = validator
+        .validate_into::<Product>(&json!({"name": "Mouse", "price": 10}))
+        .unwrap_err();
+    assert_eq!(errors.errors.len(), 1);
+    assert_eq!(errors.errors[0].path, "");
+    assert!(errors.errors[0].message.contains("tags"));
+}
+
+#[test]
+fn test_validate_serialize() {
+    let schema = json!({
+        "name": {"type": "string", "required": true, "min": 3},
+        "price": {"type": "number", "required": true, "min": 0},
+        "tags": {"type": "array", "max": 2}
+    });
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let product = Product { name: "Keyboard".to_string(), price: 12.5, tags: vec!["pc".to_string()] };
+    assert!(validator.validate_serialize(&product).is_valid);
+
+    let product = Product { name: "Pc".to_string(), price: -1.0, tags: vec![] };
+    let result = validator.validate_serialize(&product);
+    assert_eq!(result.errors.as_array().unwrap().len(), 2);
+    assert_eq!(result.errors[0]["field"], "name");
+
+    // 切片等非结构体类型也可以直接验证
+    let validator = LinkValidator::new(&json!({"type": "array", "maxItems": 2})).unwrap();
+    assert!(!validator.validate_serialize(&[1, 2, 3][..]).is_valid);
+}
+
+#[test]
+fn test_validate_serialize_failure() {
+    let validator = LinkValidator::new(&json!({"type": "object"})).unwrap();
+    let mut map = std::collections::BTreeMap::new();
+    map.insert(vec![1u8], "value");
+
+    let result = validator.validate_serialize(&map);
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["code"], "serialize");
+    assert_eq!(result.errors[0]["instancePath"], "");
+    assert!(result.errors[0]["message"].as_str().unwrap().starts_with("Failed to serialize"));
+}
+
+#[test]
+fn test_validate_str_and_slice() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "min": 3}
+    }))
+    .expect("Compilation failed");
+
+    assert!(validator.validate_str(r#"{"name": "Keyboard"}"#).is_valid);
+    let result = validator.validate_slice(br#"{"name": "Pc"}"#);
+    assert!(!result.is_valid);
+    assert_eq!(result.errors[0]["field"], "name");
+
+    let result = validator.validate_str("{\n  \"name\": }");
+    assert!(!result.is_valid);
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+    assert_eq!(result.errors[0]["code"], "parse.json");
+    assert_eq!(result.errors[0]["field"], "");
+    assert_eq!(result.errors[0]["params"], json!({"line": 2, "column": 11}));
+
+    let result = validator.validate_slice(&[0xff, 0xfe]);
+    assert_eq!(result.errors[0]["code"], "parse.json");
+}
@@ -0,0 +1,89 @@
+//! Discriminated union tests for link-validator
+
+use link_validator::{lint, minify, LinkValidator, MinifyOptions, SampleOptions};
+use serde_json::json;
+
+fn payload_rules() -> serde_json::Value {
+    json!({
+        "discriminator": "kind",
+        "variants": {
+            "email": {"to": {"type": "email", "required": true}, "subject": {"type": "string", "max": 20}},
+            "sms": {"phone": {"type": "string", "required": true, "pattern": "^[0-9]{6,15}$"}}
+        }
+    })
+}
+
+#[test]
+fn test_field_level_union() {
+    let validator = LinkValidator::new(&json!({"payload": {"required": true, "discriminator": "kind", "variants": payload_rules()["variants"]}})).unwrap();
+
+    let schema = &validator.json_schema()["properties"]["payload"];
+    assert_eq!(schema["type"], json!("object"));
+    assert_eq!(schema["properties"]["kind"], json!({"enum": ["email", "sms"]}));
+    assert_eq!(schema["oneOf"][0]["properties"]["kind"], json!({"const": "email"}));
+    assert_eq!(schema["oneOf"][1]["required"], json!(["phone", "kind"]));
+
+    assert!(validator.validate(&json!({"payload": {"kind": "email", "to": "a@b.co"}})).is_valid);
+    assert!(validator.validate(&json!({"payload": {"kind": "sms", "phone": "13800000000"}})).is_valid);
+    assert!(!validator.validate(&json!({"payload": {"kind": "sms", "to": "a@b.co"}})).is_valid);
+    assert!(!validator.validate(&json!({"payload": {"kind": "push"}})).is_valid);
+    assert!(!validator.validate(&json!({"payload": {"to": "a@b.co"}})).is_valid);
+}
+
+#[test]
+fn test_top_level_union() {
+    let validator = LinkValidator::new(&payload_rules()).unwrap();
+
+    assert_eq!(validator.json_schema()["required"], json!(["kind"]));
+    assert!(validator.validate(&json!({"kind": "email", "to": "a@b.co", "subject": "hi"})).is_valid);
+    assert!(!validator.validate(&json!({"kind": "email", "to": "a@b.co", "subject": "x".repeat(21)})).is_valid);
+
+    // 其他入口也识别根节点联合
+    let minified = minify(&payload_rules(), &MinifyOptions::new()).unwrap();
+    assert!(minified.get("oneOf").is_some());
+
+    for seed in 0..20 {
+        let sample = validator.generate_sample(&SampleOptions::new().seed(seed));
+        assert!(validator.validate(&sample).is_valid, "{}", sample);
+    }
+}
+
+#[test]
+fn test_union_date_bounds_use_matching_variant() {
+    let validator = LinkValidator::new(&json!({
+        "discriminator": "kind",
+        "variants": {
+            "past": {"at": {"type": "date", "format": "date", "maxDate": "2000-01-01"}},
+            "future": {"at": {"type": "date", "format": "date", "minDate": "2100-01-01"}}
+        }
+    }))
+    .unwrap();
+
+    assert!(validator.validate(&json!({"kind": "past", "at": "1999-12-31"})).is_valid);
+    assert!(validator.validate(&json!({"kind": "future", "at": "2100-06-01"})).is_valid);
+    let result = validator.validate(&json!({"kind": "past", "at": "2050-01-01"}));
+    assert_eq!(result.errors.as_array().unwrap().len(), 1, "{:?}", result.errors);
+}
+
+#[test]
+fn test_invalid_union_rules() {
+    let error = LinkValidator::new(&json!({"payload": {"discriminator": "kind", "variants": {}}})).unwrap_err();
+    assert!(error.contains("non-empty 'variants'"), "{}", error);
+
+    let error = LinkValidator::new(&json!({"payload": {"type": "object", "variants": {"a": {}}}})).unwrap_err();
+    assert!(error.contains("requires a string 'discriminator'"), "{}", error);
+
+    let validator = LinkValidator::new(&json!({"payload": {"type": "string", "discriminator": "kind", "variants": {"a": {}}}})).unwrap();
+    assert!(validator.warnings().iter().any(|w| w.message.contains("only supported for object type")));
+}
+
+#[test]
+fn test_lint_checks_variants() {
+    let findings = lint(&json!({
+        "discriminator": "kind",
+        "variants": {"sms": {"phone": {"type": "string", "min": 10, "max": 5}}}
+    }));
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].path, "[sms].phone");
+    assert_eq!(findings[0].code, "min-greater-than-max");
+}
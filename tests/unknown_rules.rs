@@ -0,0 +1,66 @@
+//! Unknown rule policy tests for link-validator
+
+use link_validator::{CompileOptions, LinkValidator, UnknownRulePolicy, WarningKind};
+use serde_json::json;
+
+fn rules() -> serde_json::Value {
+    json!({
+        "name": {"type": "string", "requird": true},
+        "address": {
+            "type": "object",
+            "fields": {"zip": {"type": "string", "lenght": 5}}
+        },
+        "note": {"type": "string", "whitespace": true}
+    })
+}
+
+#[test]
+fn test_warn_by_default() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+    let warnings = validator.warnings();
+    assert!(warnings.iter().any(|w| w.message == "Field 'name': unsupported rule 'requird'"));
+    assert!(warnings.iter().any(|w| w.message == "Field 'zip': unsupported rule 'lenght'"));
+}
+
+#[test]
+fn test_unknown_rule_kind() {
+    let validator = LinkValidator::new(&rules()).unwrap();
+    let mut unknown: Vec<_> =
+        validator.warnings().iter().filter(|w| w.kind == WarningKind::UnknownRule).map(|w| w.rule.as_deref()).collect();
+    unknown.sort();
+    assert_eq!(unknown, [Some("lenght"), Some("requird")]);
+
+    // 其他警告的类别为 Other，序列化时不输出 kind
+    let other = validator.warnings().iter().find(|w| w.rule.as_deref() == Some("whitespace")).unwrap();
+    assert_eq!(other.kind, WarningKind::Other);
+    assert!(serde_json::to_value(other).unwrap().get("kind").is_none());
+
+    let unknown = validator.warnings().iter().find(|w| w.kind == WarningKind::UnknownRule).unwrap();
+    assert_eq!(serde_json::to_value(unknown).unwrap()["kind"], "unknown-rule");
+}
+
+#[test]
+fn test_ignore_unknown_rules() {
+    let options = CompileOptions::new().unknown_rule_policy(UnknownRulePolicy::Ignore);
+    let validator = LinkValidator::with_options(&rules(), &options).unwrap();
+    let warnings = validator.warnings();
+    assert!(!warnings.iter().any(|w| w.message.contains("unsupported rule")));
+    // 其他转换警告不受影响
+    assert!(warnings.iter().any(|w| w.message.contains("whitespace rule")));
+}
+
+#[test]
+fn test_error_on_unknown_rules() {
+    let options = CompileOptions::new().unknown_rule_policy(UnknownRulePolicy::Error);
+    let error = LinkValidator::with_options(&rules(), &options).unwrap_err();
+    assert!(error.starts_with("Unknown rules are not allowed:"));
+    assert!(error.contains("'requird'"));
+    assert!(error.contains("'lenght'"));
+}
+
+#[test]
+fn test_error_policy_accepts_known_rules() {
+    let options = CompileOptions::new().unknown_rule_policy(UnknownRulePolicy::Error);
+    let validator = LinkValidator::with_options(&json!({"name": {"type": "string", "required": true, "min": 2}}), &options).unwrap();
+    assert!(validator.validate(&json!({"name": "ann"})).is_valid);
+}
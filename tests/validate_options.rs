@@ -0,0 +1,219 @@
+//! Validate options tests for link-validator
+
+use link_validator::{ClientPolicy, LinkValidator, ValidateOptions};
+use serde_json::{json, Value};
+
+#[test]
+fn test_collect_all_errors_by_default() {
+    let schema = json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "email": {"type": "email", "required": true}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let data = json!({
+        "username": "jo", // Too short
+        "email": "invalid-email"
+    });
+
+    let result = validator.validate_with(&data, &ValidateOptions::new());
+    assert!(!result.is_valid);
+    assert_eq!(result.errors.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_fail_fast_stops_at_first_error() {
+    let schema = json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "email": {"type": "email", "required": true}
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let data = json!({
+        "username": "jo",
+        "email": "invalid-email"
+    });
+
+    let options = ValidateOptions::new().fail_fast(true);
+    let result = validator.validate_with(&data, &options);
+    assert!(!result.is_valid);
+
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].get("field").is_some());
+
+    // 合法数据在 fail_fast 模式下同样通过
+    let valid_data = json!({"username": "john", "email": "john@example.com"});
+    assert!(validator.validate_with(&valid_data, &options).is_valid);
+}
+
+#[test]
+fn test_max_errors_truncates_error_list() {
+    let schema = json!({
+        "rows": {
+            "type": "array",
+            "fields": {
+                "count": {"type": "integer", "required": true}
+            }
+        }
+    });
+
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let items: Vec<_> = (0..100).map(|i| json!({"count": format!("{}", i)})).collect();
+    let data = json!({"rows": items});
+
+    let result = validator.validate_with(&data, &ValidateOptions::new().max_errors(10));
+    assert!(!result.is_valid);
+    assert!(result.truncated);
+    assert_eq!(result.errors.as_array().unwrap().len(), 10);
+
+    // 错误数量未超过限制时不标记截断
+    let result = validator.validate_with(&data, &ValidateOptions::new().max_errors(100));
+    assert!(!result.truncated);
+    assert_eq!(result.errors.as_array().unwrap().len(), 100);
+}
+
+#[test]
+fn test_keys_limit_validation_to_listed_fields() {
+    let schema = json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "password": {"type": "string", "required": true, "min": 8},
+        "profile": {
+            "type": "object",
+            "fields": {
+                "age": {"type": "integer", "min": 0}
+            }
+        }
+    });
+    let validator = LinkValidator::new(&schema).expect("Compilation failed");
+
+    let data = json!({"username": "jo", "profile": {"age": -1}});
+    assert_eq!(validator.validate(&data).errors.as_array().unwrap().len(), 3);
+
+    let result = validator.validate_with(&data, &ValidateOptions::new().keys(["username"]));
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["field"], "username");
+
+    // 未列出的必填字段不报缺失，嵌套字段按顶层键归属
+    let result = validator.validate_with(&data, &ValidateOptions::new().keys(["password", "profile"]));
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|e| e["message"].as_str().unwrap().contains("\"password\"")));
+    assert!(errors.iter().any(|e| e["field"] == "profile.age"));
+
+    // 根节点错误不受 keys 限制
+    let result = validator.validate_with(&json!("not an object"), &ValidateOptions::new().keys(["username"]));
+    assert!(!result.is_valid);
+}
+
+#[test]
+fn test_first_is_fail_fast() {
+    let validator = LinkValidator::new(&json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "email": {"type": "email", "required": true}
+    }))
+    .unwrap();
+    let result = validator.validate_with(&json!({"username": "jo"}), &ValidateOptions::new().first(true));
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_first_fields_keeps_first_error_per_field() {
+    let validator = LinkValidator::new(&json!({
+        "username": [{"type": "string", "min": 3}, {"pattern": "^[a-z]+$"}],
+        "password": [{"type": "string", "min": 8}, {"pattern": "[0-9]"}],
+        "profile": {"type": "object", "fields": {
+            "nickname": [{"type": "string", "min": 3}, {"pattern": "^[a-z]+$"}],
+            "bio": {"type": "string", "required": true},
+            "site": {"type": "string", "required": true}
+        }}
+    }))
+    .unwrap();
+    let data = json!({"username": "A", "password": "x", "profile": {"nickname": "B"}});
+    let count = |options: &ValidateOptions, field: &str| {
+        let result = validator.validate_with(&data, options);
+        result.errors.as_array().unwrap().iter().filter(|e| e["field"] == field).count()
+    };
+
+    let listed = ValidateOptions::new().first_fields(["username", "profile"]);
+    assert_eq!(count(&listed, "username"), 1);
+    assert_eq!(count(&listed, "password"), 2);
+    assert_eq!(count(&listed, "profile.nickname"), 1);
+    // 缺少的必填字段各自计数
+    assert_eq!(count(&listed, "profile"), 2);
+
+    let all = ValidateOptions::new().first_fields_all(true);
+    assert_eq!(count(&all, "password"), 1);
+    assert_eq!(count(&all.first_fields_all(false), "password"), 2);
+}
+
+#[test]
+fn test_custom_messages() {
+    let validator = LinkValidator::new(&json!({
+        "name": {"type": "string", "required": true, "min": 3},
+        "role": {"type": "enum", "enum": ["admin", "member"]},
+        "email": {"type": "email"},
+        "code": {"type": "string", "pattern": "^[A-Z]+$"}
+    }))
+    .unwrap();
+    let options = ValidateOptions::new()
+        .message("required", "%s is required")
+        .message("string.min", "%s must be at least %s characters")
+        .message("enum", "%s must be one of %s")
+        .message("types.email", "%s is not a valid %s")
+        .message("pattern.mismatch", "%s value %s does not match pattern %s");
+    let message = |data: Value| validator.validate_with(&data, &options).errors[0]["message"].clone();
+
+    assert_eq!(message(json!({})), "name is required");
+    assert_eq!(message(json!({"name": "al"})), "name must be at least 3 characters");
+    assert_eq!(message(json!({"name": "alice", "role": "x"})), "role must be one of admin, member");
+    assert_eq!(message(json!({"name": "alice", "email": "nope"})), "email is not a valid email");
+    assert_eq!(message(json!({"name": "alice", "code": "ab"})), "code value ab does not match pattern ^[A-Z]+$");
+}
+
+#[test]
+fn test_omit_warnings() {
+    let validator = LinkValidator::new(&json!({"phone": {"type": "string", "required": true}}))
+        .unwrap()
+        .with_client_policy(ClientPolicy::new().downgrade_below("2.0.0", ["/phone"]));
+    let options = ValidateOptions::new().client_version("1.0.0");
+    assert_eq!(validator.validate_with(&json!({}), &options).warnings.as_array().unwrap().len(), 1);
+    let result = validator.validate_with(&json!({}), &options.omit_warnings(true));
+    assert!(result.is_valid);
+    assert!(result.warnings.as_array().unwrap().is_empty());
+
+    // async-validator 的 suppressWarning 只关闭控制台警告，不影响输出的警告
+    let async_options = ValidateOptions::from_async_options(&json!({"suppressWarning": true})).unwrap();
+    let result = validator.validate_with(&json!({}), &async_options.client_version("1.0.0"));
+    assert_eq!(result.warnings.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn test_async_validator_options_object() {
+    let validator = LinkValidator::new(&json!({
+        "username": [{"type": "string", "required": true, "min": 3}, {"pattern": "^[a-z]+$"}],
+        "password": {"type": "string", "required": true}
+    }))
+    .unwrap();
+    let options = json!({
+        "firstFields": ["username"],
+        "keys": ["username"],
+        "messages": {"string": {"min": "%s 至少 %s 个字符"}}
+    });
+    let result = validator.validate_with_options(&json!({"username": "A"}), &options).unwrap();
+    let errors = result.errors.as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["message"], "username 至少 3 个字符");
+
+    let result = validator.validate_with_options(&json!({}), &json!({"first": true})).unwrap();
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+
+    assert!(validator.validate_with_options(&json!({}), &json!({"first": "yes"})).is_err());
+    assert!(validator.validate_with_options(&json!({}), &json!({"firstFields": [1]})).is_err());
+    assert!(validator.validate_with_options(&json!({}), &json!({"deep": true})).is_err());
+    assert!(validator.validate_with_options(&json!({}), &json!({"messages": {"required": 1}})).is_err());
+}
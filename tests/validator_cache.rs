@@ -0,0 +1,47 @@
+//! Validator cache tests for link-validator
+
+use link_validator::{CompileOptions, NullPolicy, ValidatorCache};
+use serde_json::json;
+use std::sync::Arc;
+
+#[test]
+fn test_reuses_compiled_validators() {
+    let cache = ValidatorCache::new(10);
+    let a = json!({"name": {"type": "string", "required": true}, "age": {"type": "integer"}});
+    let same = json!({"age": {"type": "integer"}, "name": {"required": true, "type": "string"}});
+
+    let first = cache.get_or_compile(&a).unwrap();
+    let second = cache.get_or_compile(&same).unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(cache.len(), 1);
+
+    // 编译选项不同时分别缓存
+    let options = CompileOptions::new().null_policy(NullPolicy::AsMissing);
+    let with_options = cache.get_or_compile_with(&a, &options).unwrap();
+    assert!(!Arc::ptr_eq(&first, &with_options));
+    assert_eq!(cache.len(), 2);
+
+    // 编译失败不缓存
+    assert!(cache.get_or_compile(&json!({"type": 12})).is_err());
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn test_evicts_least_recently_used() {
+    let cache = ValidatorCache::new(2);
+    let schema = |n: usize| json!({"field": {"type": "string", "max": n}});
+
+    let first = cache.get_or_compile(&schema(1)).unwrap();
+    cache.get_or_compile(&schema(2)).unwrap();
+    cache.get_or_compile(&schema(1)).unwrap();
+    cache.get_or_compile(&schema(3)).unwrap();
+    assert_eq!(cache.len(), 2);
+
+    // schema(1) 最近使用过，仍在缓存中；schema(2) 被淘汰
+    assert!(Arc::ptr_eq(&first, &cache.get_or_compile(&schema(1)).unwrap()));
+    let recompiled = cache.get_or_compile(&schema(2)).unwrap();
+    assert!(!recompiled.validate(&json!({"field": "abc"})).is_valid);
+}
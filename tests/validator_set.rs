@@ -0,0 +1,97 @@
+//! Validator set tests for link-validator
+
+use link_validator::{CompileOptions, ValidatorSet};
+use serde_json::json;
+
+fn set() -> ValidatorSet {
+    let mut set = ValidatorSet::new();
+    set.define("address", json!({
+        "city": {"type": "string", "required": true},
+        "zip": {"type": "string", "pattern": "^[0-9]{5}$"}
+    }));
+    set.define("contact", json!({
+        "email": {"type": "email", "required": true},
+        "address": {"type": "object", "$use": "address"}
+    }));
+    set.add("user", json!({
+        "name": {"type": "string", "required": true},
+        "contact": {"type": "object", "required": true, "$use": "contact"}
+    }));
+    set.add("shop", json!({
+        "branches": {"type": "array", "$use": "address"}
+    }));
+    set.add("ping", json!({
+        "type": "object",
+        "properties": {"origin": {"$ref": "#/$defs/address"}}
+    }));
+    set
+}
+
+#[test]
+fn test_compile_and_validate_by_name() {
+    let mut set = set();
+    assert!(set.is_empty());
+    set.compile().unwrap();
+    assert_eq!(set.names().collect::<Vec<_>>(), vec!["ping", "shop", "user"]);
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.get("user").unwrap().name(), Some("user"));
+
+    let user = json!({"name": "john", "contact": {"email": "j@example.com", "address": {"city": "Paris"}}});
+    assert!(set.validate("user", &user).unwrap().is_valid);
+    let invalid = json!({"name": "john", "contact": {"email": "j@example.com", "address": {"zip": "x"}}});
+    assert!(!set.validate("user", &invalid).unwrap().is_valid);
+
+    assert!(set.validate("shop", &json!({"branches": [{"city": "Rome"}]})).unwrap().is_valid);
+    assert!(!set.validate("shop", &json!({"branches": [{"zip": "12345"}]})).unwrap().is_valid);
+}
+
+#[test]
+fn test_json_schema_shares_definitions() {
+    let mut set = set();
+    set.compile().unwrap();
+    assert!(set.validate("ping", &json!({"origin": {"city": "Oslo"}})).unwrap().is_valid);
+    assert!(!set.validate("ping", &json!({"origin": {"zip": "1"}})).unwrap().is_valid);
+}
+
+#[test]
+fn test_own_definitions_take_precedence() {
+    let mut set = set();
+    set.add("local", json!({
+        "defs": {"address": {"street": {"type": "string", "required": true}}},
+        "home": {"type": "object", "required": true, "$use": "address"}
+    }));
+    set.compile().unwrap();
+    assert!(set.validate("local", &json!({"home": {"street": "Main"}})).unwrap().is_valid);
+    assert!(!set.validate("local", &json!({"home": {"city": "Paris"}})).unwrap().is_valid);
+}
+
+#[test]
+fn test_unknown_and_uncompiled_names() {
+    let mut set = set();
+    assert_eq!(set.validate("user", &json!({})).unwrap_err(), "Schema 'user' is not compiled");
+    set.compile().unwrap();
+    assert_eq!(set.validate("order", &json!({})).unwrap_err(), "Unknown schema 'order'");
+}
+
+#[test]
+fn test_compile_errors_keep_pending_schemas() {
+    let mut set = ValidatorSet::new();
+    set.add("broken", json!({"name": {"type": "string", "pattern": "("}}));
+    set.add("fine", json!({"name": {"type": "string"}}));
+    let error = set.compile().unwrap_err();
+    assert!(error.starts_with("Failed to compile 'broken': "), "{}", error);
+    assert!(set.validate("broken", &json!({})).unwrap_err().contains("not compiled"));
+
+    set.add("broken", json!({"name": {"type": "string"}}));
+    set.compile().unwrap();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_compile_options_apply_to_every_schema() {
+    let mut set = ValidatorSet::with_options(&CompileOptions::new().max_fields(1));
+    set.add("small", json!({"a": {"type": "string"}}));
+    set.compile().unwrap();
+    set.add("large", json!({"a": {"type": "string"}, "b": {"type": "string"}}));
+    assert!(set.compile().unwrap_err().contains("Schema limit exceeded"));
+}
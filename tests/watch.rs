@@ -0,0 +1,64 @@
+//! Schema hot-reload tests for link-validator
+#![cfg(feature = "watch")]
+
+use link_validator::WatchedValidator;
+use serde_json::json;
+use std::fs;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// 等待条件成立，超时返回 false
+fn wait_for(condition: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    false
+}
+
+#[test]
+fn test_reload_on_change() {
+    let dir = std::env::temp_dir().join(format!("link-validator-watch-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("user.json");
+    fs::write(&path, r#"{"name": {"type": "string", "required": true}}"#).unwrap();
+
+    let watched = WatchedValidator::with_debounce(&path, Duration::from_millis(50)).unwrap();
+    let data = json!({"name": "jo"});
+    let before = watched.current();
+    assert!(watched.validate(&data).is_valid);
+
+    // 收紧规则后自动生效
+    fs::write(&path, r#"{"name": {"type": "string", "required": true, "min": 3}}"#).unwrap();
+    assert!(wait_for(|| watched.generation() == 1));
+    assert!(!watched.validate(&data).is_valid);
+    // 之前取得的验证器不受影响
+    assert!(before.validate(&data).is_valid);
+
+    // 无法编译的修改不会替换当前验证器
+    fs::write(&path, "{ broken").unwrap();
+    assert!(wait_for(|| watched.last_error().is_some()));
+    assert_eq!(watched.generation(), 1);
+    assert!(!watched.validate(&data).is_valid);
+
+    // 内容不变的写入不会重新加载
+    fs::write(&path, r#"{"name": {"type": "string", "required": true, "min": 3}}"#).unwrap();
+    assert!(wait_for(|| watched.generation() == 2));
+    fs::write(&path, r#"{"name": {"type": "string", "required": true, "min": 3}}"#).unwrap();
+    thread::sleep(Duration::from_millis(300));
+    assert_eq!(watched.generation(), 2);
+
+    fs::write(&path, r#"{"name": {"type": "string"}}"#).unwrap();
+    assert!(watched.reload().is_ok());
+    assert!(watched.last_error().is_none());
+    assert!(watched.validate(&json!({})).is_valid);
+}
+
+#[test]
+fn test_initial_load_error() {
+    let err = WatchedValidator::new("/nonexistent/schema.json").unwrap_err();
+    assert!(err.starts_with("Failed to read"), "{}", err);
+}
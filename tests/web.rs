@@ -0,0 +1,165 @@
+//! Web integration tests for link-validator
+#![cfg(feature = "web")]
+
+use http::{Request, StatusCode};
+use link_validator::{LinkValidator, Validated};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+#[derive(Debug, Deserialize)]
+struct Signup {
+    username: String,
+    age: Option<u32>,
+}
+
+fn validator() -> LinkValidator {
+    let schema = json!({
+        "username": {"type": "string", "required": true, "min": 3},
+        "age": {"type": "integer", "min": 0}
+    });
+    LinkValidator::new(&schema).unwrap()
+}
+
+fn request(content_type: &str, body: &str) -> Request<Vec<u8>> {
+    Request::post("/signup")
+        .header("content-type", content_type)
+        .body(body.as_bytes().to_vec())
+        .unwrap()
+}
+
+#[test]
+fn test_valid_body_is_extracted() {
+    let validator = validator();
+    let signup = Validated::<Signup>::from_request(&validator, &request("application/json", r#"{"username": "john", "age": 30}"#))
+        .unwrap();
+    assert_eq!(signup.username, "john");
+    assert_eq!(signup.into_inner().age, Some(30));
+}
+
+#[test]
+fn test_invalid_body_returns_422_with_errors() {
+    let validator = validator();
+    let rejection = Validated::<Signup>::from_request(&validator, &request("application/json; charset=utf-8", r#"{"username": "jo"}"#))
+        .unwrap_err();
+    assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(rejection.errors()[0]["field"], "username");
+
+    let response = rejection.into_response();
+    assert_eq!(response.status(), 422);
+    assert_eq!(response.headers()["content-type"], "application/json");
+    let body: Value = serde_json::from_str(response.body()).unwrap();
+    assert_eq!(body["errors"].as_array().unwrap().len(), 1);
+    assert!(body["errors"][0]["message"].is_string());
+}
+
+#[test]
+fn test_malformed_requests() {
+    let validator = validator();
+
+    let rejection = Validated::<Signup>::from_request(&validator, &request("application/json", "{")).unwrap_err();
+    assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(rejection.errors()[0]["field"], "");
+
+    let rejection = Validated::<Signup>::from_request(&validator, &request("text/plain", r#"{"username": "john"}"#)).unwrap_err();
+    assert_eq!(rejection.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    assert!(Validated::<Signup>::from_request(&validator, &request("application/problem+json", r#"{"username": "john"}"#)).is_ok());
+}
+
+#[cfg(feature = "axum")]
+mod axum_extractor {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::extract::{FromRef, FromRequest};
+    use axum::response::IntoResponse;
+
+    #[derive(Clone)]
+    struct AppState {
+        signup: LinkValidator,
+    }
+
+    impl FromRef<AppState> for LinkValidator {
+        fn from_ref(state: &AppState) -> Self {
+            state.signup.clone()
+        }
+    }
+
+    fn extract(content_type: &str, body: &str) -> Result<Validated<Signup>, link_validator::ValidationRejection> {
+        let state = AppState { signup: validator() };
+        let request = Request::post("/signup")
+            .header("content-type", content_type)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        runtime.block_on(<Validated<Signup> as FromRequest<AppState>>::from_request(request, &state))
+    }
+
+    #[test]
+    fn test_extracts_from_state() {
+        let Validated(signup) = extract("application/json", r#"{"username": "john", "age": 30}"#).unwrap();
+        assert_eq!(signup.username, "john");
+        assert_eq!(signup.age, Some(30));
+    }
+
+    #[test]
+    fn test_rejection_is_422_json() {
+        let rejection = extract("application/json", r#"{"username": "jo"}"#).unwrap_err();
+        let response = IntoResponse::into_response(rejection);
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.headers()["content-type"], "application/json");
+
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let body = runtime.block_on(to_bytes(response.into_body(), usize::MAX)).unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["errors"][0]["field"], "username");
+
+        assert_eq!(extract("text/plain", "{}").unwrap_err().status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        assert_eq!(extract("application/json", "{").unwrap_err().status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix_extractor {
+    use super::*;
+    use actix_web::{test, web, App, HttpResponse};
+
+    async fn signup(Validated(signup): Validated<Signup>) -> HttpResponse {
+        HttpResponse::Ok().body(signup.username)
+    }
+
+    fn post(content_type: &str, body: &'static str, register: bool) -> (u16, String) {
+        actix_web::rt::System::new().block_on(async move {
+            let mut app = App::new().route("/signup", web::post().to(signup));
+            if register {
+                app = app.app_data(web::Data::new(validator()));
+            }
+            let app = test::init_service(app).await;
+            let request = test::TestRequest::post()
+                .uri("/signup")
+                .insert_header(("content-type", content_type))
+                .set_payload(body)
+                .to_request();
+            let response = test::call_service(&app, request).await;
+            let status = response.status().as_u16();
+            let body = test::read_body(response).await;
+            (status, String::from_utf8(body.to_vec()).unwrap())
+        })
+    }
+
+    #[test]
+    fn test_extracts_from_app_data() {
+        assert_eq!(post("application/json", r#"{"username": "john"}"#, true), (200, "john".to_string()));
+    }
+
+    #[test]
+    fn test_rejection_is_422_json() {
+        let (status, body) = post("application/json", r#"{"username": "jo"}"#, true);
+        assert_eq!(status, 422);
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(body["errors"][0]["field"], "username");
+
+        assert_eq!(post("text/plain", "{}", true).0, 415);
+        assert_eq!(post("application/json", "{", true).0, 400);
+        assert_eq!(post("application/json", r#"{"username": "john"}"#, false).0, 500);
+    }
+}
@@ -0,0 +1,147 @@
+//! YAML input tests for link-validator
+#![cfg(feature = "yaml")]
+
+use link_validator::{LinkValidator, SchemaFormat};
+use serde_json::json;
+
+#[test]
+fn test_yaml_async_rules() {
+    let validator = LinkValidator::from_yaml_str(r#"
+# 用户注册规则
+username:
+  type: string
+  required: true
+  min: 3
+email: {type: email, required: true}
+role:
+  - type: enum
+    enum: [admin, "member"]
+address:
+  type: object
+  fields:
+    city: {type: string, required: true}
+"#).expect("Compilation failed");
+    assert_eq!(validator.format(), SchemaFormat::AsyncValidator);
+
+    let result = validator.validate_yaml_str(r#"
+username: john
+email: john@example.com
+role: admin
+address:
+  city: Beijing
+"#).unwrap();
+    assert!(result.is_valid, "{}", result.errors);
+
+    let result = validator.validate_yaml_str("username: jo\nemail: john@example.com\naddress: {}\n").unwrap();
+    assert!(!result.is_valid);
+    assert_eq!(result.errors.as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_yaml_scalars_and_collections() {
+    // JSON Schema 忽略未知关键字，用 json_schema() 检查解析结果
+    let validator = LinkValidator::from_yaml_str(r#"---
+definitions:
+  data:
+    x-scalars:
+      plain: hello world  # 注释
+      quoted: "tab\there \u00e9"
+      single: 'it''s # not a comment'
+      int: -42
+      hex: 0x1F
+      float: 1.5e3
+      version: 1.2.3
+      empty:
+      tilde: ~
+      yes: true
+    x-lists:
+    - a
+    - - nested
+      - list
+    - key: value
+      other: [1, {k: v}, ]
+    x-same-indent:
+    - 1
+    - 2
+    x-literal: |
+      line one
+        indented
+    x-folded: >-
+      folded
+      text
+
+      para
+...
+"#).expect("Compilation failed");
+
+    assert_eq!(validator.json_schema()["definitions"]["data"], json!({
+        "x-scalars": {
+            "plain": "hello world",
+            "quoted": "tab\there é",
+            "single": "it's # not a comment",
+            "int": -42,
+            "hex": 31,
+            "float": 1500.0,
+            "version": "1.2.3",
+            "empty": null,
+            "tilde": null,
+            "yes": true
+        },
+        "x-lists": ["a", ["nested", "list"], {"key": "value", "other": [1, {"k": "v"}]}],
+        "x-same-indent": [1, 2],
+        "x-literal": "line one\n  indented\n",
+        "x-folded": "folded text\npara"
+    }));
+}
+
+#[test]
+fn test_yaml_multiline_anchors_and_tags() {
+    let validator = LinkValidator::from_yaml_str(r#"
+definitions:
+  data:
+    x-flow: [1,
+      2, {k: v,
+          other: w}]
+    x-plain: this plain scalar
+      spans two lines
+    x-quoted: "quoted scalar
+      continues"
+    x-base: &base {type: string, min: 2}
+    x-alias: *base
+    x-tagged: !!str 123
+"#).expect("Compilation failed");
+
+    assert_eq!(validator.json_schema()["definitions"]["data"], json!({
+        "x-flow": [1, 2, {"k": "v", "other": "w"}],
+        "x-plain": "this plain scalar spans two lines",
+        "x-quoted": "quoted scalar continues",
+        "x-base": {"type": "string", "min": 2},
+        "x-alias": {"type": "string", "min": 2},
+        "x-tagged": "123"
+    }));
+
+    // 用别名复用规则
+    let validator = LinkValidator::from_yaml_str("first: &name {type: string, required: true, min: 2}\nlast: *name\n").unwrap();
+    let result = validator.validate_yaml_str("first: Ada\nlast: L\n").unwrap();
+    assert_eq!(result.errors.as_array().unwrap().len(), 1);
+    assert_eq!(result.errors[0]["field"], "last");
+}
+
+#[test]
+fn test_yaml_errors() {
+    let cases = [
+        "a: foo: bar\n",
+        "a:\n\tb: 1\n",
+        "a: 1\n    b: 2\n",
+        "a: 1\n---\nb: 2\n",
+        "a: [1, 2\n",
+        "a: \"open\n",
+    ];
+    for input in cases {
+        let err = LinkValidator::from_yaml_str(input).unwrap_err();
+        assert!(err.starts_with("Failed to parse YAML"), "{:?}: {}", input, err);
+    }
+
+    let validator = LinkValidator::from_yaml_str("name: {type: string}").unwrap();
+    assert!(validator.validate_yaml_str("name: [oops").is_err());
+}
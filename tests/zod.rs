@@ -0,0 +1,97 @@
+//! Zod export tests for link-validator
+
+use link_validator::LinkValidator;
+use serde_json::json;
+
+#[test]
+fn test_rules_to_zod() {
+    let rules = json!({
+        "name": {"type": "string", "required": true, "min": 2, "max": 20},
+        "code": {"type": "string", "len": 6, "pattern": "^[A-Z/]+$"},
+        "email": {"type": "email", "required": true},
+        "age": {"type": "integer", "min": 0, "max": 150},
+        "price": {"type": "number"},
+        "agree": {"type": "boolean"},
+        "role": {"type": "enum", "enum": ["admin", "user"]},
+        "level": {"type": "enum", "enum": [1, 2]},
+        "address": {
+            "type": "object",
+            "required": true,
+            "fields": {"zip-code": {"type": "string", "required": true}}
+        },
+        "tags": {"type": "array", "max": 3}
+    });
+    let zod = LinkValidator::new(&rules).unwrap().to_zod();
+
+    assert!(zod.starts_with("z.object({\n"), "{}", zod);
+    assert!(zod.contains("\n  name: z.string().min(2).max(20),\n"), "{}", zod);
+    assert!(zod.contains("\n  code: z.string().length(6).regex(/^[A-Z\\/]+$/).optional(),\n"), "{}", zod);
+    assert!(zod.contains("\n  email: z.string().email(),\n"), "{}", zod);
+    assert!(zod.contains("\n  age: z.number().int().gte(0).lte(150).optional(),\n"), "{}", zod);
+    assert!(zod.contains("\n  price: z.number().optional(),\n"), "{}", zod);
+    assert!(zod.contains("\n  agree: z.boolean().optional(),\n"), "{}", zod);
+    assert!(zod.contains("\n  role: z.enum([\"admin\", \"user\"]).optional(),\n"), "{}", zod);
+    assert!(zod.contains("\n  level: z.union([z.literal(1), z.literal(2)]).optional(),\n"), "{}", zod);
+    assert!(zod.contains("\n  address: z.object({\n    \"zip-code\": z.string(),\n  }),\n"), "{}", zod);
+    assert!(zod.contains("\n  tags: z.array(z.any()).max(3).optional(),\n"), "{}", zod);
+    assert!(zod.ends_with("\n})"), "{}", zod);
+}
+
+#[test]
+fn test_items_by_to_discriminated_union() {
+    let rules = json!({
+        "blocks": {
+            "type": "array",
+            "required": true,
+            "itemsBy": {
+                "field": "kind",
+                "mapping": {
+                    "image": {"src": {"type": "url", "required": true}},
+                    "text": {"body": {"type": "string"}}
+                }
+            }
+        }
+    });
+    let zod = LinkValidator::new(&rules).unwrap().to_zod();
+    let expected = r#"z.object({
+  blocks: z.array(z.discriminatedUnion("kind", [
+    z.object({
+      kind: z.literal("image"),
+      src: z.string().url(),
+    }),
+    z.object({
+      kind: z.literal("text"),
+      body: z.string().optional(),
+    }),
+  ])),
+})"#;
+    assert_eq!(zod, expected);
+}
+
+#[test]
+fn test_json_schema_to_zod() {
+    let schema = json!({
+        "type": "object",
+        "additionalProperties": false,
+        "required": ["node"],
+        "properties": {
+            "node": {"$ref": "#/definitions/Node"},
+            "nickname": {"type": ["string", "null"]},
+            "version": {"const": "v1"},
+            "score": {"type": "number", "exclusiveMinimum": 0},
+            "meta": {"type": "object", "additionalProperties": {"type": "string"}}
+        },
+        "definitions": {
+            "Node": {"type": "object", "properties": {"child": {"$ref": "#/definitions/Node"}}}
+        }
+    });
+    let zod = LinkValidator::new(&schema).unwrap().to_zod();
+
+    assert!(zod.ends_with("}).strict()"), "{}", zod);
+    // 递归引用无法展开
+    assert!(zod.contains("node: z.object({\n    child: z.any().optional(),\n  }),"), "{}", zod);
+    assert!(zod.contains("nickname: z.string().nullable().optional(),"), "{}", zod);
+    assert!(zod.contains("version: z.literal(\"v1\").optional(),"), "{}", zod);
+    assert!(zod.contains("score: z.number().gt(0).optional(),"), "{}", zod);
+    assert!(zod.contains("meta: z.record(z.string()).optional(),"), "{}", zod);
+}